@@ -0,0 +1,52 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Embeds the error guides in `docs/error-guides` into the `leo` binary, for `leo explain`.
+
+use std::{env, fs, path::Path};
+
+fn main() {
+    let guides_dir = Path::new(&env::var("CARGO_MANIFEST_DIR").unwrap()).join("docs/error-guides");
+    println!("cargo:rerun-if-changed={}", guides_dir.display());
+
+    // The guides are at `{kind}/{name}.md`, where `kind` is the kind of error, e.g., `parser`.
+    let mut guides = Vec::new();
+    for kind in fs::read_dir(&guides_dir).expect("failed to read the error guides directory") {
+        let kind = kind.expect("failed to read the error guides directory").path();
+        if !kind.is_dir() {
+            continue;
+        }
+        for guide in fs::read_dir(&kind).expect("failed to read an error guides directory") {
+            let guide = guide.expect("failed to read an error guides directory").path();
+            if guide.extension().map_or(false, |extension| extension == "md") {
+                let kind = kind.file_name().unwrap().to_string_lossy().into_owned();
+                let name = guide.file_stem().unwrap().to_string_lossy().into_owned();
+                guides.push((kind, name, guide));
+            }
+        }
+    }
+    guides.sort();
+
+    let mut out = String::from("/// The error guides, by the kind and name of their errors.\n");
+    out.push_str("pub const ERROR_GUIDES: &[(&str, &str, &str)] = &[\n");
+    for (kind, name, path) in guides {
+        out.push_str(&format!("    ({:?}, {:?}, include_str!({:?})),\n", kind, name, path));
+    }
+    out.push_str("];\n");
+
+    let out_path = Path::new(&env::var("OUT_DIR").unwrap()).join("error_guides.rs");
+    fs::write(out_path, out).expect("failed to write the error guides index");
+}
@@ -19,8 +19,8 @@
 //! An example of these changes are transforming Self -> to the circuit name.
 
 use leo_ast::*;
-use leo_errors::{AstError, Result};
-use leo_span::{sym, Span, Symbol};
+use leo_errors::{emitter::Handler, AstError, Result};
+use leo_span::{sym, symbol::with_session_globals, BytePos, Span, Symbol};
 
 use indexmap::IndexMap;
 
@@ -30,14 +30,24 @@ use indexmap::IndexMap;
 /// Tuple array types and expressions error if a size of 0 is given.
 /// Compound operators become simple assignments.
 /// Functions missing output type return a empty tuple.
-#[derive(Default)]
-pub struct Canonicalizer {
+/// Errors are emitted through the handler, so that all of them are reported.
+pub struct Canonicalizer<'a> {
+    // The handler through which errors are emitted.
+    pub(crate) handler: &'a Handler,
     // If we are in a circuit keep track of the circuit name.
     circuit_name: Option<Identifier>,
     in_circuit: bool,
 }
 
-impl Canonicalizer {
+impl<'a> Canonicalizer<'a> {
+    pub fn new(handler: &'a Handler) -> Self {
+        Self {
+            handler,
+            circuit_name: None,
+            in_circuit: false,
+        }
+    }
+
     pub fn canonicalize_accesses(
         &mut self,
         start: Expression,
@@ -53,6 +63,7 @@ impl Canonicalizer {
                         array: left,
                         index: Box::new(index),
                         span: span.clone(),
+                        id: NodeID::next(),
                     })));
                 }
                 AssigneeAccess::ArrayRange(start, stop) => {
@@ -61,6 +72,7 @@ impl Canonicalizer {
                         left: start.map(Box::new),
                         right: stop.map(Box::new),
                         span: span.clone(),
+                        id: NodeID::next(),
                     })));
                 }
                 AssigneeAccess::Tuple(positive_number, _) => {
@@ -68,6 +80,7 @@ impl Canonicalizer {
                         tuple: left,
                         index: positive_number,
                         span: span.clone(),
+                        id: NodeID::next(),
                     })));
                 }
                 AssigneeAccess::Member(identifier) => {
@@ -76,6 +89,7 @@ impl Canonicalizer {
                         name: identifier,
                         span: span.clone(),
                         type_: None,
+                        id: NodeID::next(),
                     })));
                 }
             }
@@ -146,6 +160,7 @@ impl Canonicalizer {
                     inner,
                     op: unary.op.clone(),
                     span: unary.span.clone(),
+                    id: unary.id,
                 });
             }
             Expression::Binary(binary) => {
@@ -157,6 +172,7 @@ impl Canonicalizer {
                     right,
                     op: binary.op,
                     span: binary.span.clone(),
+                    id: binary.id,
                 });
             }
             Expression::Ternary(ternary) => {
@@ -169,6 +185,7 @@ impl Canonicalizer {
                     if_true,
                     if_false,
                     span: ternary.span.clone(),
+                    id: ternary.id,
                 });
             }
 
@@ -180,6 +197,7 @@ impl Canonicalizer {
                     inner,
                     target_type,
                     span: cast.span.clone(),
+                    id: cast.id,
                 });
             }
 
@@ -193,6 +211,7 @@ impl Canonicalizer {
                             array,
                             index,
                             span: array_access.span.clone(),
+                            id: array_access.id,
                         })
                     }
                     AccessExpression::ArrayRange(array_range_access) => {
@@ -211,6 +230,7 @@ impl Canonicalizer {
                             left,
                             right,
                             span: array_range_access.span.clone(),
+                            id: array_range_access.id,
                         })
                     }
                     AccessExpression::Member(member_access) => AccessExpression::Member(MemberAccess {
@@ -218,6 +238,7 @@ impl Canonicalizer {
                         name: member_access.name.clone(),
                         span: member_access.span.clone(),
                         type_: None,
+                        id: member_access.id,
                     }),
                     AccessExpression::Tuple(tuple_access) => {
                         let tuple = Box::new(self.canonicalize_expression(&tuple_access.tuple));
@@ -226,6 +247,7 @@ impl Canonicalizer {
                             tuple,
                             index: tuple_access.index.clone(),
                             span: tuple_access.span.clone(),
+                            id: tuple_access.id,
                         })
                     }
                     AccessExpression::Static(static_access) => AccessExpression::Static(StaticAccess {
@@ -233,6 +255,7 @@ impl Canonicalizer {
                         name: static_access.name.clone(),
                         type_: self.canonicalize_self_type(static_access.type_.as_ref()),
                         span: static_access.span.clone(),
+                        id: static_access.id,
                     }),
                 };
 
@@ -256,6 +279,7 @@ impl Canonicalizer {
                 return Expression::ArrayInline(ArrayInlineExpression {
                     elements,
                     span: array_inline.span.clone(),
+                    id: array_inline.id,
                 });
             }
 
@@ -266,6 +290,7 @@ impl Canonicalizer {
                     dimensions: array_init.dimensions.clone(),
                     element,
                     span: array_init.span.clone(),
+                    id: array_init.id,
                 });
             }
 
@@ -279,6 +304,7 @@ impl Canonicalizer {
                 return Expression::TupleInit(TupleInitExpression {
                     elements,
                     span: tuple_init.span.clone(),
+                    id: tuple_init.id,
                 });
             }
 
@@ -296,6 +322,7 @@ impl Canonicalizer {
                         .map(|member| self.canonicalize_circuit_variable_initializer(member))
                         .collect(),
                     span: circuit_init.span.clone(),
+                    id: circuit_init.id,
                 });
             }
             Expression::Call(call) => {
@@ -307,6 +334,7 @@ impl Canonicalizer {
                         .map(|arg| self.canonicalize_expression(arg))
                         .collect(),
                     span: call.span.clone(),
+                    id: call.id,
                 });
             }
             Expression::Identifier(identifier) => {
@@ -357,6 +385,7 @@ impl Canonicalizer {
         Block {
             statements,
             span: block.span.clone(),
+            id: block.id,
         }
     }
 
@@ -367,6 +396,7 @@ impl Canonicalizer {
                 Statement::Return(ReturnStatement {
                     expression,
                     span: return_statement.span.clone(),
+                    id: return_statement.id,
                 })
             }
             Statement::Definition(definition) => {
@@ -378,7 +408,9 @@ impl Canonicalizer {
                     variable_names: definition.variable_names.clone(),
                     type_,
                     value,
+                    docs: definition.docs.clone(),
                     span: definition.span.clone(),
+                    id: definition.id,
                 })
             }
             Statement::Assign(assign) => {
@@ -390,6 +422,7 @@ impl Canonicalizer {
                     value,
                     operation: assign.operation,
                     span: assign.span.clone(),
+                    id: assign.id,
                 }))
             }
             Statement::Conditional(conditional) => {
@@ -405,6 +438,7 @@ impl Canonicalizer {
                     block,
                     next,
                     span: conditional.span.clone(),
+                    id: conditional.id,
                 })
             }
             Statement::Iteration(iteration) => {
@@ -419,6 +453,7 @@ impl Canonicalizer {
                     inclusive: iteration.inclusive,
                     block,
                     span: iteration.span.clone(),
+                    id: iteration.id,
                 }))
             }
             Statement::Console(console_function_call) => {
@@ -450,11 +485,13 @@ impl Canonicalizer {
                 Statement::Console(ConsoleStatement {
                     function,
                     span: console_function_call.span.clone(),
+                    id: console_function_call.id,
                 })
             }
             Statement::Expression(expression) => Statement::Expression(ExpressionStatement {
                 expression: self.canonicalize_expression(&expression.expression),
                 span: expression.span.clone(),
+                id: expression.id,
             }),
             Statement::Block(block) => Statement::Block(self.canonicalize_block(block)),
         }
@@ -478,14 +515,15 @@ impl Canonicalizer {
 
     fn canonicalize_circuit_member(&mut self, circuit_member: &CircuitMember) -> CircuitMember {
         match circuit_member {
-            CircuitMember::CircuitConst(identifier, type_, value) => {
+            CircuitMember::CircuitConst(identifier, type_, value, docs) => {
                 return CircuitMember::CircuitConst(
                     identifier.clone(),
                     type_.clone(),
                     self.canonicalize_expression(value),
+                    docs.clone(),
                 );
             }
-            CircuitMember::CircuitVariable(_, _) => {}
+            CircuitMember::CircuitVariable(..) => {}
             CircuitMember::CircuitFunction(function) => {
                 let input = function
                     .input
@@ -493,9 +531,10 @@ impl Canonicalizer {
                     .map(|input| self.canonicalize_function_input(input))
                     .collect();
                 let output = self.canonicalize_self_type(function.output.as_ref());
-                let block = self.canonicalize_block(&function.block);
+                let block = function.block.as_ref().map(|block| self.canonicalize_block(block));
 
                 return CircuitMember::CircuitFunction(Box::new(Function {
+                    docs: function.docs.clone(),
                     annotations: function.annotations.clone(),
                     identifier: function.identifier.clone(),
                     const_: function.const_,
@@ -504,6 +543,7 @@ impl Canonicalizer {
                     block,
                     core_mapping: function.core_mapping.clone(),
                     span: function.span.clone(),
+                    id: function.id,
                 }));
             }
         }
@@ -512,7 +552,7 @@ impl Canonicalizer {
     }
 }
 
-impl ReconstructingReducer for Canonicalizer {
+impl ReconstructingReducer for Canonicalizer<'_> {
     fn in_circuit(&self) -> bool {
         self.in_circuit
     }
@@ -524,7 +564,11 @@ impl ReconstructingReducer for Canonicalizer {
     fn reduce_type(&mut self, _type_: &Type, new: Type, span: &Span) -> Result<Type> {
         match new.clone() {
             Type::Array(base, dims) if dims.is_empty() => Ok(Type::Array(base, dims)),
-            Type::Array(_, dims) if dims.is_zero() => Err(AstError::invalid_array_dimension_size(span).into()),
+            Type::Array(_, dims) if dims.is_zero() => {
+                self.handler
+                    .emit_err(AstError::invalid_array_dimension_size(span).into());
+                Ok(new)
+            }
             // Reduce `ArrayDimensions` into nested `Array` types.
             Type::Array(base, dims) => {
                 let mut iter = dims.0.into_iter().rev();
@@ -533,66 +577,76 @@ impl ReconstructingReducer for Canonicalizer {
                 let base = ctor(base, dim);
                 Ok(iter.fold(base, |ty, dim| ctor(Box::new(ty), dim)))
             }
-            Type::SelfType if !self.in_circuit => Err(AstError::big_self_outside_of_circuit(span).into()),
-            Type::Tuple(types) if types.len() == 1 => Err(AstError::invalid_tuple_dimension_size(span).into()),
+            Type::SelfType if !self.in_circuit => {
+                self.handler
+                    .emit_err(AstError::big_self_outside_of_circuit(span).into());
+                Ok(new)
+            }
+            Type::Tuple(types) if types.len() == 1 => {
+                self.handler
+                    .emit_err(AstError::invalid_tuple_dimension_size(span).into());
+                Ok(new)
+            }
             _ => Ok(new),
         }
     }
 
-    fn reduce_string(&mut self, string: &[Char], span: &Span) -> Result<Expression> {
+    fn reduce_string(&mut self, string: &[Char], span: &Span, id: NodeID) -> Result<Expression> {
         if string.is_empty() {
-            return Err(AstError::empty_string(span).into());
+            self.handler.emit_err(AstError::empty_string(span).into());
         }
 
+        // Each character gets the span of its (possibly escaped) source in the string literal.
+        let bytes = with_session_globals(|s| s.source_map.contents_of_span(span))
+            .unwrap_or_default()
+            .into_bytes();
         let mut elements = Vec::new();
         let mut col_adder = 0;
         for (index, character) in string.iter().enumerate() {
-            let col_start = span.col_start + index + 1 + col_adder; // account for open quote
-            let bytes = span.content.clone().into_bytes();
-            let col_stop = if bytes[col_start - 1] == b'\\' {
-                let mut width = 0;
-
-                match bytes[col_start] {
-                    b'x' => width += 3,
-                    b'u' => {
-                        width += 1;
-                        let mut index = 1;
-                        while bytes[col_start + index] != b'}' {
+            let start = index + 1 + col_adder; // account for open quote
+            let char_span = if bytes.is_empty() {
+                span.clone()
+            } else {
+                let stop = if bytes[start] == b'\\' {
+                    let mut width = 0;
+
+                    match bytes[start + 1] {
+                        b'x' => width += 3,
+                        b'u' => {
+                            width += 1;
+                            let mut index = 2;
+                            while bytes[start + index] != b'}' {
+                                width += 1;
+                                index += 1;
+                            }
                             width += 1;
-                            index += 1;
                         }
-                        width += 1;
+                        _ => width += 1,
                     }
-                    _ => width += 1,
-                }
-                col_adder += width;
-                col_start + 1 + width
-            } else {
-                col_start + 1
+                    col_adder += width;
+                    start + 1 + width
+                } else {
+                    start + 1
+                };
+                Span::new(
+                    span.lo + BytePos::from_usize(start),
+                    span.lo + BytePos::from_usize(stop),
+                )
             };
 
             elements.push(SpreadOrExpression::Expression(Expression::Value(
                 ValueExpression::Char(CharValue {
                     character: character.clone(),
-                    span: Span::new(
-                        span.line_start,
-                        span.line_stop,
-                        col_start,
-                        col_stop,
-                        span.path.clone(),
-                        span.content.clone(),
-                    ),
+                    span: char_span,
+                    id: NodeID::next(),
                 }),
             )));
         }
 
-        if elements.is_empty() {
-            return Err(AstError::invalid_array_dimension_size(span).into());
-        }
-
         Ok(Expression::ArrayInline(ArrayInlineExpression {
             elements,
             span: span.clone(),
+            id,
         }))
     }
 
@@ -605,12 +659,16 @@ impl ReconstructingReducer for Canonicalizer {
             element,
             dimensions: ArrayDimensions::single(dim),
             span: array_init.span.clone(),
+            id: NodeID::next(),
         };
 
         let mut iter = array_init.dimensions.iter().rev().cloned();
         // We know the array has non-zero dimensions.
         let init = mk_expr(Box::new(element), iter.next().unwrap());
-        Ok(iter.fold(init, |elem, dim| mk_expr(Box::new(Expression::ArrayInit(elem)), dim)))
+        let mut outer = iter.fold(init, |elem, dim| mk_expr(Box::new(Expression::ArrayInit(elem)), dim));
+        // The outermost array keeps the ID of the original expression.
+        outer.id = array_init.id;
+        Ok(outer)
     }
 
     fn reduce_definition(
@@ -627,7 +685,9 @@ impl ReconstructingReducer for Canonicalizer {
             variable_names,
             type_,
             value,
+            docs: definition.docs.clone(),
             span: definition.span.clone(),
+            id: definition.id,
         })
     }
 
@@ -652,6 +712,7 @@ impl ReconstructingReducer for Canonicalizer {
                     right,
                     op,
                     span: assign.span.clone(),
+                    id: NodeID::next(),
                 });
 
                 Ok(AssignStatement {
@@ -659,6 +720,7 @@ impl ReconstructingReducer for Canonicalizer {
                     assignee,
                     value: new_value,
                     span: assign.span.clone(),
+                    id: assign.id,
                 })
             }
             value => Ok(AssignStatement {
@@ -666,6 +728,7 @@ impl ReconstructingReducer for Canonicalizer {
                 assignee,
                 value,
                 span: assign.span.clone(),
+                id: assign.id,
             }),
         }
     }
@@ -678,7 +741,7 @@ impl ReconstructingReducer for Canonicalizer {
         input: Vec<FunctionInput>,
         const_: bool,
         output: Option<Type>,
-        block: Block,
+        block: Option<Block>,
     ) -> Result<Function> {
         let new_output = match output {
             None => Some(Type::Tuple(vec![])),
@@ -686,6 +749,7 @@ impl ReconstructingReducer for Canonicalizer {
         };
 
         Ok(Function {
+            docs: function.docs.clone(),
             identifier,
             annotations,
             input,
@@ -694,22 +758,25 @@ impl ReconstructingReducer for Canonicalizer {
             block,
             core_mapping: function.core_mapping.clone(),
             span: function.span.clone(),
+            id: function.id,
         })
     }
 
     fn reduce_circuit(
         &mut self,
-        _circuit: &Circuit,
+        circuit: &Circuit,
         circuit_name: Identifier,
         members: Vec<CircuitMember>,
     ) -> Result<Circuit> {
         self.circuit_name = Some(circuit_name.clone());
         let circ = Circuit {
+            docs: circuit.docs.clone(),
             circuit_name,
             members: members
                 .iter()
                 .map(|member| self.canonicalize_circuit_member(member))
                 .collect(),
+            id: circuit.id,
         };
         self.circuit_name = None;
         Ok(circ)
@@ -24,8 +24,12 @@ pub use canonicalizer::*;
 use leo_ast::{Ast, AstPass, Program, ReconstructingDirector};
 use leo_errors::Result;
 
-impl AstPass for Canonicalizer {
+impl AstPass for Canonicalizer<'_> {
     fn do_pass(self, ast: Program) -> Result<Ast> {
-        Ok(Ast::new(ReconstructingDirector::new(self).reduce_program(&ast)?))
+        let handler = self.handler;
+        let program = ReconstructingDirector::new(self).reduce_program(&ast)?;
+        handler.last_err()?;
+
+        Ok(Ast::new(program))
     }
 }
@@ -17,44 +17,52 @@
 use crate::resolver::*;
 
 use leo_ast::*;
-use leo_errors::{AstError, Result, Span};
+use leo_errors::{emitter::Handler, AstError, Result};
+use leo_span::{Span, Symbol};
 
 use indexmap::IndexMap;
 
 pub struct Importer {}
 
 impl Importer {
-    pub fn do_pass<T>(program: Program, importer: &mut T) -> Result<Ast>
+    /// Resolves the imports of `program`.
+    /// Every import that fails to resolve is reported through the `handler`,
+    /// and the result is an error if there were any.
+    pub fn do_pass<T>(handler: &Handler, program: Program, importer: &mut T) -> Result<Ast>
     where
         T: ImportResolver,
     {
+        // The prelude modules are not added yet, as this version of Leo cannot parse them,
+        // e.g., the `string` alias of an array of unspecified size.
         let mut ast = program.clone();
-        ast.imports.extend(leo_stdlib::resolve_prelude_modules()?);
 
-        let mut imported_symbols: Vec<(Vec<String>, ImportSymbol, Span)> = vec![];
+        let mut imported_packages: Vec<(Vec<Symbol>, Span)> = vec![];
         for import_statement in program.import_statements.iter() {
-            resolve_import_package(&mut imported_symbols, vec![], &import_statement.package_or_packages);
+            resolve_import_tree(&mut imported_packages, vec![], &import_statement.tree);
         }
 
-        let mut deduplicated_imports: IndexMap<Vec<String>, Span> = IndexMap::new();
-        for (package, _symbol, span) in imported_symbols.iter() {
-            deduplicated_imports.insert(package.clone(), span.clone());
+        let mut deduplicated_imports: IndexMap<Vec<Symbol>, Span> = IndexMap::new();
+        for (package, span) in imported_packages {
+            deduplicated_imports.entry(package).or_insert(span);
         }
 
         let mut wrapped_resolver = CoreImportResolver::new(importer);
 
-        let mut resolved_packages: IndexMap<Vec<String>, Program> = IndexMap::new();
+        let mut resolved_packages: IndexMap<Vec<Symbol>, Program> = IndexMap::new();
         for (package, span) in deduplicated_imports {
-            let pretty_package = package.join(".");
-
-            let resolved_package =
-                match wrapped_resolver.resolve_package(&package.iter().map(|x| &**x).collect::<Vec<_>>(), &span)? {
-                    Some(x) => x,
-                    None => return Err(AstError::unresolved_import(pretty_package, &span).into()),
-                };
-
-            resolved_packages.insert(package.clone(), resolved_package);
+            let segments = package.iter().map(|segment| segment.to_string()).collect::<Vec<_>>();
+            let pretty_package = segments.join(".");
+
+            let segments = segments.iter().map(|x| &**x).collect::<Vec<_>>();
+            match wrapped_resolver.resolve_package(handler, &segments, &span) {
+                Ok(Some(resolved_package)) => {
+                    resolved_packages.insert(package, resolved_package);
+                }
+                Ok(None) => handler.emit_err(AstError::unresolved_import(pretty_package, &span).into()),
+                Err(error) => handler.emit_err(error),
+            }
         }
+        handler.last_err()?;
 
         ast.imports.extend(resolved_packages);
 
@@ -62,67 +70,24 @@ impl Importer {
     }
 }
 
-/// Enumerates what names are imported from a package.
-#[derive(Clone)]
-enum ImportSymbol {
-    /// Import the symbol by name.
-    Direct(String),
-
-    /// Import the symbol by name and store it under an alias.
-    Alias(String, String), // from remote -> to local
-
-    /// Import all symbols from the package.
-    All,
-}
-
-fn resolve_import_package(
-    output: &mut Vec<(Vec<String>, ImportSymbol, Span)>,
-    mut package_segments: Vec<String>,
-    package_or_packages: &PackageOrPackages,
-) {
-    match package_or_packages {
-        PackageOrPackages::Package(package) => {
-            package_segments.push(package.name.name.to_string());
-            resolve_import_package_access(output, package_segments, &package.access);
-        }
-        PackageOrPackages::Packages(packages) => {
-            package_segments.push(packages.name.name.to_string());
-            for access in packages.accesses.clone() {
-                resolve_import_package_access(output, package_segments.clone(), &access);
+/// Collects the packages imported by `tree`, prefixed by the `package_segments` of any enclosing tree.
+///
+/// The last segment of a leaf names an item of its package, e.g., `bar` in `import foo.bar;`,
+/// unless the leaf is the package itself, as in `import foo;`.
+fn resolve_import_tree(output: &mut Vec<(Vec<Symbol>, Span)>, mut package_segments: Vec<Symbol>, tree: &ImportTree) {
+    package_segments.extend(tree.base.iter().map(|segment| segment.name));
+
+    match &tree.kind {
+        ImportTreeKind::Glob { .. } => output.push((package_segments, tree.span.clone())),
+        ImportTreeKind::Leaf { .. } => {
+            if package_segments.len() > 1 {
+                package_segments.pop();
             }
+            output.push((package_segments, tree.span.clone()));
         }
-    }
-}
-
-fn resolve_import_package_access(
-    output: &mut Vec<(Vec<String>, ImportSymbol, Span)>,
-    mut package_segments: Vec<String>,
-    package: &PackageAccess,
-) {
-    match package {
-        PackageAccess::Star { span } => {
-            output.push((package_segments, ImportSymbol::All, span.clone()));
-        }
-        PackageAccess::SubPackage(subpackage) => {
-            resolve_import_package(
-                output,
-                package_segments,
-                &PackageOrPackages::Package(*(*subpackage).clone()),
-            );
-        }
-        PackageAccess::Symbol(symbol) => {
-            let span = symbol.symbol.span.clone();
-            let symbol = if let Some(alias) = symbol.alias.as_ref() {
-                ImportSymbol::Alias(symbol.symbol.name.to_string(), alias.name.to_string())
-            } else {
-                ImportSymbol::Direct(symbol.symbol.name.to_string())
-            };
-            output.push((package_segments, symbol, span));
-        }
-        PackageAccess::Multiple(packages) => {
-            package_segments.push(packages.name.name.to_string());
-            for subaccess in packages.accesses.iter() {
-                resolve_import_package_access(output, package_segments.clone(), subaccess);
+        ImportTreeKind::Nested { tree: nested } => {
+            for subtree in nested {
+                resolve_import_tree(output, package_segments.clone(), subtree);
             }
         }
     }
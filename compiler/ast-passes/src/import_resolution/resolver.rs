@@ -15,19 +15,26 @@
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
 use leo_ast::Program;
-use leo_errors::{Result, Span};
+use leo_errors::{emitter::Handler, Result};
+use leo_span::Span;
 use leo_stdlib::resolve_stdlib_module;
 
 use indexmap::IndexMap;
 
 pub trait ImportResolver {
-    fn resolve_package(&mut self, package_segments: &[&str], span: &Span) -> Result<Option<Program>>;
+    fn resolve_package(&mut self, handler: &Handler, package_segments: &[&str], span: &Span)
+        -> Result<Option<Program>>;
 }
 
 pub struct NullImportResolver;
 
 impl ImportResolver for NullImportResolver {
-    fn resolve_package(&mut self, _package_segments: &[&str], _span: &Span) -> Result<Option<Program>> {
+    fn resolve_package(
+        &mut self,
+        _handler: &Handler,
+        _package_segments: &[&str],
+        _span: &Span,
+    ) -> Result<Option<Program>> {
         Ok(None)
     }
 }
@@ -43,11 +50,16 @@ impl<'a, T: ImportResolver> CoreImportResolver<'a, T> {
 }
 
 impl<'a, T: ImportResolver> ImportResolver for CoreImportResolver<'a, T> {
-    fn resolve_package(&mut self, package_segments: &[&str], span: &Span) -> Result<Option<Program>> {
-        if !package_segments.is_empty() && package_segments.get(0).unwrap() == &"std" {
-            Ok(Some(resolve_stdlib_module(&*package_segments[1..].join("."))?))
+    fn resolve_package(
+        &mut self,
+        handler: &Handler,
+        package_segments: &[&str],
+        span: &Span,
+    ) -> Result<Option<Program>> {
+        if package_segments.first() == Some(&"std") {
+            Ok(Some(resolve_stdlib_module(handler, &package_segments[1..].join("."))?))
         } else {
-            self.inner.resolve_package(package_segments, span)
+            self.inner.resolve_package(handler, package_segments, span)
         }
     }
 }
@@ -57,7 +69,12 @@ pub struct MockedImportResolver {
 }
 
 impl ImportResolver for MockedImportResolver {
-    fn resolve_package(&mut self, package_segments: &[&str], _span: &Span) -> Result<Option<Program>> {
+    fn resolve_package(
+        &mut self,
+        _handler: &Handler,
+        package_segments: &[&str],
+        _span: &Span,
+    ) -> Result<Option<Program>> {
         Ok(self.packages.get(&package_segments.join(".")).cloned())
     }
 }
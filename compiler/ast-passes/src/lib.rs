@@ -19,7 +19,8 @@
 pub mod canonicalization;
 pub use canonicalization::*;
 
-// Temporarily disable import resolution
-// until we migrate stdlib and then import resolution.
-/* pub mod import_resolution;
-pub use import_resolution::*; */
+pub mod import_resolution;
+pub use import_resolution::*;
+
+pub mod native_evaluation;
+pub use native_evaluation::*;
@@ -0,0 +1,37 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Implements the AstPass trait for the NativeCallEvaluator
+//! which is a ReconstructingReducer trait to evaluate the calls of native functions of the standard library.
+//! This allows for easy calling of the native evaluation pass.
+
+pub mod native_call_evaluator;
+pub use native_call_evaluator::*;
+
+use leo_ast::{Ast, AstPass, Program, ReconstructingDirector};
+use leo_errors::Result;
+
+impl AstPass for NativeCallEvaluator {
+    fn do_pass(self, ast: Program) -> Result<Ast> {
+        // The imported programs are kept as they are, as their calls refer to their own imports.
+        let mut program = ast;
+        let imports = std::mem::take(&mut program.imports);
+        let mut program = ReconstructingDirector::new(self).reduce_program(&program)?;
+        program.imports = imports;
+
+        Ok(Ast::new(program))
+    }
+}
@@ -0,0 +1,127 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Creates a struct that implements a ReconstructingReducer
+//! such that it replaces the calls of native functions by their results.
+//! For example, `u16_to_bytes_le(258u16)` of `std.bits` becomes `[2u8, 1u8]`.
+
+use leo_ast::*;
+use leo_errors::Result;
+use leo_span::{sym, Symbol};
+use leo_stdlib::NativeRegistry;
+
+use indexmap::IndexMap;
+
+/// Replaces the calls of the native functions imported from the standard library
+/// by their results, when the compiler can evaluate them, i.e., on literal arguments.
+/// The other calls, e.g., those on variables or of `Blake2s::hash`, are kept as they are,
+/// so that they reach the core library through the core mapping of their function.
+pub struct NativeCallEvaluator {
+    // The native functions of the standard library.
+    registry: NativeRegistry,
+    // The qualified names of the imported native functions, by the names they are imported as.
+    native_functions: IndexMap<Symbol, String>,
+}
+
+impl NativeCallEvaluator {
+    /// Returns a pass evaluating the native calls of `program`, given its import statements.
+    pub fn new(program: &Program) -> Self {
+        let registry = NativeRegistry::stdlib();
+        let mut native_functions = IndexMap::new();
+        for import_statement in program.import_statements.iter() {
+            Self::collect_native_functions(&registry, &mut native_functions, vec![], &import_statement.tree);
+        }
+
+        Self {
+            registry,
+            native_functions,
+        }
+    }
+
+    /// Collects the native functions imported by `tree` from the standard library,
+    /// where `path` are the segments of any enclosing tree.
+    fn collect_native_functions(
+        registry: &NativeRegistry,
+        native_functions: &mut IndexMap<Symbol, String>,
+        mut path: Vec<Symbol>,
+        tree: &ImportTree,
+    ) {
+        path.extend(tree.base.iter().map(|segment| segment.name));
+        let qualified = |path: &[Symbol]| match path {
+            [std, rest @ ..] if *std == sym::std && !rest.is_empty() => Some(
+                rest.iter()
+                    .map(|segment| segment.to_string())
+                    .collect::<Vec<_>>()
+                    .join("."),
+            ),
+            _ => None,
+        };
+
+        match &tree.kind {
+            ImportTreeKind::Glob { .. } => {
+                if let Some(module) = qualified(&path) {
+                    let prefix = format!("{}.", module);
+                    for name in registry.names() {
+                        // Only the functions of the module itself, not those of its circuits.
+                        if let Some(function) = name.strip_prefix(&prefix).filter(|function| !function.contains('.')) {
+                            native_functions.insert(Symbol::intern(function), name.to_string());
+                        }
+                    }
+                }
+            }
+            ImportTreeKind::Leaf { alias } => {
+                if let Some(name) = qualified(&path).filter(|name| registry.get(name).is_some()) {
+                    let imported_as = alias.as_ref().map_or_else(|| *path.last().unwrap(), |alias| alias.name);
+                    native_functions.insert(imported_as, name);
+                }
+            }
+            ImportTreeKind::Nested { tree: nested } => {
+                for subtree in nested {
+                    Self::collect_native_functions(registry, native_functions, path.clone(), subtree);
+                }
+            }
+        }
+    }
+}
+
+impl ReconstructingReducer for NativeCallEvaluator {
+    fn in_circuit(&self) -> bool {
+        false
+    }
+
+    fn swap_in_circuit(&mut self) {}
+
+    fn reduce_expression(&mut self, _expression: &Expression, new: Expression) -> Result<Expression> {
+        let call = match &new {
+            Expression::Call(call) => call,
+            _ => return Ok(new),
+        };
+        let name = match &*call.function {
+            Expression::Identifier(identifier) => match self.native_functions.get(&identifier.name) {
+                Some(name) => name,
+                None => return Ok(new),
+            },
+            _ => return Ok(new),
+        };
+
+        let result = self
+            .registry
+            .get(name)
+            .and_then(|function| function.evaluate.as_ref())
+            .and_then(|evaluate| evaluate(&call.arguments));
+        Ok(result.unwrap_or(new))
+    }
+}
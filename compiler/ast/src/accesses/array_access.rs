@@ -14,15 +14,17 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{Expression, Node};
+use crate::{Expression, Node, NodeID};
 use leo_span::Span;
 
 use std::fmt;
 
+use derivative::Derivative;
 use serde::{Deserialize, Serialize};
 
 /// An array element access expression `array[index]`.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Derivative, Debug, Clone, Eq, Serialize, Deserialize)]
+#[derivative(PartialEq)]
 pub struct ArrayAccess {
     /// The expression, evaluating to an array, that is being indexed.
     pub array: Box<Expression>,
@@ -30,6 +32,9 @@ pub struct ArrayAccess {
     pub index: Box<Expression>,
     /// The span of the entire expression `array[index]`.
     pub span: Span,
+    /// The ID of the node.
+    #[derivative(PartialEq = "ignore")]
+    pub id: NodeID,
 }
 
 impl fmt::Display for ArrayAccess {
@@ -14,17 +14,19 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{Expression, Node};
+use crate::{Expression, Node, NodeID};
 use leo_span::Span;
 
 use std::fmt;
 
+use derivative::Derivative;
 use serde::{Deserialize, Serialize};
 
 /// An access to a certain range of elements in an `array`.
 ///
 /// Examples include `array[0..3]`, `array[3..]`, `array[..3]`, and `array[..]`.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Derivative, Debug, Clone, Eq, Serialize, Deserialize)]
+#[derivative(PartialEq)]
 pub struct ArrayRangeAccess {
     /// The array to extract a range of elements from.
     pub array: Box<Expression>,
@@ -34,6 +36,9 @@ pub struct ArrayRangeAccess {
     pub right: Option<Box<Expression>>,
     /// A span for the entire expression `array[<range>]`.
     pub span: Span,
+    /// The ID of the node.
+    #[derivative(PartialEq = "ignore")]
+    pub id: NodeID,
 }
 
 impl fmt::Display for ArrayRangeAccess {
@@ -14,17 +14,19 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{Expression, Identifier, Node};
+use crate::{Expression, Identifier, Node, NodeID};
 use leo_span::Span;
 
 use std::fmt;
 
+use derivative::Derivative;
 use serde::{Deserialize, Serialize};
 
 /// A field access expression `inner.name` to some structure with *named fields*.
 ///
 /// For accesses to a positional fields in e.g., a tuple, see `TupleAccess`.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Derivative, Debug, Clone, Eq, Serialize, Deserialize)]
+#[derivative(PartialEq)]
 pub struct MemberAccess {
     /// The structure that the field `name` is being extracted from.
     pub inner: Box<Expression>,
@@ -35,6 +37,9 @@ pub struct MemberAccess {
     // FIXME(Centril): Type information shouldn't be injected into an AST,
     // so this field should eventually be removed.
     pub type_: Option<crate::Type>,
+    /// The ID of the node.
+    #[derivative(PartialEq = "ignore")]
+    pub id: NodeID,
 }
 
 impl fmt::Display for MemberAccess {
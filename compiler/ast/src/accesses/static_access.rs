@@ -14,16 +14,18 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{Expression, Identifier, Node, Type};
+use crate::{Expression, Identifier, Node, NodeID, Type};
 
 use leo_span::Span;
 
+use derivative::Derivative;
 use serde::{Deserialize, Serialize};
 use std::fmt;
 
 /// An access expression to a static member, e.g., a constant in a circuit.
 /// An example would be `Foo::Const` or `Foo::function` in `Foo::function()`.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Derivative, Debug, Clone, Eq, Serialize, Deserialize)]
+#[derivative(PartialEq)]
 pub struct StaticAccess {
     /// Represents the container for the static member to access.
     /// Usually this is a circuit.
@@ -35,6 +37,9 @@ pub struct StaticAccess {
     pub type_: Option<Type>,
     /// The span for the entire expression `inner::name`.
     pub span: Span,
+    /// The ID of the node.
+    #[derivative(PartialEq = "ignore")]
+    pub id: NodeID,
 }
 
 impl fmt::Display for StaticAccess {
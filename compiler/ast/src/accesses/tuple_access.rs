@@ -14,15 +14,17 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{Expression, Node, PositiveNumber};
+use crate::{Expression, Node, NodeID, PositiveNumber};
 use leo_span::Span;
 
 use std::fmt;
 
+use derivative::Derivative;
 use serde::{Deserialize, Serialize};
 
 /// An tuple access expression, e.g., `tuple.index`.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Derivative, Debug, Clone, Eq, Serialize, Deserialize)]
+#[derivative(PartialEq)]
 pub struct TupleAccess {
     /// An expression evaluating to some tuple type, e.g., `(5, 2)`.
     pub tuple: Box<Expression>,
@@ -30,6 +32,9 @@ pub struct TupleAccess {
     pub index: PositiveNumber,
     /// The span for the entire expression `tuple.index`.
     pub span: Span,
+    /// The ID of the node.
+    #[derivative(PartialEq = "ignore")]
+    pub id: NodeID,
 }
 
 impl fmt::Display for TupleAccess {
@@ -14,25 +14,33 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{Identifier, Type};
+use crate::{Identifier, NodeID, Type};
 use leo_span::Span;
 
 use std::fmt;
 
+use derivative::Derivative;
 use serde::{Deserialize, Serialize};
 
 /// A type alias `type name = represents;`.
 ///
 /// That is, `name` will become another name for `represents`.
 /// This does not create a new type, that is, `name` is the same type as `represents`.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Derivative, Debug, Clone, Eq, Serialize, Deserialize)]
+#[derivative(PartialEq)]
 pub struct Alias {
+    /// The doc comments of the alias, one line each, without the `///` or `/** */` markers.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub docs: Vec<String>,
     /// The new name for `represents`.
     pub name: Identifier,
     /// A span for the entire `type name = represents;`.
     pub span: Span,
     /// The type that `name` will evaluate and is equal to.
     pub represents: Type,
+    /// The ID of the node.
+    #[derivative(PartialEq = "ignore")]
+    pub id: NodeID,
 }
 
 impl fmt::Display for Alias {
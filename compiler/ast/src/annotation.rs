@@ -32,20 +32,29 @@ pub struct Annotation {
 }
 
 /// The set of allowed annotations.
-const ALLOWED_ANNOTATIONS: &[Symbol] = &[sym::test];
+const ALLOWED_ANNOTATIONS: &[Symbol] = &[sym::allow, sym::test];
+
+/// The set of internal annotations, only allowed in the standard library.
+/// These are handled by [`crate::Program::handle_internal_annotations`].
+const INTERNAL_ANNOTATIONS: &[Symbol] = &[sym::CoreFunction, sym::AlwaysConst];
 
 impl Annotation {
     /// Is the annotation valid?
-    pub fn is_valid_annotation(&self) -> bool {
-        ALLOWED_ANNOTATIONS.iter().any(|name| self.name.name == *name)
+    /// Internal annotations are valid only if `allow_internal` is set, i.e., in the standard library.
+    pub fn is_valid_annotation(&self, allow_internal: bool) -> bool {
+        ALLOWED_ANNOTATIONS.contains(&self.name.name)
+            || allow_internal && INTERNAL_ANNOTATIONS.contains(&self.name.name)
     }
 }
 
 impl fmt::Display for Annotation {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "@{:}(", self.name)?;
-        for arg in &self.arguments {
-            write!(f, "{:},", arg)?;
+        for (i, arg) in self.arguments.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{:}", arg)?;
         }
         write!(f, ")")
     }
@@ -14,8 +14,10 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
+use crate::NodeID;
 use leo_span::Span;
 
+use derivative::Derivative;
 use serde::{Deserialize, Serialize};
 use std::fmt;
 
@@ -65,10 +67,14 @@ impl fmt::Display for Chars {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Derivative, Debug, Clone, Eq, Serialize, Deserialize)]
+#[derivative(PartialEq)]
 pub struct CharValue {
     pub character: Char,
     pub span: Span,
+    /// The ID of the node.
+    #[derivative(PartialEq = "ignore")]
+    pub id: NodeID,
 }
 
 impl fmt::Display for CharValue {
@@ -14,8 +14,9 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{CircuitMember, Identifier};
+use crate::{CircuitMember, Identifier, NodeID};
 
+use derivative::Derivative;
 use serde::{Deserialize, Serialize};
 use std::fmt;
 
@@ -25,12 +26,19 @@ use std::fmt;
 /// Type identity is decided by the full path including `circuit_name`,
 /// as the record is nominal, not structural.
 /// The fields are named so `circuit Foo(u8, u16)` is not allowed.
-#[derive(Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Derivative, Clone, Eq, Serialize, Deserialize)]
+#[derivative(PartialEq)]
 pub struct Circuit {
+    /// The doc comments of the circuit, one line each, without the `///` or `/** */` markers.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub docs: Vec<String>,
     /// The name of the type in the type system in this module.
     pub circuit_name: Identifier,
     /// The fields, constant variables, and functions of this structure.
     pub members: Vec<CircuitMember>,
+    /// The ID of the node.
+    #[derivative(PartialEq = "ignore")]
+    pub id: NodeID,
 }
 
 impl Circuit {
@@ -33,6 +33,9 @@ pub enum CircuitMember {
         /// The expression representing the constant's value.
         /// Checked to be of the type above.
         Expression,
+        /// The doc comments of the constant.
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        Vec<String>,
     ),
     /// A varible definition in a circuit;
     /// For example: `foobar: u8;`.
@@ -41,6 +44,9 @@ pub enum CircuitMember {
         Identifier,
         /// The type the constant has.
         Type,
+        /// The doc comments of the variable.
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        Vec<String>,
     ),
     /// A function definition in a circuit.
     /// For example: `function bar() -> u8 { return 2u8; }`.
@@ -53,10 +59,10 @@ pub enum CircuitMember {
 impl fmt::Display for CircuitMember {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
-            CircuitMember::CircuitConst(ref identifier, ref type_, ref value) => {
+            CircuitMember::CircuitConst(ref identifier, ref type_, ref value, _) => {
                 write!(f, "{}: {} = {}", identifier, type_, value)
             }
-            CircuitMember::CircuitVariable(ref identifier, ref type_) => write!(f, "{}: {}", identifier, type_),
+            CircuitMember::CircuitVariable(ref identifier, ref type_, _) => write!(f, "{}: {}", identifier, type_),
             CircuitMember::CircuitFunction(ref function) => write!(f, "{}", function),
         }
     }
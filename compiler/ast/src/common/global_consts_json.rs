@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{DefinitionStatement, Identifier};
+use crate::{DefinitionStatement, Identifier, NodeID};
 
 use leo_span::Symbol;
 
@@ -50,6 +50,7 @@ pub fn deserialize<'de, D: Deserializer<'de>>(
                     .map(|ident_name| Identifier {
                         name: Symbol::intern(ident_name),
                         span: Default::default(),
+                        id: NodeID::DUMMY,
                     })
                     .collect::<Vec<Identifier>>(),
                 program,
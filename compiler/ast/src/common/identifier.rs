@@ -16,7 +16,7 @@
 
 use leo_span::{Span, Symbol};
 
-use crate::Node;
+use crate::{Node, NodeID};
 use serde::{
     de::{
         Visitor, {self},
@@ -40,6 +40,8 @@ pub struct Identifier {
     pub name: Symbol,
     /// A span locating where the identifier occured in the source.
     pub span: Span,
+    /// The ID of the node. Ignored by equality and hashing.
+    pub id: NodeID,
 }
 
 impl Node for Identifier {
@@ -58,6 +60,7 @@ impl Identifier {
         Self {
             name,
             span: Span::default(),
+            id: NodeID::next(),
         }
     }
 
@@ -104,6 +107,7 @@ impl Serialize for Identifier {
         let mut key: BTreeMap<String, String> = BTreeMap::new();
         key.insert("name".to_string(), self.name.to_string());
         key.insert("span".to_string(), to_json_string(&self.span)?);
+        key.insert("id".to_string(), to_json_string(&self.id)?);
 
         // Convert the serialized object into a string for use as a key.
         serializer.serialize_str(&to_json_string(&key)?)
@@ -143,7 +147,12 @@ impl<'de> Deserialize<'de> for Identifier {
                     None => return Err(E::custom("missing 'span' in serialized Identifier struct")),
                 };
 
-                Ok(Identifier { name, span })
+                let id: NodeID = match key.get("id") {
+                    Some(id) => to_json_string(id)?,
+                    None => return Err(E::custom("missing 'id' in serialized Identifier struct")),
+                };
+
+                Ok(Identifier { name, span, id })
             }
         }
 
@@ -0,0 +1,580 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Tree diffs between two versions of an AST, e.g., the snapshots of two compiler phases.
+//!
+//! A diff of programs follows their structure: the items are matched by name,
+//! and the statements of a block are matched by their code, descending into the nested blocks.
+//! Only the changed items are shown, as Leo code, see [`print_program`].
+//! A diff of JSON values, for the ASTs which cannot be read back, follows the objects and arrays instead.
+
+use crate::*;
+
+use leo_errors::{AstError, Result};
+use serde_json::Value;
+use std::fmt;
+
+/// How a line of a diff relates the two versions.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum DiffKind {
+    /// The line is in both versions.
+    Unchanged,
+    /// The line is only in the old version.
+    Removed,
+    /// The line is only in the new version.
+    Added,
+}
+
+/// A line of a diff, indented by its depth in the tree.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DiffLine {
+    pub kind: DiffKind,
+    pub depth: usize,
+    pub text: String,
+}
+
+/// The structural differences between two versions of an AST, as lines of code.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct AstDiff {
+    pub lines: Vec<DiffLine>,
+}
+
+impl AstDiff {
+    /// Returns the diff from the program `old` to the program `new`.
+    pub fn programs(old: &Program, new: &Program) -> Self {
+        let mut diff = Self::default();
+        diff.program(old, new);
+        diff
+    }
+
+    /// Returns the diff from the JSON AST `old` to the JSON AST `new`.
+    /// The identifiers, which are serialized as strings holding their JSON, are shown by name.
+    pub fn json(old: &Value, new: &Value) -> Self {
+        let mut diff = Self::default();
+        diff.json_value(
+            None,
+            &identifiers_by_name(old.clone()),
+            &identifiers_by_name(new.clone()),
+            0,
+        );
+        diff
+    }
+
+    /// Returns the diff from the JSON AST file at `old` to the JSON AST file at `new`.
+    pub fn json_files(old: std::path::PathBuf, new: std::path::PathBuf) -> Result<Self> {
+        let read = |path: std::path::PathBuf| -> Result<Value> {
+            let data = std::fs::read_to_string(&path).map_err(|e| AstError::failed_to_read_json_file(&path, &e))?;
+            Ok(serde_json::from_str(&data).map_err(|e| AstError::failed_to_read_json_file(&path, &e))?)
+        };
+        Ok(Self::json(&read(old)?, &read(new)?))
+    }
+
+    /// Returns `true` if the two versions are the same.
+    pub fn is_empty(&self) -> bool {
+        self.lines.iter().all(|line| line.kind == DiffKind::Unchanged)
+    }
+
+    fn push(&mut self, kind: DiffKind, depth: usize, code: &str) {
+        for text in code.lines() {
+            self.lines.push(DiffLine {
+                kind,
+                depth,
+                text: text.to_string(),
+            });
+        }
+    }
+
+    /// Separates the changed items of a program.
+    fn separate(&mut self) {
+        if !self.lines.is_empty() {
+            self.lines.push(DiffLine {
+                kind: DiffKind::Unchanged,
+                depth: 0,
+                text: String::new(),
+            });
+        }
+    }
+
+    /// Adds the lines of the items which only one version has, or which differ, matching the items by `key`.
+    /// Changed items are diffed by `changed`, the others are printed by `print`.
+    fn items<T>(
+        &mut self,
+        old: &[&T],
+        new: &[&T],
+        key: impl Fn(&T) -> String,
+        print: impl Fn(&T) -> String,
+        mut changed: impl FnMut(&mut Self, &T, &T),
+    ) {
+        let old_keys = old.iter().map(|item| key(item)).collect::<Vec<_>>();
+        let new_keys = new.iter().map(|item| key(item)).collect::<Vec<_>>();
+        for step in align(&old_keys, &new_keys) {
+            match step {
+                Step::Both(i, j) if print(old[i]) != print(new[j]) => {
+                    self.separate();
+                    changed(self, old[i], new[j]);
+                }
+                Step::Both(..) => {}
+                Step::Old(i) => {
+                    self.separate();
+                    self.push(DiffKind::Removed, 0, &print(old[i]));
+                }
+                Step::New(j) => {
+                    self.separate();
+                    self.push(DiffKind::Added, 0, &print(new[j]));
+                }
+            }
+        }
+    }
+
+    fn program(&mut self, old: &Program, new: &Program) {
+        let replace = |diff: &mut Self, old: String, new: String| {
+            diff.push(DiffKind::Removed, 0, &old);
+            diff.push(DiffKind::Added, 0, &new);
+        };
+
+        self.items(
+            &old.import_statements.iter().collect::<Vec<_>>(),
+            &new.import_statements.iter().collect::<Vec<_>>(),
+            print_import,
+            print_import,
+            |_, _, _| {},
+        );
+
+        let print_alias = |alias: &Alias| format!("type {} = {};", alias.name, print_type(&alias.represents));
+        self.items(
+            &old.aliases.values().collect::<Vec<_>>(),
+            &new.aliases.values().collect::<Vec<_>>(),
+            |alias| alias.name.to_string(),
+            print_alias,
+            |diff, old, new| replace(diff, print_alias(old), print_alias(new)),
+        );
+
+        let print_circuit = |circuit: &Circuit| {
+            let mut program = Program::new(String::new());
+            program.circuits.insert(circuit.circuit_name.clone(), circuit.clone());
+            print_program(&program)
+        };
+        self.items(
+            &old.circuits.values().collect::<Vec<_>>(),
+            &new.circuits.values().collect::<Vec<_>>(),
+            |circuit| circuit.circuit_name.to_string(),
+            print_circuit,
+            |diff, old, new| diff.circuit(old, new),
+        );
+
+        let print_const =
+            |definition: &DefinitionStatement| print_statement(&Statement::Definition(definition.clone()));
+        self.items(
+            &old.global_consts.values().collect::<Vec<_>>(),
+            &new.global_consts.values().collect::<Vec<_>>(),
+            |definition| {
+                let names = definition.variable_names.iter().map(|name| name.identifier.to_string());
+                names.collect::<Vec<_>>().join(",")
+            },
+            print_const,
+            |diff, old, new| replace(diff, print_const(old), print_const(new)),
+        );
+
+        self.items(
+            &old.functions.values().collect::<Vec<_>>(),
+            &new.functions.values().collect::<Vec<_>>(),
+            |function| function.identifier.to_string(),
+            print_function,
+            |diff, old, new| diff.function(old, new, 0),
+        );
+    }
+
+    fn circuit(&mut self, old: &Circuit, new: &Circuit) {
+        self.push(DiffKind::Unchanged, 0, &format!("circuit {} {{", new.circuit_name));
+
+        // The member variables and constants are matched by their code, the functions by their name.
+        let key = |member: &CircuitMember| match member {
+            CircuitMember::CircuitFunction(function) => format!("function {}", function.identifier),
+            member => print_member(member),
+        };
+        let old_keys = old.members.iter().map(key).collect::<Vec<_>>();
+        let new_keys = new.members.iter().map(key).collect::<Vec<_>>();
+        for step in align(&old_keys, &new_keys) {
+            match step {
+                Step::Both(i, j) => match (&old.members[i], &new.members[j]) {
+                    // Functions equal by name, so they are compared by their code.
+                    (CircuitMember::CircuitFunction(old), CircuitMember::CircuitFunction(new))
+                        if print_function(old) != print_function(new) =>
+                    {
+                        self.function(old, new, 1)
+                    }
+                    (_, member) => self.push(DiffKind::Unchanged, 1, &print_member(member)),
+                },
+                Step::Old(i) => self.push(DiffKind::Removed, 1, &print_member(&old.members[i])),
+                Step::New(j) => self.push(DiffKind::Added, 1, &print_member(&new.members[j])),
+            }
+        }
+
+        self.push(DiffKind::Unchanged, 0, "}");
+    }
+
+    fn function(&mut self, old: &Function, new: &Function, depth: usize) {
+        let (old_block, new_block) = match (&old.block, &new.block) {
+            (Some(old_block), Some(new_block)) => (old_block, new_block),
+            // A native function has no body to compare, so the whole function is replaced.
+            _ => {
+                self.push(DiffKind::Removed, depth, &print_function(old));
+                self.push(DiffKind::Added, depth, &print_function(new));
+                return;
+            }
+        };
+
+        let (old_header, new_header) = (function_header(old), function_header(new));
+        if old_header == new_header {
+            self.push(DiffKind::Unchanged, depth, &new_header);
+        } else {
+            self.push(DiffKind::Removed, depth, &old_header);
+            self.push(DiffKind::Added, depth, &new_header);
+        }
+        self.block(old_block, new_block, depth + 1);
+        self.push(DiffKind::Unchanged, depth, "}");
+    }
+
+    /// Adds the lines of the statements of two versions of a block, without its braces.
+    fn block(&mut self, old: &Block, new: &Block, depth: usize) {
+        let old_code = old.statements.iter().map(print_statement).collect::<Vec<_>>();
+        let new_code = new.statements.iter().map(print_statement).collect::<Vec<_>>();
+
+        // Within a run of changed statements, two statements with blocks of the same kind are diffed.
+        let mut removed = Vec::new();
+        let mut added = Vec::new();
+        let flush = |diff: &mut Self, removed: &mut Vec<usize>, added: &mut Vec<usize>| {
+            for i in removed.drain(..) {
+                diff.push(DiffKind::Removed, depth, &old_code[i]);
+            }
+            for j in added.drain(..) {
+                diff.push(DiffKind::Added, depth, &new_code[j]);
+            }
+        };
+        let steps = align(&old_code, &new_code);
+        let mut k = 0;
+        while k < steps.len() {
+            if let Step::Both(_, j) = steps[k] {
+                flush(self, &mut removed, &mut added);
+                self.push(DiffKind::Unchanged, depth, &new_code[j]);
+                k += 1;
+                continue;
+            }
+
+            // Collect the run of changed statements.
+            let (mut olds, mut news) = (Vec::new(), Vec::new());
+            while let Some(step) = steps.get(k) {
+                match step {
+                    Step::Old(i) => olds.push(*i),
+                    Step::New(j) => news.push(*j),
+                    Step::Both(..) => break,
+                }
+                k += 1;
+            }
+            for n in 0..olds.len().max(news.len()) {
+                match (olds.get(n), news.get(n)) {
+                    (Some(&i), Some(&j)) if self.nested(&old.statements[i], &new.statements[j], depth, true) => {
+                        flush(self, &mut removed, &mut added);
+                        self.nested(&old.statements[i], &new.statements[j], depth, false);
+                    }
+                    (i, j) => {
+                        removed.extend(i);
+                        added.extend(j);
+                    }
+                }
+            }
+        }
+        flush(self, &mut removed, &mut added);
+    }
+
+    /// Diffs two statements with blocks of the same kind, returning `false` if they are not.
+    /// When `check` is set, only returns whether they are.
+    fn nested(&mut self, old: &Statement, new: &Statement, depth: usize, check: bool) -> bool {
+        match (old, new) {
+            (Statement::Conditional(old), Statement::Conditional(new)) => {
+                if !check {
+                    self.conditional(old, new, depth, "");
+                }
+                true
+            }
+            (Statement::Iteration(old), Statement::Iteration(new)) => {
+                if !check {
+                    let header = |iteration: &IterationStatement| {
+                        let mut header = print_statement(&Statement::Iteration(Box::new(IterationStatement {
+                            block: Block {
+                                statements: Vec::new(),
+                                span: iteration.block.span.clone(),
+                                id: iteration.block.id,
+                            },
+                            ..iteration.clone()
+                        })));
+                        header.pop();
+                        header
+                    };
+                    self.header(header(old), header(new), depth);
+                    self.block(&old.block, &new.block, depth + 1);
+                    self.push(DiffKind::Unchanged, depth, "}");
+                }
+                true
+            }
+            (Statement::Block(old), Statement::Block(new)) => {
+                if !check {
+                    self.push(DiffKind::Unchanged, depth, "{");
+                    self.block(old, new, depth + 1);
+                    self.push(DiffKind::Unchanged, depth, "}");
+                }
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Diffs two conditionals, the first line of which starts with `prefix`, e.g., `} else `.
+    fn conditional(&mut self, old: &ConditionalStatement, new: &ConditionalStatement, depth: usize, prefix: &str) {
+        let header = |conditional: &ConditionalStatement| {
+            let statement = print_statement(&Statement::Conditional(ConditionalStatement {
+                block: Block {
+                    statements: Vec::new(),
+                    span: conditional.block.span.clone(),
+                    id: conditional.block.id,
+                },
+                next: None,
+                ..conditional.clone()
+            }));
+            format!("{}{}", prefix, &statement[..statement.len() - 1])
+        };
+        self.header(header(old), header(new), depth);
+        self.block(&old.block, &new.block, depth + 1);
+
+        match (old.next.as_deref(), new.next.as_deref()) {
+            (Some(Statement::Conditional(old)), Some(Statement::Conditional(new))) => {
+                self.conditional(old, new, depth, "} else ")
+            }
+            (Some(Statement::Block(old)), Some(Statement::Block(new))) => {
+                self.push(DiffKind::Unchanged, depth, "} else {");
+                self.block(old, new, depth + 1);
+                self.push(DiffKind::Unchanged, depth, "}");
+            }
+            (None, None) => self.push(DiffKind::Unchanged, depth, "}"),
+            (old, new) => {
+                let print = |next: Option<&Statement>| match next {
+                    Some(next) => format!("}} else {}", print_statement(next)),
+                    None => "}".to_string(),
+                };
+                self.push(DiffKind::Removed, depth, &print(old));
+                self.push(DiffKind::Added, depth, &print(new));
+            }
+        }
+    }
+
+    /// Adds the first lines of two versions of a statement with a block.
+    fn header(&mut self, old: String, new: String, depth: usize) {
+        if old == new {
+            self.push(DiffKind::Unchanged, depth, &new);
+        } else {
+            self.push(DiffKind::Removed, depth, &old);
+            self.push(DiffKind::Added, depth, &new);
+        }
+    }
+
+    /// Adds the lines of two versions of the JSON value at `key`, if they differ.
+    fn json_value(&mut self, key: Option<&str>, old: &Value, new: &Value, depth: usize) {
+        if old == new {
+            return;
+        }
+        let label = |value: &Value| match key {
+            Some(key) => format!("{}: {}", key, value),
+            None => value.to_string(),
+        };
+        let child_depth = depth + key.is_some() as usize;
+
+        match (old, new) {
+            (Value::Object(old_map), Value::Object(new_map)) => {
+                if let Some(key) = key {
+                    self.push(DiffKind::Unchanged, depth, &format!("{}:", key));
+                }
+                for (name, old_value) in old_map.iter() {
+                    match new_map.get(name) {
+                        Some(new_value) => self.json_value(Some(name), old_value, new_value, child_depth),
+                        None => self.push(DiffKind::Removed, child_depth, &format!("{}: {}", name, old_value)),
+                    }
+                }
+                for (name, new_value) in new_map.iter().filter(|(name, _)| !old_map.contains_key(*name)) {
+                    self.push(DiffKind::Added, child_depth, &format!("{}: {}", name, new_value));
+                }
+            }
+            (Value::Array(old_values), Value::Array(new_values)) => {
+                if let Some(key) = key {
+                    self.push(DiffKind::Unchanged, depth, &format!("{}:", key));
+                }
+                let steps = align(old_values, new_values);
+                let mut k = 0;
+                while k < steps.len() {
+                    let (mut olds, mut news) = (Vec::new(), Vec::new());
+                    while let Some(step) = steps.get(k) {
+                        match step {
+                            Step::Old(i) => olds.push(*i),
+                            Step::New(j) => news.push(*j),
+                            Step::Both(..) if olds.is_empty() && news.is_empty() => {}
+                            Step::Both(..) => break,
+                        }
+                        k += 1;
+                    }
+                    // Changed elements at the same position are diffed, the others are removed or added.
+                    for n in 0..olds.len().max(news.len()) {
+                        match (olds.get(n), news.get(n)) {
+                            (Some(&i), Some(&j)) => {
+                                self.json_value(Some(&format!("[{}]", j)), &old_values[i], &new_values[j], child_depth)
+                            }
+                            (Some(&i), None) => {
+                                self.push(DiffKind::Removed, child_depth, &format!("[{}]: {}", i, old_values[i]))
+                            }
+                            (None, Some(&j)) => {
+                                self.push(DiffKind::Added, child_depth, &format!("[{}]: {}", j, new_values[j]))
+                            }
+                            (None, None) => {}
+                        }
+                    }
+                }
+            }
+            _ => {
+                self.push(DiffKind::Removed, depth, &label(old));
+                self.push(DiffKind::Added, depth, &label(new));
+            }
+        }
+    }
+}
+
+impl fmt::Display for AstDiff {
+    /// Formats the lines like a unified diff, each line starting with ` `, `-`, or `+`.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for line in self.lines.iter() {
+            let marker = match line.kind {
+                DiffKind::Unchanged => ' ',
+                DiffKind::Removed => '-',
+                DiffKind::Added => '+',
+            };
+            if line.text.is_empty() {
+                writeln!(f, "{}", marker.to_string().trim_end())?;
+            } else {
+                writeln!(f, "{} {}{}", marker, "    ".repeat(line.depth), line.text)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// A step of an alignment of two sequences.
+#[derive(Clone, Copy, Debug)]
+enum Step {
+    /// The elements at these positions are equal.
+    Both(usize, usize),
+    /// The element at this position of the old sequence is not in the new one.
+    Old(usize),
+    /// The element at this position of the new sequence is not in the old one.
+    New(usize),
+}
+
+/// Aligns `old` and `new` along a longest common subsequence.
+/// Between two common elements, the steps of the old elements come first.
+fn align<T: PartialEq>(old: &[T], new: &[T]) -> Vec<Step> {
+    // `lengths[i][j]` is the length of a longest common subsequence of `old[i..]` and `new[j..]`.
+    let mut lengths = vec![vec![0usize; new.len() + 1]; old.len() + 1];
+    for i in (0..old.len()).rev() {
+        for j in (0..new.len()).rev() {
+            lengths[i][j] = if old[i] == new[j] {
+                lengths[i + 1][j + 1] + 1
+            } else {
+                lengths[i + 1][j].max(lengths[i][j + 1])
+            };
+        }
+    }
+
+    let mut steps = Vec::new();
+    let (mut i, mut j) = (0, 0);
+    while i < old.len() || j < new.len() {
+        if i < old.len() && j < new.len() && old[i] == new[j] {
+            steps.push(Step::Both(i, j));
+            i += 1;
+            j += 1;
+        } else if j == new.len() || (i < old.len() && lengths[i + 1][j] >= lengths[i][j + 1]) {
+            steps.push(Step::Old(i));
+            i += 1;
+        } else {
+            steps.push(Step::New(j));
+            j += 1;
+        }
+    }
+    steps
+}
+
+/// Returns the first lines of the code of `function`, up to the opening brace of its body.
+fn function_header(function: &Function) -> String {
+    let mut header = print_function(&Function {
+        block: function.block.as_ref().map(|block| Block {
+            statements: Vec::new(),
+            span: block.span.clone(),
+            id: block.id,
+        }),
+        ..function.clone()
+    });
+    header.pop();
+    header
+}
+
+/// Returns the code of a circuit member.
+fn print_member(member: &CircuitMember) -> String {
+    let circuit = Circuit {
+        docs: Vec::new(),
+        circuit_name: Identifier::new(leo_span::Symbol::intern("_")),
+        members: vec![member.clone()],
+        id: NodeID::DUMMY,
+    };
+    let mut program = Program::new(String::new());
+    program.circuits.insert(circuit.circuit_name.clone(), circuit);
+
+    // Drop the lines of the circuit and the indentation of the member.
+    let code = print_program(&program);
+    let lines = code.lines().collect::<Vec<_>>();
+    lines[1..lines.len() - 1]
+        .iter()
+        .map(|line| line.strip_prefix("    ").unwrap_or(line))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Replaces the identifiers in `value`, which are serialized as strings holding their JSON, by their names.
+fn identifiers_by_name(value: Value) -> Value {
+    fn name(string: String) -> String {
+        match serde_json::from_str::<Value>(&string) {
+            Ok(Value::Object(mut map)) if map.contains_key("span") => match map.remove("name") {
+                Some(Value::String(name)) => name,
+                _ => string,
+            },
+            _ => string,
+        }
+    }
+
+    match value {
+        Value::Object(map) => map
+            .into_iter()
+            .map(|(key, value)| (name(key), identifiers_by_name(value)))
+            .collect(),
+        Value::Array(values) => values.into_iter().map(identifiers_by_name).collect(),
+        Value::String(string) => Value::String(name(string)),
+        value => value,
+    }
+}
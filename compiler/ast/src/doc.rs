@@ -0,0 +1,609 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Documentation of Leo modules, generated from their doc comments as Markdown or HTML pages.
+//!
+//! Each module has a page with the signatures and the doc comments of its items, and an index page lists the modules.
+//! The types in a signature link to the items they name,
+//! and so do the names in brackets in a doc comment, e.g., [`Point`] or [`Point.x`].
+
+use crate::*;
+
+use indexmap::IndexMap;
+
+/// The format of the documentation pages.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum DocFormat {
+    Markdown,
+    Html,
+}
+
+impl DocFormat {
+    /// Returns the extension of the files of the pages, e.g., `md`.
+    pub fn extension(self) -> &'static str {
+        match self {
+            DocFormat::Markdown => "md",
+            DocFormat::Html => "html",
+        }
+    }
+}
+
+/// A module to document, e.g., a file of a package or of the standard library.
+#[derive(Clone, Debug)]
+pub struct DocModule {
+    /// The path of the module, e.g., `["std", "unstable", "blake2s"]`.
+    pub path: Vec<String>,
+    /// The program of the module.
+    pub program: Program,
+}
+
+impl DocModule {
+    /// Returns the name of the module, e.g., `std.unstable.blake2s`.
+    pub fn name(&self) -> String {
+        self.path.join(".")
+    }
+}
+
+/// A page of documentation.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DocPage {
+    /// The name of the file of the page, e.g., `std.unstable.blake2s.md`.
+    pub file_name: String,
+    /// The content of the page.
+    pub content: String,
+}
+
+/// Returns the documentation pages of `modules` in the given `format`, the index page being the first.
+pub fn generate_docs(modules: &[DocModule], format: DocFormat) -> Vec<DocPage> {
+    let items = modules
+        .iter()
+        .map(|module| module_items(&module.program))
+        .collect::<Vec<_>>();
+
+    // The items and their members are linked to by name, e.g., `Point` or `Point.x`.
+    let mut links = Links {
+        names: IndexMap::new(),
+        files: modules
+            .iter()
+            .map(|module| format!("{}.{}", module.name(), format.extension()))
+            .collect(),
+    };
+    for (module, items) in items.iter().enumerate() {
+        for item in items {
+            links.add(&item.name, module, &item.anchor);
+            for member in item.members.iter() {
+                links.add(&format!("{}.{}", item.name, member.name), module, &member.anchor);
+            }
+        }
+    }
+
+    let mut pages = vec![DocPage {
+        file_name: format!("index.{}", format.extension()),
+        content: match format {
+            DocFormat::Markdown => markdown_index(modules, &links),
+            DocFormat::Html => html_index(modules, &links),
+        },
+    }];
+    for (i, (module, items)) in modules.iter().zip(items.iter()).enumerate() {
+        let mut page = DocWriter {
+            out: String::new(),
+            links: &links,
+            module: i,
+        };
+        match format {
+            DocFormat::Markdown => page.markdown_module(module, items),
+            DocFormat::Html => page.html_module(module, items),
+        }
+        pages.push(DocPage {
+            file_name: links.files[i].clone(),
+            content: page.out,
+        });
+    }
+    pages
+}
+
+/// The sections of a module page, by the kind of their items.
+const SECTIONS: [(&str, &str); 4] = [
+    ("type", "Type Aliases"),
+    ("const", "Constants"),
+    ("circuit", "Circuits"),
+    ("function", "Functions"),
+];
+
+/// An item or a member of a circuit, as documented.
+struct DocItem {
+    /// The kind of the item, e.g., `circuit`.
+    kind: &'static str,
+    name: String,
+    /// The anchor of the item on the page of its module, e.g., `circuit.Point` or `circuit.Point.x`.
+    anchor: String,
+    /// The code declaring the item, without a body.
+    signature: String,
+    docs: Vec<String>,
+    /// The names of the types in the signature.
+    types: Vec<String>,
+    members: Vec<DocItem>,
+}
+
+/// Returns the documented items of `program`, in the order of [`SECTIONS`].
+fn module_items(program: &Program) -> Vec<DocItem> {
+    let mut items = Vec::new();
+
+    for alias in program.aliases.values() {
+        items.push(DocItem {
+            kind: "type",
+            name: alias.name.to_string(),
+            anchor: format!("type.{}", alias.name),
+            signature: format!("type {} = {};", alias.name, print_type(&alias.represents)),
+            docs: alias.docs.clone(),
+            types: type_names(&alias.represents),
+            members: Vec::new(),
+        });
+    }
+
+    for definition in program.global_consts.values() {
+        let name = definition
+            .variable_names
+            .iter()
+            .map(|name| name.identifier.to_string())
+            .collect::<Vec<_>>()
+            .join(", ");
+        items.push(DocItem {
+            kind: "const",
+            anchor: format!("const.{}", name.replace(", ", ".")),
+            name,
+            signature: print_statement(&Statement::Definition(DefinitionStatement {
+                docs: Vec::new(),
+                ..definition.clone()
+            })),
+            docs: definition.docs.clone(),
+            types: definition.type_.as_ref().map(type_names).unwrap_or_default(),
+            members: Vec::new(),
+        });
+    }
+
+    for circuit in program.circuits.values() {
+        let anchor = format!("circuit.{}", circuit.circuit_name);
+        let members = circuit
+            .members
+            .iter()
+            .map(|member| {
+                let mut item = match member {
+                    CircuitMember::CircuitConst(name, type_, value, docs) => DocItem {
+                        kind: "const",
+                        name: name.to_string(),
+                        anchor: String::new(),
+                        signature: format!(
+                            "static const {}: {} = {};",
+                            name,
+                            print_type(type_),
+                            print_expression(value)
+                        ),
+                        docs: docs.clone(),
+                        types: type_names(type_),
+                        members: Vec::new(),
+                    },
+                    CircuitMember::CircuitVariable(name, type_, docs) => DocItem {
+                        kind: "variable",
+                        name: name.to_string(),
+                        anchor: String::new(),
+                        signature: format!("{}: {}", name, print_type(type_)),
+                        docs: docs.clone(),
+                        types: type_names(type_),
+                        members: Vec::new(),
+                    },
+                    CircuitMember::CircuitFunction(function) => function_item(function),
+                };
+                item.anchor = format!("{}.{}", anchor, item.name);
+                item
+            })
+            .collect();
+        items.push(DocItem {
+            kind: "circuit",
+            name: circuit.circuit_name.to_string(),
+            anchor,
+            signature: format!("circuit {}", circuit.circuit_name),
+            docs: circuit.docs.clone(),
+            types: Vec::new(),
+            members,
+        });
+    }
+
+    items.extend(program.functions.values().map(|function| {
+        let mut item = function_item(function);
+        item.anchor = format!("function.{}", item.name);
+        item
+    }));
+
+    items
+}
+
+/// Returns the documented item of `function`, without an anchor.
+fn function_item(function: &Function) -> DocItem {
+    let mut types = Vec::new();
+    let input_types = function.input.iter().filter_map(|input| match input {
+        FunctionInput::Variable(variable) => Some(&variable.type_),
+        _ => None,
+    });
+    for type_ in input_types.chain(function.output.as_ref()) {
+        for name in type_names(type_) {
+            if !types.contains(&name) {
+                types.push(name);
+            }
+        }
+    }
+
+    // The signature is the code of the function without its docs and its body.
+    let signature = print_function(&Function {
+        docs: Vec::new(),
+        block: None,
+        ..function.clone()
+    });
+
+    DocItem {
+        kind: "function",
+        name: function.identifier.to_string(),
+        anchor: String::new(),
+        signature: signature.trim_end_matches(';').to_string(),
+        docs: function.docs.clone(),
+        types,
+        members: Vec::new(),
+    }
+}
+
+/// Returns the names of the circuits and aliases in `type_`, without repetitions.
+fn type_names(type_: &Type) -> Vec<String> {
+    fn collect(type_: &Type, names: &mut Vec<String>) {
+        match type_ {
+            Type::Identifier(identifier) if !names.contains(&identifier.to_string()) => {
+                names.push(identifier.to_string())
+            }
+            Type::Array(element, _) => collect(element, names),
+            Type::Tuple(elements) => elements.iter().for_each(|element| collect(element, names)),
+            _ => {}
+        }
+    }
+
+    let mut names = Vec::new();
+    collect(type_, &mut names);
+    names
+}
+
+/// The items which can be linked to, by name.
+struct Links {
+    /// The modules and the anchors of the items of a name.
+    names: IndexMap<String, Vec<(usize, String)>>,
+    /// The file names of the pages of the modules.
+    files: Vec<String>,
+}
+
+impl Links {
+    fn add(&mut self, name: &str, module: usize, anchor: &str) {
+        self.names
+            .entry(name.to_string())
+            .or_default()
+            .push((module, anchor.to_string()));
+    }
+
+    /// Returns the link to the item `name` from the page of `module`,
+    /// preferring the items of `module` to those of other modules.
+    fn resolve(&self, name: &str, module: usize) -> Option<String> {
+        let targets = self.names.get(name)?;
+        let (target, anchor) = targets
+            .iter()
+            .find(|(target, _)| *target == module)
+            .unwrap_or(&targets[0]);
+        Some(match *target == module {
+            true => format!("#{}", anchor),
+            false => format!("{}#{}", self.files[*target], anchor),
+        })
+    }
+
+    /// Returns the link to the page of `module`.
+    fn page(&self, module: usize) -> String {
+        self.files[module].clone()
+    }
+}
+
+/// A block of a doc comment.
+enum DocBlock {
+    /// Lines of text, to be joined.
+    Paragraph(String),
+    /// A fenced code block, with its language, if any.
+    Code(String, String),
+}
+
+/// Splits the lines of a doc comment into paragraphs and code blocks.
+fn doc_blocks(lines: &[String]) -> Vec<DocBlock> {
+    let mut blocks = Vec::new();
+    let mut paragraph = Vec::new();
+    let mut code: Option<(String, Vec<&str>)> = None;
+    for line in lines {
+        match (&mut code, line.trim_start().strip_prefix("```")) {
+            (Some((language, code_lines)), Some(_)) => {
+                blocks.push(DocBlock::Code(std::mem::take(language), code_lines.join("\n")));
+                code = None;
+            }
+            (Some((_, code_lines)), None) => code_lines.push(line),
+            (None, Some(language)) => {
+                if !paragraph.is_empty() {
+                    blocks.push(DocBlock::Paragraph(std::mem::take(&mut paragraph).join("\n")));
+                }
+                code = Some((language.trim().to_string(), Vec::new()));
+            }
+            (None, None) if line.trim().is_empty() => {
+                if !paragraph.is_empty() {
+                    blocks.push(DocBlock::Paragraph(std::mem::take(&mut paragraph).join("\n")));
+                }
+            }
+            (None, None) => paragraph.push(line.as_str()),
+        }
+    }
+    // An unclosed code block ends with the comment.
+    if let Some((language, code_lines)) = code {
+        blocks.push(DocBlock::Code(language, code_lines.join("\n")));
+    }
+    if !paragraph.is_empty() {
+        blocks.push(DocBlock::Paragraph(paragraph.join("\n")));
+    }
+    blocks
+}
+
+/// Splits `text` into parts, linking the names in brackets which `resolve` knows, e.g., [`Point`].
+/// A bracket followed by `(` or `[` is already a link, so it is left as it is.
+fn intra_doc_links(text: &str, resolve: impl Fn(&str) -> Option<String>) -> Vec<(String, Option<String>)> {
+    let mut parts = Vec::new();
+    let mut rest = text;
+    while let Some(open) = rest.find('[') {
+        let close = match rest[open..].find(']') {
+            Some(close) => open + close,
+            None => break,
+        };
+        let label = &rest[open + 1..close];
+        let after = &rest[close + 1..];
+        match resolve(label.trim_matches('`')).filter(|_| !after.starts_with('(') && !after.starts_with('[')) {
+            Some(link) => {
+                parts.push((rest[..open].to_string(), None));
+                parts.push((label.to_string(), Some(link)));
+                rest = after;
+            }
+            None => {
+                parts.push((rest[..=open].to_string(), None));
+                rest = &rest[open + 1..];
+            }
+        }
+    }
+    parts.push((rest.to_string(), None));
+    parts
+}
+
+/// Escapes the HTML special characters of `text`.
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/// Returns the HTML of `text`, whose parts in backticks are code.
+fn inline_code_html(text: &str) -> String {
+    text.split('`')
+        .enumerate()
+        .map(|(i, part)| match i % 2 {
+            0 => escape_html(part),
+            _ => format!("<code>{}</code>", escape_html(part)),
+        })
+        .collect()
+}
+
+/// The style of the HTML pages.
+const HTML_STYLE: &str = "body { font-family: sans-serif; max-width: 60em; margin: auto; padding: 1em; }
+pre { background: #f4f4f4; padding: 0.5em; overflow-x: auto; }
+section.member { margin-left: 2em; }
+a { color: #2a6db0; }";
+
+/// Returns the HTML page around `body`.
+fn html_page(title: &str, body: &str) -> String {
+    format!(
+        "<!DOCTYPE html>
+<html>
+<head>
+<meta charset=\"utf-8\">
+<title>{}</title>
+<style>
+{}
+</style>
+</head>
+<body>
+{}</body>
+</html>
+",
+        escape_html(title),
+        HTML_STYLE,
+        body
+    )
+}
+
+fn markdown_index(modules: &[DocModule], links: &Links) -> String {
+    let mut out = String::from("# Modules\n\n");
+    for (i, module) in modules.iter().enumerate() {
+        out.push_str(&format!("- [`{}`]({})\n", module.name(), links.page(i)));
+    }
+    out
+}
+
+fn html_index(modules: &[DocModule], links: &Links) -> String {
+    let mut body = String::from("<h1>Modules</h1>\n<ul>\n");
+    for (i, module) in modules.iter().enumerate() {
+        body.push_str(&format!(
+            "<li><a href=\"{}\"><code>{}</code></a></li>\n",
+            escape_html(&links.page(i)),
+            escape_html(&module.name())
+        ));
+    }
+    body.push_str("</ul>\n");
+    html_page("Modules", &body)
+}
+
+/// Writes the page of a module.
+struct DocWriter<'a> {
+    out: String,
+    links: &'a Links,
+    /// The index of the module of the page.
+    module: usize,
+}
+
+impl DocWriter<'_> {
+    fn write(&mut self, text: &str) {
+        self.out.push_str(text);
+    }
+
+    fn resolve(&self, name: &str) -> Option<String> {
+        self.links.resolve(name, self.module)
+    }
+
+    fn markdown_module(&mut self, module: &DocModule, items: &[DocItem]) {
+        self.write(&format!("# Module `{}`\n\n[Modules](index.md)\n", module.name()));
+        for (kind, title) in SECTIONS.iter() {
+            let section = items.iter().filter(|item| item.kind == *kind).collect::<Vec<_>>();
+            if !section.is_empty() {
+                self.write(&format!("\n## {}\n", title));
+                for item in section {
+                    self.markdown_item(item, 3);
+                }
+            }
+        }
+    }
+
+    fn markdown_item(&mut self, item: &DocItem, level: usize) {
+        self.write(&format!(
+            "\n<a id=\"{}\"></a>\n{} `{}`\n\n```leo\n{}\n```\n",
+            item.anchor,
+            "#".repeat(level),
+            item.name,
+            item.signature
+        ));
+
+        // Markdown has no links in code, so the types of the signature are linked after it.
+        let types = item
+            .types
+            .iter()
+            .filter_map(|name| Some(format!("[`{}`]({})", name, self.resolve(name)?)))
+            .collect::<Vec<_>>();
+        if !types.is_empty() {
+            self.write(&format!("\nTypes: {}\n", types.join(", ")));
+        }
+
+        for block in doc_blocks(&item.docs) {
+            match block {
+                DocBlock::Paragraph(text) => {
+                    let text = intra_doc_links(&text, |name| self.resolve(name))
+                        .into_iter()
+                        .map(|(text, link)| match link {
+                            Some(link) => format!("[{}]({})", text, link),
+                            None => text,
+                        })
+                        .collect::<String>();
+                    self.write(&format!("\n{}\n", text));
+                }
+                DocBlock::Code(language, code) => self.write(&format!("\n```{}\n{}\n```\n", language, code)),
+            }
+        }
+
+        for member in item.members.iter() {
+            self.markdown_item(member, level + 1);
+        }
+    }
+
+    fn html_module(&mut self, module: &DocModule, items: &[DocItem]) {
+        let name = module.name();
+        self.write(&format!(
+            "<nav><a href=\"index.html\">Modules</a></nav>\n<h1>Module <code>{}</code></h1>\n",
+            escape_html(&name)
+        ));
+        for (kind, title) in SECTIONS.iter() {
+            let section = items.iter().filter(|item| item.kind == *kind).collect::<Vec<_>>();
+            if !section.is_empty() {
+                self.write(&format!("<h2>{}</h2>\n", title));
+                for item in section {
+                    self.html_item(item, 3);
+                }
+            }
+        }
+        self.out = html_page(&name, &self.out);
+    }
+
+    fn html_item(&mut self, item: &DocItem, level: usize) {
+        let class = if level > 3 { " class=\"member\"" } else { "" };
+        self.write(&format!(
+            "<section id=\"{}\"{}>\n<h{}><code>{}</code></h{}>\n<pre><code>{}</code></pre>\n",
+            escape_html(&item.anchor),
+            class,
+            level.min(6),
+            escape_html(&item.name),
+            level.min(6),
+            self.signature_html(item)
+        ));
+
+        for block in doc_blocks(&item.docs) {
+            match block {
+                DocBlock::Paragraph(text) => {
+                    let html = intra_doc_links(&text, |name| self.resolve(name))
+                        .into_iter()
+                        .map(|(text, link)| match link {
+                            Some(link) => format!("<a href=\"{}\">{}</a>", escape_html(&link), inline_code_html(&text)),
+                            None => inline_code_html(&text),
+                        })
+                        .collect::<String>();
+                    self.write(&format!("<p>{}</p>\n", html));
+                }
+                DocBlock::Code(_, code) => self.write(&format!("<pre><code>{}</code></pre>\n", escape_html(&code))),
+            }
+        }
+
+        for member in item.members.iter() {
+            self.html_item(member, level + 1);
+        }
+        self.write("</section>\n");
+    }
+
+    /// Returns the HTML of the signature of `item`, linking the names of its types.
+    fn signature_html(&self, item: &DocItem) -> String {
+        let mut html = String::new();
+        let mut word = String::new();
+        let flush = |html: &mut String, word: &mut String| {
+            let link = match item.types.contains(word) {
+                true => self.resolve(word),
+                false => None,
+            };
+            match link {
+                Some(link) => html.push_str(&format!("<a href=\"{}\">{}</a>", escape_html(&link), word)),
+                None => html.push_str(&escape_html(word)),
+            }
+            word.clear();
+        };
+        for c in item.signature.chars() {
+            if c.is_alphanumeric() || c == '_' {
+                word.push(c);
+            } else {
+                flush(&mut html, &mut word);
+                html.push_str(&escape_html(&c.to_string()));
+            }
+        }
+        flush(&mut html, &mut word);
+        html
+    }
+}
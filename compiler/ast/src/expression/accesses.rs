@@ -46,6 +46,21 @@ impl fmt::Display for AccessExpression {
     }
 }
 
+impl AccessExpression {
+    /// Returns the ID of the access.
+    pub fn id(&self) -> NodeID {
+        use AccessExpression::*;
+
+        match &self {
+            Array(access) => access.id,
+            ArrayRange(access) => access.id,
+            Member(access) => access.id,
+            Tuple(access) => access.id,
+            Static(access) => access.id,
+        }
+    }
+}
+
 impl Node for AccessExpression {
     fn span(&self) -> &Span {
         use AccessExpression::*;
@@ -18,7 +18,8 @@ use super::*;
 
 /// An array initializer expression, e.g., `[42; 5]`.
 /// constructing an array of `element` repeated according to `dimensions`.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Derivative, Debug, Clone, Eq, Serialize, Deserialize)]
+#[derivative(PartialEq)]
 pub struct ArrayInitExpression {
     /// The expression that all elements in the array will evaluate to.
     pub element: Box<Expression>,
@@ -29,6 +30,9 @@ pub struct ArrayInitExpression {
     pub dimensions: ArrayDimensions,
     /// The span of the entire expression from `[` to `]`.
     pub span: Span,
+    /// The ID of the node.
+    #[derivative(PartialEq = "ignore")]
+    pub id: NodeID,
 }
 
 impl fmt::Display for ArrayInitExpression {
@@ -130,7 +130,8 @@ impl BinaryOperation {
 
 /// A binary expression `left op right` of two operands separated by some operator.
 /// For example, `foo + bar`.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Derivative, Debug, Clone, Eq, Serialize, Deserialize)]
+#[derivative(PartialEq)]
 pub struct BinaryExpression {
     /// The left operand of the expression.
     pub left: Box<Expression>,
@@ -140,6 +141,9 @@ pub struct BinaryExpression {
     pub op: BinaryOperation,
     /// The span from `left` to `right`.
     pub span: Span,
+    /// The ID of the node.
+    #[derivative(PartialEq = "ignore")]
+    pub id: NodeID,
 }
 
 impl fmt::Display for BinaryExpression {
@@ -17,7 +17,8 @@
 use super::*;
 
 /// A function call expression, e.g., `foo(args)` or `Foo::bar(args)`.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Derivative, Debug, Clone, Eq, Serialize, Deserialize)]
+#[derivative(PartialEq)]
 pub struct CallExpression {
     /// An expression evaluating to a callable function,
     /// either a member of a structure or a free function.
@@ -26,6 +27,9 @@ pub struct CallExpression {
     pub arguments: Vec<Expression>,
     /// Span of the entire call `function(arguments)`.
     pub span: Span,
+    /// The ID of the node.
+    #[derivative(PartialEq = "ignore")]
+    pub id: NodeID,
 }
 
 impl fmt::Display for CallExpression {
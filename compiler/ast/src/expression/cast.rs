@@ -19,7 +19,8 @@ use crate::Type;
 use super::*;
 
 /// A cast expression `e as U`.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Derivative, Debug, Clone, Eq, Serialize, Deserialize)]
+#[derivative(PartialEq)]
 pub struct CastExpression {
     /// The expression `e` of a type `T` that is being cast to `U`.
     pub inner: Box<Expression>,
@@ -27,6 +28,9 @@ pub struct CastExpression {
     pub target_type: Type,
     /// Span for the entire expression `e as U` to.
     pub span: Span,
+    /// The ID of the node.
+    #[derivative(PartialEq = "ignore")]
+    pub id: NodeID,
 }
 
 impl fmt::Display for CastExpression {
@@ -38,7 +38,8 @@ impl fmt::Display for CircuitVariableInitializer {
 }
 
 /// A circuit initialization expression, e.g., `Foo { bar: 42, baz }`.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Derivative, Debug, Clone, Eq, Serialize, Deserialize)]
+#[derivative(PartialEq)]
 pub struct CircuitInitExpression {
     /// The name of the structure type to initialize.
     pub name: Identifier,
@@ -49,6 +50,9 @@ pub struct CircuitInitExpression {
     pub members: Vec<CircuitVariableInitializer>,
     /// A span from `name` to `}`.
     pub span: Span,
+    /// The ID of the node.
+    #[derivative(PartialEq = "ignore")]
+    pub id: NodeID,
 }
 
 impl fmt::Display for CircuitInitExpression {
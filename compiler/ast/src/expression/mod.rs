@@ -14,10 +14,11 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{ArrayDimensions, GroupValue, Identifier, IntegerType, Node, SpreadOrExpression};
+use crate::{ArrayDimensions, GroupValue, Identifier, IntegerType, Node, NodeID, SpreadOrExpression};
 
 use leo_span::Span;
 
+use derivative::Derivative;
 use serde::{Deserialize, Serialize};
 use std::fmt;
 
@@ -79,6 +80,28 @@ pub enum Expression {
     Err(ErrExpression),
 }
 
+impl Expression {
+    /// Returns the ID of the expression.
+    pub fn id(&self) -> NodeID {
+        use Expression::*;
+        match &self {
+            Identifier(n) => n.id,
+            Value(n) => n.id(),
+            Binary(n) => n.id,
+            Unary(n) => n.id,
+            Ternary(n) => n.id,
+            ArrayInline(n) => n.id,
+            ArrayInit(n) => n.id,
+            TupleInit(n) => n.id,
+            CircuitInit(n) => n.id,
+            Call(n) => n.id,
+            Cast(n) => n.id,
+            Access(n) => n.id(),
+            Err(n) => n.id,
+        }
+    }
+}
+
 impl Node for Expression {
     fn span(&self) -> &Span {
         use Expression::*;
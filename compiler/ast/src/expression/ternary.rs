@@ -17,7 +17,8 @@
 use super::*;
 
 /// A ternary conditional expression, that is, `condition ? if_true : if_false`.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Derivative, Debug, Clone, Eq, Serialize, Deserialize)]
+#[derivative(PartialEq)]
 pub struct TernaryExpression {
     /// The condition determining which branch to pick.
     pub condition: Box<Expression>,
@@ -27,6 +28,9 @@ pub struct TernaryExpression {
     pub if_false: Box<Expression>,
     /// The span from `condition` to `if_false`.
     pub span: Span,
+    /// The ID of the node.
+    #[derivative(PartialEq = "ignore")]
+    pub id: NodeID,
 }
 
 impl fmt::Display for TernaryExpression {
@@ -17,13 +17,17 @@
 use super::*;
 
 /// A tuple construction expression, e.g., `(foo, false, 42)`.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Derivative, Debug, Clone, Eq, Serialize, Deserialize)]
+#[derivative(PartialEq)]
 pub struct TupleInitExpression {
     /// The elements of the tuple.
     /// In the example above, it would be `foo`, `false`, and `42`.
     pub elements: Vec<Expression>,
     /// The span from `(` to `)`.
     pub span: Span,
+    /// The ID of the node.
+    #[derivative(PartialEq = "ignore")]
+    pub id: NodeID,
 }
 
 impl fmt::Display for TupleInitExpression {
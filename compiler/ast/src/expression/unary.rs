@@ -40,7 +40,8 @@ impl AsRef<str> for UnaryOperation {
 }
 
 /// An unary expression applying an operator to an inner expression.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Derivative, Debug, Clone, Eq, Serialize, Deserialize)]
+#[derivative(PartialEq)]
 pub struct UnaryExpression {
     /// The inner expression `op` is applied to.
     pub inner: Box<Expression>,
@@ -48,6 +49,9 @@ pub struct UnaryExpression {
     pub op: UnaryOperation,
     /// The span covering `op inner`.
     pub span: Span,
+    /// The ID of the node.
+    #[derivative(PartialEq = "ignore")]
+    pub id: NodeID,
 }
 
 impl fmt::Display for UnaryExpression {
@@ -15,44 +15,82 @@
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
 use super::*;
-use crate::{Char, CharValue};
+use crate::{Char, CharValue, NodeID};
 
 /// A literal expression.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Derivative, Debug, Clone, Eq, Serialize, Deserialize)]
+#[derivative(PartialEq)]
 pub enum ValueExpression {
     // todo: deserialize values here
     /// An address literal, e.g., `aleo1qnr4dkkvkgfqph0vzc3y6z2eu975wnpz2925ntjccd5cfqxtyu8sta57j8`.
-    Address(String, #[serde(with = "leo_span::span_json")] Span),
+    Address(
+        String,
+        #[serde(with = "leo_span::span_json")] Span,
+        #[derivative(PartialEq = "ignore")]
+        #[serde(with = "crate::node_id_json")]
+        NodeID,
+    ),
     /// A boolean literal, either `true` or `false`.
-    Boolean(String, #[serde(with = "leo_span::span_json")] Span),
+    Boolean(
+        String,
+        #[serde(with = "leo_span::span_json")] Span,
+        #[derivative(PartialEq = "ignore")]
+        #[serde(with = "crate::node_id_json")]
+        NodeID,
+    ),
     /// A char literal, e.g., `'a'`, representing a single unicode code point.
     Char(CharValue),
     /// A field literal, e.g., `42field`.
     /// That is, a signed number followed by the keyword `field`.
-    Field(String, #[serde(with = "leo_span::span_json")] Span),
+    Field(
+        String,
+        #[serde(with = "leo_span::span_json")] Span,
+        #[derivative(PartialEq = "ignore")]
+        #[serde(with = "crate::node_id_json")]
+        NodeID,
+    ),
     /// A group literal, either product or affine.
     /// For example, `42group` or `(12, 52)group`.
     Group(Box<GroupValue>),
     /// A negated non-integer literal, e.g., `-4.2`.
-    Implicit(String, #[serde(with = "leo_span::span_json")] Span),
+    Implicit(
+        String,
+        #[serde(with = "leo_span::span_json")] Span,
+        #[derivative(PartialEq = "ignore")]
+        #[serde(with = "crate::node_id_json")]
+        NodeID,
+    ),
     /// An integer literal, e.g., `42`.
-    Integer(IntegerType, String, #[serde(with = "leo_span::span_json")] Span),
+    Integer(
+        IntegerType,
+        String,
+        #[serde(with = "leo_span::span_json")] Span,
+        #[derivative(PartialEq = "ignore")]
+        #[serde(with = "crate::node_id_json")]
+        NodeID,
+    ),
     /// A string literal, e.g., `"foobar"`.
-    String(Vec<Char>, #[serde(with = "leo_span::span_json")] Span),
+    String(
+        Vec<Char>,
+        #[serde(with = "leo_span::span_json")] Span,
+        #[derivative(PartialEq = "ignore")]
+        #[serde(with = "crate::node_id_json")]
+        NodeID,
+    ),
 }
 
 impl fmt::Display for ValueExpression {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         use ValueExpression::*;
         match &self {
-            Address(address, _) => write!(f, "{}", address),
-            Boolean(boolean, _) => write!(f, "{}", boolean),
+            Address(address, _, _) => write!(f, "{}", address),
+            Boolean(boolean, _, _) => write!(f, "{}", boolean),
             Char(character) => write!(f, "{}", character),
-            Field(field, _) => write!(f, "{}", field),
-            Implicit(implicit, _) => write!(f, "{}", implicit),
-            Integer(type_, value, _) => write!(f, "{}{}", value, type_),
+            Field(field, _, _) => write!(f, "{}", field),
+            Implicit(implicit, _, _) => write!(f, "{}", implicit),
+            Integer(type_, value, _, _) => write!(f, "{}{}", value, type_),
             Group(group) => write!(f, "{}", group),
-            String(string, _) => {
+            String(string, _, _) => {
                 for character in string.iter() {
                     write!(f, "{}", character)?;
                 }
@@ -62,19 +100,39 @@ impl fmt::Display for ValueExpression {
     }
 }
 
+impl ValueExpression {
+    /// Returns the ID of the literal.
+    pub fn id(&self) -> NodeID {
+        use ValueExpression::*;
+        match &self {
+            Address(_, _, id)
+            | Boolean(_, _, id)
+            | Field(_, _, id)
+            | Implicit(_, _, id)
+            | Integer(_, _, _, id)
+            | String(_, _, id) => *id,
+            Char(character) => character.id,
+            Group(group) => match &**group {
+                GroupValue::Single(_, _, id) => *id,
+                GroupValue::Tuple(tuple) => tuple.id,
+            },
+        }
+    }
+}
+
 impl Node for ValueExpression {
     fn span(&self) -> &Span {
         use ValueExpression::*;
         match &self {
-            Address(_, span)
-            | Boolean(_, span)
-            | Field(_, span)
-            | Implicit(_, span)
-            | Integer(_, _, span)
-            | String(_, span) => span,
+            Address(_, span, _)
+            | Boolean(_, span, _)
+            | Field(_, span, _)
+            | Implicit(_, span, _)
+            | Integer(_, _, span, _)
+            | String(_, span, _) => span,
             Char(character) => &character.span,
             Group(group) => match &**group {
-                GroupValue::Single(_, span) => span,
+                GroupValue::Single(_, span, _) => span,
                 GroupValue::Tuple(tuple) => &tuple.span,
             },
         }
@@ -83,15 +141,15 @@ impl Node for ValueExpression {
     fn set_span(&mut self, new_span: Span) {
         use ValueExpression::*;
         match self {
-            Address(_, span)
-            | Boolean(_, span)
-            | Field(_, span)
-            | Implicit(_, span)
-            | Integer(_, _, span)
-            | String(_, span) => *span = new_span,
+            Address(_, span, _)
+            | Boolean(_, span, _)
+            | Field(_, span, _)
+            | Implicit(_, span, _)
+            | Integer(_, _, span, _)
+            | String(_, span, _) => *span = new_span,
             Char(character) => character.span = new_span,
             Group(group) => match &mut **group {
-                GroupValue::Single(_, span) => *span = new_span,
+                GroupValue::Single(_, span, _) => *span = new_span,
                 GroupValue::Tuple(tuple) => tuple.span = new_span,
             },
         }
@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{Annotation, Block, FunctionInput, Identifier, Node, Type};
+use crate::{Annotation, Block, FunctionInput, Identifier, Node, NodeID, Type};
 use leo_span::{sym, Span, Symbol};
 
 use indexmap::IndexMap;
@@ -25,6 +25,9 @@ use std::fmt;
 /// A function definition.
 #[derive(Clone, Serialize, Deserialize)]
 pub struct Function {
+    /// The doc comments of the function, one line each, without the `///` or `/** */` markers.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub docs: Vec<String>,
     /// A map of all the annotations from their base names to the whole.
     pub annotations: IndexMap<Symbol, Annotation>,
     /// The function identifier, e.g., `foo` in `function foo(...) { ... }`.
@@ -38,10 +41,14 @@ pub struct Function {
     /// Any mapping to the core library.
     /// Always `None` when initially parsed.
     pub core_mapping: Cell<Option<Symbol>>,
-    /// The body of the function.
-    pub block: Block,
+    /// The body of the function, or `None` if the function is native,
+    /// i.e., declared as `function foo(...);` in the standard library.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub block: Option<Block>,
     /// The entire span of the function definition.
     pub span: Span,
+    /// The ID of the node.
+    pub id: NodeID,
 }
 
 impl PartialEq for Function {
@@ -58,6 +65,11 @@ impl Function {
         self.identifier.name
     }
 
+    /// Returns `true` if the function is native, i.e., has no body.
+    pub fn is_native(&self) -> bool {
+        self.block.is_none()
+    }
+
     /// Returns `true` if the function name is `main`.
     pub fn is_main(&self) -> bool {
         self.name() == sym::main
@@ -95,9 +107,13 @@ impl Function {
         let parameters = self.input.iter().map(|x| x.to_string()).collect::<Vec<_>>().join(",");
         let returns = self.output.as_ref().map(|type_| type_.to_string());
         if returns.is_none() {
-            write!(f, "({}) {}", parameters, self.block)
+            write!(f, "({})", parameters)?;
         } else {
-            write!(f, "({}) -> {} {}", parameters, returns.unwrap(), self.block)
+            write!(f, "({}) -> {}", parameters, returns.unwrap())?;
+        }
+        match &self.block {
+            Some(block) => write!(f, " {}", block),
+            None => write!(f, ";"),
         }
     }
 }
@@ -14,17 +14,25 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::groups::GroupCoordinate;
+use crate::{groups::GroupCoordinate, NodeID};
 use leo_span::Span;
 
+use derivative::Derivative;
 use serde::{Deserialize, Serialize};
 use std::fmt;
 
 /// A group literal.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Derivative, Debug, Clone, Eq, Serialize, Deserialize)]
+#[derivative(PartialEq)]
 pub enum GroupValue {
     /// Product group literal, e.g., `42group`.
-    Single(String, #[serde(with = "leo_span::span_json")] Span),
+    Single(
+        String,
+        #[serde(with = "leo_span::span_json")] Span,
+        #[derivative(PartialEq = "ignore")]
+        #[serde(with = "crate::node_id_json")]
+        NodeID,
+    ),
     /// An affine group literal with (x, y) coordinates.
     Tuple(GroupTuple),
 }
@@ -32,14 +40,14 @@ pub enum GroupValue {
 impl GroupValue {
     pub fn set_span(&mut self, new_span: Span) {
         match self {
-            GroupValue::Single(_, old_span) => *old_span = new_span,
+            GroupValue::Single(_, old_span, _) => *old_span = new_span,
             GroupValue::Tuple(tuple) => tuple.span = new_span,
         }
     }
 
     pub fn span(&self) -> &Span {
         match self {
-            GroupValue::Single(_, span) => span,
+            GroupValue::Single(_, span, _) => span,
             GroupValue::Tuple(tuple) => &tuple.span,
         }
     }
@@ -48,14 +56,15 @@ impl GroupValue {
 impl fmt::Display for GroupValue {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
-            GroupValue::Single(string, _) => write!(f, "{}", string),
+            GroupValue::Single(string, _, _) => write!(f, "{}", string),
             GroupValue::Tuple(tuple) => write!(f, "{}", tuple),
         }
     }
 }
 
 /// An affine group literal, e.g., `(42, 24)group`.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Derivative, Debug, Clone, Eq, Serialize, Deserialize)]
+#[derivative(PartialEq)]
 pub struct GroupTuple {
     /// The left component of the type, e.g., `42` in the case above.
     pub x: GroupCoordinate,
@@ -63,6 +72,9 @@ pub struct GroupTuple {
     pub y: GroupCoordinate,
     /// The span from `(` to `)`.
     pub span: Span,
+    /// The ID of the node.
+    #[derivative(PartialEq = "ignore")]
+    pub id: NodeID,
 }
 
 impl fmt::Display for GroupTuple {
@@ -14,19 +14,24 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::Identifier;
+use crate::{Identifier, NodeID};
 use leo_span::{Span, Symbol};
 
+use derivative::Derivative;
 use serde::{Deserialize, Serialize};
 use std::fmt;
 
 /// Represents an import statement in a Leo program.
-#[derive(Clone, Eq, PartialEq, Serialize, Deserialize)]
+#[derive(Derivative, Clone, Eq, Serialize, Deserialize)]
+#[derivative(PartialEq)]
 pub struct ImportStatement {
     /// The tree specifying what items or packages to import.
     pub tree: ImportTree,
     /// The span, excluding the `;`.
     pub span: Span,
+    /// The ID of the node.
+    #[derivative(PartialEq = "ignore")]
+    pub id: NodeID,
 }
 
 impl ImportStatement {
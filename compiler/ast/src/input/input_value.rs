@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{CharValue, Expression, GroupValue, IntegerType, Node, SpreadOrExpression, Type, ValueExpression};
+use crate::{CharValue, Expression, GroupValue, IntegerType, Node, NodeID, SpreadOrExpression, Type, ValueExpression};
 use leo_errors::{InputError, LeoError, ParserError, Result};
 
 use serde::{Deserialize, Serialize};
@@ -38,25 +38,25 @@ impl TryFrom<(Type, Expression)> for InputValue {
         Ok(match value {
             (type_, Expression::Value(value)) => {
                 match (type_, value) {
-                    (Type::Address, ValueExpression::Address(value, _)) => Self::Address(value),
-                    (Type::Boolean, ValueExpression::Boolean(value, span)) => {
+                    (Type::Address, ValueExpression::Address(value, _, _)) => Self::Address(value),
+                    (Type::Boolean, ValueExpression::Boolean(value, span, _)) => {
                         let bool_value = value.parse::<bool>().map_err(|_| ParserError::unexpected_eof(&span))?; // TODO: change error
                         Self::Boolean(bool_value)
                     }
                     (Type::Char, ValueExpression::Char(value)) => Self::Char(value),
-                    (Type::Field, ValueExpression::Field(value, _) | ValueExpression::Implicit(value, _)) => {
+                    (Type::Field, ValueExpression::Field(value, _, _) | ValueExpression::Implicit(value, _, _)) => {
                         Self::Field(value)
                     }
                     (Type::Group, ValueExpression::Group(value)) => Self::Group(*value),
-                    (Type::IntegerType(type_), ValueExpression::Implicit(value, _)) => Self::Integer(type_, value),
-                    (Type::IntegerType(expected), ValueExpression::Integer(actual, value, span)) => {
+                    (Type::IntegerType(type_), ValueExpression::Implicit(value, _, _)) => Self::Integer(type_, value),
+                    (Type::IntegerType(expected), ValueExpression::Integer(actual, value, span, _)) => {
                         if expected == actual {
                             Self::Integer(expected, value)
                         } else {
                             return Err(InputError::unexpected_type(expected.to_string(), actual, &span).into());
                         }
                     }
-                    (Type::Array(type_, _), ValueExpression::String(string, span)) => {
+                    (Type::Array(type_, _), ValueExpression::String(string, span, _)) => {
                         if !matches!(*type_, Type::Char) {
                             return Err(InputError::string_is_array_of_chars(type_, &span).into());
                         }
@@ -68,6 +68,7 @@ impl TryFrom<(Type, Expression)> for InputValue {
                                     Self::Char(CharValue {
                                         character: c,
                                         span: span.clone(),
+                                        id: NodeID::DUMMY,
                                     })
                                 })
                                 .collect(),
@@ -70,9 +70,27 @@ pub use self::statements::*;
 pub mod types;
 pub use self::types::*;
 
+pub mod visitor;
+pub use self::visitor::*;
+
 mod node;
 pub use node::*;
 
+mod node_tables;
+pub use node_tables::*;
+
+pub mod printer;
+pub use printer::*;
+
+pub mod diff;
+pub use diff::*;
+
+pub mod doc;
+pub use doc::*;
+
+mod schema;
+pub use schema::AST_SCHEMA_VERSION;
+
 use leo_errors::{AstError, Result};
 
 /// The abstract syntax tree (AST) for a Leo program.
@@ -99,29 +117,28 @@ impl Ast {
         self.ast
     }
 
-    /// Serializes the ast into a JSON string.
+    /// Serializes the ast into a JSON string of the versioned schema, see [`AST_SCHEMA_VERSION`].
     pub fn to_json_string(&self) -> Result<String> {
-        Ok(serde_json::to_string_pretty(&self.ast).map_err(|e| AstError::failed_to_convert_ast_to_json_string(&e))?)
+        Ok(serde_json::to_string_pretty(&self.to_schema_value()?)
+            .map_err(|e| AstError::failed_to_convert_ast_to_json_string(&e))?)
     }
 
-    // Converts the ast into a JSON value.
-    // Note that there is no corresponding `from_json_value` function
-    // since we modify JSON values leaving them unable to be converted
-    // back into Programs.
+    /// Converts the ast into a JSON value of the versioned schema, see [`AST_SCHEMA_VERSION`].
     pub fn to_json_value(&self) -> Result<serde_json::Value> {
-        Ok(serde_json::to_value(&self.ast).map_err(|e| AstError::failed_to_convert_ast_to_json_value(&e))?)
+        self.to_schema_value()
     }
 
-    /// Serializes the ast into a JSON file.
+    /// Serializes the ast into a JSON file of the versioned schema, see [`AST_SCHEMA_VERSION`].
     pub fn to_json_file(&self, mut path: std::path::PathBuf, file_name: &str) -> Result<()> {
         path.push(file_name);
         let file = std::fs::File::create(&path).map_err(|e| AstError::failed_to_create_ast_json_file(&path, &e))?;
         let writer = std::io::BufWriter::new(file);
-        Ok(serde_json::to_writer_pretty(writer, &self.ast)
+        Ok(serde_json::to_writer_pretty(writer, &self.to_schema_value()?)
             .map_err(|e| AstError::failed_to_write_ast_to_json_file(&path, &e))?)
     }
 
-    /// Serializes the ast into a JSON value and removes keys from object mappings before writing to a file.
+    /// Serializes the program of the ast into a JSON value and removes keys from object mappings before writing to a file.
+    /// The file is meant for comparing ASTs in tests, and cannot be read back.
     pub fn to_json_file_without_keys(
         &self,
         mut path: std::path::PathBuf,
@@ -132,7 +149,8 @@ impl Ast {
         let file = std::fs::File::create(&path).map_err(|e| AstError::failed_to_create_ast_json_file(&path, &e))?;
         let writer = std::io::BufWriter::new(file);
 
-        let mut value = self.to_json_value().unwrap();
+        let mut value =
+            serde_json::to_value(&self.ast).map_err(|e| AstError::failed_to_convert_ast_to_json_value(&e))?;
         for key in excluded_keys {
             value = remove_key_from_json(value, key);
         }
@@ -142,10 +160,21 @@ impl Ast {
             .map_err(|e| AstError::failed_to_write_ast_to_json_file(&path, &e))?)
     }
 
-    /// Deserializes the JSON string into a ast.
+    /// Prints the program of the ast as Leo code into a file, see [`print_program`].
+    pub fn to_leo_file(&self, mut path: std::path::PathBuf, file_name: &str) -> Result<()> {
+        path.push(file_name);
+        Ok(std::fs::write(&path, print_program(&self.ast))
+            .map_err(|e| AstError::failed_to_write_ast_to_leo_file(&path, &e))?)
+    }
+
+    /// Deserializes the JSON string of the versioned schema into a ast.
     pub fn from_json_string(json: &str) -> Result<Self> {
-        let ast: Program = serde_json::from_str(json).map_err(|e| AstError::failed_to_read_json_string_to_ast(&e))?;
-        Ok(Self { ast })
+        Self::from_json_value(serde_json::from_str(json).map_err(|e| AstError::failed_to_read_json_string_to_ast(&e))?)
+    }
+
+    /// Converts the JSON value of the versioned schema into a ast.
+    pub fn from_json_value(value: serde_json::Value) -> Result<Self> {
+        Self::from_schema_value(value)
     }
 
     /// Deserializes the JSON string into a ast from a file.
@@ -14,7 +14,11 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use leo_span::Span;
+use leo_span::{symbol::with_session_globals_if_set, Span};
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use std::fmt;
 
 /// A node in the AST.
 pub trait Node:
@@ -26,3 +30,77 @@ pub trait Node:
     /// Sets the span of the node.
     fn set_span(&mut self, span: Span);
 }
+
+/// The ID of a node in the AST, unique within a session.
+///
+/// The parser gives an ID to every expression, statement, and item, so that analyses can attach
+/// information to the nodes through side tables, see [`NodeTables`](crate::NodeTables), instead of changing them.
+/// IDs are serialized as their index, and a deserialized ID is reserved in the session,
+/// so that the IDs handed out afterwards are still unique.
+///
+/// IDs never take part in the equality of nodes, as the nodes skip their `id` field when compared,
+/// so that a node equals its copy with other IDs.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct NodeID(pub u32);
+
+impl NodeID {
+    /// The ID of the nodes which were not given one, e.g., the deserialized nodes.
+    pub const DUMMY: Self = Self(0);
+
+    /// Returns a new ID, or the dummy ID if there is no session.
+    pub fn next() -> Self {
+        with_session_globals_if_set(|globals| Self(globals.next_node_id())).unwrap_or(Self::DUMMY)
+    }
+
+    /// Returns the index of the ID, which identifies its node.
+    pub fn index(self) -> u32 {
+        self.0
+    }
+
+    /// Returns whether this is the dummy ID.
+    pub fn is_dummy(self) -> bool {
+        self == Self::DUMMY
+    }
+}
+
+impl Serialize for NodeID {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u32(self.0)
+    }
+}
+
+impl<'de> Deserialize<'de> for NodeID {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let index = u32::deserialize(deserializer)?;
+        with_session_globals_if_set(|globals| globals.reserve_node_id(index));
+        Ok(Self(index))
+    }
+}
+
+impl fmt::Display for NodeID {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "#{}", self.0)
+    }
+}
+
+/// Serializes the IDs in tuple-like enum variants under the key `id`, like the IDs in structs,
+/// so that they are as easy to find and remove from the JSON AST, see [`leo_span::span_json`].
+pub mod node_id_json {
+    use super::NodeID;
+
+    use serde::{ser::SerializeMap, Deserialize, Deserializer, Serializer};
+    use std::collections::BTreeMap;
+
+    /// Serializes the ID as a mapping from `id` to the ID.
+    pub fn serialize<S: Serializer>(id: &NodeID, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(Some(1))?;
+        map.serialize_entry("id", id)?;
+        map.end()
+    }
+
+    /// Deserializes the ID from a mapping from `id` to the ID.
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<NodeID, D::Error> {
+        let mut map = BTreeMap::<String, NodeID>::deserialize(deserializer)?;
+        map.remove("id").ok_or_else(|| serde::de::Error::missing_field("id"))
+    }
+}
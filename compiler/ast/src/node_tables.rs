@@ -0,0 +1,134 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::*;
+
+use indexmap::IndexMap;
+
+/// A side table from the IDs of the nodes to some information about them.
+pub type NodeMap<T> = IndexMap<NodeID, T>;
+
+/// The side tables of a program, keyed by the IDs of its nodes.
+///
+/// The parent links are computed from the program, whereas the types and the resolved symbols
+/// are left for the analyses to fill in, so that they need not mutate nodes like [`MemberAccess::type_`].
+#[derive(Clone, Debug, Default)]
+pub struct NodeTables {
+    /// The parent of each node, i.e., the closest node with an ID containing it.
+    /// The top level items have no parent.
+    pub parents: NodeMap<NodeID>,
+    /// The type of each expression.
+    pub types: NodeMap<Type>,
+    /// The node defining the symbol which each identifier refers to, e.g., a function or a definition.
+    pub definitions: NodeMap<NodeID>,
+}
+
+impl NodeTables {
+    /// Returns the tables of `program`, with its parent links.
+    /// Nodes with the dummy ID are skipped, their children being linked to the closest node with an ID.
+    pub fn new(program: &Program) -> Self {
+        let mut collector = ParentCollector::default();
+        collector.visit_program(program);
+
+        Self {
+            parents: collector.parents,
+            ..Default::default()
+        }
+    }
+
+    /// Returns the parent of the node `id`, if any.
+    pub fn parent(&self, id: NodeID) -> Option<NodeID> {
+        self.parents.get(&id).copied()
+    }
+
+    /// Returns the ancestors of the node `id`, from its parent up to the top level item.
+    pub fn ancestors(&self, id: NodeID) -> impl Iterator<Item = NodeID> + '_ {
+        std::iter::successors(self.parent(id), move |id| self.parent(*id))
+    }
+
+    /// Returns the type of the expression `id`, if known.
+    pub fn type_of(&self, id: NodeID) -> Option<&Type> {
+        self.types.get(&id)
+    }
+
+    /// Returns the node defining the symbol which the identifier `id` refers to, if resolved.
+    pub fn definition(&self, id: NodeID) -> Option<NodeID> {
+        self.definitions.get(&id).copied()
+    }
+}
+
+/// Collects the parent links of a program.
+#[derive(Default)]
+struct ParentCollector {
+    parents: NodeMap<NodeID>,
+    /// The IDs of the nodes being visited, the innermost one last.
+    stack: Vec<NodeID>,
+}
+
+impl ParentCollector {
+    /// Links the node `id` to its parent, and visits its children through `walk`.
+    /// A node reached again through another view of it, e.g., the block of a block statement,
+    /// is not its own parent.
+    fn enter(&mut self, id: NodeID, walk: impl FnOnce(&mut Self)) {
+        if id.is_dummy() || self.stack.last() == Some(&id) {
+            return walk(self);
+        }
+        if let Some(parent) = self.stack.last() {
+            self.parents.insert(id, *parent);
+        }
+        self.stack.push(id);
+        walk(self);
+        self.stack.pop();
+    }
+}
+
+impl<'a> Visitor<'a> for ParentCollector {
+    fn visit_identifier(&mut self, identifier: &'a Identifier) {
+        self.enter(identifier.id, |_| {});
+    }
+
+    fn visit_expression(&mut self, expression: &'a Expression) {
+        self.enter(expression.id(), |v| walk_expression(v, expression));
+    }
+
+    fn visit_statement(&mut self, statement: &'a Statement) {
+        self.enter(statement.id(), |v| walk_statement(v, statement));
+    }
+
+    fn visit_definition(&mut self, definition: &'a DefinitionStatement) {
+        self.enter(definition.id, |v| walk_definition(v, definition));
+    }
+
+    fn visit_block(&mut self, block: &'a Block) {
+        self.enter(block.id, |v| walk_block(v, block));
+    }
+
+    fn visit_import_statement(&mut self, import: &'a ImportStatement) {
+        self.enter(import.id, |v| walk_import_statement(v, import));
+    }
+
+    fn visit_alias(&mut self, alias: &'a Alias) {
+        self.enter(alias.id, |v| walk_alias(v, alias));
+    }
+
+    fn visit_circuit(&mut self, circuit: &'a Circuit) {
+        self.enter(circuit.id, |v| walk_circuit(v, circuit));
+    }
+
+    fn visit_function(&mut self, function: &'a Function) {
+        self.enter(function.id, |v| walk_function(v, function));
+    }
+}
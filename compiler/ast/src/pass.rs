@@ -18,6 +18,8 @@ use crate::{Ast, Program};
 use leo_errors::Result;
 
 /// A pass consuming a `Program` and possibly returning an `Ast`.
+/// Passes emit their errors through a `Handler`, so that all of them are reported,
+/// and fail at the end if there were any.
 pub trait AstPass {
     fn do_pass(self, ast: Program) -> Result<Ast>;
 }
@@ -0,0 +1,644 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Prints the AST as Leo code.
+//!
+//! Unlike the `Display` implementations, which are meant for messages, the code printed here parses back
+//! into the same AST, up to the spans and the node IDs.
+//! Parentheses are added where the precedence of the operators requires them, and only there.
+
+use crate::*;
+
+/// The precedence of an expression, from the loosest to the tightest binding.
+/// An operand of a lower precedence than its position requires is put in parentheses.
+mod precedence {
+    pub const TERNARY: u8 = 1;
+    pub const OR: u8 = 2;
+    pub const AND: u8 = 3;
+    pub const EQUALITY: u8 = 4;
+    pub const ORDERING: u8 = 5;
+    pub const BIT_OR: u8 = 6;
+    pub const BIT_XOR: u8 = 7;
+    pub const BIT_AND: u8 = 8;
+    pub const SHIFT: u8 = 9;
+    pub const ADDITIVE: u8 = 10;
+    pub const MULTIPLICATIVE: u8 = 11;
+    pub const POW: u8 = 12;
+    pub const CAST: u8 = 13;
+    pub const UNARY: u8 = 14;
+    pub const POSTFIX: u8 = 15;
+    pub const PRIMARY: u8 = 16;
+}
+
+/// Returns the Leo code of `program`.
+/// The imported programs are not printed, only the import statements.
+pub fn print_program(program: &Program) -> String {
+    let mut printer = CodePrinter::default();
+    printer.program(program);
+    printer.out
+}
+
+/// Returns the Leo code of `statement`, without a trailing newline.
+pub fn print_statement(statement: &Statement) -> String {
+    let mut printer = CodePrinter::default();
+    printer.statement(statement);
+    printer.out
+}
+
+/// Returns the Leo code of `expression`.
+pub fn print_expression(expression: &Expression) -> String {
+    let mut printer = CodePrinter::default();
+    printer.expression(expression, 0);
+    printer.out
+}
+
+/// Returns the Leo code of `function`, including its annotations.
+pub fn print_function(function: &Function) -> String {
+    let mut printer = CodePrinter::default();
+    printer.function(function);
+    printer.out
+}
+
+/// Returns the Leo code of `type_`.
+pub fn print_type(type_: &Type) -> String {
+    let mut printer = CodePrinter::default();
+    printer.type_(type_);
+    printer.out
+}
+
+/// Returns the Leo code of the `import` statement `import`.
+pub fn print_import(import: &ImportStatement) -> String {
+    let mut printer = CodePrinter::default();
+    printer.import(import);
+    printer.out
+}
+
+/// Accumulates the code printed so far, indenting blocks by four spaces.
+#[derive(Default)]
+struct CodePrinter {
+    out: String,
+    indent: usize,
+}
+
+impl CodePrinter {
+    fn write(&mut self, text: &str) {
+        self.out.push_str(text);
+    }
+
+    /// Starts a new line at the current indentation.
+    fn newline(&mut self) {
+        self.out.push('\n');
+        for _ in 0..self.indent {
+            self.out.push_str("    ");
+        }
+    }
+
+    /// Writes `docs` as `///` comments, each followed by a new line.
+    fn docs(&mut self, docs: &[String]) {
+        for line in docs {
+            match line.is_empty() {
+                true => self.write("///"),
+                false => self.write(&format!("/// {}", line)),
+            }
+            self.newline();
+        }
+    }
+
+    /// Writes `items` with `print`, separated by `, `.
+    fn comma_list<T>(&mut self, items: &[T], mut print: impl FnMut(&mut Self, &T)) {
+        for (i, item) in items.iter().enumerate() {
+            if i > 0 {
+                self.write(", ");
+            }
+            print(self, item);
+        }
+    }
+
+    fn program(&mut self, program: &Program) {
+        let mut first = true;
+        let mut separate = |printer: &mut Self| {
+            if !std::mem::take(&mut first) {
+                printer.write("\n");
+            }
+        };
+
+        if !program.import_statements.is_empty() {
+            separate(self);
+            for import in program.import_statements.iter() {
+                self.import(import);
+                self.write("\n");
+            }
+        }
+        if !program.aliases.is_empty() {
+            separate(self);
+            for alias in program.aliases.values() {
+                self.docs(&alias.docs);
+                self.write(&format!("type {} = ", alias.name));
+                self.type_(&alias.represents);
+                self.write(";\n");
+            }
+        }
+        for circuit in program.circuits.values() {
+            separate(self);
+            self.circuit(circuit);
+            self.write("\n");
+        }
+        if !program.global_consts.is_empty() {
+            separate(self);
+            for definition in program.global_consts.values() {
+                self.definition(definition);
+                self.write("\n");
+            }
+        }
+        for function in program.functions.values() {
+            separate(self);
+            self.function(function);
+            self.write("\n");
+        }
+    }
+
+    fn import(&mut self, import: &ImportStatement) {
+        self.write("import ");
+        self.import_tree(&import.tree);
+        self.write(";");
+    }
+
+    fn import_tree(&mut self, tree: &ImportTree) {
+        let base = tree.base.iter().map(|part| part.to_string()).collect::<Vec<_>>();
+        self.write(&base.join("."));
+        match &tree.kind {
+            ImportTreeKind::Glob { .. } => self.write(".*"),
+            ImportTreeKind::Leaf { alias: None } => {}
+            ImportTreeKind::Leaf { alias: Some(alias) } => self.write(&format!(" as {}", alias)),
+            ImportTreeKind::Nested { tree } => {
+                self.write(".(");
+                self.comma_list(tree, Self::import_tree);
+                self.write(")");
+            }
+        }
+    }
+
+    fn circuit(&mut self, circuit: &Circuit) {
+        self.docs(&circuit.docs);
+        self.write(&format!("circuit {} {{", circuit.circuit_name));
+        self.indent += 1;
+        for (i, member) in circuit.members.iter().enumerate() {
+            // Functions are set apart from the members before them.
+            if i > 0 && matches!(member, CircuitMember::CircuitFunction(_)) {
+                self.write("\n");
+            }
+            self.newline();
+            match member {
+                CircuitMember::CircuitConst(name, type_, value, docs) => {
+                    self.docs(docs);
+                    self.write(&format!("static const {}: ", name));
+                    self.type_(type_);
+                    self.write(" = ");
+                    self.expression(value, 0);
+                    self.write(";");
+                }
+                CircuitMember::CircuitVariable(name, type_, docs) => {
+                    self.docs(docs);
+                    self.write(&format!("{}: ", name));
+                    self.type_(type_);
+                    self.write(",");
+                }
+                CircuitMember::CircuitFunction(function) => self.function(function),
+            }
+        }
+        self.indent -= 1;
+        if !circuit.members.is_empty() {
+            self.newline();
+        }
+        self.write("}");
+    }
+
+    fn function(&mut self, function: &Function) {
+        self.docs(&function.docs);
+        for annotation in function.annotations.values() {
+            self.write(&format!("@{}", annotation.name));
+            if !annotation.arguments.is_empty() {
+                let arguments = annotation.arguments.iter().map(|x| x.to_string()).collect::<Vec<_>>();
+                self.write(&format!("({})", arguments.join(", ")));
+            }
+            self.newline();
+        }
+        if function.const_ {
+            self.write("const ");
+        }
+        self.write(&format!("function {}(", function.identifier));
+        self.comma_list(&function.input, |printer, input| match input {
+            FunctionInput::SelfKeyword(_) => printer.write("self"),
+            FunctionInput::ConstSelfKeyword(_) => printer.write("const self"),
+            FunctionInput::RefSelfKeyword(_) => printer.write("&self"),
+            FunctionInput::Variable(variable) => {
+                if variable.const_ {
+                    printer.write("const ");
+                }
+                printer.write(&format!("{}: ", variable.identifier));
+                printer.type_(&variable.type_);
+            }
+        });
+        self.write(")");
+        if let Some(output) = &function.output {
+            self.write(" -> ");
+            self.type_(output);
+        }
+        match &function.block {
+            Some(block) => {
+                self.write(" ");
+                self.block(block);
+            }
+            None => self.write(";"),
+        }
+    }
+
+    fn type_(&mut self, type_: &Type) {
+        match type_ {
+            Type::Array(element, dimensions) => {
+                self.write("[");
+                self.type_(element);
+                self.write(&format!("; {}]", dimensions));
+            }
+            Type::Tuple(types) => {
+                self.write("(");
+                self.comma_list(types, Self::type_);
+                self.write(")");
+            }
+            Type::Identifier(name) => self.write(&name.to_string()),
+            Type::SelfType => self.write("Self"),
+            type_ => self.write(&type_.to_string()),
+        }
+    }
+
+    fn block(&mut self, block: &Block) {
+        self.write("{");
+        self.indent += 1;
+        for statement in block.statements.iter() {
+            self.newline();
+            self.statement(statement);
+        }
+        self.indent -= 1;
+        if !block.statements.is_empty() {
+            self.newline();
+        }
+        self.write("}");
+    }
+
+    fn statement(&mut self, statement: &Statement) {
+        match statement {
+            Statement::Return(return_) => {
+                self.write("return ");
+                self.expression(&return_.expression, 0);
+                self.write(";");
+            }
+            Statement::Definition(definition) => self.definition(definition),
+            Statement::Assign(assign) => {
+                self.assignee(&assign.assignee);
+                self.write(&format!(" {} ", assign.operation.as_ref()));
+                self.expression(&assign.value, 0);
+                self.write(";");
+            }
+            Statement::Conditional(conditional) => {
+                self.write("if ");
+                self.condition(&conditional.condition);
+                self.write(" ");
+                self.block(&conditional.block);
+                if let Some(next) = &conditional.next {
+                    self.write(" else ");
+                    self.statement(next);
+                }
+            }
+            Statement::Iteration(iteration) => {
+                self.write(&format!("for {} in ", iteration.variable));
+                self.expression(&iteration.start, 0);
+                self.write(if iteration.inclusive { "..=" } else { ".." });
+                self.condition(&iteration.stop);
+                self.write(" ");
+                self.block(&iteration.block);
+            }
+            Statement::Console(console) => {
+                self.write("console.");
+                match &console.function {
+                    ConsoleFunction::Assert(expression) => {
+                        self.write("assert(");
+                        self.expression(expression, 0);
+                        self.write(")");
+                    }
+                    ConsoleFunction::Error(args) => {
+                        self.write("error");
+                        self.console_args(args);
+                    }
+                    ConsoleFunction::Log(args) => {
+                        self.write("log");
+                        self.console_args(args);
+                    }
+                }
+                self.write(";");
+            }
+            Statement::Expression(expression) => {
+                self.expression(&expression.expression, 0);
+                self.write(";");
+            }
+            Statement::Block(block) => self.block(block),
+        }
+    }
+
+    fn definition(&mut self, definition: &DefinitionStatement) {
+        self.docs(&definition.docs);
+        self.write(&format!("{} ", definition.declaration_type));
+        // The mutability of a variable follows from the declaration, so it is not printed.
+        let names = definition
+            .variable_names
+            .iter()
+            .map(|name| name.identifier.to_string())
+            .collect::<Vec<_>>();
+        if names.len() == 1 {
+            self.write(&names[0]);
+        } else {
+            self.write(&format!("({})", names.join(", ")));
+        }
+        if let Some(type_) = &definition.type_ {
+            self.write(": ");
+            self.type_(type_);
+        }
+        self.write(" = ");
+        self.expression(&definition.value, 0);
+        self.write(";");
+    }
+
+    fn assignee(&mut self, assignee: &Assignee) {
+        self.write(&assignee.identifier.to_string());
+        for access in assignee.accesses.iter() {
+            match access {
+                AssigneeAccess::ArrayRange(left, right) => self.range(left.as_ref(), right.as_ref()),
+                AssigneeAccess::ArrayIndex(index) => {
+                    self.write("[");
+                    self.expression(index, 0);
+                    self.write("]");
+                }
+                AssigneeAccess::Tuple(index, _) => self.write(&format!(".{}", index)),
+                AssigneeAccess::Member(name) => self.write(&format!(".{}", name)),
+            }
+        }
+    }
+
+    fn console_args(&mut self, args: &ConsoleArgs) {
+        self.write("(");
+        self.string(&args.string);
+        for parameter in args.parameters.iter() {
+            self.write(", ");
+            self.expression(parameter, 0);
+        }
+        self.write(")");
+    }
+
+    /// Writes the condition of an `if` or the end of a `for` range, which are followed by a block.
+    /// A circuit initialization there would be read as the block, so the condition is then put in parentheses.
+    fn condition(&mut self, condition: &Expression) {
+        let mut finder = CircuitInitFinder(false);
+        finder.visit_expression(condition);
+        if finder.0 {
+            self.write("(");
+            self.expression(condition, 0);
+            self.write(")");
+        } else {
+            self.expression(condition, 0);
+        }
+    }
+
+    /// Writes `expression`, in parentheses if it binds looser than `min_precedence`.
+    fn expression(&mut self, expression: &Expression, min_precedence: u8) {
+        let parenthesize = expression_precedence(expression) < min_precedence;
+        if parenthesize {
+            self.write("(");
+        }
+
+        match expression {
+            Expression::Identifier(identifier) => self.write(&identifier.to_string()),
+            Expression::Value(value) => self.value(value),
+            Expression::Binary(binary) => {
+                let precedence = binary_precedence(binary.op);
+                let (left, right) = match binary.op {
+                    // `**` is right associative, and its operands are casts.
+                    BinaryOperation::Pow => (precedence::CAST, precedence),
+                    // Equalities do not chain.
+                    BinaryOperation::Eq | BinaryOperation::Ne => (precedence + 1, precedence + 1),
+                    _ => (precedence, precedence + 1),
+                };
+                self.expression(&binary.left, left);
+                self.write(&format!(" {} ", binary.op.as_ref()));
+                self.expression(&binary.right, right);
+            }
+            Expression::Unary(unary) => {
+                self.write(unary.op.as_ref());
+                self.expression(&unary.inner, precedence::UNARY);
+            }
+            Expression::Ternary(ternary) => {
+                self.expression(&ternary.condition, precedence::OR);
+                self.write(" ? ");
+                self.expression(&ternary.if_true, 0);
+                self.write(" : ");
+                self.expression(&ternary.if_false, precedence::TERNARY);
+            }
+            Expression::Cast(cast) => {
+                self.expression(&cast.inner, precedence::CAST);
+                self.write(" as ");
+                self.type_(&cast.target_type);
+            }
+            Expression::Access(access) => match access {
+                AccessExpression::Array(access) => {
+                    self.expression(&access.array, precedence::POSTFIX);
+                    self.write("[");
+                    self.expression(&access.index, 0);
+                    self.write("]");
+                }
+                AccessExpression::ArrayRange(access) => {
+                    self.expression(&access.array, precedence::POSTFIX);
+                    self.range(access.left.as_deref(), access.right.as_deref());
+                }
+                AccessExpression::Member(access) => {
+                    self.expression(&access.inner, precedence::POSTFIX);
+                    self.write(&format!(".{}", access.name));
+                }
+                AccessExpression::Tuple(access) => {
+                    self.expression(&access.tuple, precedence::POSTFIX);
+                    self.write(&format!(".{}", access.index));
+                }
+                AccessExpression::Static(access) => {
+                    self.expression(&access.inner, precedence::POSTFIX);
+                    self.write(&format!("::{}", access.name));
+                }
+            },
+            Expression::ArrayInline(array) => {
+                self.write("[");
+                self.comma_list(&array.elements, |printer, element| match element {
+                    SpreadOrExpression::Spread(expression) => {
+                        printer.write("...");
+                        printer.expression(expression, 0);
+                    }
+                    SpreadOrExpression::Expression(expression) => printer.expression(expression, 0),
+                });
+                self.write("]");
+            }
+            Expression::ArrayInit(array) => {
+                self.write("[");
+                self.expression(&array.element, 0);
+                self.write(&format!("; {}]", array.dimensions));
+            }
+            Expression::TupleInit(tuple) => {
+                self.write("(");
+                self.comma_list(&tuple.elements, |printer, element| printer.expression(element, 0));
+                self.write(")");
+            }
+            Expression::CircuitInit(circuit) => {
+                self.write(&format!("{} {{", circuit.name));
+                if !circuit.members.is_empty() {
+                    self.write(" ");
+                    self.comma_list(&circuit.members, |printer, member| {
+                        printer.write(&member.identifier.to_string());
+                        if let Some(expression) = &member.expression {
+                            printer.write(": ");
+                            printer.expression(expression, 0);
+                        }
+                    });
+                    self.write(" ");
+                }
+                self.write("}");
+            }
+            Expression::Call(call) => {
+                self.expression(&call.function, precedence::POSTFIX);
+                self.write("(");
+                self.comma_list(&call.arguments, |printer, argument| printer.expression(argument, 0));
+                self.write(")");
+            }
+            Expression::Err(err) => self.write(&err.to_string()),
+        }
+
+        if parenthesize {
+            self.write(")");
+        }
+    }
+
+    /// Writes the range `[left..right]` of an array access or assignee.
+    fn range(&mut self, left: Option<&Expression>, right: Option<&Expression>) {
+        self.write("[");
+        if let Some(left) = left {
+            self.expression(left, 0);
+        }
+        self.write("..");
+        if let Some(right) = right {
+            self.expression(right, 0);
+        }
+        self.write("]");
+    }
+
+    fn value(&mut self, value: &ValueExpression) {
+        match value {
+            ValueExpression::Address(address, ..) => self.write(address),
+            ValueExpression::Boolean(boolean, ..) => self.write(boolean),
+            ValueExpression::Char(character) => {
+                self.write("'");
+                self.char(&character.character);
+                self.write("'");
+            }
+            ValueExpression::Field(field, ..) => self.write(&format!("{}field", field)),
+            ValueExpression::Group(group) => match &**group {
+                GroupValue::Single(group, ..) => self.write(&format!("{}group", group)),
+                GroupValue::Tuple(tuple) => self.write(&format!("({}, {})group", tuple.x, tuple.y)),
+            },
+            ValueExpression::Implicit(implicit, ..) => self.write(implicit),
+            ValueExpression::Integer(type_, integer, ..) => self.write(&format!("{}{}", integer, type_)),
+            ValueExpression::String(string, ..) => self.string(string),
+        }
+    }
+
+    fn string(&mut self, string: &[Char]) {
+        self.write("\"");
+        for character in string {
+            self.char(character);
+        }
+        self.write("\"");
+    }
+
+    /// Writes `character` as it appears within a char or string literal, escaping it if needed.
+    fn char(&mut self, character: &Char) {
+        let escaped = match character {
+            Char::Scalar('\0') => "\\0".to_string(),
+            Char::Scalar('\t') => "\\t".to_string(),
+            Char::Scalar('\n') => "\\n".to_string(),
+            Char::Scalar('\r') => "\\r".to_string(),
+            Char::Scalar('"') => "\\\"".to_string(),
+            Char::Scalar('\'') => "\\'".to_string(),
+            Char::Scalar('\\') => "\\\\".to_string(),
+            Char::Scalar(c) if c.is_ascii_control() => format!("\\x{:02x}", *c as u32),
+            Char::Scalar(c) if c.is_control() => format!("\\u{{{:x}}}", *c as u32),
+            Char::Scalar(c) => c.to_string(),
+            Char::NonScalar(c) => format!("\\u{{{:x}}}", c),
+        };
+        self.write(&escaped);
+    }
+}
+
+/// Returns the precedence of the operator of `expression`.
+fn expression_precedence(expression: &Expression) -> u8 {
+    match expression {
+        Expression::Ternary(_) => precedence::TERNARY,
+        Expression::Binary(binary) => binary_precedence(binary.op),
+        Expression::Cast(_) => precedence::CAST,
+        Expression::Unary(_) => precedence::UNARY,
+        Expression::Access(_) | Expression::Call(_) => precedence::POSTFIX,
+        // Negative literals, which the passes may create, read as a negation.
+        Expression::Value(value) if value_is_negative(value) => precedence::UNARY,
+        _ => precedence::PRIMARY,
+    }
+}
+
+fn binary_precedence(op: BinaryOperation) -> u8 {
+    use BinaryOperation::*;
+    match op {
+        Or => precedence::OR,
+        And => precedence::AND,
+        Eq | Ne => precedence::EQUALITY,
+        Lt | Le | Gt | Ge => precedence::ORDERING,
+        BitOr => precedence::BIT_OR,
+        BitXor => precedence::BIT_XOR,
+        BitAnd => precedence::BIT_AND,
+        Shl | Shr | ShrSigned => precedence::SHIFT,
+        Add | Sub => precedence::ADDITIVE,
+        Mul | Div | Mod => precedence::MULTIPLICATIVE,
+        Pow => precedence::POW,
+    }
+}
+
+fn value_is_negative(value: &ValueExpression) -> bool {
+    match value {
+        ValueExpression::Field(value, ..)
+        | ValueExpression::Implicit(value, ..)
+        | ValueExpression::Integer(_, value, ..) => value.starts_with('-'),
+        ValueExpression::Group(group) => matches!(&**group, GroupValue::Single(value, ..) if value.starts_with('-')),
+        _ => false,
+    }
+}
+
+/// Finds whether an expression contains a circuit initialization.
+struct CircuitInitFinder(bool);
+
+impl<'a> Visitor<'a> for CircuitInitFinder {
+    fn visit_circuit_init(&mut self, _circuit_init: &'a CircuitInitExpression) {
+        self.0 = true;
+    }
+}
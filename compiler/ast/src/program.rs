@@ -58,31 +58,7 @@ impl AsRef<Program> for Program {
 
 impl fmt::Display for Program {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        for import in self.import_statements.iter() {
-            import.fmt(f)?;
-            writeln!(f,)?;
-        }
-        writeln!(f,)?;
-        for (_, alias) in self.aliases.iter() {
-            alias.fmt(f)?;
-            writeln!(f,)?;
-        }
-        writeln!(f,)?;
-        for (_, import) in self.imports.iter() {
-            import.fmt(f)?;
-            writeln!(f,)?;
-        }
-        writeln!(f,)?;
-        for (_, circuit) in self.circuits.iter() {
-            circuit.fmt(f)?;
-            writeln!(f,)?;
-        }
-        writeln!(f,)?;
-        for (_, function) in self.functions.iter() {
-            function.fmt(f)?;
-            writeln!(f,)?;
-        }
-        write!(f, "")
+        f.write_str(&crate::print_program(self))
     }
 }
 
@@ -101,18 +77,20 @@ impl Program {
         }
     }
 
-    /// Handles all internal annotations like `@CoreFunction` and `@AlwaysConst`.
+    /// Handles all internal annotations like `@CoreFunction` and `@AlwaysConst`,
+    /// on both global functions and circuit functions.
     pub fn handle_internal_annotations(&mut self) {
         self.circuits
             .iter_mut()
             .flat_map(|(_, circuit)| &mut circuit.members)
             .filter_map(|member| {
                 if let CircuitMember::CircuitFunction(function) = member {
-                    Some(function)
+                    Some(&mut **function)
                 } else {
                     None
                 }
             })
+            .chain(self.functions.values_mut())
             .for_each(|function| {
                 function.annotations.retain(|name, core_map| {
                     match *name {
@@ -93,8 +93,8 @@ impl<R: ReconstructingReducer> ReconstructingDirector<R> {
         self.reducer.reduce_group_value(group_value, new)
     }
 
-    pub fn reduce_string(&mut self, string: &[Char], span: &Span) -> Result<Expression> {
-        self.reducer.reduce_string(string, span)
+    pub fn reduce_string(&mut self, string: &[Char], span: &Span, id: NodeID) -> Result<Expression> {
+        self.reducer.reduce_string(string, span, id)
     }
 
     pub fn reduce_value(&mut self, value: &ValueExpression) -> Result<Expression> {
@@ -102,7 +102,7 @@ impl<R: ReconstructingReducer> ReconstructingDirector<R> {
             ValueExpression::Group(group_value) => {
                 Expression::Value(ValueExpression::Group(Box::new(self.reduce_group_value(group_value)?)))
             }
-            ValueExpression::String(string, span) => self.reduce_string(string, span)?,
+            ValueExpression::String(string, span, id) => self.reduce_string(string, span, *id)?,
             _ => Expression::Value(value.clone()),
         };
 
@@ -439,9 +439,11 @@ impl<R: ReconstructingReducer> ReconstructingDirector<R> {
             aliases.insert(
                 name.clone(),
                 Alias {
+                    docs: alias.docs.clone(),
                     name: alias.name.clone(),
                     span: alias.span.clone(),
                     represents,
+                    id: alias.id,
                 },
             );
         }
@@ -533,14 +535,16 @@ impl<R: ReconstructingReducer> ReconstructingDirector<R> {
 
     pub fn reduce_circuit_member(&mut self, circuit_member: &CircuitMember) -> Result<CircuitMember> {
         let new = match circuit_member {
-            CircuitMember::CircuitConst(identifier, type_, value) => CircuitMember::CircuitConst(
+            CircuitMember::CircuitConst(identifier, type_, value, docs) => CircuitMember::CircuitConst(
                 self.reduce_identifier(identifier)?,
                 self.reduce_type(type_, &identifier.span)?,
                 self.reduce_expression(value)?,
+                docs.clone(),
             ),
-            CircuitMember::CircuitVariable(identifier, type_) => CircuitMember::CircuitVariable(
+            CircuitMember::CircuitVariable(identifier, type_, docs) => CircuitMember::CircuitVariable(
                 self.reduce_identifier(identifier)?,
                 self.reduce_type(type_, &identifier.span)?,
+                docs.clone(),
             ),
             CircuitMember::CircuitFunction(function) => {
                 CircuitMember::CircuitFunction(Box::new(self.reduce_function(function)?))
@@ -586,7 +590,11 @@ impl<R: ReconstructingReducer> ReconstructingDirector<R> {
             .map(|type_| self.reduce_type(type_, &function.span))
             .transpose()?;
 
-        let block = self.reduce_block(&function.block)?;
+        let block = function
+            .block
+            .as_ref()
+            .map(|block| self.reduce_block(block))
+            .transpose()?;
 
         self.reducer.reduce_function(
             function,
@@ -44,6 +44,7 @@ pub trait ReconstructingReducer {
         Ok(Identifier {
             name: identifier.name,
             span: identifier.span.clone(),
+            id: identifier.id,
         })
     }
 
@@ -52,6 +53,7 @@ pub trait ReconstructingReducer {
             x: group_tuple.x.clone(),
             y: group_tuple.y.clone(),
             span: group_tuple.span.clone(),
+            id: group_tuple.id,
         })
     }
 
@@ -59,10 +61,11 @@ pub trait ReconstructingReducer {
         Ok(new)
     }
 
-    fn reduce_string(&mut self, string: &[Char], span: &Span) -> Result<Expression> {
+    fn reduce_string(&mut self, string: &[Char], span: &Span, id: NodeID) -> Result<Expression> {
         Ok(Expression::Value(ValueExpression::String(
             string.to_vec(),
             span.clone(),
+            id,
         )))
     }
 
@@ -82,6 +85,7 @@ pub trait ReconstructingReducer {
             right: Box::new(right),
             op,
             span: binary.span.clone(),
+            id: binary.id,
         })
     }
 
@@ -95,6 +99,7 @@ pub trait ReconstructingReducer {
             inner: Box::new(inner),
             op,
             span: unary.span.clone(),
+            id: unary.id,
         })
     }
 
@@ -110,6 +115,7 @@ pub trait ReconstructingReducer {
             if_true: Box::new(if_true),
             if_false: Box::new(if_false),
             span: ternary.span.clone(),
+            id: ternary.id,
         })
     }
 
@@ -118,6 +124,7 @@ pub trait ReconstructingReducer {
             inner: Box::new(inner),
             target_type,
             span: cast.span.clone(),
+            id: cast.id,
         })
     }
 
@@ -131,6 +138,7 @@ pub trait ReconstructingReducer {
             array: Box::new(array),
             index: Box::new(index),
             span: array_access.span.clone(),
+            id: array_access.id,
         })
     }
 
@@ -146,6 +154,7 @@ pub trait ReconstructingReducer {
             left: left.map(|expr| Box::new(expr)),
             right: right.map(|expr| Box::new(expr)),
             span: array_rage_access.span.clone(),
+            id: array_rage_access.id,
         })
     }
 
@@ -161,6 +170,7 @@ pub trait ReconstructingReducer {
             name,
             span: member_access.span.clone(),
             type_,
+            id: member_access.id,
         })
     }
 
@@ -169,6 +179,7 @@ pub trait ReconstructingReducer {
             tuple: Box::new(tuple),
             index: tuple_access.index.clone(),
             span: tuple_access.span.clone(),
+            id: tuple_access.id,
         })
     }
 
@@ -184,6 +195,7 @@ pub trait ReconstructingReducer {
             name,
             type_,
             span: static_access.span.clone(),
+            id: static_access.id,
         })
     }
 
@@ -195,6 +207,7 @@ pub trait ReconstructingReducer {
         Ok(ArrayInlineExpression {
             elements,
             span: array_inline.span.clone(),
+            id: array_inline.id,
         })
     }
 
@@ -207,6 +220,7 @@ pub trait ReconstructingReducer {
             element: Box::new(element),
             dimensions: array_init.dimensions.clone(),
             span: array_init.span.clone(),
+            id: array_init.id,
         })
     }
 
@@ -218,6 +232,7 @@ pub trait ReconstructingReducer {
         Ok(TupleInitExpression {
             elements,
             span: tuple_init.span.clone(),
+            id: tuple_init.id,
         })
     }
 
@@ -240,6 +255,7 @@ pub trait ReconstructingReducer {
             name,
             members,
             span: circuit_init.span.clone(),
+            id: circuit_init.id,
         })
     }
 
@@ -253,6 +269,7 @@ pub trait ReconstructingReducer {
             function: Box::new(function),
             arguments,
             span: call.span.clone(),
+            id: call.id,
         })
     }
 
@@ -265,6 +282,7 @@ pub trait ReconstructingReducer {
         Ok(ReturnStatement {
             expression,
             span: return_statement.span.clone(),
+            id: return_statement.id,
         })
     }
 
@@ -288,7 +306,9 @@ pub trait ReconstructingReducer {
             variable_names,
             type_,
             value,
+            docs: definition.docs.clone(),
             span: definition.span.clone(),
+            id: definition.id,
         })
     }
 
@@ -320,6 +340,7 @@ pub trait ReconstructingReducer {
             assignee,
             value,
             span: assign.span.clone(),
+            id: assign.id,
         })
     }
 
@@ -335,6 +356,7 @@ pub trait ReconstructingReducer {
             block,
             next: statement.map(|statement| Box::new(statement)),
             span: conditional.span.clone(),
+            id: conditional.id,
         })
     }
 
@@ -353,6 +375,7 @@ pub trait ReconstructingReducer {
             inclusive: iteration.inclusive,
             block,
             span: iteration.span.clone(),
+            id: iteration.id,
         })
     }
 
@@ -360,6 +383,7 @@ pub trait ReconstructingReducer {
         Ok(ConsoleStatement {
             function,
             span: console.span.clone(),
+            id: console.id,
         })
     }
 
@@ -371,6 +395,7 @@ pub trait ReconstructingReducer {
         Ok(ExpressionStatement {
             expression,
             span: expression_statement.span.clone(),
+            id: expression_statement.id,
         })
     }
 
@@ -378,6 +403,7 @@ pub trait ReconstructingReducer {
         Ok(Block {
             statements,
             span: block.span.clone(),
+            id: block.id,
         })
     }
 
@@ -433,6 +459,7 @@ pub trait ReconstructingReducer {
         Ok(ImportStatement {
             tree,
             span: import.span.clone(),
+            id: import.id,
         })
     }
 
@@ -446,11 +473,16 @@ pub trait ReconstructingReducer {
 
     fn reduce_circuit(
         &mut self,
-        _circuit: &Circuit,
+        circuit: &Circuit,
         circuit_name: Identifier,
         members: Vec<CircuitMember>,
     ) -> Result<Circuit> {
-        Ok(Circuit { circuit_name, members })
+        Ok(Circuit {
+            docs: circuit.docs.clone(),
+            circuit_name,
+            members,
+            id: circuit.id,
+        })
     }
 
     fn reduce_annotation(&mut self, annotation: &Annotation, name: Identifier) -> Result<Annotation> {
@@ -470,9 +502,10 @@ pub trait ReconstructingReducer {
         input: Vec<FunctionInput>,
         const_: bool,
         output: Option<Type>,
-        block: Block,
+        block: Option<Block>,
     ) -> Result<Function> {
         Ok(Function {
+            docs: function.docs.clone(),
             identifier,
             annotations,
             input,
@@ -481,6 +514,7 @@ pub trait ReconstructingReducer {
             block,
             core_mapping: function.core_mapping.clone(),
             span: function.span.clone(),
+            id: function.id,
         })
     }
 }
@@ -0,0 +1,125 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The versioned JSON schema of the AST, which [`Ast::to_json_string`] writes and [`Ast::from_json_string`] reads.
+//! See the README for its description.
+
+use crate::{Ast, Program};
+use leo_errors::{AstError, Result};
+use leo_span::symbol::with_session_globals;
+
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeSet;
+
+/// The version of the JSON schema of the AST, which changes whenever the serialization of a node does.
+pub const AST_SCHEMA_VERSION: u32 = 4;
+
+/// A source file which the spans of a JSON AST point into.
+#[derive(Deserialize, Serialize)]
+struct SourceJson {
+    path: String,
+    source: String,
+}
+
+/// A JSON AST to be written.
+#[derive(Serialize)]
+struct AstJson<'a> {
+    schema_version: u32,
+    sources: Vec<SourceJson>,
+    program: &'a Program,
+}
+
+/// A JSON AST being read, whose program is read once its sources are in the source map.
+#[derive(Deserialize)]
+struct AstJsonValue {
+    schema_version: u32,
+    #[serde(default)]
+    sources: Vec<SourceJson>,
+    program: serde_json::Value,
+}
+
+impl Ast {
+    /// Converts the AST into a JSON value of the current schema, along with the source files of its spans.
+    pub(crate) fn to_schema_value(&self) -> Result<serde_json::Value> {
+        let program =
+            serde_json::to_value(self.as_repr()).map_err(|e| AstError::failed_to_convert_ast_to_json_value(&e))?;
+        let mut paths = BTreeSet::new();
+        collect_span_paths(&program, &mut paths);
+        let sources = paths
+            .into_iter()
+            .filter_map(|path| {
+                let file = with_session_globals(|s| s.source_map.find_source_file_by_path(&path))?;
+                Some(SourceJson {
+                    path,
+                    source: file.src.clone(),
+                })
+            })
+            .collect();
+
+        let json = AstJson {
+            schema_version: AST_SCHEMA_VERSION,
+            sources,
+            program: self.as_repr(),
+        };
+        Ok(serde_json::to_value(&json).map_err(|e| AstError::failed_to_convert_ast_to_json_value(&e))?)
+    }
+
+    /// Reads an AST from a JSON value of the current schema.
+    /// Its source files are added to the source map, unless they are there already, so that its spans point into them.
+    pub(crate) fn from_schema_value(value: serde_json::Value) -> Result<Self> {
+        let json: AstJsonValue =
+            serde_json::from_value(value).map_err(|e| AstError::failed_to_read_json_string_to_ast(&e))?;
+        if json.schema_version != AST_SCHEMA_VERSION {
+            return Err(AstError::unsupported_ast_schema_version(json.schema_version, AST_SCHEMA_VERSION).into());
+        }
+
+        with_session_globals(|s| {
+            for SourceJson { path, source } in json.sources {
+                match s.source_map.find_source_file_by_path(&path) {
+                    Some(file) if file.src == source => {}
+                    _ => {
+                        s.source_map.new_source(&source, &path);
+                    }
+                }
+            }
+        });
+        let program =
+            Program::deserialize(json.program).map_err(|e| AstError::failed_to_read_json_string_to_ast(&e))?;
+        Ok(Self::new(program))
+    }
+}
+
+/// Collects the paths of the spans in a JSON `value`,
+/// including those in the strings which identifiers are serialized into.
+fn collect_span_paths(value: &serde_json::Value, paths: &mut BTreeSet<String>) {
+    match value {
+        serde_json::Value::Object(map) => {
+            if let (Some(serde_json::Value::String(path)), true) = (map.get("path"), map.contains_key("line_start")) {
+                if !path.is_empty() {
+                    paths.insert(path.clone());
+                }
+            }
+            map.values().for_each(|value| collect_span_paths(value, paths));
+        }
+        serde_json::Value::Array(values) => values.iter().for_each(|value| collect_span_paths(value, paths)),
+        serde_json::Value::String(string) if string.starts_with('{') => {
+            if let Ok(value) = serde_json::from_str(string) {
+                collect_span_paths(&value, paths);
+            }
+        }
+        _ => {}
+    }
+}
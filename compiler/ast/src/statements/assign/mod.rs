@@ -14,10 +14,11 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{Expression, Node};
+use crate::{Expression, Node, NodeID};
 
 use leo_span::Span;
 
+use derivative::Derivative;
 use serde::{Deserialize, Serialize};
 use std::fmt;
 
@@ -82,7 +83,8 @@ impl AsRef<str> for AssignOperation {
 }
 
 /// An assignment statement, `assignee operation? = value`.
-#[derive(Clone, PartialEq, Eq, Serialize, Deserialize, Debug)]
+#[derive(Derivative, Clone, Eq, Serialize, Deserialize, Debug)]
+#[derivative(PartialEq)]
 pub struct AssignStatement {
     /// The assignment operation.
     /// For plain assignment, use `AssignOperation::Assign`.
@@ -93,6 +95,9 @@ pub struct AssignStatement {
     pub value: Expression,
     /// The span, excluding the semicolon.
     pub span: Span,
+    /// The ID of the node.
+    #[derivative(PartialEq = "ignore")]
+    pub id: NodeID,
 }
 
 impl fmt::Display for AssignStatement {
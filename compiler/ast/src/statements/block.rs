@@ -14,19 +14,24 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{Node, Statement};
+use crate::{Node, NodeID, Statement};
 use leo_span::Span;
 
+use derivative::Derivative;
 use serde::{Deserialize, Serialize};
 use std::fmt;
 
 /// A block `{ [stmt]* }` consisting of a list of statements to execute in order.
-#[derive(Clone, PartialEq, Eq, Serialize, Deserialize, Debug)]
+#[derive(Derivative, Clone, Eq, Serialize, Deserialize, Debug)]
+#[derivative(PartialEq)]
 pub struct Block {
     /// The list of statements to execute.
     pub statements: Vec<Statement>,
     /// The span from `{` to `}`.
     pub span: Span,
+    /// The ID of the node.
+    #[derivative(PartialEq = "ignore")]
+    pub id: NodeID,
 }
 
 impl fmt::Display for Block {
@@ -14,14 +14,16 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{Block, Expression, Node, Statement};
+use crate::{Block, Expression, Node, NodeID, Statement};
 use leo_span::Span;
 
+use derivative::Derivative;
 use serde::{Deserialize, Serialize};
 use std::fmt;
 
 /// An `if condition block (else next)?` statement.
-#[derive(Clone, PartialEq, Eq, Serialize, Deserialize, Debug)]
+#[derive(Derivative, Clone, Eq, Serialize, Deserialize, Debug)]
+#[derivative(PartialEq)]
 pub struct ConditionalStatement {
     /// The `bool`-typed condition deciding what to evaluate.
     pub condition: Expression,
@@ -31,6 +33,9 @@ pub struct ConditionalStatement {
     pub next: Option<Box<Statement>>,
     /// The span from `if` to `next` or to `block`.
     pub span: Span,
+    /// The ID of the node.
+    #[derivative(PartialEq = "ignore")]
+    pub id: NodeID,
 }
 
 impl fmt::Display for ConditionalStatement {
@@ -38,7 +38,7 @@ impl fmt::Display for ConsoleFunction {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
             ConsoleFunction::Assert(assert) => write!(f, "assert({})", assert),
-            ConsoleFunction::Error(error) => write!(f, "error{})", error),
+            ConsoleFunction::Error(error) => write!(f, "error({})", error),
             ConsoleFunction::Log(log) => write!(f, "log({})", log),
         }
     }
@@ -14,19 +14,24 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{ConsoleFunction, Node};
+use crate::{ConsoleFunction, Node, NodeID};
 use leo_span::Span;
 
+use derivative::Derivative;
 use serde::{Deserialize, Serialize};
 use std::fmt;
 
 /// A console logging statement like `console.log(...);`.
-#[derive(Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Derivative, Clone, Eq, Serialize, Deserialize)]
+#[derivative(PartialEq)]
 pub struct ConsoleStatement {
     /// The logging function to run.
     pub function: ConsoleFunction,
     /// The span excluding the semicolon.
     pub span: Span,
+    /// The ID of the node.
+    #[derivative(PartialEq = "ignore")]
+    pub id: NodeID,
 }
 
 impl fmt::Display for ConsoleStatement {
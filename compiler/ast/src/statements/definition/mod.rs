@@ -14,9 +14,10 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{Expression, Node, Type};
+use crate::{Expression, Node, NodeID, Type};
 use leo_span::Span;
 
+use derivative::Derivative;
 use serde::{Deserialize, Serialize};
 use std::fmt;
 
@@ -27,7 +28,8 @@ mod declare;
 pub use declare::*;
 
 /// A `let` or `const` declaration statement.
-#[derive(Clone, PartialEq, Eq, Serialize, Deserialize, Debug)]
+#[derive(Derivative, Clone, Eq, Serialize, Deserialize, Debug)]
+#[derivative(PartialEq)]
 pub struct DefinitionStatement {
     /// What sort of declaration is this? `let` or `const`?.
     pub declaration_type: Declare,
@@ -37,8 +39,14 @@ pub struct DefinitionStatement {
     pub type_: Option<Type>,
     /// An initializer value for the bindings.
     pub value: Expression,
+    /// The doc comments of a global constant, one line each, without the `///` or `/** */` markers.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub docs: Vec<String>,
     /// The span excluding the semicolon.
     pub span: Span,
+    /// The ID of the node.
+    #[derivative(PartialEq = "ignore")]
+    pub id: NodeID,
 }
 
 impl fmt::Display for DefinitionStatement {
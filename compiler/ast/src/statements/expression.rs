@@ -14,19 +14,24 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{Expression, Node};
+use crate::{Expression, Node, NodeID};
 use leo_span::Span;
 
+use derivative::Derivative;
 use serde::{Deserialize, Serialize};
 use std::fmt;
 
 /// An expression statement `expr;`.
-#[derive(Clone, PartialEq, Eq, Serialize, Deserialize, Debug)]
+#[derive(Derivative, Clone, Eq, Serialize, Deserialize, Debug)]
+#[derivative(PartialEq)]
 pub struct ExpressionStatement {
     /// The expression to evaluate purely for its side-effects.
     pub expression: Expression,
     /// The span excluding the semicolon.
     pub span: Span,
+    /// The ID of the node.
+    #[derivative(PartialEq = "ignore")]
+    pub id: NodeID,
 }
 
 impl fmt::Display for ExpressionStatement {
@@ -0,0 +1,26 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! This module contains the [`Visitor`] and [`VisitorMut`] traits, which traverse the AST
+//! without rebuilding it, unlike the [`ReconstructingDirector`](crate::ReconstructingDirector).
+//! They suit the analyses reading the AST, e.g., linters, symbol collection, and metrics,
+//! and the passes changing a few nodes in place.
+
+pub mod visit;
+pub use visit::*;
+
+pub mod visit_mut;
+pub use visit_mut::*;
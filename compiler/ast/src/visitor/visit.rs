@@ -0,0 +1,528 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! This module contains the [`Visitor`] trait, which visits the AST without changing it,
+//! and a `walk_*` function for each node, which visits the children of the node.
+
+use crate::*;
+
+/// A visitor reading the nodes of the AST, which it can borrow for as long as the AST lives.
+/// By default, each method visits the children of its node through the `walk_*` function of the node,
+/// which an overriding method can call to keep visiting the children.
+pub trait Visitor<'a> {
+    // Types
+    fn visit_type(&mut self, type_: &'a Type) {
+        walk_type(self, type_);
+    }
+
+    fn visit_identifier(&mut self, _identifier: &'a Identifier) {}
+
+    // Expressions
+    fn visit_expression(&mut self, expression: &'a Expression) {
+        walk_expression(self, expression);
+    }
+
+    fn visit_value(&mut self, _value: &'a ValueExpression) {}
+
+    fn visit_binary(&mut self, binary: &'a BinaryExpression) {
+        walk_binary(self, binary);
+    }
+
+    fn visit_unary(&mut self, unary: &'a UnaryExpression) {
+        walk_unary(self, unary);
+    }
+
+    fn visit_ternary(&mut self, ternary: &'a TernaryExpression) {
+        walk_ternary(self, ternary);
+    }
+
+    fn visit_cast(&mut self, cast: &'a CastExpression) {
+        walk_cast(self, cast);
+    }
+
+    fn visit_access(&mut self, access: &'a AccessExpression) {
+        walk_access(self, access);
+    }
+
+    fn visit_array_access(&mut self, array_access: &'a ArrayAccess) {
+        walk_array_access(self, array_access);
+    }
+
+    fn visit_array_range_access(&mut self, array_range_access: &'a ArrayRangeAccess) {
+        walk_array_range_access(self, array_range_access);
+    }
+
+    fn visit_member_access(&mut self, member_access: &'a MemberAccess) {
+        walk_member_access(self, member_access);
+    }
+
+    fn visit_tuple_access(&mut self, tuple_access: &'a TupleAccess) {
+        walk_tuple_access(self, tuple_access);
+    }
+
+    fn visit_static_access(&mut self, static_access: &'a StaticAccess) {
+        walk_static_access(self, static_access);
+    }
+
+    fn visit_array_inline(&mut self, array_inline: &'a ArrayInlineExpression) {
+        walk_array_inline(self, array_inline);
+    }
+
+    fn visit_array_init(&mut self, array_init: &'a ArrayInitExpression) {
+        walk_array_init(self, array_init);
+    }
+
+    fn visit_tuple_init(&mut self, tuple_init: &'a TupleInitExpression) {
+        walk_tuple_init(self, tuple_init);
+    }
+
+    fn visit_circuit_init(&mut self, circuit_init: &'a CircuitInitExpression) {
+        walk_circuit_init(self, circuit_init);
+    }
+
+    fn visit_circuit_variable_initializer(&mut self, variable: &'a CircuitVariableInitializer) {
+        walk_circuit_variable_initializer(self, variable);
+    }
+
+    fn visit_call(&mut self, call: &'a CallExpression) {
+        walk_call(self, call);
+    }
+
+    // Statements
+    fn visit_statement(&mut self, statement: &'a Statement) {
+        walk_statement(self, statement);
+    }
+
+    fn visit_return(&mut self, return_statement: &'a ReturnStatement) {
+        walk_return(self, return_statement);
+    }
+
+    fn visit_definition(&mut self, definition: &'a DefinitionStatement) {
+        walk_definition(self, definition);
+    }
+
+    fn visit_variable_name(&mut self, variable_name: &'a VariableName) {
+        walk_variable_name(self, variable_name);
+    }
+
+    fn visit_assign(&mut self, assign: &'a AssignStatement) {
+        walk_assign(self, assign);
+    }
+
+    fn visit_assignee(&mut self, assignee: &'a Assignee) {
+        walk_assignee(self, assignee);
+    }
+
+    fn visit_assignee_access(&mut self, access: &'a AssigneeAccess) {
+        walk_assignee_access(self, access);
+    }
+
+    fn visit_conditional(&mut self, conditional: &'a ConditionalStatement) {
+        walk_conditional(self, conditional);
+    }
+
+    fn visit_iteration(&mut self, iteration: &'a IterationStatement) {
+        walk_iteration(self, iteration);
+    }
+
+    fn visit_console(&mut self, console: &'a ConsoleStatement) {
+        walk_console(self, console);
+    }
+
+    fn visit_console_args(&mut self, args: &'a ConsoleArgs) {
+        walk_console_args(self, args);
+    }
+
+    fn visit_expression_statement(&mut self, expression: &'a ExpressionStatement) {
+        walk_expression_statement(self, expression);
+    }
+
+    fn visit_block(&mut self, block: &'a Block) {
+        walk_block(self, block);
+    }
+
+    // Program
+    fn visit_program(&mut self, program: &'a Program) {
+        walk_program(self, program);
+    }
+
+    fn visit_import_statement(&mut self, import: &'a ImportStatement) {
+        walk_import_statement(self, import);
+    }
+
+    fn visit_import_tree(&mut self, tree: &'a ImportTree) {
+        walk_import_tree(self, tree);
+    }
+
+    fn visit_alias(&mut self, alias: &'a Alias) {
+        walk_alias(self, alias);
+    }
+
+    fn visit_circuit(&mut self, circuit: &'a Circuit) {
+        walk_circuit(self, circuit);
+    }
+
+    fn visit_circuit_member(&mut self, circuit_member: &'a CircuitMember) {
+        walk_circuit_member(self, circuit_member);
+    }
+
+    fn visit_function(&mut self, function: &'a Function) {
+        walk_function(self, function);
+    }
+
+    fn visit_function_input(&mut self, input: &'a FunctionInput) {
+        walk_function_input(self, input);
+    }
+
+    fn visit_function_input_variable(&mut self, variable: &'a FunctionInputVariable) {
+        walk_function_input_variable(self, variable);
+    }
+}
+
+// Types
+pub fn walk_type<'a, V: Visitor<'a> + ?Sized>(visitor: &mut V, type_: &'a Type) {
+    match type_ {
+        Type::Array(type_, _) => visitor.visit_type(type_),
+        Type::Tuple(types) => {
+            for type_ in types.iter() {
+                visitor.visit_type(type_);
+            }
+        }
+        Type::Identifier(identifier) => visitor.visit_identifier(identifier),
+        _ => {}
+    }
+}
+
+// Expressions
+pub fn walk_expression<'a, V: Visitor<'a> + ?Sized>(visitor: &mut V, expression: &'a Expression) {
+    match expression {
+        Expression::Identifier(identifier) => visitor.visit_identifier(identifier),
+        Expression::Value(value) => visitor.visit_value(value),
+        Expression::Binary(binary) => visitor.visit_binary(binary),
+        Expression::Unary(unary) => visitor.visit_unary(unary),
+        Expression::Ternary(ternary) => visitor.visit_ternary(ternary),
+        Expression::Cast(cast) => visitor.visit_cast(cast),
+        Expression::Access(access) => visitor.visit_access(access),
+        Expression::ArrayInline(array_inline) => visitor.visit_array_inline(array_inline),
+        Expression::ArrayInit(array_init) => visitor.visit_array_init(array_init),
+        Expression::TupleInit(tuple_init) => visitor.visit_tuple_init(tuple_init),
+        Expression::CircuitInit(circuit_init) => visitor.visit_circuit_init(circuit_init),
+        Expression::Call(call) => visitor.visit_call(call),
+        Expression::Err(_) => {}
+    }
+}
+
+pub fn walk_binary<'a, V: Visitor<'a> + ?Sized>(visitor: &mut V, binary: &'a BinaryExpression) {
+    visitor.visit_expression(&binary.left);
+    visitor.visit_expression(&binary.right);
+}
+
+pub fn walk_unary<'a, V: Visitor<'a> + ?Sized>(visitor: &mut V, unary: &'a UnaryExpression) {
+    visitor.visit_expression(&unary.inner);
+}
+
+pub fn walk_ternary<'a, V: Visitor<'a> + ?Sized>(visitor: &mut V, ternary: &'a TernaryExpression) {
+    visitor.visit_expression(&ternary.condition);
+    visitor.visit_expression(&ternary.if_true);
+    visitor.visit_expression(&ternary.if_false);
+}
+
+pub fn walk_cast<'a, V: Visitor<'a> + ?Sized>(visitor: &mut V, cast: &'a CastExpression) {
+    visitor.visit_expression(&cast.inner);
+    visitor.visit_type(&cast.target_type);
+}
+
+pub fn walk_access<'a, V: Visitor<'a> + ?Sized>(visitor: &mut V, access: &'a AccessExpression) {
+    match access {
+        AccessExpression::Array(access) => visitor.visit_array_access(access),
+        AccessExpression::ArrayRange(access) => visitor.visit_array_range_access(access),
+        AccessExpression::Member(access) => visitor.visit_member_access(access),
+        AccessExpression::Tuple(access) => visitor.visit_tuple_access(access),
+        AccessExpression::Static(access) => visitor.visit_static_access(access),
+    }
+}
+
+pub fn walk_array_access<'a, V: Visitor<'a> + ?Sized>(visitor: &mut V, array_access: &'a ArrayAccess) {
+    visitor.visit_expression(&array_access.array);
+    visitor.visit_expression(&array_access.index);
+}
+
+pub fn walk_array_range_access<'a, V: Visitor<'a> + ?Sized>(visitor: &mut V, array_range_access: &'a ArrayRangeAccess) {
+    visitor.visit_expression(&array_range_access.array);
+    if let Some(left) = &array_range_access.left {
+        visitor.visit_expression(left);
+    }
+    if let Some(right) = &array_range_access.right {
+        visitor.visit_expression(right);
+    }
+}
+
+pub fn walk_member_access<'a, V: Visitor<'a> + ?Sized>(visitor: &mut V, member_access: &'a MemberAccess) {
+    visitor.visit_expression(&member_access.inner);
+    visitor.visit_identifier(&member_access.name);
+    if let Some(type_) = &member_access.type_ {
+        visitor.visit_type(type_);
+    }
+}
+
+pub fn walk_tuple_access<'a, V: Visitor<'a> + ?Sized>(visitor: &mut V, tuple_access: &'a TupleAccess) {
+    visitor.visit_expression(&tuple_access.tuple);
+}
+
+pub fn walk_static_access<'a, V: Visitor<'a> + ?Sized>(visitor: &mut V, static_access: &'a StaticAccess) {
+    visitor.visit_expression(&static_access.inner);
+    visitor.visit_identifier(&static_access.name);
+    if let Some(type_) = &static_access.type_ {
+        visitor.visit_type(type_);
+    }
+}
+
+pub fn walk_array_inline<'a, V: Visitor<'a> + ?Sized>(visitor: &mut V, array_inline: &'a ArrayInlineExpression) {
+    for element in array_inline.elements.iter() {
+        match element {
+            SpreadOrExpression::Expression(expression) | SpreadOrExpression::Spread(expression) => {
+                visitor.visit_expression(expression)
+            }
+        }
+    }
+}
+
+pub fn walk_array_init<'a, V: Visitor<'a> + ?Sized>(visitor: &mut V, array_init: &'a ArrayInitExpression) {
+    visitor.visit_expression(&array_init.element);
+}
+
+pub fn walk_tuple_init<'a, V: Visitor<'a> + ?Sized>(visitor: &mut V, tuple_init: &'a TupleInitExpression) {
+    for element in tuple_init.elements.iter() {
+        visitor.visit_expression(element);
+    }
+}
+
+pub fn walk_circuit_init<'a, V: Visitor<'a> + ?Sized>(visitor: &mut V, circuit_init: &'a CircuitInitExpression) {
+    visitor.visit_identifier(&circuit_init.name);
+    for member in circuit_init.members.iter() {
+        visitor.visit_circuit_variable_initializer(member);
+    }
+}
+
+pub fn walk_circuit_variable_initializer<'a, V: Visitor<'a> + ?Sized>(
+    visitor: &mut V,
+    variable: &'a CircuitVariableInitializer,
+) {
+    visitor.visit_identifier(&variable.identifier);
+    if let Some(expression) = &variable.expression {
+        visitor.visit_expression(expression);
+    }
+}
+
+pub fn walk_call<'a, V: Visitor<'a> + ?Sized>(visitor: &mut V, call: &'a CallExpression) {
+    visitor.visit_expression(&call.function);
+    for argument in call.arguments.iter() {
+        visitor.visit_expression(argument);
+    }
+}
+
+// Statements
+pub fn walk_statement<'a, V: Visitor<'a> + ?Sized>(visitor: &mut V, statement: &'a Statement) {
+    match statement {
+        Statement::Return(return_statement) => visitor.visit_return(return_statement),
+        Statement::Definition(definition) => visitor.visit_definition(definition),
+        Statement::Assign(assign) => visitor.visit_assign(assign),
+        Statement::Conditional(conditional) => visitor.visit_conditional(conditional),
+        Statement::Iteration(iteration) => visitor.visit_iteration(iteration),
+        Statement::Console(console) => visitor.visit_console(console),
+        Statement::Expression(expression) => visitor.visit_expression_statement(expression),
+        Statement::Block(block) => visitor.visit_block(block),
+    }
+}
+
+pub fn walk_return<'a, V: Visitor<'a> + ?Sized>(visitor: &mut V, return_statement: &'a ReturnStatement) {
+    visitor.visit_expression(&return_statement.expression);
+}
+
+pub fn walk_definition<'a, V: Visitor<'a> + ?Sized>(visitor: &mut V, definition: &'a DefinitionStatement) {
+    for variable_name in definition.variable_names.iter() {
+        visitor.visit_variable_name(variable_name);
+    }
+    if let Some(type_) = &definition.type_ {
+        visitor.visit_type(type_);
+    }
+    visitor.visit_expression(&definition.value);
+}
+
+pub fn walk_variable_name<'a, V: Visitor<'a> + ?Sized>(visitor: &mut V, variable_name: &'a VariableName) {
+    visitor.visit_identifier(&variable_name.identifier);
+}
+
+pub fn walk_assign<'a, V: Visitor<'a> + ?Sized>(visitor: &mut V, assign: &'a AssignStatement) {
+    visitor.visit_assignee(&assign.assignee);
+    visitor.visit_expression(&assign.value);
+}
+
+pub fn walk_assignee<'a, V: Visitor<'a> + ?Sized>(visitor: &mut V, assignee: &'a Assignee) {
+    visitor.visit_identifier(&assignee.identifier);
+    for access in assignee.accesses.iter() {
+        visitor.visit_assignee_access(access);
+    }
+}
+
+pub fn walk_assignee_access<'a, V: Visitor<'a> + ?Sized>(visitor: &mut V, access: &'a AssigneeAccess) {
+    match access {
+        AssigneeAccess::ArrayRange(left, right) => {
+            if let Some(left) = left {
+                visitor.visit_expression(left);
+            }
+            if let Some(right) = right {
+                visitor.visit_expression(right);
+            }
+        }
+        AssigneeAccess::ArrayIndex(index) => visitor.visit_expression(index),
+        AssigneeAccess::Member(identifier) => visitor.visit_identifier(identifier),
+        AssigneeAccess::Tuple(..) => {}
+    }
+}
+
+pub fn walk_conditional<'a, V: Visitor<'a> + ?Sized>(visitor: &mut V, conditional: &'a ConditionalStatement) {
+    visitor.visit_expression(&conditional.condition);
+    visitor.visit_block(&conditional.block);
+    if let Some(next) = &conditional.next {
+        visitor.visit_statement(next);
+    }
+}
+
+pub fn walk_iteration<'a, V: Visitor<'a> + ?Sized>(visitor: &mut V, iteration: &'a IterationStatement) {
+    visitor.visit_identifier(&iteration.variable);
+    visitor.visit_expression(&iteration.start);
+    visitor.visit_expression(&iteration.stop);
+    visitor.visit_block(&iteration.block);
+}
+
+pub fn walk_console<'a, V: Visitor<'a> + ?Sized>(visitor: &mut V, console: &'a ConsoleStatement) {
+    match &console.function {
+        ConsoleFunction::Assert(expression) => visitor.visit_expression(expression),
+        ConsoleFunction::Error(args) | ConsoleFunction::Log(args) => visitor.visit_console_args(args),
+    }
+}
+
+pub fn walk_console_args<'a, V: Visitor<'a> + ?Sized>(visitor: &mut V, args: &'a ConsoleArgs) {
+    for parameter in args.parameters.iter() {
+        visitor.visit_expression(parameter);
+    }
+}
+
+pub fn walk_expression_statement<'a, V: Visitor<'a> + ?Sized>(visitor: &mut V, expression: &'a ExpressionStatement) {
+    visitor.visit_expression(&expression.expression);
+}
+
+pub fn walk_block<'a, V: Visitor<'a> + ?Sized>(visitor: &mut V, block: &'a Block) {
+    for statement in block.statements.iter() {
+        visitor.visit_statement(statement);
+    }
+}
+
+// Program
+pub fn walk_program<'a, V: Visitor<'a> + ?Sized>(visitor: &mut V, program: &'a Program) {
+    for input in program.expected_input.iter() {
+        visitor.visit_function_input(input);
+    }
+    for import in program.import_statements.iter() {
+        visitor.visit_import_statement(import);
+    }
+    for import in program.imports.values() {
+        visitor.visit_program(import);
+    }
+    for alias in program.aliases.values() {
+        visitor.visit_alias(alias);
+    }
+    for circuit in program.circuits.values() {
+        visitor.visit_circuit(circuit);
+    }
+    for function in program.functions.values() {
+        visitor.visit_function(function);
+    }
+    for definition in program.global_consts.values() {
+        visitor.visit_definition(definition);
+    }
+}
+
+pub fn walk_import_statement<'a, V: Visitor<'a> + ?Sized>(visitor: &mut V, import: &'a ImportStatement) {
+    visitor.visit_import_tree(&import.tree);
+}
+
+pub fn walk_import_tree<'a, V: Visitor<'a> + ?Sized>(visitor: &mut V, tree: &'a ImportTree) {
+    for identifier in tree.base.iter() {
+        visitor.visit_identifier(identifier);
+    }
+    match &tree.kind {
+        ImportTreeKind::Glob { .. } | ImportTreeKind::Leaf { alias: None } => {}
+        ImportTreeKind::Leaf { alias: Some(alias) } => visitor.visit_identifier(alias),
+        ImportTreeKind::Nested { tree } => {
+            for tree in tree.iter() {
+                visitor.visit_import_tree(tree);
+            }
+        }
+    }
+}
+
+pub fn walk_alias<'a, V: Visitor<'a> + ?Sized>(visitor: &mut V, alias: &'a Alias) {
+    visitor.visit_identifier(&alias.name);
+    visitor.visit_type(&alias.represents);
+}
+
+pub fn walk_circuit<'a, V: Visitor<'a> + ?Sized>(visitor: &mut V, circuit: &'a Circuit) {
+    visitor.visit_identifier(&circuit.circuit_name);
+    for member in circuit.members.iter() {
+        visitor.visit_circuit_member(member);
+    }
+}
+
+pub fn walk_circuit_member<'a, V: Visitor<'a> + ?Sized>(visitor: &mut V, circuit_member: &'a CircuitMember) {
+    match circuit_member {
+        CircuitMember::CircuitConst(identifier, type_, value, _) => {
+            visitor.visit_identifier(identifier);
+            visitor.visit_type(type_);
+            visitor.visit_expression(value);
+        }
+        CircuitMember::CircuitVariable(identifier, type_, _) => {
+            visitor.visit_identifier(identifier);
+            visitor.visit_type(type_);
+        }
+        CircuitMember::CircuitFunction(function) => visitor.visit_function(function),
+    }
+}
+
+pub fn walk_function<'a, V: Visitor<'a> + ?Sized>(visitor: &mut V, function: &'a Function) {
+    visitor.visit_identifier(&function.identifier);
+    for input in function.input.iter() {
+        visitor.visit_function_input(input);
+    }
+    if let Some(output) = &function.output {
+        visitor.visit_type(output);
+    }
+    if let Some(block) = &function.block {
+        visitor.visit_block(block);
+    }
+}
+
+pub fn walk_function_input<'a, V: Visitor<'a> + ?Sized>(visitor: &mut V, input: &'a FunctionInput) {
+    if let FunctionInput::Variable(variable) = input {
+        visitor.visit_function_input_variable(variable);
+    }
+}
+
+pub fn walk_function_input_variable<'a, V: Visitor<'a> + ?Sized>(visitor: &mut V, variable: &'a FunctionInputVariable) {
+    visitor.visit_identifier(&variable.identifier);
+    visitor.visit_type(&variable.type_);
+}
@@ -0,0 +1,528 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! This module contains the [`VisitorMut`] trait, which visits the AST to change it in place,
+//! and a `walk_*_mut` function for each node, which visits the children of the node.
+
+use crate::*;
+
+/// A visitor changing the nodes of the AST in place, without rebuilding the AST.
+/// By default, each method visits the children of its node through the `walk_*_mut` function of the node,
+/// which an overriding method can call to keep visiting the children.
+pub trait VisitorMut {
+    // Types
+    fn visit_type_mut(&mut self, type_: &mut Type) {
+        walk_type_mut(self, type_);
+    }
+
+    fn visit_identifier_mut(&mut self, _identifier: &mut Identifier) {}
+
+    // Expressions
+    fn visit_expression_mut(&mut self, expression: &mut Expression) {
+        walk_expression_mut(self, expression);
+    }
+
+    fn visit_value_mut(&mut self, _value: &mut ValueExpression) {}
+
+    fn visit_binary_mut(&mut self, binary: &mut BinaryExpression) {
+        walk_binary_mut(self, binary);
+    }
+
+    fn visit_unary_mut(&mut self, unary: &mut UnaryExpression) {
+        walk_unary_mut(self, unary);
+    }
+
+    fn visit_ternary_mut(&mut self, ternary: &mut TernaryExpression) {
+        walk_ternary_mut(self, ternary);
+    }
+
+    fn visit_cast_mut(&mut self, cast: &mut CastExpression) {
+        walk_cast_mut(self, cast);
+    }
+
+    fn visit_access_mut(&mut self, access: &mut AccessExpression) {
+        walk_access_mut(self, access);
+    }
+
+    fn visit_array_access_mut(&mut self, array_access: &mut ArrayAccess) {
+        walk_array_access_mut(self, array_access);
+    }
+
+    fn visit_array_range_access_mut(&mut self, array_range_access: &mut ArrayRangeAccess) {
+        walk_array_range_access_mut(self, array_range_access);
+    }
+
+    fn visit_member_access_mut(&mut self, member_access: &mut MemberAccess) {
+        walk_member_access_mut(self, member_access);
+    }
+
+    fn visit_tuple_access_mut(&mut self, tuple_access: &mut TupleAccess) {
+        walk_tuple_access_mut(self, tuple_access);
+    }
+
+    fn visit_static_access_mut(&mut self, static_access: &mut StaticAccess) {
+        walk_static_access_mut(self, static_access);
+    }
+
+    fn visit_array_inline_mut(&mut self, array_inline: &mut ArrayInlineExpression) {
+        walk_array_inline_mut(self, array_inline);
+    }
+
+    fn visit_array_init_mut(&mut self, array_init: &mut ArrayInitExpression) {
+        walk_array_init_mut(self, array_init);
+    }
+
+    fn visit_tuple_init_mut(&mut self, tuple_init: &mut TupleInitExpression) {
+        walk_tuple_init_mut(self, tuple_init);
+    }
+
+    fn visit_circuit_init_mut(&mut self, circuit_init: &mut CircuitInitExpression) {
+        walk_circuit_init_mut(self, circuit_init);
+    }
+
+    fn visit_circuit_variable_initializer_mut(&mut self, variable: &mut CircuitVariableInitializer) {
+        walk_circuit_variable_initializer_mut(self, variable);
+    }
+
+    fn visit_call_mut(&mut self, call: &mut CallExpression) {
+        walk_call_mut(self, call);
+    }
+
+    // Statements
+    fn visit_statement_mut(&mut self, statement: &mut Statement) {
+        walk_statement_mut(self, statement);
+    }
+
+    fn visit_return_mut(&mut self, return_statement: &mut ReturnStatement) {
+        walk_return_mut(self, return_statement);
+    }
+
+    fn visit_definition_mut(&mut self, definition: &mut DefinitionStatement) {
+        walk_definition_mut(self, definition);
+    }
+
+    fn visit_variable_name_mut(&mut self, variable_name: &mut VariableName) {
+        walk_variable_name_mut(self, variable_name);
+    }
+
+    fn visit_assign_mut(&mut self, assign: &mut AssignStatement) {
+        walk_assign_mut(self, assign);
+    }
+
+    fn visit_assignee_mut(&mut self, assignee: &mut Assignee) {
+        walk_assignee_mut(self, assignee);
+    }
+
+    fn visit_assignee_access_mut(&mut self, access: &mut AssigneeAccess) {
+        walk_assignee_access_mut(self, access);
+    }
+
+    fn visit_conditional_mut(&mut self, conditional: &mut ConditionalStatement) {
+        walk_conditional_mut(self, conditional);
+    }
+
+    fn visit_iteration_mut(&mut self, iteration: &mut IterationStatement) {
+        walk_iteration_mut(self, iteration);
+    }
+
+    fn visit_console_mut(&mut self, console: &mut ConsoleStatement) {
+        walk_console_mut(self, console);
+    }
+
+    fn visit_console_args_mut(&mut self, args: &mut ConsoleArgs) {
+        walk_console_args_mut(self, args);
+    }
+
+    fn visit_expression_statement_mut(&mut self, expression: &mut ExpressionStatement) {
+        walk_expression_statement_mut(self, expression);
+    }
+
+    fn visit_block_mut(&mut self, block: &mut Block) {
+        walk_block_mut(self, block);
+    }
+
+    // Program
+    fn visit_program_mut(&mut self, program: &mut Program) {
+        walk_program_mut(self, program);
+    }
+
+    fn visit_import_statement_mut(&mut self, import: &mut ImportStatement) {
+        walk_import_statement_mut(self, import);
+    }
+
+    fn visit_import_tree_mut(&mut self, tree: &mut ImportTree) {
+        walk_import_tree_mut(self, tree);
+    }
+
+    fn visit_alias_mut(&mut self, alias: &mut Alias) {
+        walk_alias_mut(self, alias);
+    }
+
+    fn visit_circuit_mut(&mut self, circuit: &mut Circuit) {
+        walk_circuit_mut(self, circuit);
+    }
+
+    fn visit_circuit_member_mut(&mut self, circuit_member: &mut CircuitMember) {
+        walk_circuit_member_mut(self, circuit_member);
+    }
+
+    fn visit_function_mut(&mut self, function: &mut Function) {
+        walk_function_mut(self, function);
+    }
+
+    fn visit_function_input_mut(&mut self, input: &mut FunctionInput) {
+        walk_function_input_mut(self, input);
+    }
+
+    fn visit_function_input_variable_mut(&mut self, variable: &mut FunctionInputVariable) {
+        walk_function_input_variable_mut(self, variable);
+    }
+}
+
+// Types
+pub fn walk_type_mut<V: VisitorMut + ?Sized>(visitor: &mut V, type_: &mut Type) {
+    match type_ {
+        Type::Array(type_, _) => visitor.visit_type_mut(type_),
+        Type::Tuple(types) => {
+            for type_ in types.iter_mut() {
+                visitor.visit_type_mut(type_);
+            }
+        }
+        Type::Identifier(identifier) => visitor.visit_identifier_mut(identifier),
+        _ => {}
+    }
+}
+
+// Expressions
+pub fn walk_expression_mut<V: VisitorMut + ?Sized>(visitor: &mut V, expression: &mut Expression) {
+    match expression {
+        Expression::Identifier(identifier) => visitor.visit_identifier_mut(identifier),
+        Expression::Value(value) => visitor.visit_value_mut(value),
+        Expression::Binary(binary) => visitor.visit_binary_mut(binary),
+        Expression::Unary(unary) => visitor.visit_unary_mut(unary),
+        Expression::Ternary(ternary) => visitor.visit_ternary_mut(ternary),
+        Expression::Cast(cast) => visitor.visit_cast_mut(cast),
+        Expression::Access(access) => visitor.visit_access_mut(access),
+        Expression::ArrayInline(array_inline) => visitor.visit_array_inline_mut(array_inline),
+        Expression::ArrayInit(array_init) => visitor.visit_array_init_mut(array_init),
+        Expression::TupleInit(tuple_init) => visitor.visit_tuple_init_mut(tuple_init),
+        Expression::CircuitInit(circuit_init) => visitor.visit_circuit_init_mut(circuit_init),
+        Expression::Call(call) => visitor.visit_call_mut(call),
+        Expression::Err(_) => {}
+    }
+}
+
+pub fn walk_binary_mut<V: VisitorMut + ?Sized>(visitor: &mut V, binary: &mut BinaryExpression) {
+    visitor.visit_expression_mut(&mut binary.left);
+    visitor.visit_expression_mut(&mut binary.right);
+}
+
+pub fn walk_unary_mut<V: VisitorMut + ?Sized>(visitor: &mut V, unary: &mut UnaryExpression) {
+    visitor.visit_expression_mut(&mut unary.inner);
+}
+
+pub fn walk_ternary_mut<V: VisitorMut + ?Sized>(visitor: &mut V, ternary: &mut TernaryExpression) {
+    visitor.visit_expression_mut(&mut ternary.condition);
+    visitor.visit_expression_mut(&mut ternary.if_true);
+    visitor.visit_expression_mut(&mut ternary.if_false);
+}
+
+pub fn walk_cast_mut<V: VisitorMut + ?Sized>(visitor: &mut V, cast: &mut CastExpression) {
+    visitor.visit_expression_mut(&mut cast.inner);
+    visitor.visit_type_mut(&mut cast.target_type);
+}
+
+pub fn walk_access_mut<V: VisitorMut + ?Sized>(visitor: &mut V, access: &mut AccessExpression) {
+    match access {
+        AccessExpression::Array(access) => visitor.visit_array_access_mut(access),
+        AccessExpression::ArrayRange(access) => visitor.visit_array_range_access_mut(access),
+        AccessExpression::Member(access) => visitor.visit_member_access_mut(access),
+        AccessExpression::Tuple(access) => visitor.visit_tuple_access_mut(access),
+        AccessExpression::Static(access) => visitor.visit_static_access_mut(access),
+    }
+}
+
+pub fn walk_array_access_mut<V: VisitorMut + ?Sized>(visitor: &mut V, array_access: &mut ArrayAccess) {
+    visitor.visit_expression_mut(&mut array_access.array);
+    visitor.visit_expression_mut(&mut array_access.index);
+}
+
+pub fn walk_array_range_access_mut<V: VisitorMut + ?Sized>(visitor: &mut V, array_range_access: &mut ArrayRangeAccess) {
+    visitor.visit_expression_mut(&mut array_range_access.array);
+    if let Some(left) = &mut array_range_access.left {
+        visitor.visit_expression_mut(left);
+    }
+    if let Some(right) = &mut array_range_access.right {
+        visitor.visit_expression_mut(right);
+    }
+}
+
+pub fn walk_member_access_mut<V: VisitorMut + ?Sized>(visitor: &mut V, member_access: &mut MemberAccess) {
+    visitor.visit_expression_mut(&mut member_access.inner);
+    visitor.visit_identifier_mut(&mut member_access.name);
+    if let Some(type_) = &mut member_access.type_ {
+        visitor.visit_type_mut(type_);
+    }
+}
+
+pub fn walk_tuple_access_mut<V: VisitorMut + ?Sized>(visitor: &mut V, tuple_access: &mut TupleAccess) {
+    visitor.visit_expression_mut(&mut tuple_access.tuple);
+}
+
+pub fn walk_static_access_mut<V: VisitorMut + ?Sized>(visitor: &mut V, static_access: &mut StaticAccess) {
+    visitor.visit_expression_mut(&mut static_access.inner);
+    visitor.visit_identifier_mut(&mut static_access.name);
+    if let Some(type_) = &mut static_access.type_ {
+        visitor.visit_type_mut(type_);
+    }
+}
+
+pub fn walk_array_inline_mut<V: VisitorMut + ?Sized>(visitor: &mut V, array_inline: &mut ArrayInlineExpression) {
+    for element in array_inline.elements.iter_mut() {
+        match element {
+            SpreadOrExpression::Expression(expression) | SpreadOrExpression::Spread(expression) => {
+                visitor.visit_expression_mut(expression)
+            }
+        }
+    }
+}
+
+pub fn walk_array_init_mut<V: VisitorMut + ?Sized>(visitor: &mut V, array_init: &mut ArrayInitExpression) {
+    visitor.visit_expression_mut(&mut array_init.element);
+}
+
+pub fn walk_tuple_init_mut<V: VisitorMut + ?Sized>(visitor: &mut V, tuple_init: &mut TupleInitExpression) {
+    for element in tuple_init.elements.iter_mut() {
+        visitor.visit_expression_mut(element);
+    }
+}
+
+pub fn walk_circuit_init_mut<V: VisitorMut + ?Sized>(visitor: &mut V, circuit_init: &mut CircuitInitExpression) {
+    visitor.visit_identifier_mut(&mut circuit_init.name);
+    for member in circuit_init.members.iter_mut() {
+        visitor.visit_circuit_variable_initializer_mut(member);
+    }
+}
+
+pub fn walk_circuit_variable_initializer_mut<V: VisitorMut + ?Sized>(
+    visitor: &mut V,
+    variable: &mut CircuitVariableInitializer,
+) {
+    visitor.visit_identifier_mut(&mut variable.identifier);
+    if let Some(expression) = &mut variable.expression {
+        visitor.visit_expression_mut(expression);
+    }
+}
+
+pub fn walk_call_mut<V: VisitorMut + ?Sized>(visitor: &mut V, call: &mut CallExpression) {
+    visitor.visit_expression_mut(&mut call.function);
+    for argument in call.arguments.iter_mut() {
+        visitor.visit_expression_mut(argument);
+    }
+}
+
+// Statements
+pub fn walk_statement_mut<V: VisitorMut + ?Sized>(visitor: &mut V, statement: &mut Statement) {
+    match statement {
+        Statement::Return(return_statement) => visitor.visit_return_mut(return_statement),
+        Statement::Definition(definition) => visitor.visit_definition_mut(definition),
+        Statement::Assign(assign) => visitor.visit_assign_mut(assign),
+        Statement::Conditional(conditional) => visitor.visit_conditional_mut(conditional),
+        Statement::Iteration(iteration) => visitor.visit_iteration_mut(iteration),
+        Statement::Console(console) => visitor.visit_console_mut(console),
+        Statement::Expression(expression) => visitor.visit_expression_statement_mut(expression),
+        Statement::Block(block) => visitor.visit_block_mut(block),
+    }
+}
+
+pub fn walk_return_mut<V: VisitorMut + ?Sized>(visitor: &mut V, return_statement: &mut ReturnStatement) {
+    visitor.visit_expression_mut(&mut return_statement.expression);
+}
+
+pub fn walk_definition_mut<V: VisitorMut + ?Sized>(visitor: &mut V, definition: &mut DefinitionStatement) {
+    for variable_name in definition.variable_names.iter_mut() {
+        visitor.visit_variable_name_mut(variable_name);
+    }
+    if let Some(type_) = &mut definition.type_ {
+        visitor.visit_type_mut(type_);
+    }
+    visitor.visit_expression_mut(&mut definition.value);
+}
+
+pub fn walk_variable_name_mut<V: VisitorMut + ?Sized>(visitor: &mut V, variable_name: &mut VariableName) {
+    visitor.visit_identifier_mut(&mut variable_name.identifier);
+}
+
+pub fn walk_assign_mut<V: VisitorMut + ?Sized>(visitor: &mut V, assign: &mut AssignStatement) {
+    visitor.visit_assignee_mut(&mut assign.assignee);
+    visitor.visit_expression_mut(&mut assign.value);
+}
+
+pub fn walk_assignee_mut<V: VisitorMut + ?Sized>(visitor: &mut V, assignee: &mut Assignee) {
+    visitor.visit_identifier_mut(&mut assignee.identifier);
+    for access in assignee.accesses.iter_mut() {
+        visitor.visit_assignee_access_mut(access);
+    }
+}
+
+pub fn walk_assignee_access_mut<V: VisitorMut + ?Sized>(visitor: &mut V, access: &mut AssigneeAccess) {
+    match access {
+        AssigneeAccess::ArrayRange(left, right) => {
+            if let Some(left) = left {
+                visitor.visit_expression_mut(left);
+            }
+            if let Some(right) = right {
+                visitor.visit_expression_mut(right);
+            }
+        }
+        AssigneeAccess::ArrayIndex(index) => visitor.visit_expression_mut(index),
+        AssigneeAccess::Member(identifier) => visitor.visit_identifier_mut(identifier),
+        AssigneeAccess::Tuple(..) => {}
+    }
+}
+
+pub fn walk_conditional_mut<V: VisitorMut + ?Sized>(visitor: &mut V, conditional: &mut ConditionalStatement) {
+    visitor.visit_expression_mut(&mut conditional.condition);
+    visitor.visit_block_mut(&mut conditional.block);
+    if let Some(next) = &mut conditional.next {
+        visitor.visit_statement_mut(next);
+    }
+}
+
+pub fn walk_iteration_mut<V: VisitorMut + ?Sized>(visitor: &mut V, iteration: &mut IterationStatement) {
+    visitor.visit_identifier_mut(&mut iteration.variable);
+    visitor.visit_expression_mut(&mut iteration.start);
+    visitor.visit_expression_mut(&mut iteration.stop);
+    visitor.visit_block_mut(&mut iteration.block);
+}
+
+pub fn walk_console_mut<V: VisitorMut + ?Sized>(visitor: &mut V, console: &mut ConsoleStatement) {
+    match &mut console.function {
+        ConsoleFunction::Assert(expression) => visitor.visit_expression_mut(expression),
+        ConsoleFunction::Error(args) | ConsoleFunction::Log(args) => visitor.visit_console_args_mut(args),
+    }
+}
+
+pub fn walk_console_args_mut<V: VisitorMut + ?Sized>(visitor: &mut V, args: &mut ConsoleArgs) {
+    for parameter in args.parameters.iter_mut() {
+        visitor.visit_expression_mut(parameter);
+    }
+}
+
+pub fn walk_expression_statement_mut<V: VisitorMut + ?Sized>(visitor: &mut V, expression: &mut ExpressionStatement) {
+    visitor.visit_expression_mut(&mut expression.expression);
+}
+
+pub fn walk_block_mut<V: VisitorMut + ?Sized>(visitor: &mut V, block: &mut Block) {
+    for statement in block.statements.iter_mut() {
+        visitor.visit_statement_mut(statement);
+    }
+}
+
+// Program
+pub fn walk_program_mut<V: VisitorMut + ?Sized>(visitor: &mut V, program: &mut Program) {
+    for input in program.expected_input.iter_mut() {
+        visitor.visit_function_input_mut(input);
+    }
+    for import in program.import_statements.iter_mut() {
+        visitor.visit_import_statement_mut(import);
+    }
+    for import in program.imports.values_mut() {
+        visitor.visit_program_mut(import);
+    }
+    for alias in program.aliases.values_mut() {
+        visitor.visit_alias_mut(alias);
+    }
+    for circuit in program.circuits.values_mut() {
+        visitor.visit_circuit_mut(circuit);
+    }
+    for function in program.functions.values_mut() {
+        visitor.visit_function_mut(function);
+    }
+    for definition in program.global_consts.values_mut() {
+        visitor.visit_definition_mut(definition);
+    }
+}
+
+pub fn walk_import_statement_mut<V: VisitorMut + ?Sized>(visitor: &mut V, import: &mut ImportStatement) {
+    visitor.visit_import_tree_mut(&mut import.tree);
+}
+
+pub fn walk_import_tree_mut<V: VisitorMut + ?Sized>(visitor: &mut V, tree: &mut ImportTree) {
+    for identifier in tree.base.iter_mut() {
+        visitor.visit_identifier_mut(identifier);
+    }
+    match &mut tree.kind {
+        ImportTreeKind::Glob { .. } | ImportTreeKind::Leaf { alias: None } => {}
+        ImportTreeKind::Leaf { alias: Some(alias) } => visitor.visit_identifier_mut(alias),
+        ImportTreeKind::Nested { tree } => {
+            for tree in tree.iter_mut() {
+                visitor.visit_import_tree_mut(tree);
+            }
+        }
+    }
+}
+
+pub fn walk_alias_mut<V: VisitorMut + ?Sized>(visitor: &mut V, alias: &mut Alias) {
+    visitor.visit_identifier_mut(&mut alias.name);
+    visitor.visit_type_mut(&mut alias.represents);
+}
+
+pub fn walk_circuit_mut<V: VisitorMut + ?Sized>(visitor: &mut V, circuit: &mut Circuit) {
+    visitor.visit_identifier_mut(&mut circuit.circuit_name);
+    for member in circuit.members.iter_mut() {
+        visitor.visit_circuit_member_mut(member);
+    }
+}
+
+pub fn walk_circuit_member_mut<V: VisitorMut + ?Sized>(visitor: &mut V, circuit_member: &mut CircuitMember) {
+    match circuit_member {
+        CircuitMember::CircuitConst(identifier, type_, value, _) => {
+            visitor.visit_identifier_mut(identifier);
+            visitor.visit_type_mut(type_);
+            visitor.visit_expression_mut(value);
+        }
+        CircuitMember::CircuitVariable(identifier, type_, _) => {
+            visitor.visit_identifier_mut(identifier);
+            visitor.visit_type_mut(type_);
+        }
+        CircuitMember::CircuitFunction(function) => visitor.visit_function_mut(function),
+    }
+}
+
+pub fn walk_function_mut<V: VisitorMut + ?Sized>(visitor: &mut V, function: &mut Function) {
+    visitor.visit_identifier_mut(&mut function.identifier);
+    for input in function.input.iter_mut() {
+        visitor.visit_function_input_mut(input);
+    }
+    if let Some(output) = &mut function.output {
+        visitor.visit_type_mut(output);
+    }
+    if let Some(block) = &mut function.block {
+        visitor.visit_block_mut(block);
+    }
+}
+
+pub fn walk_function_input_mut<V: VisitorMut + ?Sized>(visitor: &mut V, input: &mut FunctionInput) {
+    if let FunctionInput::Variable(variable) = input {
+        visitor.visit_function_input_variable_mut(variable);
+    }
+}
+
+pub fn walk_function_input_variable_mut<V: VisitorMut + ?Sized>(visitor: &mut V, variable: &mut FunctionInputVariable) {
+    visitor.visit_identifier_mut(&mut variable.identifier);
+    visitor.visit_type_mut(&mut variable.type_);
+}
@@ -22,21 +22,30 @@
 #![allow(clippy::upper_case_acronyms)]
 #![doc = include_str!("../README.md")]
 
-pub use leo_ast::Ast;
+#[cfg(test)]
+mod test;
+
 use leo_ast::AstPass;
+pub use leo_ast::{Ast, DocFormat};
 use leo_errors::emitter::Handler;
 use leo_errors::{CompilerError, Result};
-use leo_span::symbol::create_session_if_not_set_then;
+use leo_imports::ImportParser;
+use leo_span::{sym, symbol::create_session_if_not_set_then};
 
+use indexmap::IndexMap;
 use sha2::{Digest, Sha256};
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 /// The primary entry point of the Leo compiler.
 pub struct Compiler<'a> {
     handler: &'a Handler,
     main_file_path: PathBuf,
     output_directory: PathBuf,
+    /// The directory names of registry dependencies in `imports/`, keyed by import name.
+    imports_map: IndexMap<String, String>,
+    /// The root directories of path and git dependencies, keyed by import name.
+    local_packages: IndexMap<String, PathBuf>,
 }
 
 impl<'a> Compiler<'a> {
@@ -48,9 +57,27 @@ impl<'a> Compiler<'a> {
             handler,
             main_file_path,
             output_directory,
+            imports_map: Default::default(),
+            local_packages: Default::default(),
         }
     }
 
+    ///
+    /// Resolves the imports of registry dependencies through the directory names in `imports_map`.
+    ///
+    pub fn with_imports_map(mut self, imports_map: IndexMap<String, String>) -> Self {
+        self.imports_map = imports_map;
+        self
+    }
+
+    ///
+    /// Resolves the imports of path and git dependencies from the package roots in `local_packages`.
+    ///
+    pub fn with_local_packages(mut self, local_packages: IndexMap<String, PathBuf>) -> Self {
+        self.local_packages = local_packages;
+        self
+    }
+
     ///
     /// Returns a SHA256 checksum of the program file.
     ///
@@ -70,6 +97,9 @@ impl<'a> Compiler<'a> {
     ///
     /// Runs the compiler stages.
     ///
+    /// Each stage emits all of its errors through the handler,
+    /// and the compiler stops after the first stage that had errors.
+    ///
     fn compiler_stages(self) -> Result<leo_ast::Ast> {
         // Load the program file.
         let program_string = fs::read_to_string(&self.main_file_path)
@@ -83,11 +113,25 @@ impl<'a> Compiler<'a> {
         )?;
         // Write the AST snapshot post parsing.
         ast.to_json_file_without_keys(self.output_directory.clone(), "initial_ast.json", &["span"])?;
+        ast.to_leo_file(self.output_directory.clone(), "initial_ast.leo")?;
+
+        // Resolve the imports of the standard library and of the package dependencies.
+        let mut import_parser =
+            ImportParser::new(self.main_file_path.clone(), self.imports_map).with_local_packages(self.local_packages);
+        ast = leo_ast_passes::Importer::do_pass(self.handler, ast.into_repr(), &mut import_parser)?;
+        // Write the AST snapshot post import resolution.
+        ast.to_json_file_without_keys(self.output_directory.clone(), "imports_resolved_ast.json", &["span"])?;
+        ast.to_leo_file(self.output_directory.clone(), "imports_resolved_ast.leo")?;
+
+        // Evaluate the calls of the native functions imported from the standard library.
+        let program = ast.into_repr();
+        ast = leo_ast_passes::NativeCallEvaluator::new(&program).do_pass(program)?;
 
         // Canonicalize the AST.
-        ast = leo_ast_passes::Canonicalizer::do_pass(Default::default(), ast.into_repr())?;
+        ast = leo_ast_passes::Canonicalizer::new(self.handler).do_pass(ast.into_repr())?;
         // Write the AST snapshot post parsing
-        ast.to_json_file_without_keys(self.output_directory, "canonicalization_ast.json", &["span"])?;
+        ast.to_json_file_without_keys(self.output_directory.clone(), "canonicalization_ast.json", &["span"])?;
+        ast.to_leo_file(self.output_directory, "canonicalization_ast.leo")?;
 
         Ok(ast)
     }
@@ -98,4 +142,94 @@ impl<'a> Compiler<'a> {
     pub fn compile(self) -> Result<leo_ast::Ast> {
         create_session_if_not_set_then(|_| self.compiler_stages())
     }
+
+    ///
+    /// Returns a compiled Leo program, and the names of its test functions, i.e., those annotated with `@test`.
+    ///
+    pub fn compile_with_tests(self) -> Result<(leo_ast::Ast, Vec<String>)> {
+        create_session_if_not_set_then(|_| {
+            let ast = self.compiler_stages()?;
+            let tests = ast
+                .as_repr()
+                .functions
+                .values()
+                .filter(|function| function.annotations.contains_key(&sym::test))
+                .map(|function| function.identifier.to_string())
+                .collect();
+            Ok((ast, tests))
+        })
+    }
+}
+
+///
+/// Returns the differences between the AST snapshots of the compiler phases `from` and `to`,
+/// e.g., `initial` and `canonicalization`, in `output_directory`.
+///
+/// The snapshots written as Leo code are compared as programs when both phases have one,
+/// the JSON snapshots otherwise.
+///
+pub fn diff_ast_snapshots(
+    handler: &Handler,
+    output_directory: &Path,
+    from: &str,
+    to: &str,
+) -> Result<leo_ast::AstDiff> {
+    let snapshot = |phase: &str, extension: &str| {
+        let stem = if phase.ends_with("_ast") {
+            phase.to_string()
+        } else {
+            format!("{}_ast", phase)
+        };
+        output_directory.join(stem).with_extension(extension)
+    };
+    let (from_leo, to_leo) = (snapshot(from, "leo"), snapshot(to, "leo"));
+
+    if from_leo.exists() && to_leo.exists() {
+        return create_session_if_not_set_then(|_| {
+            let parse = |path: &PathBuf| -> Result<leo_ast::Program> {
+                let source = fs::read_to_string(path).map_err(|e| CompilerError::file_read_error(path, e))?;
+                Ok(leo_parser::parse_ast(handler, path.to_str().unwrap_or_default(), source)?.into_repr())
+            };
+            Ok(leo_ast::AstDiff::programs(&parse(&from_leo)?, &parse(&to_leo)?))
+        });
+    }
+
+    for (phase, path) in [(from, snapshot(from, "json")), (to, snapshot(to, "json"))] {
+        if !path.exists() {
+            return Err(CompilerError::ast_snapshot_not_found(phase, output_directory).into());
+        }
+    }
+    leo_ast::AstDiff::json_files(snapshot(from, "json"), snapshot(to, "json"))
+}
+
+///
+/// Returns the documentation pages of the Leo files `files`, each named by its module path,
+/// and of the modules of the standard library, see [`leo_ast::generate_docs`].
+///
+pub fn document_modules(
+    handler: &Handler,
+    files: Vec<(Vec<String>, PathBuf)>,
+    format: leo_ast::DocFormat,
+) -> Result<Vec<leo_ast::DocPage>> {
+    create_session_if_not_set_then(|_| {
+        let mut modules = Vec::new();
+        for (path, file) in files {
+            let source = fs::read_to_string(&file).map_err(|e| CompilerError::file_read_error(&file, e))?;
+            let program = leo_parser::parse_ast(handler, file.to_str().unwrap_or_default(), source)?.into_repr();
+            modules.push(leo_ast::DocModule { path, program });
+        }
+
+        // The modules of the standard library which this version of Leo cannot parse are left out,
+        // e.g., the prelude `string` alias of an array of unspecified size.
+        for name in leo_stdlib::stdlib_module_names() {
+            let (stdlib_handler, _) = Handler::new_with_buf();
+            if let Ok(program) = leo_stdlib::resolve_stdlib_module(&stdlib_handler, &name) {
+                let mut path = vec!["std".to_string()];
+                path.extend(name.split('.').map(str::to_string));
+                modules.push(leo_ast::DocModule { path, program });
+            }
+        }
+
+        Ok(leo_ast::generate_docs(&modules, format))
+    })
 }
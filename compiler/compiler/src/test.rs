@@ -0,0 +1,192 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::Compiler;
+use leo_errors::emitter::Handler;
+use leo_span::symbol::create_session_if_not_set_then;
+
+use indexmap::IndexMap;
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+/// Creates a package named `name` in `directory` with the source `main`, returning its root.
+fn create_package(directory: &Path, name: &str, main: &str) -> PathBuf {
+    let root = directory.join(name);
+    fs::create_dir_all(root.join("src")).unwrap();
+    fs::create_dir_all(root.join("outputs")).unwrap();
+    fs::write(root.join("src/main.leo"), main).unwrap();
+    root
+}
+
+/// Creates a fresh directory for the test `name`.
+fn test_directory(name: &str) -> PathBuf {
+    let directory = std::env::temp_dir().join(format!("leo-compiler-{}-{}", name, std::process::id()));
+    if directory.exists() {
+        fs::remove_dir_all(&directory).unwrap();
+    }
+    fs::create_dir_all(&directory).unwrap();
+    directory
+}
+
+const DEPENDENCY_MAIN: &str = "function double(a: u32) -> u32 {
+    return a + a;
+}
+";
+
+const IMPORTING_MAIN: &str = "import shared.double;
+
+function main(a: u32) -> u32 {
+    return double(a);
+}
+";
+
+#[test]
+fn compiles_imports_of_path_dependencies() {
+    let directory = test_directory("path-dependency");
+    let dependency = create_package(&directory, "shared", DEPENDENCY_MAIN);
+    let package = create_package(&directory, "app", IMPORTING_MAIN);
+
+    let mut local_packages = IndexMap::new();
+    local_packages.insert("shared".to_string(), dependency);
+
+    create_session_if_not_set_then(|_| {
+        let (handler, _) = Handler::new_with_buf();
+        let ast = Compiler::new(&handler, package.join("src/main.leo"), package.join("outputs"))
+            .with_local_packages(local_packages)
+            .compile()
+            .expect("the import of the path dependency should resolve");
+
+        let imports = &ast.as_repr().imports;
+        let (path, program) = imports
+            .iter()
+            .find(|(path, _)| path.iter().map(ToString::to_string).eq(["shared".to_string()]))
+            .expect("the dependency should be imported");
+        assert_eq!(path.len(), 1);
+        assert!(program.functions.keys().any(|name| name.name.to_string() == "double"));
+        assert!(package.join("outputs/imports_resolved_ast.json").exists());
+    });
+
+    fs::remove_dir_all(&directory).unwrap();
+}
+
+#[test]
+fn rejects_imports_of_unknown_dependencies() {
+    let directory = test_directory("unknown-dependency");
+    let package = create_package(&directory, "app", IMPORTING_MAIN);
+
+    let (handler, buf) = Handler::new_with_buf();
+    let result = Compiler::new(&handler, package.join("src/main.leo"), package.join("outputs")).compile();
+    assert!(result.is_err());
+    let errors = buf.extract().to_string();
+    assert!(errors.contains("Cannot find imported package `shared`"));
+
+    fs::remove_dir_all(&directory).unwrap();
+}
+
+#[test]
+fn compiles_test_functions() {
+    let directory = test_directory("test-functions");
+    let package = create_package(
+        &directory,
+        "app",
+        "function main(a: u32) -> u32 {
+    return a;
+}
+
+@test
+function main_returns_input() {
+    console.assert(main(1u32) == 1u32);
+}
+",
+    );
+
+    let (handler, _) = Handler::new_with_buf();
+    let (_, tests) = Compiler::new(&handler, package.join("src/main.leo"), package.join("outputs"))
+        .compile_with_tests()
+        .unwrap();
+    assert_eq!(tests, vec!["main_returns_input".to_string()]);
+
+    fs::remove_dir_all(&directory).unwrap();
+}
+
+#[test]
+fn evaluates_calls_of_bit_conversions() {
+    let directory = test_directory("bit-conversions");
+    let package = create_package(
+        &directory,
+        "app",
+        "import std.bits.(u32_to_bytes_le, u8_from_bits_be as from_bits);
+
+function main() -> [u8; 4] {
+    let a = from_bits([false, false, false, false, false, true, false, true]);
+    return u32_to_bytes_le(16909060u32);
+}
+",
+    );
+
+    // The AST is printed in the session of the compiler, which interned its symbols.
+    let main = create_session_if_not_set_then(|_| {
+        let (handler, _) = Handler::new_with_buf();
+        let ast = Compiler::new(&handler, package.join("src/main.leo"), package.join("outputs"))
+            .compile()
+            .unwrap();
+        ast.as_repr().to_string()
+    });
+    assert!(main.contains("let a = 5u8;"), "{}", main);
+    assert!(main.contains("return [4u8, 3u8, 2u8, 1u8];"), "{}", main);
+
+    fs::remove_dir_all(&directory).unwrap();
+}
+
+#[test]
+fn compiles_calls_of_bit_conversions_on_variables() {
+    let directory = test_directory("bit-conversions-on-variables");
+    let package = create_package(
+        &directory,
+        "app",
+        "import std.bits.u32_to_bytes_le;
+
+function main(a: u32) -> [u8; 4] {
+    return u32_to_bytes_le(a);
+}
+",
+    );
+
+    // The call is kept, and reaches the core library through the core mapping of the imported function.
+    let (main, core_mapping) = create_session_if_not_set_then(|_| {
+        let (handler, _) = Handler::new_with_buf();
+        let ast = Compiler::new(&handler, package.join("src/main.leo"), package.join("outputs"))
+            .compile()
+            .unwrap();
+        let program = ast.as_repr();
+        let bits = program.imports.values().next().unwrap();
+        let function = bits
+            .functions
+            .values()
+            .find(|function| function.identifier.to_string() == "u32_to_bytes_le")
+            .unwrap();
+        (
+            program.to_string(),
+            function.core_mapping.get().map(|mapping| mapping.to_string()),
+        )
+    });
+    assert!(main.contains("return u32_to_bytes_le(a);"), "{}", main);
+    assert_eq!(core_mapping.as_deref(), Some("u32_to_bytes_le"));
+
+    fs::remove_dir_all(&directory).unwrap();
+}
@@ -16,9 +16,10 @@
 
 use leo_ast::Program;
 use leo_ast_passes::ImportResolver;
-use leo_errors::{ImportError, LeoError, Result, Span};
+use leo_errors::{emitter::Handler, ImportError, Result};
+use leo_span::Span;
 
-use indexmap::{IndexMap, IndexSet};
+use indexmap::IndexMap;
 use std::path::PathBuf;
 
 /// Stores imported packages.
@@ -28,9 +29,12 @@ use std::path::PathBuf;
 #[derive(Clone, Default)]
 pub struct ImportParser {
     program_path: PathBuf,
-    partial_imports: IndexSet<String>,
+    /// The packages currently being imported, with the span of the import that started each.
+    partial_imports: IndexMap<String, Span>,
     imports: IndexMap<String, Program>,
     pub imports_map: IndexMap<String, String>,
+    /// The root directories of path and git dependencies, keyed by import name.
+    pub(crate) local_packages: IndexMap<String, PathBuf>,
 }
 
 impl ImportParser {
@@ -40,15 +44,30 @@ impl ImportParser {
             partial_imports: Default::default(),
             imports: Default::default(),
             imports_map,
+            local_packages: Default::default(),
         }
     }
+
+    /// Adds the root directories of path and git dependencies, keyed by import name.
+    pub fn with_local_packages(mut self, local_packages: IndexMap<String, PathBuf>) -> Self {
+        self.local_packages = local_packages;
+        self
+    }
 }
 
 impl ImportResolver for ImportParser {
-    fn resolve_package(&mut self, package_segments: &[&str], span: &Span) -> Result<Option<Program>> {
+    fn resolve_package(
+        &mut self,
+        handler: &Handler,
+        package_segments: &[&str],
+        span: &Span,
+    ) -> Result<Option<Program>> {
         let full_path = package_segments.join(".");
-        if self.partial_imports.contains(&full_path) {
-            return Err(ImportError::recursive_imports(full_path, span).into());
+        if let Some(first) = self.partial_imports.get(&full_path) {
+            return Err(ImportError::recursive_imports(&full_path, span)
+                .with_label(first, format!("`{}` is first imported here", full_path))
+                .with_note("a package cannot import itself, directly or through the packages it imports")
+                .into());
         }
 
         if let Some(program) = self.imports.get(&full_path) {
@@ -56,11 +75,9 @@ impl ImportResolver for ImportParser {
         }
 
         let path = self.program_path.clone();
-        self.partial_imports.insert(full_path.clone());
+        self.partial_imports.insert(full_path.clone(), span.clone());
         let mut imports = self.clone(); // Self::default() was previously
-        let program = imports
-            .parse_package(path, package_segments, span)
-            .map_err(|x| -> LeoError { x })?;
+        let program = imports.parse_package(handler, path, package_segments, span)?;
 
         self.partial_imports.remove(&full_path);
         self.imports.insert(full_path, program.clone());
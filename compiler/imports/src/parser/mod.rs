@@ -16,10 +16,8 @@
 
 /// The import parser creates a hashmap of import program names -> import program structs
 pub mod parse_symbol;
-pub use self::parse_symbol::*;
 
 pub mod import_parser;
 pub use self::import_parser::*;
 
 pub mod parse_package;
-pub use self::parse_package::*;
@@ -16,7 +16,8 @@
 
 use crate::ImportParser;
 use leo_ast::Program;
-use leo_errors::{ImportError, Result, Span};
+use leo_errors::{emitter::Handler, ImportError, Result};
+use leo_span::Span;
 
 use std::{fs, fs::DirEntry, path::PathBuf};
 
@@ -27,16 +28,17 @@ static IMPORTS_DIRECTORY_NAME: &str = "imports/";
 impl ImportParser {
     fn parse_package_access(
         &mut self,
+        handler: &Handler,
         package: &DirEntry,
         remaining_segments: &[&str],
         span: &Span,
     ) -> Result<Program> {
         if !remaining_segments.is_empty() {
-            return self.parse_package(package.path(), remaining_segments, span);
+            return self.parse_package(handler, package.path(), remaining_segments, span);
         }
 
-        let program = Self::parse_import_file(package, span)?;
-        let ast = leo_ast_passes::Importer::do_pass(program, self)?.into_repr();
+        let program = Self::parse_import_file(handler, package, span)?;
+        let ast = leo_ast_passes::Importer::do_pass(handler, program, self)?.into_repr();
 
         Ok(ast)
     }
@@ -46,7 +48,13 @@ impl ImportParser {
     ///
     /// Inserts the Leo syntax tree into the `ImportParser`.
     ///
-    pub(crate) fn parse_package(&mut self, mut path: PathBuf, segments: &[&str], span: &Span) -> Result<Program> {
+    pub(crate) fn parse_package(
+        &mut self,
+        handler: &Handler,
+        mut path: PathBuf,
+        segments: &[&str],
+        span: &Span,
+    ) -> Result<Program> {
         let error_path = path.clone();
         let package_name = segments[0];
 
@@ -56,6 +64,16 @@ impl ImportParser {
             panic!("attempted to import std package from filesystem");
         }
 
+        // Resolve path and git dependencies declared in the manifest.
+        if let Some(package_path) = self.local_packages.get(package_name).cloned() {
+            if segments.len() > 1 {
+                return self.parse_package(handler, package_path, &segments[1..], span);
+            }
+
+            let program = Self::parse_main_file(handler, &package_path, package_name, span)?;
+            return Ok(leo_ast_passes::Importer::do_pass(handler, program, self)?.into_repr());
+        }
+
         // Trim path if importing from another file
         if path.is_file() {
             path.pop();
@@ -115,14 +133,14 @@ impl ImportParser {
             // Check if the package name was found in both the source and imports directory.
             match (matched_source_entry, matched_import_entry) {
                 (Some(_), Some(_)) => Err(ImportError::conflicting_imports(package_name, span).into()),
-                (Some(source_entry), None) => self.parse_package_access(&source_entry, &segments[1..], span),
-                (None, Some(import_entry)) => self.parse_package_access(&import_entry, &segments[1..], span),
+                (Some(source_entry), None) => self.parse_package_access(handler, &source_entry, &segments[1..], span),
+                (None, Some(import_entry)) => self.parse_package_access(handler, &import_entry, &segments[1..], span),
                 (None, None) => Err(ImportError::unknown_package(package_name, span).into()),
             }
         } else {
             // Enforce local package access with no found imports directory
             match matched_source_entry {
-                Some(source_entry) => self.parse_package_access(&source_entry, &segments[1..], span),
+                Some(source_entry) => self.parse_package_access(handler, &source_entry, &segments[1..], span),
                 None => Err(ImportError::unknown_package(package_name, span).into()),
             }
         }
@@ -16,9 +16,10 @@
 
 use crate::ImportParser;
 use leo_ast::Program;
-use leo_errors::{ImportError, Result, Span};
+use leo_errors::{emitter::Handler, ImportError, Result};
+use leo_span::Span;
 
-use std::fs::DirEntry;
+use std::{fs::DirEntry, path::Path};
 
 static MAIN_FILE: &str = "src/main.leo";
 
@@ -28,7 +29,7 @@ impl ImportParser {
     ///
     /// Builds an abstract syntax tree from the given file and then builds the Leo syntax tree.
     ///
-    pub(crate) fn parse_import_file(package: &DirEntry, span: &Span) -> Result<Program> {
+    pub(crate) fn parse_import_file(handler: &Handler, package: &DirEntry, span: &Span) -> Result<Program> {
         // Get the package file type.
         let file_type = package
             .file_type()
@@ -52,8 +53,32 @@ impl ImportParser {
         // Build the package abstract syntax tree.
         let program_string =
             &std::fs::read_to_string(&file_path).map_err(|x| ImportError::io_error(file_path_str, x, span))?;
-        let mut program = leo_parser::parse(file_path_str, program_string)?;
+        let mut program = leo_parser::parse(handler, file_path_str, program_string)?;
         program.name = file_name;
         Ok(program)
     }
+
+    ///
+    /// Returns a Leo syntax tree from the main file of the package at `package_path`.
+    ///
+    pub(crate) fn parse_main_file(
+        handler: &Handler,
+        package_path: &Path,
+        package_name: &str,
+        span: &Span,
+    ) -> Result<Program> {
+        let file_path = package_path.join(MAIN_FILE);
+        if !file_path.exists() {
+            return Err(ImportError::expected_main_file(file_path.as_path(), span).into());
+        }
+
+        let file_path_str = file_path.to_str().unwrap_or_default();
+
+        // Build the package abstract syntax tree.
+        let program_string =
+            &std::fs::read_to_string(&file_path).map_err(|x| ImportError::io_error(file_path_str, x, span))?;
+        let mut program = leo_parser::parse(handler, file_path_str, program_string)?;
+        program.name = package_name.to_string();
+        Ok(program)
+    }
 }
@@ -0,0 +1,181 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The green tree, i.e., the immutable nodes and tokens of the concrete syntax tree,
+//! which know their text but not their position, so that they can be shared and rebuilt cheaply.
+
+use super::SyntaxKind;
+
+use std::{fmt, rc::Rc};
+
+/// A token of the green tree, e.g., an identifier or a comment.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct GreenToken {
+    kind: SyntaxKind,
+    text: Rc<str>,
+}
+
+impl GreenToken {
+    /// Creates a token of the `kind` with the `text`.
+    pub fn new(kind: SyntaxKind, text: &str) -> Self {
+        Self {
+            kind,
+            text: text.into(),
+        }
+    }
+
+    /// Returns the kind of the token.
+    pub fn kind(&self) -> SyntaxKind {
+        self.kind
+    }
+
+    /// Returns the text of the token.
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// Returns the length of the text of the token, in bytes.
+    pub fn text_len(&self) -> usize {
+        self.text.len()
+    }
+}
+
+impl fmt::Display for GreenToken {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.text)
+    }
+}
+
+/// A node of the green tree, e.g., a function or an expression.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct GreenNode(Rc<GreenNodeData>);
+
+#[derive(Debug, Eq, Hash, PartialEq)]
+struct GreenNodeData {
+    kind: SyntaxKind,
+    text_len: usize,
+    children: Vec<GreenElement>,
+}
+
+impl GreenNode {
+    /// Creates a node of the `kind` with the `children`.
+    pub fn new(kind: SyntaxKind, children: Vec<GreenElement>) -> Self {
+        let text_len = children.iter().map(GreenElement::text_len).sum();
+        Self(Rc::new(GreenNodeData {
+            kind,
+            text_len,
+            children,
+        }))
+    }
+
+    /// Returns the kind of the node.
+    pub fn kind(&self) -> SyntaxKind {
+        self.0.kind
+    }
+
+    /// Returns the length of the text of the node, in bytes.
+    pub fn text_len(&self) -> usize {
+        self.0.text_len
+    }
+
+    /// Returns the children of the node, trivia included.
+    pub fn children(&self) -> &[GreenElement] {
+        &self.0.children
+    }
+
+    /// Returns whether the two nodes are the same, rather than equal.
+    pub(crate) fn ptr_eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.0, &other.0)
+    }
+
+    /// Returns a copy of the node, with the child at `index` replaced by `child`.
+    pub fn replace_child(&self, index: usize, child: GreenElement) -> Self {
+        self.splice_children(index..index + 1, Some(child))
+    }
+
+    /// Returns a copy of the node, with `child` inserted at `index`.
+    pub fn insert_child(&self, index: usize, child: GreenElement) -> Self {
+        self.splice_children(index..index, Some(child))
+    }
+
+    /// Returns a copy of the node, without the child at `index`.
+    pub fn remove_child(&self, index: usize) -> Self {
+        self.splice_children(index..index + 1, None)
+    }
+
+    /// Returns a copy of the node, with the children in `range` replaced by `replace_with`.
+    pub fn splice_children(
+        &self,
+        range: std::ops::Range<usize>,
+        replace_with: impl IntoIterator<Item = GreenElement>,
+    ) -> Self {
+        let mut children = self.0.children.clone();
+        children.splice(range, replace_with);
+        Self::new(self.kind(), children)
+    }
+}
+
+impl fmt::Display for GreenNode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.children().iter().try_for_each(|child| write!(f, "{}", child))
+    }
+}
+
+/// A child of a green node, either a node or a token.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub enum GreenElement {
+    Node(GreenNode),
+    Token(GreenToken),
+}
+
+impl GreenElement {
+    /// Returns the kind of the element.
+    pub fn kind(&self) -> SyntaxKind {
+        match self {
+            Self::Node(node) => node.kind(),
+            Self::Token(token) => token.kind(),
+        }
+    }
+
+    /// Returns the length of the text of the element, in bytes.
+    pub fn text_len(&self) -> usize {
+        match self {
+            Self::Node(node) => node.text_len(),
+            Self::Token(token) => token.text_len(),
+        }
+    }
+}
+
+impl From<GreenNode> for GreenElement {
+    fn from(node: GreenNode) -> Self {
+        Self::Node(node)
+    }
+}
+
+impl From<GreenToken> for GreenElement {
+    fn from(token: GreenToken) -> Self {
+        Self::Token(token)
+    }
+}
+
+impl fmt::Display for GreenElement {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Node(node) => write!(f, "{}", node),
+            Self::Token(token) => write!(f, "{}", token),
+        }
+    }
+}
@@ -0,0 +1,138 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::Token;
+
+/// The kind of a node or a token of the concrete syntax tree.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum SyntaxKind {
+    // Trivia
+    /// A run of whitespace.
+    Whitespace,
+    /// A line comment, along with the newline ending it.
+    LineComment,
+    /// A block comment.
+    BlockComment,
+
+    // Tokens
+    /// An identifier token, e.g., `foo`.
+    Ident,
+    /// A keyword token, e.g., `function` or `u8`.
+    Keyword,
+    /// A literal token, e.g., `42`, `"foo"`, or `true`.
+    Literal,
+    /// A symbol token, e.g., `+` or `{`.
+    Symbol,
+
+    // Items
+    /// The root of a file.
+    Program,
+    /// An import statement, e.g., `import foo.bar;`.
+    Import,
+    /// A type alias, e.g., `type Foo = u8;`.
+    Alias,
+    /// A circuit, from the `circuit` keyword to its closing brace.
+    Circuit,
+    /// A function, including its annotations.
+    Function,
+    /// An annotation of a function, e.g., `@test`.
+    Annotation,
+    /// A function input, e.g., `a: u8`.
+    FunctionInput,
+
+    // Statements
+    /// A block, e.g., `{ return a; }`.
+    Block,
+    /// A return statement.
+    Return,
+    /// A definition statement, or a global constant.
+    Definition,
+    /// An assignment statement.
+    Assign,
+    /// An if statement, along with its else branch.
+    Conditional,
+    /// A for loop.
+    Iteration,
+    /// A console statement.
+    Console,
+    /// An expression statement, e.g., `foo();`.
+    ExpressionStatement,
+
+    // Expressions
+    /// An identifier, either an expression or a name in another node.
+    Identifier,
+    /// A literal expression, e.g., `42u8` or `(0, 1)group`.
+    Value,
+    /// A binary expression.
+    Binary,
+    /// A unary expression.
+    Unary,
+    /// A ternary expression.
+    Ternary,
+    /// A cast expression.
+    Cast,
+    /// An array access, e.g., `a[0]`.
+    ArrayAccess,
+    /// An array range access, e.g., `a[0..2]`.
+    ArrayRangeAccess,
+    /// A member access, e.g., `a.x`.
+    MemberAccess,
+    /// A tuple access, e.g., `a.0`.
+    TupleAccess,
+    /// A static access, e.g., `Foo::bar`.
+    StaticAccess,
+    /// An inline array, e.g., `[1, 2]`.
+    ArrayInline,
+    /// An array initializer, e.g., `[0; 2]`.
+    ArrayInit,
+    /// A tuple, e.g., `(1, 2)`.
+    TupleInit,
+    /// A circuit initializer, e.g., `Foo { x: 1 }`.
+    CircuitInit,
+    /// A call, e.g., `foo(1)`.
+    Call,
+}
+
+impl SyntaxKind {
+    /// Returns the kind of a token.
+    pub(crate) fn of_token(token: &Token) -> Self {
+        match token {
+            Token::WhiteSpace => Self::Whitespace,
+            Token::CommentLine(_) => Self::LineComment,
+            Token::CommentBlock(_) => Self::BlockComment,
+            Token::Ident(_) => Self::Ident,
+            Token::StringLit(_)
+            | Token::Int(_)
+            | Token::True
+            | Token::False
+            | Token::AddressLit(_)
+            | Token::CharLit(_) => Self::Literal,
+            Token::Ampersand => Self::Symbol,
+            token if token.is_keyword() => Self::Keyword,
+            _ => Self::Symbol,
+        }
+    }
+
+    /// Returns whether this is the kind of trivia, i.e., whitespace or a comment.
+    pub fn is_trivia(self) -> bool {
+        matches!(self, Self::Whitespace | Self::LineComment | Self::BlockComment)
+    }
+
+    /// Returns whether this is the kind of a token, rather than of a node.
+    pub fn is_token(self) -> bool {
+        self <= Self::Symbol
+    }
+}
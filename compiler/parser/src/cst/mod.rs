@@ -0,0 +1,341 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The lossless concrete syntax tree (CST) of a Leo file, for tools rewriting code, e.g., codemods.
+//!
+//! Unlike the AST, the CST keeps every byte of the file, whitespace and comments included,
+//! so that printing it gives back the file as is.
+//! In the style of rowan, it is made of a green tree of immutable nodes knowing their text,
+//! and of a red tree of [`SyntaxNode`]s giving them their parents and positions.
+//! Editing a red node or token yields a new green root, see [`SyntaxNode::replace_with`].
+//!
+//! The nodes follow the AST nodes which have a span: items, statements, and expressions.
+//! Trivia belong to the innermost node around them, so that the trivia before or after a node belong to its parent.
+
+pub mod green;
+pub use green::*;
+
+pub mod kind;
+pub use kind::*;
+
+pub mod red;
+pub use red::*;
+
+use crate::{tokenizer::*, ParserContext, Token};
+
+use leo_ast::*;
+use leo_errors::{emitter::Handler, Result};
+use leo_span::Span;
+
+use std::ops::Range;
+
+/// Creates the concrete syntax tree of the program `source` at the file `path`.
+/// Only valid programs have a tree, so the syntax errors are emitted through the `handler`,
+/// and the result is an error if there were any.
+pub fn parse_cst(handler: &Handler, path: &str, source: &str) -> Result<SyntaxNode> {
+    let tokens = tokenize_with_whitespace(path, source)?;
+    let start = tokens.first().map(|token| token.span.lo.to_usize()).unwrap_or_default();
+    let significant = tokens
+        .iter()
+        .filter(|token| !SyntaxKind::of_token(&token.token).is_trivia())
+        .cloned()
+        .collect();
+    let program = ParserContext::new(handler, significant).parse_program()?;
+    handler.last_err()?;
+
+    let mut collector = RangeCollector {
+        start,
+        len: source.len(),
+        ranges: Vec::new(),
+    };
+    collector.visit_program(&program);
+    collector.collect_circuits(&tokens);
+    collector.extend_to_semicolons(&tokens);
+
+    // Sort the ranges by start, the outer ones first, keeping the order of the visit for equal ranges.
+    let mut ranges = collector.ranges;
+    ranges.sort_by_key(|(_, range)| (range.start, std::cmp::Reverse(range.end)));
+
+    let tokens = tokens.iter().map(|token| {
+        let range = token.span.lo.to_usize() - start..token.span.hi.to_usize() - start;
+        (SyntaxKind::of_token(&token.token), range)
+    });
+    Ok(SyntaxNode::new_root(build(source, tokens, ranges)))
+}
+
+impl SyntaxNode {
+    /// Converts the tree to the AST of its program, as if its text were the file `path`.
+    /// The syntax errors, which an edited tree may have, are emitted through the `handler`.
+    pub fn to_program(&self, handler: &Handler, path: &str) -> Result<Program> {
+        crate::parse(handler, path, &self.text())
+    }
+}
+
+/// Builds the green tree of the `source` from its `tokens` and the sorted `ranges` of its nodes.
+/// A range crossing the end of the node around it is skipped, as it cannot be nested.
+fn build(
+    source: &str,
+    tokens: impl Iterator<Item = (SyntaxKind, Range<usize>)>,
+    ranges: Vec<(SyntaxKind, Range<usize>)>,
+) -> GreenNode {
+    // The nodes being built, the innermost one last, along with their end and children.
+    let mut stack = vec![(SyntaxKind::Program, source.len(), Vec::new())];
+    let mut ranges = ranges.into_iter().peekable();
+
+    let close = |stack: &mut Vec<(SyntaxKind, usize, Vec<GreenElement>)>| {
+        let (kind, _, children) = stack.pop().unwrap();
+        stack.last_mut().unwrap().2.push(GreenNode::new(kind, children).into());
+    };
+
+    for (kind, range) in tokens {
+        while stack.len() > 1 && stack.last().unwrap().1 <= range.start {
+            close(&mut stack);
+        }
+        if !kind.is_trivia() {
+            while let Some((node_kind, node_range)) = ranges.next_if(|(_, node)| node.start <= range.start) {
+                if node_range.start == range.start && node_range.end <= stack.last().unwrap().1 {
+                    stack.push((node_kind, node_range.end, Vec::new()));
+                }
+            }
+        }
+        let token = GreenToken::new(kind, &source[range]);
+        stack.last_mut().unwrap().2.push(token.into());
+    }
+
+    while stack.len() > 1 {
+        close(&mut stack);
+    }
+    let (kind, _, children) = stack.pop().unwrap();
+    GreenNode::new(kind, children)
+}
+
+/// Collects the kinds and byte ranges of the AST nodes with a span.
+struct RangeCollector {
+    /// The start of the file in the source map.
+    start: usize,
+    /// The length of the file.
+    len: usize,
+    ranges: Vec<(SyntaxKind, Range<usize>)>,
+}
+
+impl RangeCollector {
+    /// Adds the node of the `kind` at the `span`, unless the span is not within the file.
+    fn add(&mut self, kind: SyntaxKind, span: &Span) {
+        let (lo, hi) = (span.lo.to_usize(), span.hi.to_usize());
+        if lo >= self.start && lo < hi && hi - self.start <= self.len {
+            self.ranges.push((kind, lo - self.start..hi - self.start));
+        }
+    }
+
+    /// Adds the circuits, which have no span, from their `circuit` keyword to their closing brace.
+    fn collect_circuits(&mut self, tokens: &[SpannedToken]) {
+        let mut depth = 0usize;
+        let mut circuit_start = None;
+        for token in tokens {
+            match token.token {
+                Token::Circuit if depth == 0 => circuit_start = Some(token.span.clone()),
+                Token::LeftCurly => depth += 1,
+                Token::RightCurly => {
+                    depth = depth.saturating_sub(1);
+                    if let (0, Some(start)) = (depth, circuit_start.take()) {
+                        self.add(SyntaxKind::Circuit, &(start + token.span.clone()));
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    /// Extends the statements to the `;` ending them, which their spans may not include.
+    fn extend_to_semicolons(&mut self, tokens: &[SpannedToken]) {
+        let semicolons: Vec<_> = tokens
+            .iter()
+            .filter(|token| !SyntaxKind::of_token(&token.token).is_trivia())
+            .collect::<Vec<_>>()
+            .windows(2)
+            .filter(|pair| pair[1].token == Token::Semicolon)
+            .map(|pair| {
+                (
+                    pair[0].span.hi.to_usize() - self.start,
+                    pair[1].span.hi.to_usize() - self.start,
+                )
+            })
+            .collect();
+        for (kind, range) in self.ranges.iter_mut() {
+            if matches!(
+                kind,
+                SyntaxKind::Import
+                    | SyntaxKind::Alias
+                    | SyntaxKind::Return
+                    | SyntaxKind::Definition
+                    | SyntaxKind::Assign
+                    | SyntaxKind::Console
+                    | SyntaxKind::ExpressionStatement
+            ) {
+                if let Ok(index) = semicolons.binary_search_by_key(&range.end, |(end, _)| *end) {
+                    range.end = semicolons[index].1;
+                }
+            }
+        }
+    }
+}
+
+impl<'a> Visitor<'a> for RangeCollector {
+    fn visit_identifier(&mut self, identifier: &'a Identifier) {
+        self.add(SyntaxKind::Identifier, &identifier.span);
+    }
+
+    fn visit_value(&mut self, value: &'a ValueExpression) {
+        self.add(SyntaxKind::Value, value.span());
+    }
+
+    fn visit_binary(&mut self, binary: &'a BinaryExpression) {
+        self.add(SyntaxKind::Binary, &binary.span);
+        walk_binary(self, binary);
+    }
+
+    fn visit_unary(&mut self, unary: &'a UnaryExpression) {
+        self.add(SyntaxKind::Unary, &unary.span);
+        walk_unary(self, unary);
+    }
+
+    fn visit_ternary(&mut self, ternary: &'a TernaryExpression) {
+        self.add(SyntaxKind::Ternary, &ternary.span);
+        walk_ternary(self, ternary);
+    }
+
+    fn visit_cast(&mut self, cast: &'a CastExpression) {
+        self.add(SyntaxKind::Cast, &cast.span);
+        walk_cast(self, cast);
+    }
+
+    fn visit_array_access(&mut self, array_access: &'a ArrayAccess) {
+        self.add(SyntaxKind::ArrayAccess, &array_access.span);
+        walk_array_access(self, array_access);
+    }
+
+    fn visit_array_range_access(&mut self, array_range_access: &'a ArrayRangeAccess) {
+        self.add(SyntaxKind::ArrayRangeAccess, &array_range_access.span);
+        walk_array_range_access(self, array_range_access);
+    }
+
+    fn visit_member_access(&mut self, member_access: &'a MemberAccess) {
+        self.add(SyntaxKind::MemberAccess, &member_access.span);
+        walk_member_access(self, member_access);
+    }
+
+    fn visit_tuple_access(&mut self, tuple_access: &'a TupleAccess) {
+        self.add(SyntaxKind::TupleAccess, &tuple_access.span);
+        walk_tuple_access(self, tuple_access);
+    }
+
+    fn visit_static_access(&mut self, static_access: &'a StaticAccess) {
+        self.add(SyntaxKind::StaticAccess, &static_access.span);
+        walk_static_access(self, static_access);
+    }
+
+    fn visit_array_inline(&mut self, array_inline: &'a ArrayInlineExpression) {
+        self.add(SyntaxKind::ArrayInline, &array_inline.span);
+        walk_array_inline(self, array_inline);
+    }
+
+    fn visit_array_init(&mut self, array_init: &'a ArrayInitExpression) {
+        self.add(SyntaxKind::ArrayInit, &array_init.span);
+        walk_array_init(self, array_init);
+    }
+
+    fn visit_tuple_init(&mut self, tuple_init: &'a TupleInitExpression) {
+        self.add(SyntaxKind::TupleInit, &tuple_init.span);
+        walk_tuple_init(self, tuple_init);
+    }
+
+    fn visit_circuit_init(&mut self, circuit_init: &'a CircuitInitExpression) {
+        self.add(SyntaxKind::CircuitInit, &circuit_init.span);
+        walk_circuit_init(self, circuit_init);
+    }
+
+    fn visit_call(&mut self, call: &'a CallExpression) {
+        self.add(SyntaxKind::Call, &call.span);
+        walk_call(self, call);
+    }
+
+    fn visit_return(&mut self, return_statement: &'a ReturnStatement) {
+        self.add(SyntaxKind::Return, &return_statement.span);
+        walk_return(self, return_statement);
+    }
+
+    fn visit_definition(&mut self, definition: &'a DefinitionStatement) {
+        self.add(SyntaxKind::Definition, &definition.span);
+        walk_definition(self, definition);
+    }
+
+    fn visit_assign(&mut self, assign: &'a AssignStatement) {
+        self.add(SyntaxKind::Assign, &assign.span);
+        walk_assign(self, assign);
+    }
+
+    fn visit_conditional(&mut self, conditional: &'a ConditionalStatement) {
+        self.add(SyntaxKind::Conditional, &conditional.span);
+        walk_conditional(self, conditional);
+    }
+
+    fn visit_iteration(&mut self, iteration: &'a IterationStatement) {
+        self.add(SyntaxKind::Iteration, &iteration.span);
+        walk_iteration(self, iteration);
+    }
+
+    fn visit_console(&mut self, console: &'a ConsoleStatement) {
+        self.add(SyntaxKind::Console, &console.span);
+        walk_console(self, console);
+    }
+
+    fn visit_expression_statement(&mut self, expression: &'a ExpressionStatement) {
+        self.add(SyntaxKind::ExpressionStatement, &expression.span);
+        walk_expression_statement(self, expression);
+    }
+
+    fn visit_block(&mut self, block: &'a Block) {
+        self.add(SyntaxKind::Block, &block.span);
+        walk_block(self, block);
+    }
+
+    fn visit_import_statement(&mut self, import: &'a ImportStatement) {
+        self.add(SyntaxKind::Import, &import.span);
+        walk_import_statement(self, import);
+    }
+
+    fn visit_alias(&mut self, alias: &'a Alias) {
+        self.add(SyntaxKind::Alias, &alias.span);
+        walk_alias(self, alias);
+    }
+
+    fn visit_function(&mut self, function: &'a Function) {
+        let span = function
+            .annotations
+            .values()
+            .fold(function.span.clone(), |span, annotation| &annotation.span + &span);
+        self.add(SyntaxKind::Function, &span);
+        for annotation in function.annotations.values() {
+            self.add(SyntaxKind::Annotation, &annotation.span);
+        }
+        walk_function(self, function);
+    }
+
+    fn visit_function_input_variable(&mut self, variable: &'a FunctionInputVariable) {
+        self.add(SyntaxKind::FunctionInput, &variable.span);
+        walk_function_input_variable(self, variable);
+    }
+}
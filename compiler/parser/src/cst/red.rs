@@ -0,0 +1,256 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The red tree, i.e., a view of the green tree giving each node and token its parent and position.
+//! Red nodes are created on demand while traversing, and editing one yields a new green root.
+
+use super::{GreenElement, GreenNode, GreenToken, SyntaxKind};
+
+use std::{fmt, ops::Range, rc::Rc};
+
+/// A node of the concrete syntax tree, along with its parent and position.
+#[derive(Clone)]
+pub struct SyntaxNode(Rc<SyntaxNodeData>);
+
+struct SyntaxNodeData {
+    green: GreenNode,
+    parent: Option<SyntaxNode>,
+    /// The index of the node among the children of its parent.
+    index: usize,
+    /// The offset of the node in the text of the root, in bytes.
+    offset: usize,
+}
+
+impl SyntaxNode {
+    /// Creates the root of a tree from a green node.
+    pub fn new_root(green: GreenNode) -> Self {
+        Self(Rc::new(SyntaxNodeData {
+            green,
+            parent: None,
+            index: 0,
+            offset: 0,
+        }))
+    }
+
+    /// Returns the green node underlying the node.
+    pub fn green(&self) -> &GreenNode {
+        &self.0.green
+    }
+
+    /// Returns the kind of the node.
+    pub fn kind(&self) -> SyntaxKind {
+        self.green().kind()
+    }
+
+    /// Returns the byte range of the node in the text of the root.
+    pub fn text_range(&self) -> Range<usize> {
+        self.0.offset..self.0.offset + self.green().text_len()
+    }
+
+    /// Returns the text of the node, trivia included.
+    pub fn text(&self) -> String {
+        self.green().to_string()
+    }
+
+    /// Returns the parent of the node, if it is not the root.
+    pub fn parent(&self) -> Option<SyntaxNode> {
+        self.0.parent.clone()
+    }
+
+    /// Returns the ancestors of the node, from its parent up to the root.
+    pub fn ancestors(&self) -> impl Iterator<Item = SyntaxNode> {
+        std::iter::successors(self.parent(), SyntaxNode::parent)
+    }
+
+    /// Returns the children of the node, tokens included.
+    pub fn children_with_tokens(&self) -> impl Iterator<Item = SyntaxElement> + '_ {
+        let mut offset = self.0.offset;
+        self.green().children().iter().enumerate().map(move |(index, child)| {
+            let child_offset = offset;
+            offset += child.text_len();
+            match child {
+                GreenElement::Node(green) => SyntaxElement::Node(SyntaxNode(Rc::new(SyntaxNodeData {
+                    green: green.clone(),
+                    parent: Some(self.clone()),
+                    index,
+                    offset: child_offset,
+                }))),
+                GreenElement::Token(green) => SyntaxElement::Token(SyntaxToken {
+                    green: green.clone(),
+                    parent: self.clone(),
+                    index,
+                    offset: child_offset,
+                }),
+            }
+        })
+    }
+
+    /// Returns the child nodes of the node.
+    pub fn children(&self) -> impl Iterator<Item = SyntaxNode> + '_ {
+        self.children_with_tokens().filter_map(SyntaxElement::into_node)
+    }
+
+    /// Returns the node and its descendant nodes, in preorder.
+    pub fn descendants(&self) -> Vec<SyntaxNode> {
+        let mut nodes = vec![self.clone()];
+        for child in self.children() {
+            nodes.extend(child.descendants());
+        }
+        nodes
+    }
+
+    /// Returns the tokens of the node, trivia included, in order.
+    pub fn tokens(&self) -> Vec<SyntaxToken> {
+        self.children_with_tokens()
+            .flat_map(|child| match child {
+                SyntaxElement::Node(node) => node.tokens(),
+                SyntaxElement::Token(token) => vec![token],
+            })
+            .collect()
+    }
+
+    /// Returns the token at the byte `offset` of the text of the root, if the node contains it.
+    pub fn token_at_offset(&self, offset: usize) -> Option<SyntaxToken> {
+        self.tokens()
+            .into_iter()
+            .find(|token| token.text_range().contains(&offset))
+    }
+
+    /// Returns the green root of a copy of the tree, with this node replaced by `replacement`.
+    pub fn replace_with(&self, replacement: GreenNode) -> GreenNode {
+        match self.parent() {
+            Some(parent) => parent.replace_with(parent.green().replace_child(self.0.index, replacement.into())),
+            None => replacement,
+        }
+    }
+}
+
+impl PartialEq for SyntaxNode {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.offset == other.0.offset && self.green().ptr_eq(other.green())
+    }
+}
+
+impl Eq for SyntaxNode {}
+
+impl fmt::Display for SyntaxNode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.green())
+    }
+}
+
+impl fmt::Debug for SyntaxNode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:?}@{:?}", self.kind(), self.text_range())
+    }
+}
+
+/// A token of the concrete syntax tree, along with its parent and position.
+#[derive(Clone, Eq, PartialEq)]
+pub struct SyntaxToken {
+    green: GreenToken,
+    parent: SyntaxNode,
+    /// The index of the token among the children of its parent.
+    index: usize,
+    /// The offset of the token in the text of the root, in bytes.
+    offset: usize,
+}
+
+impl SyntaxToken {
+    /// Returns the green token underlying the token.
+    pub fn green(&self) -> &GreenToken {
+        &self.green
+    }
+
+    /// Returns the kind of the token.
+    pub fn kind(&self) -> SyntaxKind {
+        self.green.kind()
+    }
+
+    /// Returns the text of the token.
+    pub fn text(&self) -> &str {
+        self.green.text()
+    }
+
+    /// Returns the byte range of the token in the text of the root.
+    pub fn text_range(&self) -> Range<usize> {
+        self.offset..self.offset + self.green.text_len()
+    }
+
+    /// Returns the node containing the token.
+    pub fn parent(&self) -> SyntaxNode {
+        self.parent.clone()
+    }
+
+    /// Returns the green root of a copy of the tree, with this token replaced by `replacement`.
+    pub fn replace_with(&self, replacement: GreenToken) -> GreenNode {
+        self.parent
+            .replace_with(self.parent.green().replace_child(self.index, replacement.into()))
+    }
+}
+
+impl fmt::Display for SyntaxToken {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.text())
+    }
+}
+
+impl fmt::Debug for SyntaxToken {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:?}@{:?} {:?}", self.kind(), self.text_range(), self.text())
+    }
+}
+
+/// A child of a syntax node, either a node or a token.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum SyntaxElement {
+    Node(SyntaxNode),
+    Token(SyntaxToken),
+}
+
+impl SyntaxElement {
+    /// Returns the kind of the element.
+    pub fn kind(&self) -> SyntaxKind {
+        match self {
+            Self::Node(node) => node.kind(),
+            Self::Token(token) => token.kind(),
+        }
+    }
+
+    /// Returns the byte range of the element in the text of the root.
+    pub fn text_range(&self) -> Range<usize> {
+        match self {
+            Self::Node(node) => node.text_range(),
+            Self::Token(token) => token.text_range(),
+        }
+    }
+
+    /// Returns the node, if the element is one.
+    pub fn into_node(self) -> Option<SyntaxNode> {
+        match self {
+            Self::Node(node) => Some(node),
+            Self::Token(_) => None,
+        }
+    }
+
+    /// Returns the token, if the element is one.
+    pub fn into_token(self) -> Option<SyntaxToken> {
+        match self {
+            Self::Node(_) => None,
+            Self::Token(token) => Some(token),
+        }
+    }
+}
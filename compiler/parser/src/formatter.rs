@@ -0,0 +1,559 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The formatter to print Leo code in a canonical style.
+//!
+//! This module contains the [`format_program()`] method used by `leo fmt`.
+//! The formatter works on the tokens of a file rather than on its AST, which has no comments.
+//! The tokens are grouped by their delimiters, and the groups are split into lines:
+//! blocks have one statement or circuit member per line,
+//! and a group which does not fit within the maximum width has one element per line.
+//! Comments stay on their own line, or at the end of the line of code they follow.
+
+use crate::{parser::expression::INT_TYPES, tokenizer::*, ParserContext, Token};
+
+use leo_errors::{emitter::Handler, Result};
+use leo_span::{
+    symbol::{create_session_if_not_set_then, with_session_globals},
+    Span,
+};
+
+/// The default maximum width of a formatted line.
+pub const DEFAULT_MAX_WIDTH: usize = 100;
+
+/// The indentation of each nested block or broken group.
+const INDENT: &str = "    ";
+
+/// The options of the formatter.
+#[derive(Clone, Debug)]
+pub struct FormatOptions {
+    /// The maximum width of a line.
+    /// Lines are only longer when they cannot be broken, e.g., because of a long string.
+    pub max_width: usize,
+}
+
+impl Default for FormatOptions {
+    fn default() -> Self {
+        Self {
+            max_width: DEFAULT_MAX_WIDTH,
+        }
+    }
+}
+
+/// Formats the program `source` at the file `path`.
+/// Only valid programs are formatted, so the syntax errors are emitted through the `handler`,
+/// and the result is an error if there were any.
+/// A session is created if there is none, as the formatted code does not refer to it.
+pub fn format_program(handler: &Handler, path: &str, source: &str, options: &FormatOptions) -> Result<String> {
+    create_session_if_not_set_then(|_| {
+        let tokens = crate::tokenize(path, source)?;
+        ParserContext::new(handler, tokens.clone()).parse_program()?;
+        handler.last_err()?;
+
+        let (nodes, _) = group(&mut with_source(tokens).into_iter(), true);
+        let mut printer = Printer {
+            max_width: options.max_width,
+            out: String::new(),
+        };
+        printer.write_lines(&split_lines(&nodes), 0, false);
+        Ok(printer.out)
+    })
+}
+
+/// A token along with its source code.
+#[derive(Debug)]
+struct Tok {
+    token: Token,
+    /// The source code of the token, without the newline ending a line comment.
+    text: String,
+    /// The number of newlines between the previous token and this one.
+    newlines: usize,
+}
+
+impl Tok {
+    /// Returns `true` if the token is a comment.
+    fn is_comment(&self) -> bool {
+        matches!(self.token, Token::CommentLine(_) | Token::CommentBlock(_))
+    }
+
+    /// Returns `true` if the token is a comment that must be followed by a newline,
+    /// either because it is a line comment, or because it is on its own line.
+    fn ends_line(&self) -> bool {
+        matches!(self.token, Token::CommentLine(_)) || self.is_comment() && self.newlines > 0
+    }
+}
+
+/// Returns the `tokens` along with their source code.
+fn with_source(tokens: Vec<SpannedToken>) -> Vec<Tok> {
+    let contents = |span: Span| {
+        with_session_globals(|s| s.source_map.contents_of_span(&span)).expect("a token is outside of its source file")
+    };
+
+    let mut previous: Option<Span> = None;
+    let mut previous_line_comment = false;
+    tokens
+        .into_iter()
+        .map(|SpannedToken { token, span }| {
+            let mut newlines = previous.replace(span.clone()).map_or(0, |previous| {
+                contents(Span::new(previous.hi, span.lo)).matches('\n').count()
+            });
+            // A line comment contains the newline ending it.
+            if previous_line_comment {
+                newlines += 1;
+            }
+            previous_line_comment = matches!(token, Token::CommentLine(_));
+
+            Tok {
+                text: contents(span).trim_end().to_string(),
+                token,
+                newlines,
+            }
+        })
+        .collect()
+}
+
+/// The delimiters of a group.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Delimiter {
+    /// `(` and `)`.
+    Paren,
+    /// `[` and `]`.
+    Square,
+    /// `{` and `}` around circuit members in an expression.
+    Brace,
+    /// `{` and `}` around statements or circuit members.
+    Block,
+}
+
+/// A token, or a group of them between delimiters.
+#[derive(Debug)]
+enum Node {
+    Token(Tok),
+    Group {
+        delimiter: Delimiter,
+        open: Tok,
+        children: Vec<Node>,
+        close: Tok,
+    },
+}
+
+impl Node {
+    /// Returns the first token of the node.
+    fn first(&self) -> &Tok {
+        match self {
+            Node::Token(tok) => tok,
+            Node::Group { open, .. } => open,
+        }
+    }
+
+    /// Returns the last token of the node.
+    fn last(&self) -> &Tok {
+        match self {
+            Node::Token(tok) => tok,
+            Node::Group { close, .. } => close,
+        }
+    }
+
+    /// Returns `true` if the node is the token `token`.
+    fn is(&self, token: &Token) -> bool {
+        matches!(self, Node::Token(tok) if &tok.token == token)
+    }
+
+    /// Returns `true` if the node is a group with the `delimiter`.
+    fn is_group(&self, delimiter: Delimiter) -> bool {
+        matches!(self, Node::Group { delimiter: d, .. } if *d == delimiter)
+    }
+
+    /// Returns `true` if the node is a group containing a comment which must be followed by a newline.
+    fn must_break(&self) -> bool {
+        match self {
+            Node::Token(_) => false,
+            Node::Group { children, .. } => children.iter().any(|child| match child {
+                Node::Token(tok) => tok.ends_line(),
+                group => group.must_break(),
+            }),
+        }
+    }
+
+    /// Returns `true` if the node is a group of elements separated by commas.
+    fn is_list(&self) -> bool {
+        match self {
+            Node::Group {
+                delimiter, children, ..
+            } => *delimiter != Delimiter::Block && children.iter().any(|child| child.is(&Token::Comma)),
+            Node::Token(_) => false,
+        }
+    }
+}
+
+/// Groups the `tokens` up to the closing delimiter of the enclosing group, which is returned as well.
+/// With `blocks`, that is, at the top level and in blocks, a `{` opens a block if it starts a statement,
+/// or follows the header of a function, circuit, conditional, or loop.
+/// Elsewhere, it opens the members of a circuit expression.
+fn group(tokens: &mut impl Iterator<Item = Tok>, blocks: bool) -> (Vec<Node>, Option<Tok>) {
+    let mut nodes = Vec::new();
+    let mut statement_start = true;
+    let mut header = false;
+
+    while let Some(tok) = tokens.next() {
+        let delimiter = match tok.token {
+            Token::RightParen | Token::RightSquare | Token::RightCurly => return (nodes, Some(tok)),
+            Token::LeftParen => Delimiter::Paren,
+            Token::LeftSquare => Delimiter::Square,
+            Token::LeftCurly if blocks && (statement_start || header) => Delimiter::Block,
+            Token::LeftCurly => Delimiter::Brace,
+            _ => {
+                if !tok.is_comment() {
+                    header |= matches!(
+                        tok.token,
+                        Token::Function | Token::Circuit | Token::If | Token::Else | Token::For
+                    );
+                    statement_start = matches!(tok.token, Token::Semicolon | Token::Comma);
+                }
+                nodes.push(Node::Token(tok));
+                continue;
+            }
+        };
+
+        let (children, close) = group(tokens, delimiter == Delimiter::Block);
+        // The program was parsed, so all delimiters are closed.
+        let close = close.expect("unclosed delimiter in a parsed program");
+        statement_start = delimiter == Delimiter::Block;
+        header &= delimiter != Delimiter::Block;
+        nodes.push(Node::Group {
+            delimiter,
+            open: tok,
+            children,
+            close,
+        });
+    }
+
+    (nodes, None)
+}
+
+/// A line of code, followed by the comments at its end.
+#[derive(Default)]
+struct Line<'a> {
+    /// Whether the line is preceded by a blank line.
+    blank_before: bool,
+    nodes: Vec<&'a Node>,
+    comments: Vec<&'a Tok>,
+}
+
+impl Line<'_> {
+    /// Returns `true` if the line contains neither code nor comments.
+    fn is_empty(&self) -> bool {
+        self.nodes.is_empty() && self.comments.is_empty()
+    }
+
+    /// Returns `true` if the line is an annotation, e.g., `@test` or `@CoreFunction(foo)`.
+    fn is_annotation(&self) -> bool {
+        matches!(self.nodes[..], [at, _] | [at, _, _] if at.is(&Token::At))
+    }
+}
+
+/// Splits the `nodes` of a block, or of a broken group, into lines.
+/// A line ends after a `;` or `,`, after a block, after an annotation, or before a comment on its own line.
+/// Blank lines are kept, but never more than one in a row.
+fn split_lines(nodes: &[Node]) -> Vec<Line<'_>> {
+    let mut lines: Vec<Line> = Vec::new();
+    let mut line = Line::default();
+
+    for (index, node) in nodes.iter().enumerate() {
+        if let Node::Token(tok) = node {
+            if tok.is_comment() {
+                if tok.newlines > 0 || line.is_empty() && lines.is_empty() {
+                    // A comment on its own line.
+                    if !line.is_empty() {
+                        lines.push(std::mem::take(&mut line));
+                    }
+                    lines.push(Line {
+                        blank_before: tok.newlines > 1 && !lines.is_empty(),
+                        nodes: Vec::new(),
+                        comments: vec![tok],
+                    });
+                } else if line.is_empty() {
+                    // A comment at the end of the previous line.
+                    lines.last_mut().unwrap().comments.push(tok);
+                } else if tok.ends_line() {
+                    // A comment at the end of the current line.
+                    line.comments.push(tok);
+                    lines.push(std::mem::take(&mut line));
+                } else {
+                    // A block comment within the current line.
+                    line.nodes.push(node);
+                }
+                continue;
+            }
+        }
+
+        if line.is_empty() {
+            line.blank_before = node.first().newlines > 1 && !lines.is_empty();
+        }
+        line.nodes.push(node);
+
+        let next = nodes.get(index + 1);
+        let ends_line = match node {
+            Node::Group {
+                delimiter: Delimiter::Block,
+                ..
+            } => !next.map_or(false, |next| next.is(&Token::Else)),
+            Node::Token(tok) if matches!(tok.token, Token::Semicolon | Token::Comma) => true,
+            _ => line.is_annotation() && !next.map_or(false, |next| next.is_group(Delimiter::Paren)),
+        };
+        if ends_line {
+            lines.push(std::mem::take(&mut line));
+        }
+    }
+
+    if !line.is_empty() {
+        lines.push(line);
+    }
+    lines
+}
+
+/// Returns `true` if the `token` may end an operand, so that a following `-` is a binary operator.
+fn ends_operand(token: &Token) -> bool {
+    matches!(
+        token,
+        Token::Ident(_)
+            | Token::Int(_)
+            | Token::StringLit(_)
+            | Token::CharLit(_)
+            | Token::AddressLit(_)
+            | Token::True
+            | Token::False
+            | Token::RightParen
+            | Token::RightSquare
+            | Token::RightCurly
+            | Token::LittleSelf
+            | Token::BigSelf
+            | Token::Input
+            | Token::Bool
+            | Token::Address
+            | Token::Char
+    ) || INT_TYPES.contains(token)
+}
+
+/// Returns whether each of the `nodes` in a line is preceded by a space.
+fn spacing(nodes: &[&Node]) -> Vec<bool> {
+    let mut spaces = Vec::with_capacity(nodes.len());
+    let mut previous: Option<&Token> = None;
+    let mut unary = false;
+    // The number of `?` whose `:` is yet to come.
+    let mut ternaries = 0usize;
+
+    for node in nodes {
+        let token = &node.first().token;
+        let space = match previous {
+            None => false,
+            Some(_) if unary => false,
+            Some(Token::Dot | Token::DoubleColon | Token::DotDot | Token::DotDotDot | Token::At) => false,
+            Some(_) if matches!(token, Token::Comma | Token::Semicolon | Token::Dot | Token::DoubleColon) => false,
+            Some(_) if *token == Token::DotDot => false,
+            Some(_) if *token == Token::Colon && ternaries > 0 => {
+                ternaries -= 1;
+                true
+            }
+            Some(_) if *token == Token::Colon => false,
+            // Calls and accesses, e.g., `foo(1)` and `a[0]`.
+            Some(previous) if matches!(token, Token::LeftParen | Token::LeftSquare) => !ends_operand(previous),
+            // Typed literals, e.g., `1u8` and `(0, 1)group`.
+            Some(Token::Int(_)) if INT_TYPES.contains(token) => false,
+            Some(Token::RightParen) if *token == Token::Group => false,
+            Some(_) => true,
+        };
+        spaces.push(space);
+
+        if *token == Token::Question {
+            ternaries += 1;
+        }
+        unary = *token == Token::Not
+            || matches!(token, Token::Minus | Token::Ampersand) && !previous.map_or(false, ends_operand);
+        previous = Some(&node.last().token);
+    }
+
+    spaces
+}
+
+/// Returns the `node` printed on a single line.
+/// A trailing comma in a group is dropped, as it is only printed when the group is broken.
+fn flat(node: &Node) -> String {
+    match node {
+        Node::Token(tok) => tok.text.clone(),
+        Node::Group {
+            delimiter,
+            open,
+            children,
+            close,
+        } => {
+            let mut children: Vec<&Node> = children.iter().collect();
+            if children.last().map_or(false, |last| last.is(&Token::Comma)) {
+                children.pop();
+            }
+
+            let mut out = open.text.clone();
+            let padding = *delimiter == Delimiter::Brace && !children.is_empty();
+            if padding {
+                out.push(' ');
+            }
+            for (child, space) in children.iter().zip(spacing(&children)) {
+                if space {
+                    out.push(' ');
+                }
+                out.push_str(&flat(child));
+            }
+            if padding {
+                out.push(' ');
+            }
+            out.push_str(&close.text);
+            out
+        }
+    }
+}
+
+/// Prints lines of Leo code.
+struct Printer {
+    max_width: usize,
+    out: String,
+}
+
+impl Printer {
+    /// Returns the number of characters on the last line of the output.
+    fn column(&self) -> usize {
+        let start = self.out.rfind('\n').map_or(0, |index| index + 1);
+        self.out[start..].chars().count()
+    }
+
+    /// Writes `indent` levels of indentation.
+    fn write_indent(&mut self, indent: usize) {
+        for _ in 0..indent {
+            self.out.push_str(INDENT);
+        }
+    }
+
+    /// Writes the `lines` at the level of indentation `indent`, each followed by a newline.
+    /// With `trailing_comma`, the last line of code ends with a comma.
+    fn write_lines(&mut self, lines: &[Line], indent: usize, trailing_comma: bool) {
+        let last_code = lines.iter().rposition(|line| !line.nodes.is_empty());
+
+        for (index, line) in lines.iter().enumerate() {
+            if line.blank_before {
+                self.out.push('\n');
+            }
+            self.write_indent(indent);
+
+            let comma = trailing_comma && Some(index) == last_code && !line.nodes.last().unwrap().is(&Token::Comma);
+            self.write_nodes(&line.nodes, indent, comma as usize);
+            if comma {
+                self.out.push(',');
+            }
+
+            for (position, comment) in line.comments.iter().enumerate() {
+                if position > 0 || !line.nodes.is_empty() {
+                    self.out.push(' ');
+                }
+                self.out.push_str(&comment.text);
+            }
+            self.out.push('\n');
+        }
+    }
+
+    /// Writes the `nodes` of a line at the level of indentation `indent`,
+    /// leaving room for `suffix` more characters at its end.
+    fn write_nodes(&mut self, nodes: &[&Node], indent: usize, suffix: usize) {
+        let spaces = spacing(nodes);
+        let mut start = 0;
+
+        for (index, node) in nodes.iter().enumerate() {
+            if let Node::Group {
+                delimiter: Delimiter::Block,
+                open,
+                children,
+                close,
+            } = node
+            {
+                // Leave room for the ` {` opening the block.
+                self.write_inline(&nodes[start..index], &spaces[start..index], indent, 2);
+                if spaces[index] {
+                    self.out.push(' ');
+                }
+
+                self.out.push_str(&open.text);
+                if !children.is_empty() {
+                    self.out.push('\n');
+                    self.write_lines(&split_lines(children), indent + 1, false);
+                    self.write_indent(indent);
+                }
+                self.out.push_str(&close.text);
+
+                start = index + 1;
+            }
+        }
+
+        self.write_inline(&nodes[start..], &spaces[start..], indent, suffix);
+    }
+
+    /// Writes the `nodes`, each preceded by a space where `spaces` is set.
+    /// When they do not fit on the line along with `suffix` more characters, the longest list among them is broken,
+    /// and so is any group with a comment that must be followed by a newline.
+    fn write_inline(&mut self, nodes: &[&Node], spaces: &[bool], indent: usize, suffix: usize) {
+        let flat: Vec<String> = nodes.iter().map(|node| flat(node)).collect();
+        let width: usize = flat
+            .iter()
+            .zip(spaces)
+            .map(|(flat, space)| flat.chars().count() + *space as usize)
+            .sum();
+
+        // A group literal, e.g., `(0, 1)group`, is never broken.
+        let is_group_literal = |index: usize| nodes.get(index + 1).map_or(false, |next| next.is(&Token::Group));
+        let broken = nodes.iter().position(|node| node.must_break()).or_else(|| {
+            if self.column() + width + suffix <= self.max_width {
+                return None;
+            }
+            (0..nodes.len())
+                .filter(|index| nodes[*index].is_list() && !is_group_literal(*index))
+                .rev()
+                .max_by_key(|index| flat[*index].chars().count())
+        });
+
+        let end = broken.unwrap_or(nodes.len());
+        for (flat, space) in flat[..end].iter().zip(spaces) {
+            if *space {
+                self.out.push(' ');
+            }
+            self.out.push_str(flat);
+        }
+
+        if let Some(index) = broken {
+            if spaces[index] {
+                self.out.push(' ');
+            }
+            if let Node::Group {
+                open, children, close, ..
+            } = nodes[index]
+            {
+                self.out.push_str(&open.text);
+                self.out.push('\n');
+                let trailing_comma = nodes[index].is_list() && !is_group_literal(index);
+                self.write_lines(&split_lines(children), indent + 1, trailing_comma);
+                self.write_indent(indent);
+                self.out.push_str(&close.text);
+            }
+            self.write_inline(&nodes[index + 1..], &spaces[index + 1..], indent, suffix);
+        }
+    }
+}
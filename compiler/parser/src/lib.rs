@@ -29,6 +29,12 @@ pub(crate) use tokenizer::*;
 pub mod parser;
 pub use parser::*;
 
+pub mod formatter;
+pub use formatter::*;
+
+pub mod cst;
+pub use cst::*;
+
 use leo_ast::{Ast, Input, ProgramInput, ProgramState};
 use leo_errors::emitter::Handler;
 use leo_errors::Result;
@@ -42,6 +48,11 @@ pub fn parse_ast<T: AsRef<str>, Y: AsRef<str>>(handler: &Handler, path: T, sourc
 }
 
 /// Parses program inputs from from the input file path and state file path
+/// Parses a file of the standard library into an [`Ast`], allowing native functions without bodies.
+pub fn parse_stdlib_ast<T: AsRef<str>, Y: AsRef<str>>(handler: &Handler, path: T, source: Y) -> Result<Ast> {
+    Ok(Ast::new(parser::parse_stdlib(handler, path.as_ref(), source.as_ref())?))
+}
+
 pub fn parse_program_inputs<T: AsRef<str>, Y: AsRef<str>>(
     handler: &Handler,
     input_string: T,
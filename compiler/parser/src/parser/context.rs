@@ -18,20 +18,23 @@ use crate::{assert_no_whitespace, tokenizer::*, Token, KEYWORD_TOKENS};
 
 use leo_ast::*;
 use leo_errors::emitter::Handler;
-use leo_errors::{LeoError, ParserError, Result};
+use leo_errors::{LeoError, ParserError, ParserWarning, Result};
 use leo_span::{Span, Symbol};
 
-use std::{borrow::Cow, unreachable};
+use std::{borrow::Cow, collections::HashMap, unreachable};
 
 /// Stores a program in tokenized format plus additional context.
 /// May be converted into a [`Program`] AST by parsing all tokens.
 pub struct ParserContext<'a> {
-    #[allow(dead_code)]
     pub(crate) handler: &'a Handler,
     tokens: Vec<SpannedToken>,
+    /// The lines of the doc comments before a token, by the number of tokens left when it is next.
+    docs: HashMap<usize, Vec<String>>,
     end_span: Span,
     // true if parsing an expression for if and loop statements -- means circuit inits are not legal
     pub(crate) disallow_circuit_construction: bool,
+    /// true if parsing a file of the standard library -- allows native functions and internal annotations
+    pub(crate) allow_native_functions: bool,
 }
 
 impl Iterator for ParserContext<'_> {
@@ -46,22 +49,41 @@ impl<'a> ParserContext<'a> {
     ///
     /// Returns a new [`ParserContext`] type given a vector of tokens.
     ///
-    pub fn new(handler: &'a Handler, mut tokens: Vec<SpannedToken>) -> Self {
+    pub fn new(handler: &'a Handler, tokens: Vec<SpannedToken>) -> Self {
+        // The doc comments are kept apart, attached to the token after them,
+        // while the other comments are dropped.
+        let mut docs = Vec::new();
+        let mut lines = Vec::new();
+        let mut tokens = tokens
+            .into_iter()
+            .filter(|x| match x.token.doc_comment_lines() {
+                Some(doc) => {
+                    lines.extend(doc);
+                    false
+                }
+                None if matches!(x.token, Token::CommentLine(_) | Token::CommentBlock(_)) => false,
+                None => {
+                    docs.push(std::mem::take(&mut lines));
+                    true
+                }
+            })
+            .collect::<Vec<_>>();
         tokens.reverse();
-        // todo: performance optimization here: drain filter
-        tokens = tokens
+        let count = docs.len();
+        let docs = docs
             .into_iter()
-            .filter(|x| !matches!(x.token, Token::CommentLine(_) | Token::CommentBlock(_)))
+            .enumerate()
+            .filter(|(_, lines)| !lines.is_empty())
+            .map(|(i, lines)| (count - i, lines))
             .collect();
+
         Self {
             handler,
-            end_span: tokens
-                .iter()
-                .find(|x| !x.span.content.trim().is_empty())
-                .map(|x| x.span.clone())
-                .unwrap_or_default(),
+            end_span: tokens.first().map(|x| x.span.clone()).unwrap_or_default(),
             tokens,
+            docs,
             disallow_circuit_construction: false,
+            allow_native_functions: false,
         }
     }
 
@@ -75,6 +97,55 @@ impl<'a> ParserContext<'a> {
         self.handler.emit_err(err.into());
     }
 
+    /// Emit the warning `warning`.
+    pub(crate) fn emit_warning(&self, warning: ParserWarning) {
+        self.handler.emit_warning(warning.into());
+    }
+
+    /// Emits the error `err` and skips the tokens of the item, statement, or circuit member it occurred in.
+    /// Parsing may then resume at the returned synchronization point.
+    ///
+    /// A `;`, or a `}` closing a `{` that was skipped, is consumed.
+    /// An unmatched `}`, `function`, or `circuit` ends or begins an enclosing item, so it is left to the caller.
+    /// At least one token is skipped if the error was emitted before `start_len` tokens were consumed.
+    pub(crate) fn recover(&mut self, err: LeoError, start_len: usize) {
+        self.handler.emit_err(err);
+
+        let mut depth = 0usize;
+        while let Some(token) = self.peek_option() {
+            match token.token {
+                Token::Function | Token::Circuit => break,
+                Token::Semicolon if depth == 0 => {
+                    self.bump();
+                    break;
+                }
+                Token::RightCurly if depth == 0 => break,
+                Token::RightCurly if depth == 1 => {
+                    self.bump();
+                    break;
+                }
+                Token::RightCurly => depth -= 1,
+                Token::LeftCurly => depth += 1,
+                _ => {}
+            }
+            self.bump();
+        }
+
+        if self.tokens.len() == start_len {
+            self.bump();
+        }
+    }
+
+    /// Returns the lines of the doc comments before the next token, if they were not taken already.
+    pub(crate) fn take_docs(&mut self) -> Vec<String> {
+        self.docs.remove(&self.tokens.len()).unwrap_or_default()
+    }
+
+    /// Returns the number of tokens left, for use with [`ParserContext::recover`].
+    pub(crate) fn tokens_left(&self) -> usize {
+        self.tokens.len()
+    }
+
     ///
     /// Returns an unexpected end of function [`SyntaxError`].
     ///
@@ -152,7 +223,11 @@ impl<'a> ParserContext<'a> {
                 span,
             } = self.bump().unwrap()
             {
-                return Some(Identifier { name, span });
+                return Some(Identifier {
+                    name,
+                    span,
+                    id: NodeID::next(),
+                });
             } else {
                 unreachable!("eat_identifier_ shouldn't produce this")
             }
@@ -333,11 +408,16 @@ impl<'a> ParserContext<'a> {
             return Ok(Identifier {
                 name: token.token.keyword_to_symbol().unwrap(),
                 span: token.span,
+                id: NodeID::next(),
             });
         }
         if let Some((int, span)) = self.eat_int() {
             let name = Symbol::intern(&int.value);
-            return Ok(Identifier { name, span });
+            return Ok(Identifier {
+                name,
+                span,
+                id: NodeID::next(),
+            });
         }
         self.expect_ident()
     }
@@ -351,7 +431,11 @@ impl<'a> ParserContext<'a> {
                     span,
                 } = self.bump().unwrap()
                 {
-                    Ok(Identifier { name, span })
+                    Ok(Identifier {
+                        name,
+                        span,
+                        id: NodeID::next(),
+                    })
                 } else {
                     unreachable!("expect_ident_ shouldn't produce this")
                 }
@@ -19,7 +19,7 @@ use super::*;
 use leo_errors::{ParserError, Result};
 use leo_span::sym;
 
-const INT_TYPES: &[Token] = &[
+pub(crate) const INT_TYPES: &[Token] = &[
     Token::I8,
     Token::I16,
     Token::I32,
@@ -75,6 +75,7 @@ impl ParserContext<'_> {
                 condition: Box::new(expr),
                 if_true: Box::new(if_true),
                 if_false: Box::new(if_false),
+                id: NodeID::next(),
             });
         }
         Ok(expr)
@@ -87,6 +88,7 @@ impl ParserContext<'_> {
             op,
             left: Box::new(left),
             right: Box::new(right),
+            id: NodeID::next(),
         })
     }
 
@@ -225,6 +227,7 @@ impl ParserContext<'_> {
                 span: expr.span() + &type_span,
                 inner: Box::new(expr),
                 target_type: type_,
+                id: NodeID::next(),
             })
         }
         Ok(expr)
@@ -252,6 +255,7 @@ impl ParserContext<'_> {
                 span: &op.span + inner.span(),
                 op: operation,
                 inner: Box::new(inner),
+                id: NodeID::next(),
             });
         }
         Ok(inner)
@@ -284,6 +288,7 @@ impl ParserContext<'_> {
                             array: Box::new(expr),
                             left: None,
                             right,
+                            id: NodeID::next(),
                         }));
                         continue;
                     }
@@ -302,6 +307,7 @@ impl ParserContext<'_> {
                             array: Box::new(expr),
                             left: Some(Box::new(left)),
                             right,
+                            id: NodeID::next(),
                         }));
                     } else {
                         let end = self.expect(Token::RightSquare)?;
@@ -309,6 +315,7 @@ impl ParserContext<'_> {
                             span: expr.span() + &end,
                             array: Box::new(expr),
                             index: Box::new(left),
+                            id: NodeID::next(),
                         }));
                     }
                 }
@@ -319,12 +326,14 @@ impl ParserContext<'_> {
                             inner: Box::new(expr),
                             name: ident,
                             type_: None,
+                            id: NodeID::next(),
                         }));
                     } else if let Some((num, span)) = self.eat_int() {
                         expr = Expression::Access(AccessExpression::Tuple(TupleAccess {
                             span: expr.span() + &span,
                             tuple: Box::new(expr),
                             index: num,
+                            id: NodeID::next(),
                         }));
                     } else {
                         let next = self.peek()?;
@@ -349,6 +358,7 @@ impl ParserContext<'_> {
                         span: expr.span() + &end_span,
                         function: Box::new(expr),
                         arguments,
+                        id: NodeID::next(),
                     });
                 }
                 Token::DoubleColon => {
@@ -358,6 +368,7 @@ impl ParserContext<'_> {
                         inner: Box::new(expr),
                         type_: None,
                         name: ident,
+                        id: NodeID::next(),
                     }));
                 }
                 _ => unreachable!("parse_postfix_expression_ shouldn't produce this"),
@@ -393,6 +404,7 @@ impl ParserContext<'_> {
             span: &identifier.span + &span,
             name: identifier,
             members,
+            id: NodeID::next(),
         }))
     }
 
@@ -407,6 +419,7 @@ impl ParserContext<'_> {
                     span,
                     x: left,
                     y: right,
+                    id: NodeID::next(),
                 },
             )))));
         }
@@ -431,6 +444,7 @@ impl ParserContext<'_> {
             Ok(Expression::TupleInit(TupleInitExpression {
                 span: span + &end_span,
                 elements: args,
+                id: NodeID::next(),
             }))
         }
     }
@@ -444,6 +458,7 @@ impl ParserContext<'_> {
             return Ok(Expression::ArrayInline(ArrayInlineExpression {
                 elements: Vec::new(),
                 span: span + &end.span,
+                id: NodeID::next(),
             }));
         }
         let first = self.parse_spread_or_expression()?;
@@ -463,6 +478,7 @@ impl ParserContext<'_> {
                 span: span + &end,
                 element: Box::new(first),
                 dimensions,
+                id: NodeID::next(),
             }))
         } else {
             let end_span;
@@ -488,6 +504,7 @@ impl ParserContext<'_> {
             Ok(Expression::ArrayInline(ArrayInlineExpression {
                 elements,
                 span: span + &end_span,
+                id: NodeID::next(),
             }))
         }
     }
@@ -512,7 +529,7 @@ impl ParserContext<'_> {
                         span: type_span,
                     }) => {
                         assert_no_whitespace(&span, &type_span, &value, "field")?;
-                        Expression::Value(ValueExpression::Field(value, span + type_span))
+                        Expression::Value(ValueExpression::Field(value, span + type_span, NodeID::next()))
                     }
                     Some(SpannedToken {
                         token: Token::Group,
@@ -522,6 +539,7 @@ impl ParserContext<'_> {
                         Expression::Value(ValueExpression::Group(Box::new(GroupValue::Single(
                             value,
                             span + type_span,
+                            NodeID::next(),
                         ))))
                     }
                     Some(SpannedToken { token, span: type_span }) => {
@@ -530,23 +548,29 @@ impl ParserContext<'_> {
                             Self::token_to_int_type(token).expect("unknown int type token"),
                             value,
                             span + type_span,
+                            NodeID::next(),
                         ))
                     }
-                    None => Expression::Value(ValueExpression::Implicit(value, span)),
+                    None => Expression::Value(ValueExpression::Implicit(value, span, NodeID::next())),
                 }
             }
-            Token::True => Expression::Value(ValueExpression::Boolean("true".into(), span)),
-            Token::False => Expression::Value(ValueExpression::Boolean("false".into(), span)),
-            Token::AddressLit(value) => Expression::Value(ValueExpression::Address(value, span)),
+            Token::True => Expression::Value(ValueExpression::Boolean("true".into(), span, NodeID::next())),
+            Token::False => Expression::Value(ValueExpression::Boolean("false".into(), span, NodeID::next())),
+            Token::AddressLit(value) => Expression::Value(ValueExpression::Address(value, span, NodeID::next())),
             Token::CharLit(value) => Expression::Value(ValueExpression::Char(CharValue {
                 character: value.into(),
                 span,
+                id: NodeID::next(),
             })),
-            Token::StringLit(value) => Expression::Value(ValueExpression::String(value, span)),
+            Token::StringLit(value) => Expression::Value(ValueExpression::String(value, span, NodeID::next())),
             Token::LeftParen => self.parse_tuple_expression(&span)?,
             Token::LeftSquare => self.parse_array_expression(&span)?,
             Token::Ident(name) => {
-                let ident = Identifier { name, span };
+                let ident = Identifier {
+                    name,
+                    span,
+                    id: NodeID::next(),
+                };
                 if !self.disallow_circuit_construction && self.peek_token().as_ref() == &Token::LeftCurly {
                     self.parse_circuit_expression(ident)?
                 } else {
@@ -557,6 +581,7 @@ impl ParserContext<'_> {
                 let ident = Identifier {
                     name: sym::SelfUpper,
                     span,
+                    id: NodeID::next(),
                 };
                 if !self.disallow_circuit_construction && self.peek_token().as_ref() == &Token::LeftCurly {
                     self.parse_circuit_expression(ident)?
@@ -567,11 +592,17 @@ impl ParserContext<'_> {
             Token::LittleSelf => Expression::Identifier(Identifier {
                 name: sym::SelfLower,
                 span,
+                id: NodeID::next(),
+            }),
+            Token::Input => Expression::Identifier(Identifier {
+                name: sym::input,
+                span,
+                id: NodeID::next(),
             }),
-            Token::Input => Expression::Identifier(Identifier { name: sym::input, span }),
             t if crate::type_::TYPE_TOKENS.contains(&t) => Expression::Identifier(Identifier {
                 name: t.keyword_to_symbol().unwrap(),
                 span,
+                id: NodeID::next(),
             }),
             token => {
                 return Err(ParserError::unexpected_str(token, "expression", &span).into());
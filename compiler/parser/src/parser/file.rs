@@ -17,7 +17,7 @@
 use super::*;
 use crate::KEYWORD_TOKENS;
 
-use leo_errors::{ParserError, Result};
+use leo_errors::{emitter::ALL_LINTS, LeoWarning, ParserError, ParserWarning, Result};
 use leo_span::sym;
 
 impl ParserContext<'_> {
@@ -25,64 +25,71 @@ impl ParserContext<'_> {
     /// Returns a [`Program`] AST if all tokens can be consumed and represent a valid Leo program.
     ///
     pub fn parse_program(&mut self) -> Result<Program> {
-        let mut import_statements = Vec::new();
-        let mut aliases = IndexMap::new();
-        let mut circuits = IndexMap::new();
-        let mut functions = IndexMap::new();
-        let mut global_consts = IndexMap::new();
-        // let mut tests = IndexMap::new();
+        let mut program = Program {
+            name: String::new(),
+            expected_input: Vec::new(),
+            import_statements: Vec::new(),
+            imports: IndexMap::new(),
+            aliases: IndexMap::new(),
+            circuits: IndexMap::new(),
+            functions: IndexMap::new(),
+            global_consts: IndexMap::new(),
+        };
 
         while self.has_next() {
-            let token = self.peek()?;
-            match &token.token {
-                Token::Import => {
-                    import_statements.push(self.parse_import_statement()?);
-                }
-                Token::Circuit => {
-                    self.expect(Token::Circuit)?;
+            let start_len = self.tokens_left();
+            if let Err(err) = self.parse_item(&mut program) {
+                // Report the error and continue with the next item, so that every syntax error is reported.
+                self.recover(err, start_len);
+            }
+        }
+        Ok(program)
+    }
+
+    /// Parses the next item of a program, and adds it to `program`.
+    fn parse_item(&mut self, program: &mut Program) -> Result<()> {
+        let token = self.peek()?;
+        match &token.token {
+            Token::Import => {
+                program.import_statements.push(self.parse_import_statement()?);
+            }
+            Token::Circuit => {
+                let docs = self.take_docs();
+                self.expect(Token::Circuit)?;
+                let (id, circuit) = self.parse_circuit()?;
+                program.circuits.insert(id, Circuit { docs, ..circuit });
+            }
+            Token::Ident(ident) => match *ident {
+                sym::test => return Err(ParserError::test_function(&token.span).into()),
+                kw @ (sym::Struct | sym::Class) => {
+                    self.emit_err(ParserError::unexpected(kw, "circuit", &token.span));
+                    let docs = self.take_docs();
+                    self.bump().unwrap();
                     let (id, circuit) = self.parse_circuit()?;
-                    circuits.insert(id, circuit);
-                }
-                Token::Ident(ident) => match *ident {
-                    sym::test => return Err(ParserError::test_function(&token.span).into()),
-                    kw @ (sym::Struct | sym::Class) => {
-                        self.emit_err(ParserError::unexpected(kw, "circuit", &token.span));
-                        self.bump().unwrap();
-                        let (id, circuit) = self.parse_circuit()?;
-                        circuits.insert(id, circuit);
-                    }
-                    _ => return Err(Self::unexpected_item(token).into()),
-                },
-                // Const functions share the first token with the global Const.
-                Token::Const if self.peek_is_function()? => {
-                    let (id, function) = self.parse_function_declaration()?;
-                    functions.insert(id, function);
-                }
-                Token::Const => {
-                    let (name, global_const) = self.parse_global_const_declaration()?;
-                    global_consts.insert(name, global_const);
-                }
-                Token::Function | Token::At => {
-                    let (id, function) = self.parse_function_declaration()?;
-                    functions.insert(id, function);
-                }
-                Token::Type => {
-                    let (name, alias) = self.parse_type_alias()?;
-                    aliases.insert(name, alias);
+                    program.circuits.insert(id, Circuit { docs, ..circuit });
                 }
                 _ => return Err(Self::unexpected_item(token).into()),
+            },
+            // Const functions share the first token with the global Const.
+            Token::Const if self.peek_is_function()? => {
+                let (id, function) = self.parse_function_declaration()?;
+                program.functions.insert(id, function);
+            }
+            Token::Const => {
+                let (name, global_const) = self.parse_global_const_declaration()?;
+                program.global_consts.insert(name, global_const);
+            }
+            Token::Function | Token::At => {
+                let (id, function) = self.parse_function_declaration()?;
+                program.functions.insert(id, function);
+            }
+            Token::Type => {
+                let (name, alias) = self.parse_type_alias()?;
+                program.aliases.insert(name, alias);
             }
+            _ => return Err(Self::unexpected_item(token).into()),
         }
-        Ok(Program {
-            name: String::new(),
-            expected_input: Vec::new(),
-            import_statements,
-            imports: IndexMap::new(),
-            aliases,
-            circuits,
-            functions,
-            global_consts,
-        })
+        Ok(())
     }
 
     fn unexpected_item(token: &SpannedToken) -> ParserError {
@@ -137,7 +144,11 @@ impl ParserContext<'_> {
 
         // Recover `context` instead of `test`.
         if name.name == sym::context {
-            self.emit_err(ParserError::context_annotation(&name.span));
+            self.emit_err(ParserError::context_annotation(&name.span).with_suggestion(
+                &name.span,
+                "use the `@test` annotation",
+                "test",
+            ));
             name.name = sym::test;
         }
 
@@ -210,6 +221,7 @@ impl ParserContext<'_> {
         Ok(ImportStatement {
             span: tree.span.clone(),
             tree,
+            id: NodeID::next(),
         })
     }
 
@@ -218,35 +230,20 @@ impl ParserContext<'_> {
     pub fn parse_circuit_declaration(&mut self) -> Result<Vec<CircuitMember>> {
         let mut members = Vec::new();
 
-        let (mut semi_colons, mut commas) = (false, false);
+        let (mut semi_colon, mut comma) = (None, None);
 
         while self.eat(Token::RightCurly).is_none() {
-            members.push(if self.peek_is_function()? {
-                // function
-                self.parse_member_function_declaration()?
-            } else if *self.peek_token() == Token::Static {
-                // static const
-                self.parse_const_member_variable_declaration()?
-            } else {
-                // variable
-                let variable = self.parse_member_variable_declaration()?;
-
-                if let Some(semi) = self.eat(Token::Semicolon) {
-                    if commas {
-                        self.emit_err(ParserError::mixed_commas_and_semicolons(&semi.span));
-                    }
-                    semi_colons = true;
-                }
-
-                if let Some(comma) = self.eat(Token::Comma) {
-                    if semi_colons {
-                        self.emit_err(ParserError::mixed_commas_and_semicolons(&comma.span));
-                    }
-                    commas = true;
-                }
+            // The circuit is not closed before the next circuit or the end of the file.
+            if matches!(*self.peek_token(), Token::Circuit | Token::Eof) {
+                self.expect(Token::RightCurly)?;
+            }
 
-                variable
-            });
+            let start_len = self.tokens_left();
+            match self.parse_circuit_member(&mut semi_colon, &mut comma) {
+                Ok(member) => members.push(member),
+                // Report the error and continue with the next member.
+                Err(err) => self.recover(err, start_len),
+            }
         }
 
         self.ban_mixed_member_order(&members);
@@ -254,24 +251,77 @@ impl ParserContext<'_> {
         Ok(members)
     }
 
+    /// Returns a [`CircuitMember`] AST node if the next tokens represent a circuit member.
+    /// The first semi-colon and comma separating member variables so far are tracked in `semi_colon` and `comma`.
+    fn parse_circuit_member(
+        &mut self,
+        semi_colon: &mut Option<Span>,
+        comma: &mut Option<Span>,
+    ) -> Result<CircuitMember> {
+        Ok(if self.peek_is_function()? {
+            // function
+            self.parse_member_function_declaration()?
+        } else if *self.peek_token() == Token::Static {
+            // static const
+            self.parse_const_member_variable_declaration()?
+        } else {
+            // variable
+            let variable = self.parse_member_variable_declaration()?;
+
+            if let Some(semi) = self.eat(Token::Semicolon) {
+                if let Some(first) = comma.as_ref() {
+                    self.emit_err(
+                        ParserError::mixed_commas_and_semicolons(&semi.span)
+                            .with_label(first, "the first comma is used here"),
+                    );
+                }
+                semi_colon.get_or_insert(semi.span);
+            }
+
+            if let Some(next) = self.eat(Token::Comma) {
+                if let Some(first) = semi_colon.as_ref() {
+                    self.emit_err(
+                        ParserError::mixed_commas_and_semicolons(&next.span)
+                            .with_label(first, "the first semi-colon is used here"),
+                    );
+                }
+                comma.get_or_insert(next.span);
+            }
+
+            variable
+        })
+    }
+
     /// Emits errors if order isn't `consts variables functions`.
+    /// The errors point to the first member variable or function that should have come later.
     fn ban_mixed_member_order(&self, members: &[CircuitMember]) {
-        let mut had_var = false;
-        let mut had_fun = false;
+        let mut first_var = None;
+        let mut first_fun = None;
         for member in members {
-            match member {
-                CircuitMember::CircuitConst(id, _, e) if had_var => {
-                    self.emit_err(ParserError::member_const_after_var(&(id.span() + e.span())));
+            match (member, first_var, first_fun) {
+                (CircuitMember::CircuitConst(id, _, e, _), Some(var), _) => {
+                    self.emit_err(
+                        ParserError::member_const_after_var(&(id.span() + e.span()))
+                            .with_label(var, "the first member variable is declared here"),
+                    );
                 }
-                CircuitMember::CircuitConst(id, _, e) if had_fun => {
-                    self.emit_err(ParserError::member_const_after_fun(&(id.span() + e.span())));
+                (CircuitMember::CircuitConst(id, _, e, _), _, Some(fun)) => {
+                    self.emit_err(
+                        ParserError::member_const_after_fun(&(id.span() + e.span()))
+                            .with_label(fun, "the first member function is declared here"),
+                    );
                 }
-                CircuitMember::CircuitVariable(id, _) if had_fun => {
-                    self.emit_err(ParserError::member_var_after_fun(id.span()));
+                (CircuitMember::CircuitVariable(id, ..), _, Some(fun)) => {
+                    self.emit_err(
+                        ParserError::member_var_after_fun(id.span())
+                            .with_label(fun, "the first member function is declared here"),
+                    );
+                }
+                (CircuitMember::CircuitConst(..), ..) => {}
+                (CircuitMember::CircuitVariable(id, ..), ..) => first_var = first_var.or(Some(id.span())),
+                (CircuitMember::CircuitFunction(function), ..) => {
+                    first_fun = first_fun.or(Some(function.identifier.span()))
                 }
-                CircuitMember::CircuitConst(..) => {}
-                CircuitMember::CircuitVariable(..) => had_var = true,
-                CircuitMember::CircuitFunction(..) => had_fun = true,
             }
         }
     }
@@ -287,6 +337,7 @@ impl ParserContext<'_> {
 
     /// Returns a [`CircuitMember`] AST node if the next tokens represent a circuit member static constant.
     pub fn parse_const_member_variable_declaration(&mut self) -> Result<CircuitMember> {
+        let docs = self.take_docs();
         self.expect(Token::Static)?;
         self.expect(Token::Const)?;
 
@@ -297,16 +348,17 @@ impl ParserContext<'_> {
 
         self.expect(Token::Semicolon)?;
 
-        Ok(CircuitMember::CircuitConst(name, type_, expr))
+        Ok(CircuitMember::CircuitConst(name, type_, expr, docs))
     }
 
     ///
     /// Returns a [`CircuitMember`] AST node if the next tokens represent a circuit member variable.
     ///
     pub fn parse_member_variable_declaration(&mut self) -> Result<CircuitMember> {
+        let docs = self.take_docs();
         let (name, type_) = self.parse_typed_field_name()?;
 
-        Ok(CircuitMember::CircuitVariable(name, type_))
+        Ok(CircuitMember::CircuitVariable(name, type_, docs))
     }
 
     /// Returns a [`CircuitMember`] AST node if the next tokens represent a circuit member function.
@@ -347,8 +399,10 @@ impl ParserContext<'_> {
         Ok((
             name.clone(),
             Circuit {
+                docs: Vec::new(),
                 circuit_name: name,
                 members,
+                id: NodeID::next(),
             },
         ))
     }
@@ -364,6 +418,7 @@ impl ParserContext<'_> {
             Identifier {
                 name: sym::SelfLower,
                 span: token.span,
+                id: NodeID::next(),
             }
         } else {
             self.expect_ident()?
@@ -413,13 +468,53 @@ impl ParserContext<'_> {
     /// Returns an [`(Identifier, Function)`] AST node if the next tokens represent a function name
     /// and function definition.
     pub fn parse_function_declaration(&mut self) -> Result<(Identifier, Function)> {
-        // Parse any annotations.
+        // Parse any annotations, with the doc comments before and after them.
+        let mut docs = self.take_docs();
         let mut annotations = IndexMap::new();
         while self.peek_token().as_ref() == &Token::At {
             let annotation = self.parse_annotation()?;
             annotations.insert(annotation.name.name, annotation);
+            docs.extend(self.take_docs());
+        }
+
+        // Warnings within the function, including those about its annotations, may be allowed through `@allow(...)`.
+        let handler = self.handler;
+        handler.with_allowed_lints(Self::allowed_lints(&annotations), || {
+            self.check_annotations(&annotations);
+            self.parse_function(docs, annotations)
+        })
+    }
+
+    /// Returns the lints allowed by an `@allow(...)` annotation.
+    fn allowed_lints(annotations: &IndexMap<Symbol, Annotation>) -> Vec<String> {
+        annotations
+            .get(&sym::allow)
+            .map(|allow| allow.arguments.iter().map(|lint| lint.to_string()).collect())
+            .unwrap_or_default()
+    }
+
+    /// Warns about annotations which have no effect, and about unknown lints in `@allow(...)`.
+    fn check_annotations(&self, annotations: &IndexMap<Symbol, Annotation>) {
+        for annotation in annotations.values() {
+            if !annotation.is_valid_annotation(self.allow_native_functions) {
+                self.emit_warning(ParserWarning::unknown_annotation(&annotation.name, &annotation.span));
+            } else if annotation.name.name == sym::allow {
+                for lint in annotation.arguments.iter().map(|lint| lint.to_string()) {
+                    if lint != ALL_LINTS && !LeoWarning::lint_names().any(|name| name == lint) {
+                        self.emit_warning(ParserWarning::unknown_lint(lint, &annotation.span));
+                    }
+                }
+            }
         }
+    }
 
+    /// Returns an [`(Identifier, Function)`] AST node with the given `docs` and `annotations`
+    /// if the next tokens represent a function name and function definition.
+    fn parse_function(
+        &mut self,
+        docs: Vec<String>,
+        annotations: IndexMap<Symbol, Annotation>,
+    ) -> Result<(Identifier, Function)> {
         // Parse optional const modifier.
         let const_ = self.eat(Token::Const).is_some();
 
@@ -442,20 +537,31 @@ impl ParserContext<'_> {
             None
         };
 
-        // Parse the function body.
-        let block = self.parse_block()?;
+        // Parse the function body, or the `;` ending a native function.
+        let (block, end) = if let Some(semicolon) = self.eat(Token::Semicolon) {
+            if !self.allow_native_functions {
+                self.emit_err(ParserError::native_function_outside_stdlib(&name, &semicolon.span));
+            }
+            (None, semicolon.span)
+        } else {
+            let block = self.parse_block()?;
+            let end = block.span.clone();
+            (Some(block), end)
+        };
 
         Ok((
             name.clone(),
             Function {
+                docs,
                 annotations,
                 identifier: name,
                 input: inputs,
                 const_,
                 output,
-                span: start + block.span.clone(),
+                span: start + end,
                 block,
                 core_mapping: <_>::default(),
+                id: NodeID::next(),
             },
         ))
     }
@@ -465,7 +571,11 @@ impl ParserContext<'_> {
     /// constant declaration.
     ///
     pub fn parse_global_const_declaration(&mut self) -> Result<(Vec<Identifier>, DefinitionStatement)> {
-        let statement = self.parse_definition_statement()?;
+        let docs = self.take_docs();
+        let statement = DefinitionStatement {
+            docs,
+            ..self.parse_definition_statement()?
+        };
         let variable_names = statement
             .variable_names
             .iter()
@@ -479,12 +589,22 @@ impl ParserContext<'_> {
     /// Returns a [`(String, Alias)`] AST node if the next tokens represent a type alias declaration.
     ///
     pub fn parse_type_alias(&mut self) -> Result<(Identifier, Alias)> {
+        let docs = self.take_docs();
         let start = self.expect(Token::Type)?;
         let name = self.expect_ident()?;
         self.expect(Token::Assign)?;
         let (represents, _) = self.parse_type()?;
         let span = start + self.expect(Token::Semicolon)?;
 
-        Ok((name.clone(), Alias { represents, span, name }))
+        Ok((
+            name.clone(),
+            Alias {
+                docs,
+                represents,
+                span,
+                name,
+                id: NodeID::next(),
+            },
+        ))
     }
 }
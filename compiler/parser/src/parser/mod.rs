@@ -24,7 +24,7 @@ use crate::{tokenizer::*, Token};
 use leo_ast::*;
 use leo_errors::emitter::Handler;
 use leo_errors::{ParserError, Result};
-use leo_span::{Span, Symbol};
+use leo_span::{BytePos, Span, Symbol};
 
 use indexmap::IndexMap;
 use std::unreachable;
@@ -39,10 +39,8 @@ pub mod statement;
 pub mod type_;
 
 pub(crate) fn assert_no_whitespace(left_span: &Span, right_span: &Span, left: &str, right: &str) -> Result<()> {
-    if left_span.col_stop != right_span.col_start {
-        let mut error_span = left_span + right_span;
-        error_span.col_start = left_span.col_stop - 1;
-        error_span.col_stop = right_span.col_start - 1;
+    if left_span.hi != right_span.lo {
+        let error_span = Span::new(left_span.hi - BytePos(1), right_span.lo - BytePos(1));
         return Err(ParserError::unexpected_whitespace(left, right, &error_span).into());
     }
 
@@ -50,15 +48,31 @@ pub(crate) fn assert_no_whitespace(left_span: &Span, right_span: &Span, left: &s
 }
 
 /// Creates a new program from a given file path and source code text.
+/// The syntax errors are emitted through the `handler`, and the result is an error if there were any.
 pub fn parse(handler: &Handler, path: &str, source: &str) -> Result<Program> {
     let mut tokens = ParserContext::new(handler, crate::tokenize(path, source)?);
 
-    tokens.parse_program()
+    let program = tokens.parse_program()?;
+    handler.last_err()?;
+    Ok(program)
 }
 
 /// Parses an input file at the given file `path` and `source` code text.
+/// The syntax errors are emitted through the `handler`, and the result is an error if there were any.
+/// Parses a file of the standard library, where functions may be native, i.e., declared without a body.
+pub fn parse_stdlib(handler: &Handler, path: &str, source: &str) -> Result<Program> {
+    let mut tokens = ParserContext::new(handler, crate::tokenize(path, source)?);
+    tokens.allow_native_functions = true;
+
+    let program = tokens.parse_program()?;
+    handler.last_err()?;
+    Ok(program)
+}
+
 pub fn parse_input(handler: &Handler, path: &str, source: &str) -> Result<ParsedInputFile> {
     let mut tokens = ParserContext::new(handler, crate::tokenize(path, source)?);
 
-    tokens.parse_input()
+    let input = tokens.parse_input()?;
+    handler.last_err()?;
+    Ok(input)
 }
@@ -118,12 +118,14 @@ impl ParserContext<'_> {
                     _ => unreachable!("parse_assign_statement_ shouldn't produce this"),
                 },
                 value,
+                id: NodeID::next(),
             })))
         } else {
             self.expect(Token::Semicolon)?;
             Ok(Statement::Expression(ExpressionStatement {
                 span: expr.span().clone(),
                 expression: expr,
+                id: NodeID::next(),
             }))
         }
     }
@@ -135,17 +137,43 @@ impl ParserContext<'_> {
         let start = self.expect(Token::LeftCurly)?;
 
         let mut statements = Vec::new();
+        let mut recovered = false;
         loop {
-            match self.eat(Token::RightCurly) {
-                None => {
-                    statements.push(self.parse_statement()?);
+            match *self.peek_token() {
+                Token::RightCurly => {
+                    let end = self.expect(Token::RightCurly)?;
+                    return Ok(Block {
+                        span: start + end,
+                        statements,
+                        id: NodeID::next(),
+                    });
                 }
-                Some(end) => {
+                // The block is not closed before the next item or the end of the file.
+                // When that follows a recovered statement, it has already been reported.
+                Token::Function | Token::Circuit | Token::Eof if recovered => {
                     return Ok(Block {
-                        span: start + end.span,
+                        span: start,
                         statements,
+                        id: NodeID::next(),
                     });
                 }
+                Token::Function | Token::Circuit | Token::Eof => {
+                    self.expect(Token::RightCurly)?;
+                }
+                _ => {
+                    let start_len = self.tokens_left();
+                    match self.parse_statement() {
+                        Ok(statement) => {
+                            statements.push(statement);
+                            recovered = false;
+                        }
+                        // Report the error and continue with the next statement.
+                        Err(err) => {
+                            self.recover(err, start_len);
+                            recovered = true;
+                        }
+                    }
+                }
             }
         }
     }
@@ -161,6 +189,7 @@ impl ParserContext<'_> {
         Ok(ReturnStatement {
             span: &start + expr.span(),
             expression: expr,
+            id: NodeID::next(),
         })
     }
 
@@ -186,6 +215,7 @@ impl ParserContext<'_> {
             condition: expr,
             block: body,
             next,
+            id: NodeID::next(),
         })
     }
 
@@ -212,6 +242,7 @@ impl ParserContext<'_> {
             stop,
             inclusive,
             block,
+            id: NodeID::next(),
         })
     }
 
@@ -270,6 +301,7 @@ impl ParserContext<'_> {
         Ok(ConsoleStatement {
             span: &keyword + function.span(),
             function,
+            id: NodeID::next(),
         })
     }
 
@@ -329,6 +361,8 @@ impl ParserContext<'_> {
             variable_names,
             type_,
             value: expr,
+            docs: Vec::new(),
+            id: NodeID::next(),
         })
     }
 }
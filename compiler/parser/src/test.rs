@@ -14,16 +14,24 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{tokenizer, ParserContext, SpannedToken};
-use leo_ast::{Expression, ExpressionStatement, Statement, ValueExpression};
+use crate::{
+    format_program, tokenizer, FormatOptions, GreenToken, ParserContext, SpannedToken, SyntaxKind, SyntaxNode,
+    DEFAULT_MAX_WIDTH,
+};
+use leo_ast::{
+    generate_docs, print_expression, print_import, print_program, print_statement, walk_expression, walk_statement,
+    Ast, AstDiff, DocFormat, DocModule, Expression, ExpressionStatement, Identifier, NodeID, NodeTables, Statement,
+    ValueExpression, Visitor, AST_SCHEMA_VERSION,
+};
 use leo_errors::{emitter::Handler, LeoError};
-use leo_span::{symbol::create_session_if_not_set_then, Span};
+use leo_span::{sym, symbol::create_session_if_not_set_then, Span};
 use leo_test_framework::{
     runner::{Namespace, ParseType, Runner},
     Test,
 };
 use serde::Serialize;
 use serde_yaml::Value;
+use std::collections::HashSet;
 use tokenizer::Token;
 
 struct TokenNamespace;
@@ -76,7 +84,7 @@ fn with_handler<T>(
 }
 
 fn implicit_value_expr() -> Expression {
-    Expression::Value(ValueExpression::Implicit("".into(), Span::default()))
+    Expression::Value(ValueExpression::Implicit("".into(), Span::default(), NodeID::DUMMY))
 }
 
 fn tokenize(test: Test) -> Result<Vec<SpannedToken>, String> {
@@ -93,6 +101,57 @@ fn yaml_or_fail<T: Serialize>(value: T) -> Value {
     serde_yaml::to_value(value).expect("serialization failed")
 }
 
+/// Returns the JSON of `node` without the spans and node IDs, and with the identifiers reduced to their names.
+fn json_without_spans<T: Serialize>(node: &T) -> serde_json::Value {
+    fn strip(value: serde_json::Value) -> serde_json::Value {
+        // Identifiers are serialized as strings holding their JSON, so that they can be map keys.
+        fn name(string: String) -> String {
+            match serde_json::from_str::<serde_json::Value>(&string) {
+                Ok(serde_json::Value::Object(mut map)) if map.contains_key("span") => match map.remove("name") {
+                    Some(serde_json::Value::String(name)) => name,
+                    _ => string,
+                },
+                _ => string,
+            }
+        }
+
+        match value {
+            serde_json::Value::Object(map) => map
+                .into_iter()
+                .filter(|(key, _)| key != "span" && key != "id")
+                .map(|(key, value)| (name(key), strip(value)))
+                .collect(),
+            serde_json::Value::Array(values) => values.into_iter().map(strip).collect(),
+            serde_json::Value::String(string) => serde_json::Value::String(name(string)),
+            value => value,
+        }
+    }
+
+    strip(serde_json::to_value(node).expect("failed to convert to json value"))
+}
+
+/// Checks that the code printed for `node` parses back into `node`, up to the spans and node IDs,
+/// and that printing the node parsed back gives the same code.
+fn check_printed<T: Serialize>(
+    node: &T,
+    print: impl Fn(&T) -> String,
+    parse: impl FnOnce(&mut ParserContext<'_>) -> Result<T, LeoError>,
+) -> Result<(), String> {
+    let printed = print(node);
+    let reparsed = tokenizer::tokenize("test", &printed)
+        .map_err(|e| e.to_string())
+        .and_then(|tokens| with_handler(tokens, parse))
+        .map_err(|e| format!("the printed code does not parse: {}\n{}", e, printed))?;
+    if json_without_spans(node) != json_without_spans(&reparsed) {
+        return Err(format!("the printed code parses into another AST:\n{}", printed));
+    }
+    let reprinted = print(&reparsed);
+    if reprinted != printed {
+        return Err(format!("printing is not stable:\n{}\n---\n{}", printed, reprinted));
+    }
+    Ok(())
+}
+
 struct ParseExpressionNamespace;
 
 impl Namespace for ParseExpressionNamespace {
@@ -106,7 +165,9 @@ impl Namespace for ParseExpressionNamespace {
             if all_are_comments(&tokenizer) {
                 return Ok(yaml_or_fail(implicit_value_expr()));
             }
-            with_handler(tokenizer, |p| p.parse_expression()).map(yaml_or_fail)
+            let expression = with_handler(tokenizer, |p| p.parse_expression())?;
+            check_printed(&expression, print_expression, |p| p.parse_expression())?;
+            Ok(yaml_or_fail(expression))
         })
     }
 }
@@ -125,9 +186,12 @@ impl Namespace for ParseImportNamespace {
                 return Ok(yaml_or_fail(Statement::Expression(ExpressionStatement {
                     expression: implicit_value_expr(),
                     span: Span::default(),
+                    id: NodeID::DUMMY,
                 })));
             }
-            with_handler(tokenizer, |p| p.parse_import_statement()).map(yaml_or_fail)
+            let import = with_handler(tokenizer, |p| p.parse_import_statement())?;
+            check_printed(&import, print_import, |p| p.parse_import_statement())?;
+            Ok(yaml_or_fail(import))
         })
     }
 }
@@ -146,9 +210,12 @@ impl Namespace for ParseStatementNamespace {
                 return Ok(yaml_or_fail(Statement::Expression(ExpressionStatement {
                     expression: implicit_value_expr(),
                     span: Span::default(),
+                    id: NodeID::DUMMY,
                 })));
             }
-            with_handler(tokenizer, |p| p.parse_statement()).map(yaml_or_fail)
+            let statement = with_handler(tokenizer, |p| p.parse_statement())?;
+            check_printed(&statement, print_statement, |p| p.parse_statement())?;
+            Ok(yaml_or_fail(statement))
         })
     }
 }
@@ -161,7 +228,11 @@ impl Namespace for ParseNamespace {
     }
 
     fn run_test(&self, test: Test) -> Result<Value, String> {
-        create_session_if_not_set_then(|_| with_handler(tokenize(test)?, |p| p.parse_program()).map(yaml_or_fail))
+        create_session_if_not_set_then(|_| {
+            let program = with_handler(tokenize(test)?, |p| p.parse_program())?;
+            check_printed(&program, print_program, |p| p.parse_program())?;
+            Ok(yaml_or_fail(program))
+        })
     }
 }
 
@@ -242,6 +313,68 @@ impl Namespace for InputNamespace {
     }
 }
 
+struct FormatNamespace;
+
+/// Returns the tokens of `source`, without trailing commas, and the text of its comments.
+fn tokens_and_comments(source: &str) -> Result<(Vec<Token>, Vec<String>), String> {
+    let tokens = tokenizer::tokenize("test", source).map_err(|x| x.to_string())?;
+    let (comments, tokens): (Vec<_>, Vec<_>) = tokens
+        .into_iter()
+        .map(|x| x.token)
+        .partition(|x| matches!(x, Token::CommentLine(_) | Token::CommentBlock(_)));
+
+    let trailing_comma = |(i, token): &(usize, &Token)| {
+        **token == Token::Comma
+            && matches!(
+                tokens.get(i + 1),
+                Some(Token::RightParen | Token::RightSquare | Token::RightCurly)
+            )
+    };
+    Ok((
+        tokens
+            .iter()
+            .enumerate()
+            .filter(|x| !trailing_comma(x))
+            .map(|(_, x)| x.clone())
+            .collect(),
+        comments.iter().map(|x| x.to_string().trim_end().to_string()).collect(),
+    ))
+}
+
+impl Namespace for FormatNamespace {
+    fn parse_type(&self) -> ParseType {
+        ParseType::Whole
+    }
+
+    fn run_test(&self, test: Test) -> Result<Value, String> {
+        let options = FormatOptions {
+            max_width: test
+                .config
+                .get("max_width")
+                .and_then(Value::as_u64)
+                .map_or(DEFAULT_MAX_WIDTH, |x| x as usize),
+        };
+
+        create_session_if_not_set_then(|_| {
+            let (handler, buf) = Handler::new_with_buf();
+            let format = |source: &str| {
+                handler
+                    .extend_if_error(format_program(&handler, "test", source, &options))
+                    .map_err(|_| buf.extract().to_string())
+            };
+
+            let formatted = format(&test.content)?;
+            if tokens_and_comments(&test.content)? != tokens_and_comments(&formatted)? {
+                return Err(format!("formatting changed more than whitespace:\n{}", formatted));
+            }
+            if format(&formatted)? != formatted {
+                return Err(format!("formatting again changed:\n{}", formatted));
+            }
+            Ok(Value::String(formatted))
+        })
+    }
+}
+
 struct TestRunner;
 
 impl Runner for TestRunner {
@@ -254,6 +387,7 @@ impl Runner for TestRunner {
             "Serialize" => Box::new(SerializeNamespace),
             "Input" => Box::new(InputNamespace),
             "Token" => Box::new(TokenNamespace),
+            "Format" => Box::new(FormatNamespace),
             _ => return None,
         })
     }
@@ -263,3 +397,479 @@ impl Runner for TestRunner {
 pub fn parser_tests() {
     leo_test_framework::run_tests(&TestRunner, "parser");
 }
+
+/// Collects the IDs of the expressions, statements and identifiers of a program.
+#[derive(Default)]
+struct NodeIdCollector(Vec<NodeID>);
+
+impl<'a> Visitor<'a> for NodeIdCollector {
+    fn visit_identifier(&mut self, identifier: &'a Identifier) {
+        self.0.push(identifier.id);
+    }
+
+    fn visit_expression(&mut self, expression: &'a Expression) {
+        if !matches!(expression, Expression::Identifier(_)) {
+            self.0.push(expression.id());
+        }
+        walk_expression(self, expression);
+    }
+
+    fn visit_statement(&mut self, statement: &'a Statement) {
+        self.0.push(statement.id());
+        walk_statement(self, statement);
+    }
+}
+
+const NODE_ID_PROGRAM: &str = "
+circuit Point {
+    x: u8,
+    function double(self) -> u8 { return self.x * 2; }
+}
+
+function main(a: u8) -> u8 {
+    let p = Point { x: a };
+    if a > 1u8 { return p.double(); }
+    return [1u8, 2u8][0] + (a, a).1;
+}";
+
+#[test]
+fn node_ids_are_unique() {
+    create_session_if_not_set_then(|_| {
+        let program = crate::parse(&Handler::default(), "test", NODE_ID_PROGRAM).unwrap();
+
+        let mut collector = NodeIdCollector::default();
+        collector.visit_program(&program);
+        let ids = collector.0;
+        assert!(ids.iter().all(|id| !id.is_dummy()));
+        assert_eq!(ids.iter().collect::<HashSet<_>>().len(), ids.len());
+    });
+}
+
+#[test]
+fn node_ids_are_ignored_by_equality() {
+    create_session_if_not_set_then(|_| {
+        let program = crate::parse(&Handler::default(), "test", NODE_ID_PROGRAM).unwrap();
+        let main = program
+            .functions
+            .values()
+            .find(|f| f.identifier.name == sym::main)
+            .unwrap();
+        let block = main.block.as_ref().unwrap();
+
+        // A copy of the block with new IDs equals the block.
+        let mut copy = block.clone();
+        copy.id = NodeID::next();
+        match copy.statements.last_mut() {
+            Some(Statement::Return(return_)) => {
+                return_.id = NodeID::next();
+                if let Expression::Binary(binary) = &mut return_.expression {
+                    binary.id = NodeID::next();
+                }
+            }
+            _ => panic!("expected a return statement"),
+        }
+        assert_ne!(copy.id, block.id);
+        assert_eq!(&copy, block);
+    });
+}
+
+#[test]
+fn node_tables_link_parents() {
+    create_session_if_not_set_then(|_| {
+        let program = crate::parse(&Handler::default(), "test", NODE_ID_PROGRAM).unwrap();
+        let tables = NodeTables::new(&program);
+
+        let main = program
+            .functions
+            .values()
+            .find(|f| f.identifier.name == sym::main)
+            .unwrap();
+        let block = main.block.as_ref().unwrap();
+        let return_ = match block.statements.last() {
+            Some(Statement::Return(return_)) => return_,
+            _ => panic!("expected a return statement"),
+        };
+        let left = match &return_.expression {
+            Expression::Binary(binary) => &binary.left,
+            _ => panic!("expected a binary expression"),
+        };
+
+        assert_eq!(
+            tables.ancestors(left.id()).collect::<Vec<_>>(),
+            vec![return_.expression.id(), return_.id, block.id, main.id]
+        );
+        assert_eq!(tables.parent(main.identifier.id), Some(main.id));
+        assert_eq!(tables.parent(main.id), None);
+    });
+}
+
+/// Returns the sources of the programs in the compiler tests.
+fn compiler_test_programs() -> Vec<(std::path::PathBuf, String)> {
+    fn walk(dir: &std::path::Path, out: &mut Vec<(std::path::PathBuf, String)>) {
+        for entry in std::fs::read_dir(dir).unwrap() {
+            let path = entry.unwrap().path();
+            if path.is_dir() {
+                walk(&path, out);
+            } else if path.extension().map_or(false, |ext| ext == "leo") {
+                let source = std::fs::read_to_string(&path).unwrap();
+                out.push((path, source));
+            }
+        }
+    }
+
+    let mut programs = Vec::new();
+    walk(
+        &std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("../../tests/compiler"),
+        &mut programs,
+    );
+    programs
+}
+
+#[test]
+fn cst_round_trips_programs() {
+    for (path, source) in compiler_test_programs() {
+        create_session_if_not_set_then(|_| {
+            if crate::parse(&Handler::default(), "test", &source).is_err() {
+                return;
+            }
+            let cst = crate::parse_cst(&Handler::default(), "test", &source)
+                .unwrap_or_else(|_| panic!("no CST for {}", path.display()));
+            assert_eq!(cst.to_string(), source, "{}", path.display());
+
+            // The tokens cover the text, and the nodes cover their children.
+            let mut offset = 0;
+            for token in cst.tokens() {
+                assert_eq!(token.text_range().start, offset, "{}", path.display());
+                offset = token.text_range().end;
+            }
+            for node in cst.descendants() {
+                let range = node.text_range();
+                assert!(node
+                    .children_with_tokens()
+                    .all(|child| range.start <= child.text_range().start && child.text_range().end <= range.end));
+            }
+
+            cst.to_program(&Handler::default(), "test").unwrap();
+        });
+    }
+}
+
+#[test]
+fn cst_keeps_trivia_and_edits() {
+    create_session_if_not_set_then(|_| {
+        let source = "// The entry point.\nfunction main(a: u8) -> u8 {\n    /* double */ return a + a; // twice\n}\n";
+        let cst = crate::parse_cst(&Handler::default(), "test", source).unwrap();
+        assert_eq!(cst.to_string(), source);
+
+        let function = cst.children().next().unwrap();
+        assert_eq!(function.kind(), SyntaxKind::Function);
+        assert_eq!(
+            function.text(),
+            "function main(a: u8) -> u8 {\n    /* double */ return a + a; // twice\n}"
+        );
+
+        let return_ = function
+            .descendants()
+            .into_iter()
+            .find(|node| node.kind() == SyntaxKind::Return)
+            .unwrap();
+        assert_eq!(return_.text(), "return a + a;");
+        // The comments around the statement belong to the block.
+        let block = return_.parent().unwrap();
+        assert_eq!(block.kind(), SyntaxKind::Block);
+        let comments: Vec<_> = block
+            .children_with_tokens()
+            .filter_map(|child| child.into_token())
+            .filter(|token| token.kind().is_trivia() && token.kind() != SyntaxKind::Whitespace)
+            .map(|token| token.text().to_string())
+            .collect();
+        assert_eq!(comments, vec!["/* double */", "// twice\n"]);
+
+        // Renaming `a` keeps everything else as is.
+        let renamed = cst
+            .tokens()
+            .into_iter()
+            .filter(|token| token.kind() == SyntaxKind::Ident && token.text() == "a")
+            .count();
+        assert_eq!(renamed, 3);
+        let mut root = cst.green().clone();
+        while let Some(token) = SyntaxNode::new_root(root.clone())
+            .tokens()
+            .into_iter()
+            .find(|token| token.kind() == SyntaxKind::Ident && token.text() == "a")
+        {
+            root = token.replace_with(GreenToken::new(SyntaxKind::Ident, "b"));
+        }
+        let renamed = SyntaxNode::new_root(root);
+        assert_eq!(
+            renamed.to_string(),
+            "// The entry point.\nfunction main(b: u8) -> u8 {\n    /* double */ return b + b; // twice\n}\n"
+        );
+        renamed.to_program(&Handler::default(), "test").unwrap();
+    });
+}
+
+#[test]
+fn printed_programs_parse_back() {
+    for (path, source) in compiler_test_programs() {
+        create_session_if_not_set_then(|_| {
+            let program = match crate::parse(&Handler::default(), "test", &source) {
+                Ok(program) => program,
+                Err(_) => return,
+            };
+            if let Err(error) = check_printed(&program, print_program, |p| p.parse_program()) {
+                panic!("{}: {}", path.display(), error);
+            }
+        });
+    }
+}
+
+#[test]
+fn json_ast_round_trips_programs() {
+    for (path, source) in compiler_test_programs() {
+        let json = create_session_if_not_set_then(|_| {
+            let ast = match crate::parse_ast(&Handler::default(), "test.leo", &source) {
+                Ok(ast) => ast,
+                Err(_) => return None,
+            };
+            let json = ast.to_json_string().unwrap();
+            let read = Ast::from_json_string(&json).unwrap();
+            assert_eq!(read.to_json_string().unwrap(), json, "{}", path.display());
+            Some(json)
+        });
+
+        // The JSON AST carries its sources, so that it can be read in another session.
+        if let Some(json) = json {
+            create_session_if_not_set_then(|_| {
+                let read = Ast::from_json_string(&json).unwrap();
+                assert_eq!(read.to_json_string().unwrap(), json, "{}", path.display());
+            });
+        }
+    }
+}
+
+#[test]
+fn json_ast_has_a_schema_version() {
+    create_session_if_not_set_then(|_| {
+        let ast = crate::parse_ast(&Handler::default(), "test.leo", NODE_ID_PROGRAM).unwrap();
+        let mut json = ast.to_json_value().unwrap();
+        assert_eq!(json["schema_version"], AST_SCHEMA_VERSION);
+        assert_eq!(json["sources"][0]["path"], "test.leo");
+
+        json["schema_version"] = (AST_SCHEMA_VERSION + 1).into();
+        assert!(Ast::from_json_value(json).is_err());
+    });
+}
+
+#[test]
+fn json_ast_keeps_node_ids_and_paths() {
+    let (json, ids) = create_session_if_not_set_then(|_| {
+        let ast = crate::parse_ast(&Handler::default(), "test.leo", NODE_ID_PROGRAM).unwrap();
+        let mut ids = NodeIdCollector::default();
+        ids.visit_program(ast.as_repr());
+        (ast.to_json_value().unwrap(), ids.0)
+    });
+    let main = json["program"]["functions"]
+        .as_object()
+        .unwrap()
+        .values()
+        .next()
+        .unwrap();
+    assert_eq!(main["span"]["path"], "test.leo");
+    assert!(main["id"].as_u64().unwrap() > 0);
+
+    // The IDs read are kept, and new nodes get other IDs.
+    create_session_if_not_set_then(|_| {
+        let read = Ast::from_json_value(json).unwrap();
+        let mut read_ids = NodeIdCollector::default();
+        read_ids.visit_program(read.as_repr());
+        assert_eq!(read_ids.0, ids);
+        let next = NodeID::next();
+        assert!(ids.iter().all(|id| id.index() < next.index()));
+    });
+}
+
+#[test]
+fn ast_diff_shows_changed_statements() {
+    let old = "
+function main(a: u32) -> u32 {
+    let b = a + 1;
+    if a > 2 {
+        b = 3;
+    } else {
+        b = 4;
+    }
+    return b;
+}
+
+function unchanged() {}
+
+circuit Point {
+    x: u32,
+
+    function get(self) -> u32 {
+        return self.x;
+    }
+}
+";
+    let new = "
+function main(a: u32) -> u32 {
+    let b = a + 1;
+    if a > 2 {
+        b = 5;
+    } else {
+        b = 4;
+    }
+    return b;
+}
+
+function unchanged() {}
+
+function added() {}
+
+circuit Point {
+    x: u32,
+
+    function get(self) -> u32 {
+        return self.x + 1;
+    }
+}
+";
+    let diff = create_session_if_not_set_then(|_| {
+        let old = crate::parse(&Handler::default(), "old.leo", old).unwrap();
+        let new = crate::parse(&Handler::default(), "new.leo", new).unwrap();
+        AstDiff::programs(&old, &new)
+    });
+
+    let expected = "  circuit Point {
+      x: u32,
+      function get(self) -> u32 {
+-         return self.x;
++         return self.x + 1;
+      }
+  }
+
+  function main(a: u32) -> u32 {
+      let b = a + 1;
+      if a > 2 {
+-         b = 3;
++         b = 5;
+      } else {
+          b = 4;
+      }
+      return b;
+  }
+
++ function added() {}
+";
+    assert_eq!(diff.to_string(), expected);
+    assert!(!diff.is_empty());
+
+    let same = create_session_if_not_set_then(|_| {
+        let program = crate::parse(&Handler::default(), "test.leo", NODE_ID_PROGRAM).unwrap();
+        AstDiff::programs(&program, &program)
+    });
+    assert!(same.is_empty());
+}
+
+#[test]
+fn ast_diff_shows_changed_json_values() {
+    let old = serde_json::json!({ "name": "main", "inputs": [1, 2, 3], "output": { "type": "u8" } });
+    let new = serde_json::json!({ "name": "main", "inputs": [1, 4, 3], "output": { "type": "u16" } });
+
+    let expected = "  inputs:
+-     [1]: 2
++     [1]: 4
+  output:
+-     type: \"u8\"
++     type: \"u16\"
+";
+    assert_eq!(AstDiff::json(&old, &new).to_string(), expected);
+}
+
+#[test]
+fn docs_link_items_across_modules() {
+    let geometry = "
+/// A point in the plane.
+circuit Point {
+    /// The first coordinate.
+    x: u32,
+}
+";
+    let main = "
+/// Returns the [`Point.x`] of `p`, or [nothing].
+function get(p: Point) -> u32 {
+    return p.x;
+}
+";
+    let pages = create_session_if_not_set_then(|_| {
+        let modules = [("geometry", geometry), ("main", main)]
+            .iter()
+            .map(|(name, source)| DocModule {
+                path: vec![name.to_string()],
+                program: crate::parse(&Handler::default(), name, source).unwrap(),
+            })
+            .collect::<Vec<_>>();
+        (
+            generate_docs(&modules, DocFormat::Markdown),
+            generate_docs(&modules, DocFormat::Html),
+        )
+    });
+    let (markdown, html) = pages;
+
+    let file_names = markdown.iter().map(|page| page.file_name.as_str()).collect::<Vec<_>>();
+    assert_eq!(file_names, ["index.md", "geometry.md", "main.md"]);
+    assert_eq!(
+        markdown[0].content,
+        "# Modules\n\n- [`geometry`](geometry.md)\n- [`main`](main.md)\n"
+    );
+    assert_eq!(
+        markdown[2].content,
+        "# Module `main`
+
+[Modules](index.md)
+
+## Functions
+
+<a id=\"function.get\"></a>
+### `get`
+
+```leo
+function get(p: Point) -> u32
+```
+
+Types: [`Point`](geometry.md#circuit.Point)
+
+Returns the [`Point.x`](geometry.md#circuit.Point.x) of `p`, or [nothing].
+"
+    );
+    assert!(markdown[1]
+        .content
+        .contains("<a id=\"circuit.Point.x\"></a>\n#### `x`\n\n```leo\nx: u32\n```"));
+
+    let main = &html[2].content;
+    assert_eq!(html[2].file_name, "main.html");
+    assert!(main.contains(
+        "<pre><code>function get(p: <a href=\"geometry.html#circuit.Point\">Point</a>) -&gt; u32</code></pre>"
+    ));
+    assert!(main.contains(
+        "<p>Returns the <a href=\"geometry.html#circuit.Point.x\"><code>Point.x</code></a> of <code>p</code>, or [nothing].</p>"
+    ));
+}
+
+#[test]
+fn internal_annotations_are_only_allowed_in_the_stdlib() {
+    create_session_if_not_set_then(|_| {
+        let source = "@CoreFunction\n@AlwaysConst\nfunction id(a: u8) -> u8 {\n    return a;\n}\n";
+
+        let (handler, buf) = Handler::new_with_buf();
+        crate::parse(&handler, "test", source).unwrap();
+        let warnings = buf.extract_warnings().to_string();
+        assert!(warnings.contains("unknown annotation `@CoreFunction` has no effect"));
+        assert!(warnings.contains("unknown annotation `@AlwaysConst` has no effect"));
+
+        let (handler, buf) = Handler::new_with_buf();
+        crate::parser::parse_stdlib(&handler, "test", source).unwrap();
+        assert_eq!(buf.extract_warnings().to_string(), "");
+    });
+}
@@ -20,7 +20,6 @@
 //! separated by whitespace.
 
 pub(crate) mod token;
-use std::sync::Arc;
 
 pub use self::token::KEYWORD_TOKENS;
 pub(crate) use self::token::*;
@@ -29,71 +28,40 @@ pub(crate) mod lexer;
 pub(crate) use self::lexer::*;
 
 use leo_errors::{ParserError, Result};
-use leo_span::Span;
+use leo_span::{symbol::with_session_globals, BytePos, Span};
 
 /// Creates a new vector of spanned tokens from a given file path and source code text.
 pub(crate) fn tokenize(path: &str, input: &str) -> Result<Vec<SpannedToken>> {
-    let path = Arc::new(path.to_string());
-    let mut tokens = vec![];
+    Ok(tokenize_with_whitespace(path, input)?
+        .into_iter()
+        .filter(|token| token.token != Token::WhiteSpace)
+        .collect())
+}
+
+/// Creates a new vector of spanned tokens from a given file path and source code text,
+/// keeping a whitespace token for each run of whitespace, so that the tokens cover the whole text.
+pub(crate) fn tokenize_with_whitespace(path: &str, input: &str) -> Result<Vec<SpannedToken>> {
+    let source_file = with_session_globals(|s| s.source_map.new_source(input, path));
+    let mut tokens: Vec<SpannedToken> = vec![];
     let mut index = 0usize;
-    let mut line_no = 1usize;
-    let mut line_start = 0usize;
     while input.len() > index {
-        match Token::eat(&input[index..])? {
-            (token_len, Token::WhiteSpace) => {
-                let bytes = input.as_bytes();
-                if bytes[index] == 0x000D && matches!(bytes.get(index + 1), Some(0x000A)) {
-                    // Check carriage return followed by newline.
-                    line_no += 1;
-                    line_start = index + token_len;
-                    index += token_len;
-                } else if matches!(bytes[index], 0x000A | 0x000D) {
-                    // Check new-line or carriage-return
-                    line_no += 1;
-                    line_start = index + token_len;
-                }
-                index += token_len;
-            }
-            (token_len, token) => {
-                let mut span = Span::new(
-                    line_no,
-                    line_no,
-                    index - line_start + 1,
-                    index - line_start + token_len + 1,
-                    path.clone(),
-                    input[line_start
-                        ..input[line_start..]
-                            .find('\n')
-                            .map(|i| i + line_start)
-                            .unwrap_or(input.len())]
-                        .to_string(),
-                );
-                match &token {
-                    Token::CommentLine(_) => {
-                        line_no += 1;
-                        line_start = index + token_len;
+        let (token_len, token) = Token::eat(&input[index..])?;
+        let span = Span::new(
+            source_file.start_pos + BytePos::from_usize(index),
+            source_file.start_pos + BytePos::from_usize(index + token_len),
+        );
+        match (token, tokens.last_mut()) {
+            (Token::WhiteSpace, Some(last)) if last.token == Token::WhiteSpace => last.span.hi = span.hi,
+            (token, _) => {
+                if let Token::AddressLit(address) = &token {
+                    if !check_address(address) {
+                        return Err(ParserError::invalid_address_lit(address, &span).into());
                     }
-                    Token::CommentBlock(block) => {
-                        let line_ct = block.chars().filter(|x| *x == '\n').count();
-                        line_no += line_ct;
-                        if line_ct > 0 {
-                            let last_line_index = block.rfind('\n').unwrap();
-                            line_start = index + last_line_index + 1;
-                            span.col_stop = index + token_len - line_start + 1;
-                        }
-                        span.line_stop = line_no;
-                    }
-                    Token::AddressLit(address) => {
-                        if !check_address(address) {
-                            return Err(ParserError::invalid_address_lit(address, &span).into());
-                        }
-                    }
-                    _ => (),
                 }
                 tokens.push(SpannedToken { token, span });
-                index += token_len;
             }
         }
+        index += token_len;
     }
     Ok(tokens)
 }
@@ -229,18 +197,10 @@ ppp            test
             test
             "#;
             let tokens = tokenize("test_path", raw.into()).unwrap();
-            let mut line_indicies = vec![0];
-            for (i, c) in raw.chars().enumerate() {
-                if c == '\n' {
-                    line_indicies.push(i + 1);
-                }
-            }
             for token in tokens.iter() {
                 let token_raw = token.token.to_string();
-                let start = line_indicies.get(token.span.line_start - 1).unwrap();
-                let stop = line_indicies.get(token.span.line_stop - 1).unwrap();
-                let original = &raw[*start + token.span.col_start - 1..*stop + token.span.col_stop - 1];
-                assert_eq!(original, &token_raw);
+                let original = with_session_globals(|s| s.source_map.contents_of_span(&token.span)).unwrap();
+                assert_eq!(original, token_raw);
             }
         })
     }
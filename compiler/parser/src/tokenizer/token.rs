@@ -190,6 +190,38 @@ pub const KEYWORD_TOKENS: &[Token] = &[
 ];
 
 impl Token {
+    /// Returns the lines of `self` if it is a doc comment, i.e., a `///` or a `/** */` comment.
+    /// The comment markers, the leading `*` of the lines of a block, and the space after them are removed.
+    pub fn doc_comment_lines(&self) -> Option<Vec<String>> {
+        match self {
+            Token::CommentLine(comment) if comment.starts_with("///") && !comment.starts_with("////") => {
+                let line = comment[3..].trim_end();
+                Some(vec![line.strip_prefix(' ').unwrap_or(line).to_string()])
+            }
+            Token::CommentBlock(comment)
+                if comment.starts_with("/**") && !comment.starts_with("/***") && comment.len() > 4 =>
+            {
+                let mut lines = comment[3..comment.len() - 2]
+                    .lines()
+                    .map(|line| {
+                        let line = line.trim();
+                        let line = line.strip_prefix('*').unwrap_or(line);
+                        line.strip_prefix(' ').unwrap_or(line).to_string()
+                    })
+                    .collect::<Vec<_>>();
+                // The lines of the markers are left out if there is nothing else on them.
+                if lines.first().map_or(false, |line| line.is_empty()) {
+                    lines.remove(0);
+                }
+                if lines.last().map_or(false, |line| line.is_empty()) {
+                    lines.pop();
+                }
+                Some(lines)
+            }
+            _ => None,
+        }
+    }
+
     /// Returns `true` if the `self` token equals a Leo keyword.
     pub fn is_keyword(&self) -> bool {
         KEYWORD_TOKENS.contains(self)
@@ -0,0 +1,279 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The conversions between integers and their bits or bytes of the `std.bits` module, from RFC 009.
+
+use leo_ast::{
+    ArrayInlineExpression, Expression, IntegerType, Node, NodeID, SpreadOrExpression, UnaryExpression, UnaryOperation,
+    ValueExpression,
+};
+
+use std::fmt;
+
+/// The integer types, in the order of RFC 009.
+const INTEGER_TYPES: [IntegerType; 10] = [
+    IntegerType::U8,
+    IntegerType::U16,
+    IntegerType::U32,
+    IntegerType::U64,
+    IntegerType::U128,
+    IntegerType::I8,
+    IntegerType::I16,
+    IntegerType::I32,
+    IntegerType::I64,
+    IntegerType::I128,
+];
+
+/// Whether an integer is converted into a sequence of bits or bytes, or from one.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Direction {
+    To,
+    From,
+}
+
+/// The elements of a sequence an integer is converted to or from.
+/// Bits are `bool` values, and bytes are `u8` values.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Unit {
+    Bits,
+    Bytes,
+}
+
+impl Unit {
+    /// Returns the number of bits of the unit.
+    fn size_in_bits(self) -> u32 {
+        match self {
+            Unit::Bits => 1,
+            Unit::Bytes => 8,
+        }
+    }
+}
+
+/// The order of a sequence of bits or bytes, i.e., whether the least or the most significant one is first.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Endianness {
+    Little,
+    Big,
+}
+
+/// A conversion of `std.bits`, e.g., `u32_to_bytes_le`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BitConversion {
+    pub integer: IntegerType,
+    pub direction: Direction,
+    pub unit: Unit,
+    pub endianness: Endianness,
+}
+
+impl BitConversion {
+    /// Returns all the conversions, in the order of RFC 009.
+    /// There are no byte conversions of `u8` and `i8`.
+    pub fn all() -> Vec<BitConversion> {
+        let mut conversions = Vec::new();
+        for unit in [Unit::Bits, Unit::Bytes].iter().copied() {
+            for direction in [Direction::To, Direction::From].iter().copied() {
+                for integer in INTEGER_TYPES.iter().copied() {
+                    for endianness in [Endianness::Little, Endianness::Big].iter().copied() {
+                        let conversion = BitConversion {
+                            integer,
+                            direction,
+                            unit,
+                            endianness,
+                        };
+                        if conversion.length() > 1 {
+                            conversions.push(conversion);
+                        }
+                    }
+                }
+            }
+        }
+        conversions
+    }
+
+    /// Returns the conversion with the given name, e.g., `u32_to_bytes_le`.
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::all()
+            .into_iter()
+            .find(|conversion| conversion.to_string() == name)
+    }
+
+    /// Returns the number of bits or bytes of the sequence, e.g., `4` for `u32_to_bytes_le`.
+    pub fn length(&self) -> usize {
+        (self.integer.size_in_bits() / self.unit.size_in_bits()) as usize
+    }
+
+    /// Evaluates the conversion of a literal `argument`, e.g., `258u16` or `[2u8, 1u8]`.
+    /// Returns `None` if the argument is not a literal of the input type of the conversion.
+    pub fn evaluate(&self, argument: &Expression) -> Option<Expression> {
+        let span = argument.span().clone();
+        let width = self.integer.size_in_bits();
+        let unit = self.unit.size_in_bits();
+        let mask = |bits: u32| if bits == 128 { u128::MAX } else { (1u128 << bits) - 1 };
+
+        match self.direction {
+            Direction::To => {
+                let raw = self.integer_literal(argument)?;
+                let mut elements: Vec<u128> = (0..width / unit).map(|i| (raw >> (i * unit)) & mask(unit)).collect();
+                if self.endianness == Endianness::Big {
+                    elements.reverse();
+                }
+
+                let elements = elements
+                    .into_iter()
+                    .map(|element| {
+                        let value = match self.unit {
+                            Unit::Bits => {
+                                ValueExpression::Boolean((element == 1).to_string(), span.clone(), NodeID::next())
+                            }
+                            Unit::Bytes => ValueExpression::Integer(
+                                IntegerType::U8,
+                                element.to_string(),
+                                span.clone(),
+                                NodeID::next(),
+                            ),
+                        };
+                        SpreadOrExpression::Expression(Expression::Value(value))
+                    })
+                    .collect();
+
+                Some(Expression::ArrayInline(ArrayInlineExpression {
+                    elements,
+                    span,
+                    id: NodeID::next(),
+                }))
+            }
+            Direction::From => {
+                let mut elements = self.sequence_literal(argument)?;
+                if self.endianness == Endianness::Big {
+                    elements.reverse();
+                }
+                let raw = elements
+                    .into_iter()
+                    .enumerate()
+                    .fold(0u128, |raw, (i, element)| raw | (element << (i as u32 * unit)));
+
+                // A negative value is the two's complement of its magnitude.
+                let negative = self.integer.is_signed() && (raw >> (width - 1)) & 1 == 1;
+                let magnitude = if negative {
+                    raw.wrapping_neg() & mask(width)
+                } else {
+                    raw
+                };
+
+                let literal = Expression::Value(ValueExpression::Integer(
+                    self.integer,
+                    magnitude.to_string(),
+                    span.clone(),
+                    NodeID::next(),
+                ));
+                Some(if negative {
+                    Expression::Unary(UnaryExpression {
+                        inner: Box::new(literal),
+                        op: UnaryOperation::Negate,
+                        span,
+                        id: NodeID::next(),
+                    })
+                } else {
+                    literal
+                })
+            }
+        }
+    }
+
+    /// Returns the two's complement representation of an integer literal of the input type,
+    /// which may be negated if the type is signed.
+    fn integer_literal(&self, expression: &Expression) -> Option<u128> {
+        let (negative, literal) = match expression {
+            Expression::Unary(UnaryExpression {
+                op: UnaryOperation::Negate,
+                inner,
+                ..
+            }) if self.integer.is_signed() => (true, &**inner),
+            expression => (false, expression),
+        };
+        let magnitude: u128 = match literal {
+            Expression::Value(ValueExpression::Integer(type_, value, ..)) if *type_ == self.integer => {
+                value.parse().ok()?
+            }
+            Expression::Value(ValueExpression::Implicit(value, ..)) => value.parse().ok()?,
+            _ => return None,
+        };
+
+        // The magnitude must fit in the type, e.g., `-128` and `127` for `i8`.
+        let width = self.integer.size_in_bits();
+        let limit = if self.integer.is_signed() {
+            1u128 << (width - 1)
+        } else {
+            u128::MAX >> (128 - width)
+        };
+        match (negative, self.integer.is_signed()) {
+            (true, _) if magnitude <= limit => Some(magnitude.wrapping_neg() & (u128::MAX >> (128 - width))),
+            (false, true) if magnitude < limit => Some(magnitude),
+            (false, false) if magnitude <= limit => Some(magnitude),
+            _ => None,
+        }
+    }
+
+    /// Returns the bits or bytes of an array literal of the input type, in their order in the array.
+    fn sequence_literal(&self, expression: &Expression) -> Option<Vec<u128>> {
+        let array = match expression {
+            Expression::ArrayInline(array) if array.elements.len() == self.length() => array,
+            _ => return None,
+        };
+
+        array
+            .elements
+            .iter()
+            .map(|element| match (self.unit, element) {
+                (
+                    Unit::Bits,
+                    SpreadOrExpression::Expression(Expression::Value(ValueExpression::Boolean(value, ..))),
+                ) => Some((value == "true") as u128),
+                (
+                    Unit::Bytes,
+                    SpreadOrExpression::Expression(Expression::Value(ValueExpression::Integer(
+                        IntegerType::U8,
+                        value,
+                        ..,
+                    ))),
+                )
+                | (
+                    Unit::Bytes,
+                    SpreadOrExpression::Expression(Expression::Value(ValueExpression::Implicit(value, ..))),
+                ) => value.parse::<u8>().ok().map(u128::from),
+                _ => None,
+            })
+            .collect()
+    }
+}
+
+impl fmt::Display for BitConversion {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let direction = match self.direction {
+            Direction::To => "to",
+            Direction::From => "from",
+        };
+        let unit = match self.unit {
+            Unit::Bits => "bits",
+            Unit::Bytes => "bytes",
+        };
+        let endianness = match self.endianness {
+            Endianness::Little => "le",
+            Endianness::Big => "be",
+        };
+        write!(f, "{}_{}_{}_{}", self.integer, direction, unit, endianness)
+    }
+}
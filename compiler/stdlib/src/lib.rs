@@ -16,8 +16,18 @@
 
 #![doc = include_str!("../README.md")]
 
-use leo_ast::Program;
-use leo_errors::{ImportError, Result};
+pub mod bits;
+pub use bits::*;
+
+pub mod native;
+pub use native::*;
+
+#[cfg(test)]
+mod test;
+
+use leo_ast::{CircuitMember, Program};
+use leo_errors::{emitter::Handler, ImportError, Result};
+use leo_span::Symbol;
 
 #[macro_use]
 extern crate include_dir;
@@ -27,26 +37,46 @@ use indexmap::IndexMap;
 
 static STDLIB: Dir = include_dir!(".");
 
-fn resolve_file(file: &str, mapping: Option<&str>) -> Result<Program> {
+fn resolve_file(handler: &Handler, file: &str) -> Result<Program> {
     let resolved = STDLIB
         .get_file(&file)
         .ok_or_else(|| ImportError::no_such_stdlib_file(file))?
         .contents_utf8()
         .ok_or_else(|| ImportError::failed_to_read_stdlib_file(file))?;
 
-    let ast = leo_parser::parse_ast(&file, resolved)?.into_repr();
-    ast.set_core_mapping(mapping);
+    let mut ast = leo_parser::parse_stdlib_ast(handler, file, resolved)?.into_repr();
+    ast.handle_internal_annotations();
+
+    // The native functions, i.e., those without a body, map to the core library through the registry.
+    let module = file.trim_end_matches(".leo").replace("/", ".");
+    let registry = NativeRegistry::stdlib();
+    let circuit_functions = ast.circuits.values().flat_map(|circuit| {
+        circuit.members.iter().filter_map(move |member| match member {
+            CircuitMember::CircuitFunction(function) => Some((format!("{}.", circuit.circuit_name), &**function)),
+            _ => None,
+        })
+    });
+    let global_functions = ast.functions.values().map(|function| (String::new(), function));
+    for (prefix, function) in circuit_functions.chain(global_functions) {
+        if function.is_native() {
+            let name = format!("{}.{}{}", module, prefix, function.identifier);
+            let native = registry
+                .get(&name)
+                .ok_or_else(|| ImportError::unknown_native_function(&name, &function.span))?;
+            function.core_mapping.set(Some(Symbol::intern(&native.core_mapping)));
+        }
+    }
 
     Ok(ast)
 }
 
-pub fn resolve_prelude_modules() -> Result<IndexMap<Vec<String>, Program>> {
+pub fn resolve_prelude_modules(handler: &Handler) -> Result<IndexMap<Vec<String>, Program>> {
     let mut preludes: IndexMap<Vec<String>, Program> = IndexMap::new();
 
     for module in STDLIB.find("prelude/*.leo").unwrap() {
         // If on windows repalce \\ with / as all paths are stored in unix style.
         let path = module.path().to_str().unwrap_or("").replace("\\", "/");
-        let program = resolve_file(&path, None)?;
+        let program = resolve_file(handler, &path)?;
 
         let removed_extension = path.replace(".leo", "");
         let mut parts: Vec<String> = vec![String::from("std")];
@@ -62,15 +92,22 @@ pub fn resolve_prelude_modules() -> Result<IndexMap<Vec<String>, Program>> {
     Ok(preludes)
 }
 
-pub fn resolve_stdlib_module(module: &str) -> Result<Program> {
+/// Returns the names of the modules of the standard library, e.g., `unstable.blake2s`.
+pub fn stdlib_module_names() -> Vec<String> {
+    STDLIB
+        .find("**/*.leo")
+        .unwrap()
+        .map(|module| {
+            // If on windows replace \\ with / as all paths are stored in unix style.
+            let path = module.path().to_str().unwrap_or("").replace("\\", "/");
+            path.trim_end_matches(".leo").replace("/", ".")
+        })
+        .collect()
+}
+
+pub fn resolve_stdlib_module(handler: &Handler, module: &str) -> Result<Program> {
     let mut file_path = module.replace(".", "/");
     file_path.push_str(".leo");
 
-    let mapping = if module == "unstable.blake2s" {
-        Some("blake2s")
-    } else {
-        None
-    };
-
-    resolve_file(&file_path, mapping)
+    resolve_file(handler, &file_path)
 }
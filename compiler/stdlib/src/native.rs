@@ -0,0 +1,89 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The registry of the native functions of the standard library, i.e., the functions declared without a body.
+
+use crate::BitConversion;
+use leo_ast::Expression;
+
+use indexmap::IndexMap;
+
+/// Evaluates a call of a native function on literal arguments, or returns `None` if it cannot.
+pub type NativeEvaluator = Box<dyn Fn(&[Expression]) -> Option<Expression>>;
+
+/// The implementation of a native function.
+pub struct NativeFunction {
+    /// The function of the core library computing the native function, e.g., `blake2s`.
+    pub core_mapping: String,
+    /// Evaluates the calls on literal arguments, if the function can be evaluated by the compiler.
+    pub evaluate: Option<NativeEvaluator>,
+}
+
+impl NativeFunction {
+    /// Returns a native function computed by the core function `core_mapping`, which the compiler cannot evaluate.
+    pub fn new(core_mapping: impl Into<String>) -> Self {
+        Self {
+            core_mapping: core_mapping.into(),
+            evaluate: None,
+        }
+    }
+}
+
+/// The native functions by their qualified names, e.g., `unstable.blake2s.Blake2s.hash` or `bits.u32_to_bits_le`.
+/// That is, the module, the circuit if any, and the function, separated by dots.
+#[derive(Default)]
+pub struct NativeRegistry {
+    functions: IndexMap<String, NativeFunction>,
+}
+
+impl NativeRegistry {
+    /// Returns the registry of the native functions of the standard library.
+    pub fn stdlib() -> Self {
+        let mut registry = Self::default();
+
+        registry.register("unstable.blake2s.Blake2s.hash", NativeFunction::new("blake2s"));
+
+        for conversion in BitConversion::all() {
+            registry.register(
+                format!("bits.{}", conversion),
+                NativeFunction {
+                    core_mapping: conversion.to_string(),
+                    evaluate: Some(Box::new(move |arguments| match arguments {
+                        [argument] => conversion.evaluate(argument),
+                        _ => None,
+                    })),
+                },
+            );
+        }
+
+        registry
+    }
+
+    /// Registers the native function with the qualified name `name`, replacing any previous one.
+    pub fn register(&mut self, name: impl Into<String>, function: NativeFunction) {
+        self.functions.insert(name.into(), function);
+    }
+
+    /// Returns the native function with the qualified name `name`, if any.
+    pub fn get(&self, name: &str) -> Option<&NativeFunction> {
+        self.functions.get(name)
+    }
+
+    /// Returns the qualified names of the native functions, in the order they were registered.
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.functions.keys().map(String::as_str)
+    }
+}
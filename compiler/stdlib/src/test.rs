@@ -0,0 +1,137 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{resolve_stdlib_module, BitConversion, NativeRegistry};
+use leo_ast::{
+    print_expression, CircuitMember, Expression, IntegerType, NodeID, UnaryExpression, UnaryOperation, ValueExpression,
+};
+use leo_errors::emitter::Handler;
+use leo_span::{symbol::create_session_if_not_set_then, Span};
+
+fn integer(type_: IntegerType, value: &str) -> Expression {
+    Expression::Value(ValueExpression::Integer(
+        type_,
+        value.to_string(),
+        Span::dummy(),
+        NodeID::DUMMY,
+    ))
+}
+
+fn negate(inner: Expression) -> Expression {
+    Expression::Unary(UnaryExpression {
+        inner: Box::new(inner),
+        op: UnaryOperation::Negate,
+        span: Span::dummy(),
+        id: NodeID::DUMMY,
+    })
+}
+
+#[test]
+fn bits_module_declares_every_conversion() {
+    create_session_if_not_set_then(|_| {
+        let (handler, _) = Handler::new_with_buf();
+        let program = resolve_stdlib_module(&handler, "bits").expect("std.bits should parse");
+        assert_eq!(handler.warning_count(), 0);
+
+        let mappings: Vec<String> = program
+            .functions
+            .values()
+            .map(|function| function.core_mapping.get().expect("core mapping").to_string())
+            .collect();
+        let conversions: Vec<String> = BitConversion::all().iter().map(ToString::to_string).collect();
+        assert_eq!(mappings, conversions);
+        assert_eq!(conversions.len(), 72);
+    });
+}
+
+#[test]
+fn native_functions_are_declared_without_bodies() {
+    create_session_if_not_set_then(|_| {
+        let registry = NativeRegistry::stdlib();
+
+        let mut declared = Vec::new();
+        for module in ["unstable.blake2s", "bits"].iter() {
+            let program = resolve_stdlib_module(&Handler::default(), module).expect("stdlib module should parse");
+            let circuit_functions = program.circuits.values().flat_map(|circuit| {
+                circuit.members.iter().filter_map(move |member| match member {
+                    CircuitMember::CircuitFunction(function) => Some((
+                        format!("{}.{}.{}", module, circuit.circuit_name, function.identifier),
+                        &**function,
+                    )),
+                    _ => None,
+                })
+            });
+            let global_functions =
+                (program.functions.values()).map(|function| (format!("{}.{}", module, function.identifier), function));
+            for (name, function) in circuit_functions.chain(global_functions) {
+                assert!(function.is_native(), "`{}` has a body", name);
+                assert_eq!(
+                    function.core_mapping.get().map(|mapping| mapping.to_string()),
+                    registry.get(&name).map(|native| native.core_mapping.clone())
+                );
+                declared.push(name);
+            }
+        }
+        assert_eq!(declared, registry.names().collect::<Vec<_>>());
+
+        let evaluate = registry.get("bits.u16_to_bytes_le").unwrap().evaluate.as_ref().unwrap();
+        let bytes = evaluate(&[integer(IntegerType::U16, "258")]).unwrap();
+        assert_eq!(print_expression(&bytes), "[2u8, 1u8]");
+        assert!(registry
+            .get("unstable.blake2s.Blake2s.hash")
+            .unwrap()
+            .evaluate
+            .is_none());
+    });
+}
+
+#[test]
+fn bit_conversions_evaluate_literals() {
+    create_session_if_not_set_then(|_| {
+        let evaluate = |name: &str, argument: &Expression| {
+            let conversion = BitConversion::from_name(name).expect("unknown conversion");
+            conversion.evaluate(argument).map(|result| print_expression(&result))
+        };
+
+        assert_eq!(
+            evaluate("u16_to_bytes_be", &integer(IntegerType::U16, "258")).as_deref(),
+            Some("[1u8, 2u8]")
+        );
+        assert_eq!(
+            evaluate("u8_to_bits_le", &integer(IntegerType::U8, "12")).as_deref(),
+            Some("[false, false, true, true, false, false, false, false]")
+        );
+
+        // Negative values are converted through their two's complement representation, and back.
+        let minus_two = BitConversion::from_name("i16_to_bytes_le")
+            .unwrap()
+            .evaluate(&negate(integer(IntegerType::I16, "2")))
+            .unwrap();
+        assert_eq!(print_expression(&minus_two), "[254u8, 255u8]");
+        assert_eq!(evaluate("i16_from_bytes_le", &minus_two).as_deref(), Some("-2i16"));
+        assert_eq!(
+            evaluate("i8_to_bits_be", &negate(integer(IntegerType::I8, "128"))).as_deref(),
+            Some("[true, false, false, false, false, false, false, false]")
+        );
+
+        // Arguments must be literals of the input type that fit in it.
+        assert_eq!(evaluate("i8_to_bits_be", &integer(IntegerType::I8, "128")), None);
+        assert_eq!(evaluate("u8_to_bits_le", &integer(IntegerType::U8, "256")), None);
+        assert_eq!(evaluate("u8_to_bits_le", &integer(IntegerType::U16, "1")), None);
+        assert_eq!(evaluate("u16_from_bytes_le", &integer(IntegerType::U16, "1")), None);
+        assert!(BitConversion::from_name("u8_to_bytes_le").is_none());
+    });
+}
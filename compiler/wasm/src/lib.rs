@@ -18,39 +18,52 @@
 // which is not wasm compatible. All compiler passes (such as TypeInference)
 
 use leo_ast::AstPass;
-use leo_errors::LeoError;
+use leo_errors::emitter::{ErrBuffer, Handler};
+use leo_span::symbol::create_session_if_not_set_then;
 
 use serde_json::json;
 use wasm_bindgen::prelude::*;
 
 #[wasm_bindgen(typescript_custom_section)]
 const TS_APPEND_CONTENT: &'static str = r#"
-export interface LeoError { text: string, code: string, exitCode: number }
+export interface DiagnosticSpan { file: string, line_start: number, line_stop: number, col_start: number, col_stop: number }
+export interface DiagnosticLabel { span: DiagnosticSpan, message: string }
+export interface DiagnosticSuggestion { span: DiagnosticSpan, message: string, replacement: string }
+export interface Diagnostic { severity: string, error_code: string, exit_code: number, message: string, help: string | null, span: DiagnosticSpan | null, labels: DiagnosticLabel[], notes: string[], suggestions: DiagnosticSuggestion[] }
+export interface LeoError { text: string, code: string, exitCode: number, diagnostics: Diagnostic[] }
 "#;
 
 /// Publicly accessible method.
 /// Parse the code and return an AST as JSON or an error object.
 #[wasm_bindgen(method, catch)]
 pub fn parse(program: &str) -> Result<String, JsValue> {
-    parse_program(program).map_err(error_to_value)
+    let (handler, buf) = Handler::new_with_buf();
+    create_session_if_not_set_then(|_| handler.extend_if_error(parse_program(&handler, program)))
+        .map_err(|_| errors_to_value(buf.extract()))
 }
 
 /// Parse the program and pass the Canonicalization phase;
 /// Asg is useless without compiler passes, so we need to add them once the compatibility problem in
 /// snarkvm is solved.
-fn parse_program(program: &str) -> leo_errors::Result<String> {
-    let ast = leo_parser::parse_ast("", program)?;
-    let ast = leo_ast_passes::Canonicalizer::do_pass(ast.into_repr())?.to_json_string()?;
+fn parse_program(handler: &Handler, program: &str) -> leo_errors::Result<String> {
+    let ast = leo_parser::parse_ast(handler, "", program)?;
+    let ast = leo_ast_passes::Canonicalizer::new(handler)
+        .do_pass(ast.into_repr())?
+        .to_json_string()?;
 
     Ok(ast)
 }
 
-/// Make a pretty-print JS object for the thrown error.
-fn error_to_value(err: LeoError) -> JsValue {
+/// Make a pretty-print JS object for the thrown errors.
+/// Its code is the one of the first error, and each error is also included as a machine-readable diagnostic.
+fn errors_to_value(errors: ErrBuffer) -> JsValue {
+    let text = errors.to_string();
+    let errors = errors.into_inner();
     JsValue::from_serde(&json!({
-       "error": err.to_string(),
-       "code": err.error_code(),
-       "exitCode": err.exit_code()
+       "error": text,
+       "code": errors.first().map(|err| err.error_code()),
+       "exitCode": errors.first().map(|err| err.exit_code()),
+       "diagnostics": errors.iter().map(|err| err.diagnostic()).collect::<Vec<_>>(),
     }))
     .expect("Unable to create an error object from JSON")
 }
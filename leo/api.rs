@@ -15,6 +15,7 @@
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
 use leo_errors::{CliError, LeoError, Result};
+use leo_package::registry::FileRegistry;
 
 use reqwest::{
     blocking::{multipart::Form, Client, Response},
@@ -54,6 +55,9 @@ pub trait Route {
     /// Process the reqwest Response and turn it into an Output.
     fn process(&self, res: Response) -> Result<Self::Output>;
 
+    /// Serve the route from a file system registry instead of the Aleo PM.
+    fn serve(&self, registry: &FileRegistry, auth_token: Option<String>) -> Result<Self::Output>;
+
     /// Represent self as a form data for multipart (ContentType::FormData) requests.
     fn to_form(&self) -> Option<Form> {
         None
@@ -73,15 +77,19 @@ pub struct Api {
     client: Client,
     /// Authorization token for API requests.
     auth_token: Option<String>,
+    /// The file system registry used instead of the Aleo PM, for `file://` hosts.
+    registry: Option<FileRegistry>,
 }
 
 impl Api {
     /// Returns a new instance of API.
     /// The set host and Client are created automatically.
+    /// A `file://` host selects an offline file system registry.
     pub fn new(host: String, auth_token: Option<String>) -> Api {
         Api {
             client: Client::new(),
             auth_token,
+            registry: FileRegistry::from_url(&host),
             host,
         }
     }
@@ -108,6 +116,11 @@ impl Api {
         T: Route,
         T: Serialize,
     {
+        // Serve the route offline if the host is a file system registry.
+        if let Some(registry) = &self.registry {
+            return route.serve(registry, self.auth_token());
+        }
+
         let mut res = self.client.request(T::METHOD, &format!("{}{}", self.host, T::PATH));
 
         // add body for POST and PUT requests
@@ -152,7 +165,8 @@ pub struct Fetch {
 }
 
 impl Route for Fetch {
-    type Output = Response;
+    /// The bytes of the package zip file.
+    type Output = Vec<u8>;
 
     const AUTH: bool = true;
     const CONTENT_TYPE: ContentType = ContentType::Json;
@@ -165,7 +179,12 @@ impl Route for Fetch {
             return Err(self.status_to_err(res.status()));
         };
 
-        Ok(res)
+        Ok(res.bytes().map_err(CliError::cli_bytes_conversion_error)?.to_vec())
+    }
+
+    fn serve(&self, registry: &FileRegistry, _auth_token: Option<String>) -> Result<Self::Output> {
+        let (_, bytes) = registry.fetch(&self.author, &self.package_name, self.version.as_deref())?;
+        Ok(bytes)
     }
 
     fn status_to_err(&self, status: StatusCode) -> LeoError {
@@ -189,7 +208,8 @@ pub struct Login {
 }
 
 impl Route for Login {
-    type Output = Response;
+    /// The authorization token.
+    type Output = String;
 
     const AUTH: bool = false;
     const CONTENT_TYPE: ContentType = ContentType::Json;
@@ -201,7 +221,15 @@ impl Route for Login {
             return Err(self.status_to_err(res.status()));
         }
 
-        Ok(res)
+        let mut res: HashMap<String, String> = res.json().map_err(CliError::reqwest_json_error)?;
+        res.remove("token")
+            .ok_or_else(|| CliError::unable_to_get_user_token().into())
+    }
+
+    /// A file system registry has no accounts, so the username itself is used as the token.
+    /// The token does not authenticate anything, as the registry is as trusted as its directory.
+    fn serve(&self, _registry: &FileRegistry, _auth_token: Option<String>) -> Result<Self::Output> {
+        Ok(self.email_username.clone())
     }
 
     fn status_to_err(&self, status: StatusCode) -> LeoError {
@@ -261,6 +289,14 @@ impl Route for Publish {
             })
         }
     }
+
+    /// A file system registry is not authenticated on purpose: anyone who can write to its directory
+    /// may publish under any author, so the author is taken from the remote rather than checked.
+    fn serve(&self, registry: &FileRegistry, _auth_token: Option<String>) -> Result<Self::Output> {
+        let author = self.remote.split('/').next().unwrap_or_default();
+        let entry = registry.publish(author, &self.name, &self.version, &self.file)?;
+        Ok(format!("{}/{}@{}", entry.author, entry.name, entry.version))
+    }
 }
 
 /// Handler for 'my_profile' route. Meant to be used to get profile details but
@@ -293,4 +329,9 @@ impl Route for Profile {
 
         Ok(None)
     }
+
+    /// The token of a file system registry is the username, see the login route.
+    fn serve(&self, _registry: &FileRegistry, auth_token: Option<String>) -> Result<Self::Output> {
+        Ok(auth_token)
+    }
 }
@@ -0,0 +1,68 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{commands::Command, context::Context};
+use leo_compiler::diff_ast_snapshots;
+use leo_errors::Result;
+use leo_package::outputs::OUTPUTS_DIRECTORY_NAME;
+
+use structopt::StructOpt;
+use tracing::span::Span;
+
+/// Show the differences between the AST snapshots of two compiler phases
+#[derive(StructOpt, Debug)]
+#[structopt(setting = structopt::clap::AppSettings::ColoredHelp)]
+pub struct Diff {
+    #[structopt(help = "The phase of the old AST snapshot, e.g., `initial`")]
+    from: String,
+
+    #[structopt(help = "The phase of the new AST snapshot, e.g., `canonicalization`")]
+    to: String,
+}
+
+impl Command for Diff {
+    type Input = ();
+    type Output = ();
+
+    fn log_span(&self) -> Span {
+        tracing::span!(tracing::Level::INFO, "Diffing")
+    }
+
+    fn prelude(&self, _: Context) -> Result<Self::Input> {
+        Ok(())
+    }
+
+    fn apply(self, context: Context, _: Self::Input) -> Result<Self::Output> {
+        // Sanitize the package path to the root directory.
+        let mut package_path = context.dir()?;
+        if package_path.is_file() {
+            package_path.pop();
+        }
+
+        // The snapshots are written to the output directory by `leo build`.
+        let mut output_directory = package_path;
+        output_directory.push(OUTPUTS_DIRECTORY_NAME);
+
+        let diff = diff_ast_snapshots(&context.handler(), &output_directory, &self.from, &self.to)?;
+        if diff.is_empty() {
+            tracing::info!("No differences between `{}` and `{}`", self.from, self.to);
+        } else {
+            print!("{}", diff);
+        }
+
+        Ok(())
+    }
+}
@@ -16,14 +16,20 @@
 
 use crate::{commands::Command, context::Context};
 use leo_compiler::{Ast, Compiler};
-use leo_errors::{CliError, Result};
+use leo_errors::{CliError, PackageError, Result};
 use leo_package::{
+    imports::{DependencyResolver, LocalDependency},
     // inputs::*,
     // outputs::CircuitFile
     outputs::{ChecksumFile, OutputsDirectory, OUTPUTS_DIRECTORY_NAME},
+    root::{LockFile, Manifest, Workspace, LOCKFILE_FILENAME},
     source::{MainFile, MAIN_FILENAME, SOURCE_DIRECTORY_NAME},
 };
 
+use std::{
+    convert::TryFrom,
+    path::{Path, PathBuf},
+};
 use structopt::StructOpt;
 use tracing::span::Span;
 
@@ -49,6 +55,8 @@ pub struct BuildOptions {
     pub enable_canonicalized_ast_snapshot: bool,
     #[structopt(long, help = "Writes AST snapshot after the type inference phase.")]
     pub enable_type_inferenced_ast_snapshot: bool,
+    #[structopt(long, help = "Treat warnings as errors.")]
+    pub deny_warnings: bool,
 }
 
 // impl From<BuildOptions> for CompilerOptions {
@@ -89,6 +97,18 @@ pub struct BuildOptions {
 //     }
 // }
 
+/// A package compiled by the Build command.
+pub struct BuiltPackage {
+    /// The name of the package.
+    pub name: String,
+    /// The AST of the package.
+    pub ast: Ast,
+    /// The names of the test functions of the package, i.e., those annotated with `@test`.
+    pub tests: Vec<String>,
+    /// Whether the checksum of the package changed.
+    pub checksum_differs: bool,
+}
+
 /// Compile and build program command.
 #[derive(StructOpt, Debug)]
 #[structopt(setting = structopt::clap::AppSettings::ColoredHelp)]
@@ -100,7 +120,8 @@ pub struct Build {
 
 impl Command for Build {
     type Input = ();
-    type Output = (Ast, bool);
+    /// Each built package, in build order.
+    type Output = Vec<BuiltPackage>;
 
     fn log_span(&self) -> Span {
         tracing::span!(tracing::Level::INFO, "Build")
@@ -111,22 +132,86 @@ impl Command for Build {
     }
 
     fn apply(self, context: Context, _: Self::Input) -> Result<Self::Output> {
-        let path = context.dir()?;
-        let manifest = context.manifest().map_err(|_| CliError::manifest_file_not_found())?;
-        let package_name = manifest.get_package_name();
-        let imports_map = manifest.get_imports_map().unwrap_or_default();
-
-        // Error out if there are dependencies but no lock file found.
-        if !imports_map.is_empty() && !context.lock_file_exists()? {
-            return Err(CliError::dependencies_are_not_installed().into());
-        }
-
         // Sanitize the package path to the root directory.
-        let mut package_path = path.clone();
+        let mut package_path = context.dir()?;
         if package_path.is_file() {
             package_path.pop();
         }
 
+        // Workspace members share the lock file and the `imports/` directory at the workspace root.
+        let workspace = context.workspace()?;
+        let (root, packages) = match &workspace {
+            Some((root, workspace)) => (root.clone(), workspace.members_in_build_order(root)?),
+            None => {
+                let manifest = context.manifest().map_err(|_| CliError::manifest_file_not_found())?;
+                (package_path.clone(), vec![(package_path.clone(), manifest)])
+            }
+        };
+
+        Self::check_lock_file(&root, &packages, workspace.is_some())?;
+
+        // At the root of a workspace every member is built, inside a member only the member itself.
+        let packages = match workspace {
+            Some(_) if Workspace::read_from(&package_path)?.is_none() => {
+                let manifest = context.manifest().map_err(|_| CliError::manifest_file_not_found())?;
+                vec![(package_path, manifest)]
+            }
+            _ => packages,
+        };
+
+        packages
+            .into_iter()
+            .map(|(package_path, manifest)| self.build_package(&context, &root, &package_path, &manifest))
+            .collect()
+    }
+}
+
+impl Build {
+    /// Resolves the dependency graph of the `packages`, and checks that it agrees with the lock file at `root`.
+    /// The lock file is written if it does not exist yet, and the checksums of path dependencies
+    /// are refreshed when their sources changed. Git dependencies must keep their locked checksums.
+    fn check_lock_file(root: &Path, packages: &[(PathBuf, Manifest)], workspace: bool) -> Result<()> {
+        let locked = match LockFile::exists_at(root) {
+            true => Some(LockFile::try_from(root)?),
+            false => None,
+        };
+
+        let resolver = DependencyResolver::new(root)?;
+        let lock_file = match workspace {
+            true => resolver.resolve_workspace(packages, locked.as_ref())?,
+            false => resolver.resolve(&packages[0].1, locked.as_ref())?,
+        };
+
+        match locked {
+            Some(locked) if !locked.same_graph(&lock_file) => {
+                Err(PackageError::lock_file_out_of_date(LOCKFILE_FILENAME).into())
+            }
+            Some(locked) if locked != lock_file => {
+                tracing::info!("Updating {}", LOCKFILE_FILENAME);
+                lock_file.write_to(root)
+            }
+            Some(_) => Ok(()),
+            // Packages without dependencies do not need a lock file.
+            None if lock_file.package.is_empty() => Ok(()),
+            None => {
+                tracing::info!("Writing {}", LOCKFILE_FILENAME);
+                lock_file.write_to(root)
+            }
+        }
+    }
+
+    /// Compiles the package at `package_path`, reporting errors in the message format of the `context`.
+    /// Its dependencies are resolved from the lock file and the `imports/` directory at `root`.
+    fn build_package(
+        &self,
+        context: &Context,
+        root: &Path,
+        package_path: &Path,
+        manifest: &Manifest,
+    ) -> Result<BuiltPackage> {
+        let package_name = manifest.get_package_name();
+        let package_path = package_path.to_path_buf();
+
         // Construct the path to the output directory.
         let mut output_directory = package_path.clone();
         output_directory.push(OUTPUTS_DIRECTORY_NAME);
@@ -155,11 +240,15 @@ impl Command for Build {
         // Log compilation of files to console
         tracing::info!("Compiling main program... ({:?})", main_file_path);
 
-        // let imports_map = if context.lock_file_exists()? {
-        //     context.lock_file()?.to_import_map()
-        // } else {
-        //     Default::default()
-        // };
+        // Registry dependencies are imported from `imports/`, path and git dependencies from their package roots.
+        let imports_map = match LockFile::exists_at(root) {
+            true => LockFile::try_from(root)?.to_import_map(),
+            false => Default::default(),
+        };
+        let local_packages = LocalDependency::resolve_all(root, &package_path, manifest)?
+            .into_iter()
+            .map(|dependency| (dependency.import_name, dependency.path))
+            .collect();
 
         // Load the program at `main_file_path`
         // let program = Compiler::<Fq, EdwardsGroupType>::parse_program_with_input(
@@ -177,15 +266,21 @@ impl Command for Build {
         // )?;
 
         // Initialize error handler
-        let handler = leo_errors::emitter::Handler::default();
+        let handler = context.handler().deny_warnings(self.compiler_options.deny_warnings);
 
-        let program = Compiler::new(&handler, main_file_path, output_directory);
+        let program = Compiler::new(&handler, main_file_path, output_directory)
+            .with_imports_map(imports_map)
+            .with_local_packages(local_packages);
 
         // Compute the current program checksum
         let program_checksum = program.checksum()?;
 
         // Compile the program
-        let program_compiled = program.compile()?;
+        // Errors, including denied warnings, are emitted through the handler, and only counted here
+        let (program_compiled, tests) = match handler.extend_if_error(program.compile_with_tests()) {
+            Ok(compiled) => compiled,
+            Err(()) => return Err(CliError::compilation_failed(handler.err_count()).into()),
+        };
 
         // Generate the program on the constraint system and verify correctness
         {
@@ -232,13 +327,18 @@ impl Command for Build {
         // If checksum differs, compile the program
         if checksum_differs {
             // Write the new checksum to the output directory
-            checksum_file.write_to(&path, program_checksum)?;
+            checksum_file.write_to(&package_path, program_checksum)?;
 
-            tracing::debug!("Checksum saved ({:?})", path);
+            tracing::debug!("Checksum saved ({:?})", package_path);
         }
 
         tracing::info!("Complete");
 
-        Ok((program_compiled, checksum_differs))
+        Ok(BuiltPackage {
+            name: package_name,
+            ast: program_compiled,
+            tests,
+            checksum_differs,
+        })
     }
 }
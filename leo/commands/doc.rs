@@ -0,0 +1,91 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{commands::Command, context::Context};
+use leo_compiler::{document_modules, DocFormat};
+use leo_errors::{CliError, Result};
+use leo_package::{
+    outputs::OUTPUTS_DIRECTORY_NAME,
+    source::{SourceDirectory, MAIN_FILENAME},
+};
+
+use std::fs;
+use structopt::StructOpt;
+use tracing::span::Span;
+
+/// Generate the documentation of the current package
+#[derive(StructOpt, Debug)]
+#[structopt(setting = structopt::clap::AppSettings::ColoredHelp)]
+pub struct Doc {
+    #[structopt(long, help = "Write HTML pages instead of Markdown")]
+    pub(crate) html: bool,
+}
+
+impl Command for Doc {
+    type Input = ();
+    type Output = ();
+
+    fn log_span(&self) -> Span {
+        tracing::span!(tracing::Level::INFO, "Documenting")
+    }
+
+    fn prelude(&self, _: Context) -> Result<Self::Input> {
+        Ok(())
+    }
+
+    fn apply(self, context: Context, _: Self::Input) -> Result<Self::Output> {
+        // Sanitize the package path to the root directory.
+        let mut package_path = context.dir()?;
+        if package_path.is_file() {
+            package_path.pop();
+        }
+
+        let manifest = context.manifest().map_err(|_| CliError::manifest_file_not_found())?;
+        let package_name = manifest.get_package_name();
+
+        // The main file is the module of the package, and the other files are its submodules.
+        let mut files = Vec::new();
+        for path in SourceDirectory::files(&package_path)? {
+            let mut module = vec![package_name.clone()];
+            if !path.ends_with(MAIN_FILENAME) {
+                module.extend(path.file_stem().map(|stem| stem.to_string_lossy().to_string()));
+            }
+            files.push((module, path));
+        }
+
+        let format = if self.html {
+            DocFormat::Html
+        } else {
+            DocFormat::Markdown
+        };
+        let pages = document_modules(&context.handler(), files, format)?;
+
+        // The documentation is written next to the other outputs of the package.
+        let mut docs_directory = package_path;
+        docs_directory.push(OUTPUTS_DIRECTORY_NAME);
+        docs_directory.push("docs");
+        fs::create_dir_all(&docs_directory).map_err(CliError::cli_io_error)?;
+        for page in pages.iter() {
+            fs::write(docs_directory.join(&page.file_name), &page.content).map_err(CliError::cli_io_error)?;
+        }
+
+        tracing::info!(
+            "Documentation written to {:?}",
+            docs_directory.join(&pages[0].file_name)
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,63 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{commands::Command, context::Context};
+use leo_errors::{CliError, LeoError, Result};
+
+use structopt::StructOpt;
+use tracing::Span;
+
+include!(concat!(env!("OUT_DIR"), "/error_guides.rs"));
+
+/// Returns the guide of the error with the prefixed error `code`, e.g., `EPAR0370005`.
+pub fn error_guide(code: &str) -> Result<&'static str> {
+    let (kind, name) = LeoError::find_by_code(code).ok_or_else(|| CliError::unknown_error_code(code))?;
+    ERROR_GUIDES
+        .iter()
+        .find(|(guide_kind, guide_name, _)| *guide_kind == kind && *guide_name == name)
+        .map(|(_, _, guide)| *guide)
+        .ok_or_else(|| CliError::missing_error_guide(code).into())
+}
+
+/// Print the guide explaining an error code
+#[derive(StructOpt, Debug)]
+#[structopt(setting = structopt::clap::AppSettings::ColoredHelp)]
+pub struct Explain {
+    #[structopt(help = "The error code, e.g., EPAR0370005")]
+    code: String,
+}
+
+impl Command for Explain {
+    type Input = ();
+    type Output = ();
+
+    fn log_span(&self) -> Span {
+        tracing::span!(tracing::Level::INFO, "Explaining")
+    }
+
+    fn prelude(&self, _: Context) -> Result<Self::Input> {
+        Ok(())
+    }
+
+    fn apply(self, _context: Context, _: Self::Input) -> Result<Self::Output> {
+        let guide = error_guide(&self.code.trim().to_uppercase())?;
+
+        // The guide is the output of the command, so it is not logged.
+        println!("{}", guide);
+
+        Ok(())
+    }
+}
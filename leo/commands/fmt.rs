@@ -0,0 +1,106 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{commands::Command, context::Context};
+use leo_errors::{CliError, Result};
+use leo_package::source::SourceDirectory;
+use leo_parser::{format_program, FormatOptions, DEFAULT_MAX_WIDTH};
+
+use std::{fs, path::PathBuf};
+use structopt::StructOpt;
+use tracing::span::Span;
+
+/// Format the Leo files of the current package
+#[derive(StructOpt, Debug)]
+#[structopt(setting = structopt::clap::AppSettings::ColoredHelp)]
+pub struct Fmt {
+    #[structopt(long, help = "Check that the files are formatted, without changing them")]
+    pub(crate) check: bool,
+
+    #[structopt(
+        long,
+        help = "The maximum width of a line, overriding `max_width` in the `[fmt]` section of Leo.toml"
+    )]
+    pub(crate) max_width: Option<usize>,
+}
+
+impl Command for Fmt {
+    type Input = ();
+    /// The files which were not formatted.
+    type Output = Vec<PathBuf>;
+
+    fn log_span(&self) -> Span {
+        tracing::span!(tracing::Level::INFO, "Formatting")
+    }
+
+    fn prelude(&self, _: Context) -> Result<Self::Input> {
+        Ok(())
+    }
+
+    fn apply(self, context: Context, _: Self::Input) -> Result<Self::Output> {
+        // Sanitize the package path to the root directory.
+        let mut package_path = context.dir()?;
+        if package_path.is_file() {
+            package_path.pop();
+        }
+
+        let manifest = context.manifest().map_err(|_| CliError::manifest_file_not_found())?;
+        let options = FormatOptions {
+            max_width: self
+                .max_width
+                .or_else(|| manifest.get_fmt_max_width())
+                .unwrap_or(DEFAULT_MAX_WIDTH),
+        };
+
+        let mut unformatted = Vec::new();
+        let mut error_count = 0;
+        for path in SourceDirectory::files(&package_path)? {
+            let source = fs::read_to_string(&path).map_err(CliError::cli_io_error)?;
+
+            // Each file has its own handler,
+            // so that the syntax errors in one file do not stop the others from being formatted.
+            let handler = context.handler();
+            let formatted = match format_program(&handler, &path.to_string_lossy(), &source, &options) {
+                Ok(formatted) => formatted,
+                Err(error) => {
+                    handler.emit_err(error);
+                    error_count += handler.err_count();
+                    continue;
+                }
+            };
+
+            if formatted == source {
+                continue;
+            }
+            if self.check {
+                tracing::info!("Not formatted ({:?})", path);
+            } else {
+                fs::write(&path, formatted).map_err(CliError::cli_io_error)?;
+                tracing::info!("Formatted ({:?})", path);
+            }
+            unformatted.push(path);
+        }
+
+        if error_count > 0 {
+            return Err(CliError::formatting_failed(error_count).into());
+        }
+        if self.check && !unformatted.is_empty() {
+            return Err(CliError::files_not_formatted(unformatted.len()).into());
+        }
+
+        Ok(unformatted)
+    }
+}
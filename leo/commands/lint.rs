@@ -0,0 +1,76 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{commands::Command, context::Context};
+use leo_errors::{CliError, LeoError, Result};
+use leo_linter::{lint_file, LintConfig};
+use leo_package::source::SourceDirectory;
+
+use std::fs;
+use structopt::StructOpt;
+use tracing::span::Span;
+
+/// Lint the Leo files of the current package
+#[derive(StructOpt, Debug)]
+#[structopt(setting = structopt::clap::AppSettings::ColoredHelp)]
+pub struct Lint {}
+
+impl Command for Lint {
+    type Input = ();
+    type Output = ();
+
+    fn log_span(&self) -> Span {
+        tracing::span!(tracing::Level::INFO, "Linting")
+    }
+
+    fn prelude(&self, _: Context) -> Result<Self::Input> {
+        Ok(())
+    }
+
+    fn apply(self, context: Context, _: Self::Input) -> Result<Self::Output> {
+        // Sanitize the package path to the root directory.
+        let mut package_path = context.dir()?;
+        if package_path.is_file() {
+            package_path.pop();
+        }
+
+        let manifest = context.manifest().map_err(|_| CliError::manifest_file_not_found())?;
+        let config = LintConfig::new(manifest.get_lint_levels())?;
+
+        let mut error_count = 0;
+        for path in SourceDirectory::files(&package_path)? {
+            let source = fs::read_to_string(&path).map_err(CliError::cli_io_error)?;
+
+            // Each file has its own handler,
+            // so that the errors in one file do not stop the others from being linted.
+            let handler = context.handler();
+            if let Err(error) = lint_file(&handler, &path.to_string_lossy(), &source, &config) {
+                // The errors standing behind the last error code were already emitted.
+                if !matches!(error, LeoError::LastErrorCode(_)) {
+                    handler.emit_err(error);
+                }
+                error_count += handler.err_count();
+            }
+        }
+
+        if error_count > 0 {
+            return Err(CliError::linting_failed(error_count).into());
+        }
+
+        tracing::info!("No errors");
+        Ok(())
+    }
+}
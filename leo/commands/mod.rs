@@ -30,11 +30,20 @@ pub use build::Build;
 // pub mod deploy;
 // pub use deploy::Deploy;
 //
+pub mod doc;
+pub use doc::Doc;
+//
+pub mod explain;
+pub use explain::Explain;
+//
+pub mod fmt;
+pub use fmt::Fmt;
+//
 // pub mod init;
 // pub use init::Init;
 //
-// pub mod lint;
-// pub use lint::Lint;
+pub mod lint;
+pub use lint::Lint;
 //
 // pub mod new;
 // pub use new::New;
@@ -48,8 +57,8 @@ pub use build::Build;
 // pub mod setup;
 // pub use setup::Setup;
 //
-// pub mod test;
-// pub use test::Test;
+pub mod test;
+pub use test::Test;
 //
 // pub mod update;
 // pub use update::{Automatic as UpdateAutomatic, Update};
@@ -58,7 +67,10 @@ pub use build::Build;
 // pub use watch::Watch;
 
 // Aleo PM related commands
-// pub mod package;
+pub mod package;
+
+// AST snapshot related commands
+pub mod ast;
 
 /// Base trait for the Leo CLI, see methods and their documentation for details.
 pub trait Command {
@@ -14,10 +14,6 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-// !!!!!!!!!!!!!!!!!!!!!!!!!!!!
-// COMMAND TEMPORARILY DISABLED
-// !!!!!!!!!!!!!!!!!!!!!!!!!!!!
-
 use crate::{api::Fetch, commands::Command, context::Context};
 use leo_errors::{CliError, Result};
 use leo_package::imports::{ImportsDirectory, IMPORTS_DIRECTORY_NAME};
@@ -108,16 +104,11 @@ impl Command for Add {
                 package_name: package_name.clone(),
                 version: self.version.clone(),
             };
-            let bytes = context
-                .api
-                .run_route(fetch)?
-                .bytes()
-                .map_err(CliError::cli_bytes_conversion_error)?;
-            std::io::Cursor::new(bytes)
+            std::io::Cursor::new(context.api.run_route(fetch)?)
         };
 
-        // Construct the directory structure.
-        let mut path = context.dir()?;
+        // Construct the directory structure, shared by the members of a workspace.
+        let mut path = context.root_dir()?;
         {
             ImportsDirectory::create(&path)?;
             path.push(IMPORTS_DIRECTORY_NAME);
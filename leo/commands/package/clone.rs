@@ -112,12 +112,7 @@ impl Command for Clone {
                 package_name: package_name.clone(),
                 version: self.version,
             };
-            let bytes = context
-                .api
-                .run_route(fetch)?
-                .bytes()
-                .map_err(CliError::cli_bytes_conversion_error)?;
-            std::io::Cursor::new(bytes)
+            std::io::Cursor::new(context.api.run_route(fetch)?)
         };
 
         // Construct the directory structure.
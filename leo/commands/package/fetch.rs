@@ -19,9 +19,9 @@ use crate::{
     context::{create_context, Context},
 };
 
-use leo_package::root::{
-    lock_file::{LockFile, Package},
-    Dependency,
+use leo_package::{
+    imports::DependencyResolver,
+    root::{lock_file::Package, Dependency},
 };
 
 use indexmap::{set::IndexSet, IndexMap};
@@ -35,62 +35,85 @@ use tracing::span::Span;
 pub struct Fetch {}
 
 impl Command for Fetch {
-    /// Names of dependencies in the current branch of a dependency tree.
-    type Input = IndexSet<String>;
+    type Input = ();
     type Output = ();
 
     fn log_span(&self) -> Span {
         tracing::span!(tracing::Level::INFO, "Fetching")
     }
 
-    fn prelude(&self, context: Context) -> Result<Self::Input> {
-        let package_name = context.manifest()?.get_package_name();
-
-        let mut set = IndexSet::new();
-        set.insert(package_name);
-
-        Ok(set)
+    fn prelude(&self, _: Context) -> Result<Self::Input> {
+        Ok(())
     }
 
-    fn apply(self, context: Context, tree: Self::Input) -> Result<Self::Output> {
-        let dependencies = context
-            .manifest()
-            .map_err(|_| CliError::manifest_file_not_found())?
-            .get_package_dependencies();
-
-        // If program has no dependencies in the Leo.toml, exit with success.
-        let dependencies = match dependencies {
-            Some(dependencies) => dependencies,
-            None => return Ok(()),
+    fn apply(self, context: Context, _: Self::Input) -> Result<Self::Output> {
+        // In a workspace, the dependencies of every member are fetched into the shared `imports/` directory.
+        let workspace = context.workspace()?;
+        let packages = match &workspace {
+            Some((root, workspace)) => workspace.members_in_build_order(root)?,
+            None => vec![(
+                context.dir()?,
+                context.manifest().map_err(|_| CliError::manifest_file_not_found())?,
+            )],
         };
 
-        if dependencies.is_empty() {
+        // If no program has dependencies in the Leo.toml, exit with success.
+        if packages.iter().all(|(_, manifest)| {
+            manifest
+                .get_package_dependencies()
+                .map_or(true, |dependencies| dependencies.is_empty())
+        }) {
             return Ok(());
         }
 
-        let mut lock_file = LockFile::new();
-        self.add_dependencies(context.clone(), tree, &mut lock_file, dependencies)?;
-        lock_file.write_to(&context.dir()?)?;
+        for (path, manifest) in packages.iter() {
+            let context = Context {
+                api: context.api.clone(),
+                path: Some(path.clone()),
+                message_format: context.message_format,
+            };
+
+            // Names of dependencies in the current branch of a dependency tree.
+            let mut tree = IndexSet::new();
+            tree.insert(manifest.get_package_name());
+
+            self.add_dependencies(context, tree, manifest.get_package_dependencies().unwrap_or_default())?;
+        }
+
+        // Resolve the installed dependencies into a complete lock file.
+        let root = context.root_dir()?;
+        let resolver = DependencyResolver::new(&root)?;
+        let lock_file = match workspace {
+            Some(_) => resolver.resolve_workspace(&packages, None)?,
+            None => resolver.resolve(&packages[0].1, None)?,
+        };
+        lock_file.write_to(&root)?;
 
         Ok(())
     }
 }
 
 impl Fetch {
-    /// Pulls dependencies and fills in the lock file. Also checks for
+    /// Pulls dependencies into the imports directory. Also checks for
     /// recursive dependencies with dependency tree.
     fn add_dependencies(
         &self,
         context: Context,
         mut tree: IndexSet<String>,
-        lock_file: &mut LockFile,
         dependencies: IndexMap<String, Dependency>,
     ) -> Result<()> {
         // Go through each dependency in Leo.toml and add it to the imports.
         // While adding, pull dependencies of this package as well and check for recursion.
-        for (import_name, dependency) in dependencies.into_iter() {
-            let mut package = Package::from(&dependency);
-            package.import_name = Some(import_name);
+        for dependency in dependencies.into_values() {
+            let package = match &dependency {
+                Dependency::Registry {
+                    author,
+                    version,
+                    package,
+                } => Package::registry(author, package, version),
+                // Path and git dependencies do not go through the package manager.
+                Dependency::Path { .. } | Dependency::Git { .. } => continue,
+            };
 
             // Pull the dependency first.
             let path = Add::new(
@@ -123,20 +146,9 @@ impl Fetch {
                 .get_package_dependencies();
 
             if let Some(dependencies) = imported_dependencies {
-                if !dependencies.is_empty() {
-                    // Fill in the lock file with imported dependency and information about its dependencies.
-                    package.add_dependencies(&dependencies);
-                    lock_file.add_package(package);
-
-                    // Recursively call this method for imported program.
-                    self.add_dependencies(context.clone(), tree.clone(), lock_file, dependencies)?;
-
-                    continue;
-                }
+                // Recursively call this method for imported program.
+                self.add_dependencies(context.clone(), tree.clone(), dependencies)?;
             }
-
-            // If there are no dependencies for the new import, add a single record.
-            lock_file.add_package(package);
         }
 
         Ok(())
@@ -22,11 +22,12 @@ use crate::{
 };
 use leo_errors::{CliError, Result};
 
-use std::collections::HashMap;
 use structopt::StructOpt;
 use tracing::Span;
 
 /// Login to Aleo PM and store credentials locally
+///
+/// An offline `file://` registry given with `--api` has no accounts, so any username logs in.
 #[derive(StructOpt, Debug)]
 #[structopt(setting = structopt::clap::AppSettings::ColoredHelp)]
 pub struct Login {
@@ -71,15 +72,7 @@ impl Command for Login {
                     password,
                 };
 
-                let res = api.run_route(login)?;
-                let mut res: HashMap<String, String> = res.json().map_err(CliError::reqwest_json_error)?;
-
-                let tok_opt = res.remove("token");
-                if tok_opt.is_none() {
-                    return Err(CliError::unable_to_get_user_token().into());
-                };
-
-                (tok_opt.unwrap(), email_username)
+                (api.run_route(login)?, email_username)
             }
 
             // Login with token, use get_profile route to verify that.
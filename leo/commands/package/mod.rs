@@ -35,4 +35,7 @@ pub use publish::Publish;
 pub mod remove;
 pub use remove::Remove;
 
+pub mod verify;
+pub use verify::Verify;
+
 pub use super::*;
@@ -26,6 +26,9 @@ use leo_parser::KEYWORD_TOKENS;
 use structopt::StructOpt;
 
 /// Publish package to Aleo Package Manager
+///
+/// An offline `file://` registry given with `--api` is not authenticated:
+/// anyone who can write to its directory may publish under any author.
 #[derive(StructOpt, Debug)]
 #[structopt(setting = structopt::clap::AppSettings::ColoredHelp)]
 pub struct Publish {}
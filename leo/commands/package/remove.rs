@@ -14,10 +14,6 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-// !!!!!!!!!!!!!!!!!!!!!!!!!!!!
-// COMMAND TEMPORARILY DISABLED
-// !!!!!!!!!!!!!!!!!!!!!!!!!!!!
-
 use crate::{commands::Command, context::Context};
 use leo_errors::Result;
 use leo_package::LeoPackage;
@@ -46,7 +42,7 @@ impl Command for Remove {
     }
 
     fn apply(self, context: Context, _: Self::Input) -> Result<Self::Output> {
-        let path = context.dir()?;
+        let path = context.root_dir()?;
         let package_name = self.name;
 
         LeoPackage::remove_imported_package(&package_name, &path)?;
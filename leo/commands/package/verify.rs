@@ -16,28 +16,40 @@
 
 use crate::{commands::Command, context::Context};
 use leo_errors::Result;
+use leo_package::root::{ArchiveEntry, ZipFile};
 
+use std::path::PathBuf;
 use structopt::StructOpt;
-use tracing::span::Span;
+use tracing::Span;
 
-/// Lint Leo code command
+/// Verify the integrity of a package zip file and list its contents
 #[derive(StructOpt, Debug)]
 #[structopt(setting = structopt::clap::AppSettings::ColoredHelp)]
-pub struct Lint {}
+pub struct Verify {
+    #[structopt(parse(from_os_str), help = "Path to the package zip file")]
+    file: PathBuf,
+}
 
-impl Command for Lint {
+impl Command for Verify {
     type Input = ();
-    type Output = ();
+    type Output = Vec<ArchiveEntry>;
 
     fn log_span(&self) -> Span {
-        tracing::span!(tracing::Level::INFO, "Linting")
+        tracing::span!(tracing::Level::INFO, "Verifying")
     }
 
     fn prelude(&self, _: Context) -> Result<Self::Input> {
         Ok(())
     }
 
-    fn apply(self, _: Context, _: Self::Input) -> Result<Self::Output> {
-        unimplemented!("Lint command has not been implemented yet");
+    fn apply(self, _context: Context, _: Self::Input) -> Result<Self::Output> {
+        let entries = ZipFile::verify(&self.file)?;
+
+        for entry in &entries {
+            tracing::info!("{}  {} ({} bytes)", entry.checksum, entry.name, entry.size);
+        }
+        tracing::info!("Package zip file {:?} is valid", self.file);
+
+        Ok(entries)
     }
 }
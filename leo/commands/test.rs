@@ -0,0 +1,68 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::build::{Build, BuildOptions};
+use crate::{commands::Command, context::Context};
+use leo_errors::Result;
+
+use structopt::StructOpt;
+use tracing::span::Span;
+
+/// Build program and run tests command
+///
+/// At the root of a workspace, every member is built in dependency order.
+/// Running the tests requires synthesizing their circuits, which this version of Leo does not support yet,
+/// so the test functions of each package are compiled and listed only.
+#[derive(StructOpt, Debug)]
+#[structopt(setting = structopt::clap::AppSettings::ColoredHelp)]
+pub struct Test {
+    #[structopt(flatten)]
+    pub(crate) compiler_options: BuildOptions,
+}
+
+impl Command for Test {
+    type Input = <Build as Command>::Output;
+    /// The number of test functions found.
+    type Output = usize;
+
+    fn log_span(&self) -> Span {
+        tracing::span!(tracing::Level::INFO, "Test")
+    }
+
+    fn prelude(&self, context: Context) -> Result<Self::Input> {
+        (Build {
+            compiler_options: self.compiler_options.clone(),
+        })
+        .execute(context)
+    }
+
+    fn apply(self, _context: Context, packages: Self::Input) -> Result<Self::Output> {
+        let mut count = 0;
+        for package in packages {
+            tracing::info!("Found {} tests in package `{}`", package.tests.len(), package.name);
+            for test in package.tests.iter() {
+                tracing::info!("    {} ... compiled", test);
+            }
+            count += package.tests.len();
+        }
+
+        if count > 0 {
+            tracing::warn!("Running tests is not supported yet, the tests were only compiled");
+        }
+
+        Ok(count)
+    }
+}
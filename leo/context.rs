@@ -15,8 +15,11 @@
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::{api::Api, config};
-use leo_errors::{CliError, Result};
-use leo_package::root::{LockFile, Manifest};
+use leo_errors::{
+    emitter::{Handler, MessageFormat},
+    CliError, Result,
+};
+use leo_package::root::{LockFile, Manifest, Workspace};
 
 use std::{convert::TryFrom, env::current_dir, path::PathBuf};
 
@@ -31,6 +34,9 @@ pub struct Context {
 
     /// Path at which the command is called, None when default
     pub path: Option<PathBuf>,
+
+    /// How errors and warnings are printed, set through `--message-format`
+    pub message_format: MessageFormat,
 }
 
 impl Context {
@@ -41,6 +47,30 @@ impl Context {
         }
     }
 
+    /// Get the directory holding the `Leo.lock` and `imports/` of the current package.
+    /// This is the workspace root for workspace members, and the package directory otherwise.
+    pub fn root_dir(&self) -> Result<PathBuf> {
+        match self.workspace()? {
+            Some((root, _)) => Ok(root),
+            None => self.dir(),
+        }
+    }
+
+    /// Get the workspace the current package belongs to, along with the workspace root.
+    pub fn workspace(&self) -> Result<Option<(PathBuf, Workspace)>> {
+        let root = match Workspace::find_root(&self.dir()?)? {
+            Some(root) => root,
+            None => return Ok(None),
+        };
+
+        Ok(Workspace::read_from(&root)?.map(|workspace| (root, workspace)))
+    }
+
+    /// Get a handler printing errors and warnings in the message format of the current context.
+    pub fn handler(&self) -> Handler {
+        Handler::new(self.message_format.emitter())
+    }
+
     /// Get package manifest for current context.
     pub fn manifest(&self) -> Result<Manifest> {
         Ok(Manifest::try_from(self.dir()?.as_path())?)
@@ -48,12 +78,12 @@ impl Context {
 
     /// Get lock file for current context.
     pub fn lock_file(&self) -> Result<LockFile> {
-        Ok(LockFile::try_from(self.dir()?.as_path())?)
+        Ok(LockFile::try_from(self.root_dir()?.as_path())?)
     }
 
     /// Check if lock file exists.
     pub fn lock_file_exists(&self) -> Result<bool> {
-        Ok(LockFile::exists_at(&self.dir()?))
+        Ok(LockFile::exists_at(&self.root_dir()?))
     }
 }
 
@@ -63,7 +93,11 @@ pub fn create_context(path: PathBuf, api_url: Option<String>) -> Result<Context>
 
     let api = Api::new(api_url.unwrap_or_else(|| PACKAGE_MANAGER_URL.to_string()), token);
 
-    Ok(Context { api, path: Some(path) })
+    Ok(Context {
+        api,
+        path: Some(path),
+        message_format: MessageFormat::default(),
+    })
 }
 
 /// Returns project context.
@@ -72,5 +106,9 @@ pub fn get_context(api_url: Option<String>) -> Result<Context> {
 
     let api = Api::new(api_url.unwrap_or_else(|| PACKAGE_MANAGER_URL.to_string()), token);
 
-    Ok(Context { api, path: None })
+    Ok(Context {
+        api,
+        path: None,
+        message_format: MessageFormat::default(),
+    })
 }
@@ -29,6 +29,7 @@ create_errors!(
     /// For when the AST fails to be represented as a JSON string.
     @backtraced
     failed_to_convert_ast_to_json_string {
+        code: 0,
         args: (error: impl ErrorArg),
         msg: format!("failed to convert ast to a json string {}", error),
         help: None,
@@ -37,6 +38,7 @@ create_errors!(
     /// For when the AST fails to create the AST JSON file.
     @backtraced
     failed_to_create_ast_json_file {
+        code: 1,
         args: (path: impl Debug, error: impl ErrorArg),
         msg: format!("failed to create ast json file `{:?}` {}", path, error),
         help: None,
@@ -45,6 +47,7 @@ create_errors!(
     /// For when the AST fails to write the AST JSON file.
     @backtraced
     failed_to_write_ast_to_json_file {
+        code: 2,
         args: (path: impl Debug, error: impl ErrorArg),
         msg: format!("failed to write ast to a json file `{:?}` {}", path, error),
         help: None,
@@ -53,6 +56,7 @@ create_errors!(
     /// For when the a JSON string fails to be represented as an AST.
     @backtraced
     failed_to_read_json_string_to_ast {
+        code: 3,
         args: (error: impl ErrorArg),
         msg: format!("failed to convert json string to an ast {}", error),
         help: None,
@@ -61,6 +65,7 @@ create_errors!(
     /// For when the a JSON files fails to be represented as an AST.
     @backtraced
     failed_to_read_json_file {
+        code: 4,
         args: (path: impl Debug, error: impl ErrorArg),
         msg: format!("failed to convert json file `{:?}` to an ast {}", path, error),
         help: None,
@@ -69,6 +74,7 @@ create_errors!(
     /// For when a user tries to use the `Self` keyword outside of a cricuit.
     @formatted
     big_self_outside_of_circuit {
+        code: 5,
         args: (),
         msg: "cannot call keyword `Self` outside of a circuit function",
         help: None,
@@ -77,6 +83,7 @@ create_errors!(
     /// For when a user tries to define a array dimension of 0.
     @formatted
     invalid_array_dimension_size {
+        code: 6,
         args: (),
         msg: "received dimension size of 0, expected it to be 1 or larger.",
         help: None,
@@ -85,6 +92,7 @@ create_errors!(
     /// For when a user tries to give certain statements a block rather than another statement.
     @formatted
     ast_statement_not_block {
+        code: 7,
         args: (),
         msg: "AstStatement should be be a block",
         help: None,
@@ -93,6 +101,7 @@ create_errors!(
     /// For when a user tries to construct an empty string, which is a zero size array.
     @formatted
     empty_string {
+        code: 8,
         args: (),
         msg: "Cannot constrcut an empty string: it has the type of [char; 0] which is not possible.",
         help: None,
@@ -101,6 +110,7 @@ create_errors!(
     /// This error should never be reached, but represents trying to expand a console assert.
     @formatted
     impossible_console_assert_call {
+        code: 9,
         args: (),
         msg: "Console::Assert cannot be matched here, its handled in another case.",
         help: None,
@@ -110,6 +120,7 @@ create_errors!(
     /// on the rust side.
     @backtraced
     injected_programs {
+        code: 10,
         args: (injected_import_count: impl Display),
         msg: format!("It seems the AST has {} injected imports. This is unexpected please import the library naturally", injected_import_count),
         help: None,
@@ -118,6 +129,7 @@ create_errors!(
     /// For when a import of the specified name is unresolved.
     @formatted
     unresolved_import {
+        code: 11,
         args: (name: impl Display),
         msg: format!("failed to resolve import: '{}'", name),
         help: None,
@@ -126,6 +138,7 @@ create_errors!(
     /// For when the AST fails to be represented as a JSON value.
     @backtraced
     failed_to_convert_ast_to_json_value {
+        code: 12,
         args: (error: impl ErrorArg),
         msg: format!("failed to convert ast to a json value {}", error),
         help: None,
@@ -134,8 +147,27 @@ create_errors!(
     /// For when a user tries to define a tuple dimension of 1.
     @formatted
     invalid_tuple_dimension_size {
+        code: 13,
         args: (),
         msg: "tuples of 1 element are not allowed",
         help: None,
     }
+
+    /// For when a JSON AST has a schema version which this version of Leo does not support.
+    @backtraced
+    unsupported_ast_schema_version {
+        code: 14,
+        args: (version: impl Display, supported: impl Display),
+        msg: format!("unsupported JSON AST schema version {}", version),
+        help: Some(format!("This version of Leo reads the JSON AST schema version {}.", supported)),
+    }
+
+    /// For when the AST fails to be written to a Leo file.
+    @backtraced
+    failed_to_write_ast_to_leo_file {
+        code: 15,
+        args: (path: impl Debug, error: impl ErrorArg),
+        msg: format!("failed to write ast to a leo file `{:?}` {}", path, error),
+        help: None,
+    }
 );
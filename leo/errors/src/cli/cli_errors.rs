@@ -29,6 +29,7 @@ create_errors!(
     /// Not actually ever returned anywhere outside a test.
     @backtraced
     opt_args_error {
+        code: 0,
         args: (error: impl ErrorArg),
         msg: format!("opt arg error {}", error),
         help: None,
@@ -37,6 +38,7 @@ create_errors!(
     /// For when APM returns an unidentifed API error.
     @backtraced
     unidentified_api {
+        code: 1,
         args: (),
         msg: "Unidentified API error",
         help: None,
@@ -45,6 +47,7 @@ create_errors!(
     /// For when the CLI is unable to connect to a Leo Package Manager.
     @backtraced
     unable_to_connect_aleo_pm {
+        code: 2,
         args: (),
         msg: "Unable to connect to Aleo PM. If you specified custom API endpoint, then check the URL for errors",
         help: None,
@@ -53,6 +56,7 @@ create_errors!(
     /// For when APM is unable to find to a package.
     @backtraced
     package_not_found {
+        code: 3,
         args: (),
         msg: "Package is not found - check author and/or package name",
         help: None,
@@ -61,6 +65,7 @@ create_errors!(
     /// For when APM returns an unknown API error.
     @backtraced
     unkown_api_error {
+        code: 4,
         args: (status: impl Display),
         msg: format!("Unknown API error: {}", status),
         help: None,
@@ -69,6 +74,7 @@ create_errors!(
     /// For when the APM account username is not registered, or password is incorrect.
     @backtraced
     account_not_found {
+        code: 5,
         args: (),
         msg: "This username is not yet registered or the password is incorrect",
         help: None,
@@ -77,6 +83,7 @@ create_errors!(
     /// For when APM account password is incorrect.
     @backtraced
     incorrect_password {
+        code: 6,
         args: (),
         msg: "Incorrect password",
         help: None,
@@ -85,6 +92,7 @@ create_errors!(
     /// For when a request to APM fails witha bad request status.
     @backtraced
     bad_request {
+        code: 7,
         args: (msg: impl Display),
         msg: msg,
         help: None,
@@ -94,6 +102,7 @@ create_errors!(
     /// requires the user to be logged in first.
     @backtraced
     not_logged_in {
+        code: 8,
         args: (),
         msg: "You are not logged in. Please use `leo login` to login",
         help: None,
@@ -102,6 +111,7 @@ create_errors!(
     /// For when a package with the same name and author name is already published.
     @backtraced
     already_published {
+        code: 9,
         args: (),
         msg: "This package version is already published",
         help: None,
@@ -110,6 +120,7 @@ create_errors!(
     /// For when APM is experiencing a HTTP Status of 500.
     @backtraced
     internal_server_error {
+        code: 10,
         args: (),
         msg: "Server error, please contact us at https://github.com/AleoHQ/leo/issues",
         help: None,
@@ -118,6 +129,7 @@ create_errors!(
     /// For when the reqwest library fails to get the request JSON.
     @backtraced
     reqwest_json_error {
+        code: 11,
         args: (error: impl ErrorArg),
         msg: format!("request JSON failed {}", error),
         help: None,
@@ -126,6 +138,7 @@ create_errors!(
     /// For when the user provides an incorrect command argument.
     @backtraced
     incorrect_command_argument {
+        code: 12,
         args: (),
         msg: "Incorrect argument, please use --help for information on command use",
         help: None,
@@ -134,6 +147,7 @@ create_errors!(
     /// For when the CLI experiences an IO error.
     @backtraced
     cli_io_error {
+        code: 13,
         args: (error: impl ErrorArg),
         msg: format!("cli io error {}", error),
         help: None,
@@ -142,6 +156,7 @@ create_errors!(
     /// For when the CLI experiences a bytes conversion error.
     @backtraced
     cli_bytes_conversion_error {
+        code: 14,
         args: (error: impl ErrorArg),
         msg: format!("cli bytes conversion error {}", error),
         help: None,
@@ -150,6 +165,7 @@ create_errors!(
     /// For when the CLI experiences a zip error.
     @backtraced
     cli_zip_error {
+        code: 15,
         args: (error: impl ErrorArg),
         msg: format!("cli zip error {}", error),
         help: None,
@@ -158,6 +174,7 @@ create_errors!(
     /// For when the CLI cannot find the manifest file.
     @backtraced
     manifest_file_not_found {
+        code: 16,
         args: (),
         msg: "Package manifest not found, try running `leo init`",
         help: None,
@@ -166,6 +183,7 @@ create_errors!(
     /// For when the CLI was unable to get the user token.
     @backtraced
     unable_to_get_user_token {
+        code: 17,
         args: (),
         msg: "Unable to get token",
         help: None,
@@ -174,6 +192,7 @@ create_errors!(
     /// For when the CLI was supplied an incorrect user token.
     @backtraced
     supplied_token_is_incorrect {
+        code: 18,
         args: (),
         msg: "Supplied token is incorrect",
         help: None,
@@ -182,6 +201,7 @@ create_errors!(
     /// For when the CLI user's stored credentials expire.
     @backtraced
     stored_credentials_expired {
+        code: 19,
         args: (),
         msg: "Stored credentials are incorrect or expired, please login again",
         help: None,
@@ -190,6 +210,7 @@ create_errors!(
     /// For when the user does not provide credentials to the CLI.
     @backtraced
     no_credentials_provided {
+        code: 20,
         args: (),
         msg: "No credentials provided",
         help: None,
@@ -199,6 +220,7 @@ create_errors!(
     /// and cannot logout the user.
     @backtraced
     logout_permision_denied {
+        code: 21,
         args: (),
         msg: "permission denied - check file permission in .leo folder",
         help: None,
@@ -207,6 +229,7 @@ create_errors!(
     /// For when the CLI cannot access the logout file.
     @backtraced
     cannot_access_logout_file {
+        code: 22,
         args: (),
         msg: "something went wrong, can't access the file",
         help: None,
@@ -215,6 +238,7 @@ create_errors!(
     /// For when the user tries to name a package after a Leo keyword.
     @backtraced
     package_cannot_be_named_after_a_keyword {
+        code: 23,
         args: (),
         msg: "Cannot be named a package after a keyword",
         help: None,
@@ -223,6 +247,7 @@ create_errors!(
     /// For when the user has not provided a package description.
     @backtraced
     no_package_description {
+        code: 24,
         args: (),
         msg: "No package description",
         help: None,
@@ -231,6 +256,7 @@ create_errors!(
     /// For when the user has not provided a package license.
     @backtraced
     missing_package_license {
+        code: 25,
         args: (),
         msg: "Missing package license",
         help: None,
@@ -239,6 +265,7 @@ create_errors!(
     /// For when the package is missing its remote section in the Leo.toml file.
     @backtraced
     missing_package_remote {
+        code: 26,
         args: (),
         msg: "Missing package remote",
         help: None,
@@ -247,6 +274,7 @@ create_errors!(
     /// For when the user has not provided the package author field in the Leo.toml file.
     @backtraced
     package_author_is_not_set {
+        code: 27,
         args: (),
         msg: "Package author is not set. Specify package author in [remote] section of Leo.toml",
         help: None,
@@ -255,6 +283,7 @@ create_errors!(
     /// For when the CLI fails to convert an object to TOML.
     @backtraced
     failed_to_convert_to_toml {
+        code: 28,
         args: (error: impl ErrorArg),
         msg: format!("failed to covnert to TOML {}", error),
         help: None,
@@ -263,6 +292,7 @@ create_errors!(
     /// For when the CLI fails to TOML an object.
     @backtraced
     failed_to_convert_from_toml {
+        code: 29,
         args: (error: impl ErrorArg),
         msg: format!("failed to covnert from TOML {}", error),
         help: None,
@@ -271,6 +301,7 @@ create_errors!(
     /// For when the current package directory doesn't exist.
     @backtraced
     package_directory_does_not_exist {
+        code: 30,
         args: (),
         msg: "Directory does not exist",
         help: None,
@@ -279,6 +310,7 @@ create_errors!(
     /// For when the current project has an invalid name.
     @backtraced
     invalid_project_name {
+        code: 31,
         args: (),
         msg: "Project name invalid",
         help: None,
@@ -287,6 +319,7 @@ create_errors!(
     /// For when the current package has an invalid name.
     @backtraced
     invalid_package_name {
+        code: 32,
         args: (name: impl Display),
         msg: format!("Invalid Leo package name: {}", name),
         help: None,
@@ -295,6 +328,7 @@ create_errors!(
     /// For when the package main.leo file is not found.
     @backtraced
     package_main_file_not_found {
+        code: 33,
         args: (),
         msg: "File main.leo not found in src/ directory",
         help: None,
@@ -303,6 +337,7 @@ create_errors!(
     /// For when the package directory already exists.
     @backtraced
     package_directory_already_exists {
+        code: 34,
         args: (path: impl Debug),
         msg: format!("Directory already exists {:?}", path),
         help: None,
@@ -311,6 +346,7 @@ create_errors!(
     /// For when the CLI could not a directory.
     @backtraced
     package_could_not_create_directory {
+        code: 35,
         args: (error: impl ErrorArg),
         msg: format!("Could not create directory {}", error),
         help: None,
@@ -319,6 +355,7 @@ create_errors!(
     /// For when the CLI could not setup a Leo command.
     @backtraced
     unable_to_setup {
+        code: 36,
         args: (),
         msg: "Unable to setup, see command output for more details",
         help: None,
@@ -327,6 +364,7 @@ create_errors!(
     /// For when the program file does not exist.
     @backtraced
     program_file_does_not_exist {
+        code: 37,
         args: (path: impl Display),
         msg: format!("Program file does not exist {}", path),
         help: None,
@@ -335,6 +373,7 @@ create_errors!(
     /// For when the CLI could not fetch the versions.
     @backtraced
     could_not_fetch_versions {
+        code: 38,
         args: (error: impl ErrorArg),
         msg: format!("Could not fetch versions: {}", error),
         help: None,
@@ -343,6 +382,7 @@ create_errors!(
     /// For when the CLI failed to watch the Leo package.
     @backtraced
     unable_to_watch {
+        code: 39,
         args: (error: impl ErrorArg),
         msg: format!("Unable to watch, check that directory contains Leo.toml file. Error: {}", error),
         help: None,
@@ -351,6 +391,7 @@ create_errors!(
     /// For when the CLI fails to enable ansi support.
     @backtraced
     failed_to_enable_ansi_support {
+        code: 40,
         args: (),
         msg: "failed to enable ansi_support",
         help: None,
@@ -359,6 +400,7 @@ create_errors!(
     /// For when the CLI fails to self update.
     @backtraced
     self_update_error {
+        code: 41,
         args: (error: impl ErrorArg),
         msg: format!("self update crate Error: {}", error),
         help: None,
@@ -367,6 +409,7 @@ create_errors!(
     /// For when the CLI fails to self update.
     @backtraced
     self_update_build_error {
+        code: 42,
         args: (error: impl ErrorArg),
         msg: format!("self update crate failed to build Error: {}", error),
         help: None,
@@ -375,6 +418,7 @@ create_errors!(
     /// For when the CLI has an old release version.
     @backtraced
     old_release_version {
+        code: 43,
         args: (current: impl Display, latest: impl Display),
         msg: format!("Old release version {} {}", current, latest),
         help: None,
@@ -382,6 +426,7 @@ create_errors!(
 
     @backtraced
     dependencies_are_not_installed {
+        code: 44,
         args: (),
         msg: "dependencies are not installed, please run `leo fetch` first",
         help: None,
@@ -389,6 +434,7 @@ create_errors!(
 
     @backtraced
     recursive_dependency_found {
+        code: 45,
         args: (message: impl Display),
         msg: format!("recursive dependency found \n{}", message),
         help: None,
@@ -396,10 +442,74 @@ create_errors!(
 
     @backtraced
     unable_to_read_imported_dependency_manifest {
+        code: 46,
         args: (),
         msg: "unable to parse imported dependency's manifest",
         help: None,
     }
+
+    /// For when errors were emitted while compiling a package.
+    @backtraced
+    compilation_failed {
+        code: 47,
+        args: (count: impl Display),
+        msg: format!("could not compile the package due to {} previous error(s)", count),
+        help: None,
+    }
+
+    /// For when `leo explain` is given a code that is not an error code.
+    @backtraced
+    unknown_error_code {
+        code: 48,
+        args: (code: impl Display),
+        msg: format!("`{}` is not a Leo error code", code),
+        help: Some("Error codes look like `EPAR0370005`, as printed in brackets after `Error`.".to_string()),
+    }
+
+    /// For when `leo explain` is given an error code without a guide.
+    @backtraced
+    missing_error_guide {
+        code: 49,
+        args: (code: impl Display),
+        msg: format!("there is no guide for the error code `{}` yet", code),
+        help: None,
+    }
+
+    /// For when `leo fmt` could not parse some of the files of a package.
+    @backtraced
+    formatting_failed {
+        code: 50,
+        args: (count: impl Display),
+        msg: format!("could not format the package due to {} previous error(s)", count),
+        help: None,
+    }
+
+    /// For when `leo fmt --check` finds files which are not formatted.
+    @backtraced
+    files_not_formatted {
+        code: 51,
+        args: (count: impl Display),
+        msg: format!("{} file(s) are not formatted", count),
+        help: Some("Run `leo fmt` to format them.".to_string()),
+    }
+
+    /// For when `leo lint` finds errors, including denied lints.
+    @backtraced
+    linting_failed {
+        code: 52,
+        args: (count: impl Display),
+        msg: format!("linting failed due to {} previous error(s)", count),
+        help: None,
+    }
+
+    /// For when the `[lint]` section of Leo.toml names a lint which does not exist.
+    @backtraced
+    unknown_lint {
+        code: 53,
+        args: (name: impl Display),
+        msg: format!("unknown lint `{}` in the `[lint]` section of Leo.toml", name),
+        help: None,
+    }
 );
 
 impl CliError {
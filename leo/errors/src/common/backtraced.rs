@@ -20,10 +20,62 @@ use backtrace::Backtrace;
 use color_backtrace::{BacktracePrinter, Verbosity};
 use colored::Colorize;
 use derivative::Derivative;
+use serde::{Deserialize, Serialize};
 
 /// The indent for an error message.
 pub(crate) const INDENT: &str = "    ";
 
+/// Whether a message is an error, or a warning which may have been turned into an error.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Severity {
+    /// An error, which aborts compilation.
+    Error,
+    /// A warning, which does not abort compilation.
+    Warning,
+    /// A warning that is treated as an error, e.g., through `--deny-warnings`.
+    DeniedWarning,
+}
+
+impl Default for Severity {
+    fn default() -> Self {
+        Self::Error
+    }
+}
+
+impl Severity {
+    /// Returns the first letter of codes with this severity.
+    fn code_prefix(self) -> char {
+        match self {
+            Self::Error => 'E',
+            Self::Warning | Self::DeniedWarning => 'W',
+        }
+    }
+
+    /// Returns the label of messages with this severity, colored unless running the test framework.
+    pub(crate) fn label(self, code: &str, message: &str) -> String {
+        let (label, warning) = match self {
+            Self::Error | Self::DeniedWarning => ("Error", false),
+            Self::Warning => ("Warning", true),
+        };
+        let label = format!("{} [{}]: {}", label, code, message);
+
+        // To avoid the color enabling characters for comparison with test expectations.
+        if !std::env::var("LEO_TESTFRAMEWORK")
+            .unwrap_or_default()
+            .trim()
+            .to_owned()
+            .is_empty()
+        {
+            label
+        } else if warning {
+            label.bold().yellow().to_string()
+        } else {
+            label.bold().red().to_string()
+        }
+    }
+}
+
 /// Backtraced compiler error type
 ///     undefined value `x`
 ///     --> file.leo: 2:8
@@ -35,12 +87,16 @@ pub struct BacktracedError {
     pub message: String,
     /// The error help message if it exists.
     pub help: Option<String>,
+    /// Additional notes shown after the help message.
+    pub notes: Vec<String>,
     /// The error exit code.
     pub exit_code: i32,
     /// The error leading digits identifier.
     pub code_identifier: i8,
     /// The characters representing the type of error.
     pub error_type: String,
+    /// Whether this is an error or a warning.
+    pub severity: Severity,
     #[derivative(PartialEq = "ignore")]
     #[derivative(Hash = "ignore")]
     /// The backtrace representing where the error occured in Leo.
@@ -63,13 +119,27 @@ impl BacktracedError {
         Self {
             message: message.to_string(),
             help,
+            notes: Vec::new(),
             exit_code,
             code_identifier,
             error_type,
+            severity: Severity::Error,
             backtrace,
         }
     }
 
+    /// Returns this error with the given `severity`.
+    pub fn with_severity(mut self, severity: Severity) -> Self {
+        self.severity = severity;
+        self
+    }
+
+    /// Returns this error with an additional `note:` line.
+    pub fn with_note(mut self, note: impl ToString) -> Self {
+        self.notes.push(note.to_string());
+        self
+    }
+
     /// Gets the backtraced error error code.
     pub fn exit_code(&self) -> i32 {
         let mut code: i32;
@@ -88,7 +158,8 @@ impl BacktracedError {
     /// Gets a unique error identifier.
     pub fn error_code(&self) -> String {
         format!(
-            "E{error_type}{code_identifier:0>3}{exit_code:0>4}",
+            "{prefix}{error_type}{code_identifier:0>3}{exit_code:0>4}",
+            prefix = self.severity.code_prefix(),
             error_type = self.error_type,
             code_identifier = self.code_identifier,
             exit_code = self.exit_code,
@@ -98,23 +169,7 @@ impl BacktracedError {
 
 impl fmt::Display for BacktracedError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        let error_message = format!(
-            "Error [{error_code}]: {message}",
-            error_code = self.error_code(),
-            message = self.message,
-        );
-
-        // To avoid the color enabling characters for comparison with test expectations.
-        if std::env::var("LEO_TESTFRAMEWORK")
-            .unwrap_or_default()
-            .trim()
-            .to_owned()
-            .is_empty()
-        {
-            write!(f, "{}", error_message.bold().red())?;
-        } else {
-            write!(f, "{}", error_message)?;
-        };
+        write!(f, "{}", self.severity.label(&self.error_code(), &self.message))?;
 
         if let Some(help) = &self.help {
             write!(
@@ -126,6 +181,16 @@ impl fmt::Display for BacktracedError {
             )?;
         }
 
+        for note in &self.notes {
+            write!(
+                f,
+                "\n{indent     } |\n\
+            {indent     } = note: {note}",
+                indent = INDENT,
+                note = note
+            )?;
+        }
+
         let leo_backtrace = std::env::var("LEO_BACKTRACE").unwrap_or_default().trim().to_owned();
         match leo_backtrace.as_ref() {
             "1" => {
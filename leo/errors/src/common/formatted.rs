@@ -14,13 +14,12 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{BacktracedError, INDENT};
+use crate::{BacktracedError, Severity, INDENT};
 
-use leo_span::Span;
+use leo_span::{Span, SpanLocation};
 
 use backtrace::Backtrace;
 use color_backtrace::{BacktracePrinter, Verbosity};
-use colored::Colorize;
 use std::fmt;
 
 /// Formatted compiler error type
@@ -36,10 +35,104 @@ use std::fmt;
 pub struct FormattedError {
     /// The formatted error span information.
     pub span: Span,
+    /// The lines, columns, path, and content of the span, resolved when the error is created.
+    pub location: SpanLocation,
+    /// Secondary spans related to the error, e.g., where a duplicate was first defined.
+    pub labels: Vec<Label>,
+    /// Suggested fixes of the error.
+    pub suggestions: Vec<Suggestion>,
     /// The backtrace to track where the Leo error originated.
     pub backtrace: BacktracedError,
 }
 
+/// A secondary span of a formatted error, rendered with a message below the primary span
+///      |
+///    1 | function x() {}
+///      |          - first defined here
+#[derive(Clone, Debug, Default, Hash, PartialEq)]
+pub struct Label {
+    /// The span the label points at, which may be in another file than the error.
+    pub span: Span,
+    /// The lines, columns, path, and content of the span.
+    pub location: SpanLocation,
+    /// The message explaining how the span relates to the error.
+    pub message: String,
+}
+
+impl Label {
+    /// Creates a label with `message` at `span`.
+    pub fn new(span: &Span, message: impl ToString) -> Self {
+        Self {
+            span: span.clone(),
+            location: span.location(),
+            message: message.to_string(),
+        }
+    }
+}
+
+/// A suggested fix of a formatted error, replacing the source code of a span
+///      = suggestion: use the `@test` annotation
+///      |
+///    3 | @test
+///      |  ~~~~
+#[derive(Clone, Debug, Default, Hash, PartialEq)]
+pub struct Suggestion {
+    /// The span of the source code to replace.
+    pub span: Span,
+    /// The lines, columns, path, and content of the span.
+    pub location: SpanLocation,
+    /// The message describing the fix.
+    pub message: String,
+    /// The source code replacing the span.
+    pub replacement: String,
+}
+
+impl Suggestion {
+    /// Creates a suggestion to replace the source code at `span` with `replacement`.
+    pub fn new(span: &Span, message: impl ToString, replacement: impl ToString) -> Self {
+        Self {
+            span: span.clone(),
+            location: span.location(),
+            message: message.to_string(),
+            replacement: replacement.to_string(),
+        }
+    }
+}
+
+/// Returns the underline of the columns `start` through `end`, drawn with `marker`.
+fn underline(mut start: usize, mut end: usize, marker: char) -> String {
+    if start > end {
+        std::mem::swap(&mut start, &mut end)
+    }
+
+    let mut underline = String::new();
+
+    for _ in 0..start {
+        underline.push(' ');
+        end -= 1;
+    }
+
+    for _ in 0..end {
+        underline.push(marker);
+    }
+
+    underline
+}
+
+/// Writes the numbered `content` lines starting at `line_start`.
+fn write_lines(f: &mut fmt::Formatter, line_start: usize, content: &str) -> fmt::Result {
+    for (line_no, line) in content.lines().enumerate() {
+        writeln!(
+            f,
+            "{line_no:width$} | {text}",
+            width = INDENT.len(),
+            line_no = line_start + line_no,
+            text = line,
+        )?;
+    }
+    Ok(())
+}
+
 impl FormattedError {
     /// Creates a backtraced error from a span and a backtrace.
     pub fn new_from_span<S>(
@@ -56,6 +149,9 @@ impl FormattedError {
     {
         Self {
             span: span.clone(),
+            location: span.location(),
+            labels: Vec::new(),
+            suggestions: Vec::new(),
             backtrace: BacktracedError::new_from_backtrace(
                 message.to_string(),
                 help,
@@ -67,6 +163,30 @@ impl FormattedError {
         }
     }
 
+    /// Returns this error with the given `severity`.
+    pub fn with_severity(mut self, severity: Severity) -> Self {
+        self.backtrace.severity = severity;
+        self
+    }
+
+    /// Returns this error with a secondary span labeled with `message`.
+    pub fn with_label(mut self, span: &Span, message: impl ToString) -> Self {
+        self.labels.push(Label::new(span, message));
+        self
+    }
+
+    /// Returns this error with an additional `note:` line.
+    pub fn with_note(mut self, note: impl ToString) -> Self {
+        self.backtrace = self.backtrace.with_note(note);
+        self
+    }
+
+    /// Returns this error with a suggestion to replace the source code at `span` with `replacement`.
+    pub fn with_suggestion(mut self, span: &Span, message: impl ToString, replacement: impl ToString) -> Self {
+        self.suggestions.push(Suggestion::new(span, message, replacement));
+        self
+    }
+
     /// Calls the backtraces error code.
     pub fn exit_code(&self) -> i32 {
         self.backtrace.exit_code()
@@ -80,64 +200,27 @@ impl FormattedError {
 
 impl fmt::Display for FormattedError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        let underline = |mut start: usize, mut end: usize| -> String {
-            if start > end {
-                std::mem::swap(&mut start, &mut end)
-            }
-
-            let mut underline = String::new();
-
-            for _ in 0..start {
-                underline.push(' ');
-                end -= 1;
-            }
-
-            for _ in 0..end {
-                underline.push('^');
-            }
+        let underlined = underline(self.location.col_start, self.location.col_stop, '^');
 
-            underline
-        };
-
-        let underlined = underline(self.span.col_start, self.span.col_stop);
-
-        let error_message = format!(
-            "Error [{error_code}]: {message}",
-            error_code = self.error_code(),
-            message = self.backtrace.message,
-        );
-
-        // To avoid the color enabling characters for comparison with test expectations.
-        if std::env::var("LEO_TESTFRAMEWORK")
-            .unwrap_or_default()
-            .trim()
-            .to_owned()
-            .is_empty()
-        {
-            write!(f, "{}", error_message.bold().red())?;
-        } else {
-            write!(f, "{}", error_message)?;
-        };
+        write!(
+            f,
+            "{}",
+            self.backtrace
+                .severity
+                .label(&self.error_code(), &self.backtrace.message)
+        )?;
 
         write!(
             f,
             "\n{indent     }--> {path}:{line_start}:{start}\n\
             {indent     } |\n",
             indent = INDENT,
-            path = &*self.span.path,
-            line_start = self.span.line_start,
-            start = self.span.col_start,
+            path = &*self.location.path,
+            line_start = self.location.line_start,
+            start = self.location.col_start,
         )?;
 
-        for (line_no, line) in self.span.content.lines().enumerate() {
-            writeln!(
-                f,
-                "{line_no:width$} | {text}",
-                width = INDENT.len(),
-                line_no = self.span.line_start + line_no,
-                text = line,
-            )?;
-        }
+        write_lines(f, self.location.line_start, &self.location.content)?;
 
         write!(
             f,
@@ -146,6 +229,30 @@ impl fmt::Display for FormattedError {
             underlined = underlined,
         )?;
 
+        for label in &self.labels {
+            // Labels in other files than the error point to their file first.
+            if label.location.path != self.location.path {
+                write!(
+                    f,
+                    "\n{indent     }::: {path}:{line_start}:{start}",
+                    indent = INDENT,
+                    path = &*label.location.path,
+                    line_start = label.location.line_start,
+                    start = label.location.col_start,
+                )?;
+            }
+
+            write!(f, "\n{indent     } |\n", indent = INDENT)?;
+            write_lines(f, label.location.line_start, &label.location.content)?;
+            write!(
+                f,
+                "{indent     } |{underlined} {message}",
+                indent = INDENT,
+                underlined = underline(label.location.col_start, label.location.col_stop, '-'),
+                message = label.message,
+            )?;
+        }
+
         if let Some(help) = &self.backtrace.help {
             write!(
                 f,
@@ -156,6 +263,60 @@ impl fmt::Display for FormattedError {
             )?;
         }
 
+        for note in &self.backtrace.notes {
+            write!(
+                f,
+                "\n{indent     } |\n\
+            {indent     } = note: {note}",
+                indent = INDENT,
+                note = note
+            )?;
+        }
+
+        for suggestion in &self.suggestions {
+            write!(
+                f,
+                "\n{indent     } |\n\
+            {indent     } = suggestion: {message}",
+                indent = INDENT,
+                message = suggestion.message,
+            )?;
+
+            // Single line suggestions are shown applied to their line, others by their replacement.
+            let location = &suggestion.location;
+            if location.line_start == location.line_stop && location.line_start != 0 {
+                let start = location.col_start - 1;
+                let stop = location.col_stop - 1;
+                let line = format!(
+                    "{}{}{}",
+                    &location.content[..start],
+                    suggestion.replacement,
+                    &location.content[stop..]
+                );
+                write!(
+                    f,
+                    "\n{indent     } |\n\
+                    {line_no:width$} | {text}\n",
+                    indent = INDENT,
+                    width = INDENT.len(),
+                    line_no = location.line_start,
+                    text = line,
+                )?;
+                write!(
+                    f,
+                    "{indent     } |{underlined}",
+                    indent = INDENT,
+                    underlined = underline(
+                        location.col_start,
+                        location.col_start + suggestion.replacement.len(),
+                        '~'
+                    ),
+                )?;
+            } else {
+                write!(f, ": `{}`", suggestion.replacement)?;
+            }
+        }
+
         let leo_backtrace = std::env::var("LEO_BACKTRACE").unwrap_or_default().trim().to_owned();
         match leo_backtrace.as_ref() {
             "1" => {
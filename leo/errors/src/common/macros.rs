@@ -16,17 +16,13 @@
 
 /// A macro that given an enum, exit code mask, error code string prefix,
 /// and error methods generated through a DSL creates and generates errors
-/// with a unique error code.
+/// with the error code given to each of them.
+/// The codes are stable, and recorded in `ERROR_INDEX.md`,
+/// so a new error takes the next free code rather than its position.
 #[macro_export]
 macro_rules! create_errors {
-    (@step $code:expr,) => {
-        #[inline(always)]
-        // Returns the number of unique exit codes that this error type can take on.
-        pub fn num_exit_codes() -> i32 {
-            $code
-        }
-    };
-    ($(#[$error_type_docs:meta])* $error_type:ident, exit_code_mask: $exit_code_mask:expr, error_code_prefix: $error_code_prefix:expr, $($(#[$docs:meta])* @$formatted_or_backtraced_list:ident $names:ident { args: ($($arg_names:ident: $arg_types:ty$(,)?)*), msg: $messages:expr, help: $helps:expr, })*) => {
+    (@step) => {};
+    ($(#[$error_type_docs:meta])* $error_type:ident, exit_code_mask: $exit_code_mask:expr, error_code_prefix: $error_code_prefix:expr, $($(#[$docs:meta])* @$formatted_or_backtraced_list:ident $names:ident { code: $codes:literal, args: ($($arg_names:ident: $arg_types:ty$(,)?)*), msg: $messages:expr, help: $helps:expr, })*) => {
         #[allow(unused_imports)] // Allow unused for errors that only use formatted or backtraced errors.
         use crate::{BacktracedError, FormattedError, LeoErrorCode};
 
@@ -73,13 +69,66 @@ macro_rules! create_errors {
         }
 
 
-        // Steps over the list of functions with an initial error code of 0.
         impl $error_type {
-            create_errors!(@step 0i32, $(($(#[$docs])* $formatted_or_backtraced_list, $names($($arg_names: $arg_types,)*), $messages, $helps),)*);
+            /// The codes and names of the errors, where the codes do not include the exit code mask.
+            pub const ERRORS: &'static [(i32, &'static str)] = &[$(($codes, stringify!($names)),)*];
+
+            /// Returns the prefixed error code of the error with the `code`, e.g., `EPAR0370005`.
+            pub fn prefixed_code(code: i32) -> String {
+                format!("E{}{:0>3}{:0>4}", Self::error_type(), Self::code_identifier(), Self::exit_code_mask() + code)
+            }
+
+            /// Returns the name of the error with the prefixed error `code`, if it is of this type.
+            pub fn error_name(code: &str) -> Option<&'static str> {
+                Self::ERRORS
+                    .iter()
+                    .find_map(|(error_code, name)| (Self::prefixed_code(*error_code) == code).then(|| *name))
+            }
+
+            /// Returns the error as a machine-readable diagnostic.
+            pub fn diagnostic(&self) -> crate::emitter::Diagnostic {
+                match self {
+                    Self::FormattedError(formatted) => formatted.into(),
+                    Self::BacktracedError(backtraced) => backtraced.into(),
+                }
+            }
+
+            /// Returns this error with a secondary span labeled with `message`.
+            /// Backtraced errors have no spans, and are returned unchanged.
+            pub fn with_label(self, span: &leo_span::Span, message: impl ToString) -> Self {
+                match self {
+                    Self::FormattedError(formatted) => Self::FormattedError(formatted.with_label(span, message)),
+                    backtraced => backtraced,
+                }
+            }
+
+            /// Returns this error with an additional `note:` line.
+            pub fn with_note(self, note: impl ToString) -> Self {
+                match self {
+                    Self::FormattedError(formatted) => Self::FormattedError(formatted.with_note(note)),
+                    Self::BacktracedError(backtraced) => Self::BacktracedError(backtraced.with_note(note)),
+                }
+            }
+
+            /// Returns this error with a suggestion to replace the source code at `span` with `replacement`.
+            /// Backtraced errors have no spans, and are returned unchanged.
+            pub fn with_suggestion(self, span: &leo_span::Span, message: impl ToString, replacement: impl ToString) -> Self {
+                match self {
+                    Self::FormattedError(formatted) => {
+                        Self::FormattedError(formatted.with_suggestion(span, message, replacement))
+                    }
+                    backtraced => backtraced,
+                }
+            }
+        }
+
+        // Steps over the list of functions.
+        impl $error_type {
+            create_errors!(@step $(($(#[$docs])* $formatted_or_backtraced_list, $codes, $names($($arg_names: $arg_types,)*), $messages, $helps),)*);
         }
     };
     // Matches the function if it is a formatted error.
-    (@step $code:expr, ($(#[$error_func_docs:meta])* formatted, $error_name:ident($($arg_names:ident: $arg_types:ty,)*), $message:expr, $help:expr), $(($(#[$docs:meta])* $formatted_or_backtraced_tail:ident, $names:ident($($tail_arg_names:ident: $tail_arg_types:ty,)*), $messages:expr, $helps:expr),)*) => {
+    (@step ($(#[$error_func_docs:meta])* formatted, $code:literal, $error_name:ident($($arg_names:ident: $arg_types:ty,)*), $message:expr, $help:expr), $(($(#[$docs:meta])* $formatted_or_backtraced_tail:ident, $codes:literal, $names:ident($($tail_arg_names:ident: $tail_arg_types:ty,)*), $messages:expr, $helps:expr),)*) => {
         // Formatted errors always takes a span.
         $(#[$error_func_docs])*
         // Expands additional arguments for the error defining function.
@@ -98,11 +147,11 @@ macro_rules! create_errors {
             )
         }
 
-        // Steps the error code value by one and calls on the rest of the functions.
-        create_errors!(@step $code + 1i32, $(($(#[$docs])* $formatted_or_backtraced_tail, $names($($tail_arg_names: $tail_arg_types,)*), $messages, $helps),)*);
+        // Calls on the rest of the functions.
+        create_errors!(@step $(($(#[$docs])* $formatted_or_backtraced_tail, $codes, $names($($tail_arg_names: $tail_arg_types,)*), $messages, $helps),)*);
     };
     // matches the function if it is a backtraced error.
-    (@step $code:expr, ($(#[$error_func_docs:meta])* backtraced, $error_name:ident($($arg_names:ident: $arg_types:ty,)*), $message:expr, $help:expr), $(($(#[$docs:meta])* $formatted_or_backtraced_tail:ident, $names:ident($($tail_arg_names:ident: $tail_arg_types:ty,)*), $messages:expr, $helps:expr),)*) => {
+    (@step ($(#[$error_func_docs:meta])* backtraced, $code:literal, $error_name:ident($($arg_names:ident: $arg_types:ty,)*), $message:expr, $help:expr), $(($(#[$docs:meta])* $formatted_or_backtraced_tail:ident, $codes:literal, $names:ident($($tail_arg_names:ident: $tail_arg_types:ty,)*), $messages:expr, $helps:expr),)*) => {
         $(#[$error_func_docs])*
         // Expands additional arguments for the error defining function.
         pub fn $error_name($($arg_names: $arg_types,)*) -> Self {
@@ -119,7 +168,181 @@ macro_rules! create_errors {
             )
         }
 
-        // Steps the error code value by one and calls on the rest of the functions.
-        create_errors!(@step $code + 1i32, $(($(#[$docs])* $formatted_or_backtraced_tail, $names($($tail_arg_names: $tail_arg_types,)*), $messages, $helps),)*);
+        // Calls on the rest of the functions.
+        create_errors!(@step $(($(#[$docs])* $formatted_or_backtraced_tail, $codes, $names($($tail_arg_names: $tail_arg_types,)*), $messages, $helps),)*);
+    };
+}
+
+/// A macro that given an enum, exit code mask, warning code string prefix,
+/// and warning methods generated through the same DSL as [`create_errors`]
+/// creates and generates warnings with the warning code given to each of them.
+/// The name of each warning method is also the name of its lint,
+/// which is used to allow the warning, e.g., `@allow(unknown_annotation)`.
+#[macro_export]
+macro_rules! create_warnings {
+    (@step) => {};
+    ($(#[$warning_type_docs:meta])* $warning_type:ident, exit_code_mask: $exit_code_mask:expr, warning_code_prefix: $warning_code_prefix:expr, $($(#[$docs:meta])* @$formatted_or_backtraced_list:ident $names:ident { code: $codes:literal, args: ($($arg_names:ident: $arg_types:ty$(,)?)*), msg: $messages:expr, help: $helps:expr, })*) => {
+        #[allow(unused_imports)] // Allow unused for warnings that only use formatted or backtraced warnings.
+        use crate::{BacktracedError, FormattedError, LeoErrorCode, Severity};
+
+        use backtrace::Backtrace;
+
+        // Generates the enum, which wraps errors with a warning severity.
+        #[derive(Debug, Error)]
+        $(#[$warning_type_docs])*
+        pub enum $warning_type {
+            #[error(transparent)]
+            FormattedWarning(FormattedError),
+
+            #[error(transparent)]
+            BacktracedWarning(BacktracedError),
+        }
+
+        /// Implements the trait for LeoError Codes.
+        impl LeoErrorCode for $warning_type {
+            #[inline(always)]
+            fn exit_code(&self) -> i32 {
+                match self {
+                    Self::FormattedWarning(formatted) => formatted.exit_code(),
+                    Self::BacktracedWarning(backtraced) => backtraced.exit_code()
+                }
+            }
+
+            #[inline(always)]
+            fn error_code(&self) -> String {
+                match self {
+                    Self::FormattedWarning(formatted) => formatted.error_code(),
+                    Self::BacktracedWarning(backtraced) => backtraced.error_code()
+                }
+            }
+
+            #[inline(always)]
+            fn exit_code_mask() -> i32 {
+                $exit_code_mask
+            }
+
+            #[inline(always)]
+            fn error_type() -> String {
+                $warning_code_prefix.to_string()
+            }
+        }
+
+        impl $warning_type {
+            /// The codes and lint names of the warnings, where the codes do not include the exit code mask.
+            pub const WARNINGS: &'static [(i32, &'static str)] = &[$(($codes, stringify!($names)),)*];
+
+            /// The lint names of the warnings.
+            pub const LINT_NAMES: &'static [&'static str] = &[$(stringify!($names),)*];
+
+            /// Returns the prefixed warning code of the warning with the `code`, e.g., `WPAR0370000`.
+            pub fn prefixed_code(code: i32) -> String {
+                format!("W{}{:0>3}{:0>4}", Self::error_type(), Self::code_identifier(), Self::exit_code_mask() + code)
+            }
+
+            /// Returns the name of the lint used to allow this warning.
+            pub fn lint_name(&self) -> &'static str {
+                let exit_code = match self {
+                    Self::FormattedWarning(formatted) => formatted.backtrace.exit_code,
+                    Self::BacktracedWarning(backtraced) => backtraced.exit_code,
+                };
+                Self::WARNINGS
+                    .iter()
+                    .find_map(|(code, name)| (code + Self::exit_code_mask() == exit_code).then(|| *name))
+                    .expect("every warning has a lint name")
+            }
+
+            /// Returns the warning as a machine-readable diagnostic.
+            pub fn diagnostic(&self) -> crate::emitter::Diagnostic {
+                match self {
+                    Self::FormattedWarning(formatted) => formatted.into(),
+                    Self::BacktracedWarning(backtraced) => backtraced.into(),
+                }
+            }
+
+            /// Returns this warning with a secondary span labeled with `message`.
+            /// Backtraced warnings have no spans, and are returned unchanged.
+            pub fn with_label(self, span: &leo_span::Span, message: impl ToString) -> Self {
+                match self {
+                    Self::FormattedWarning(formatted) => Self::FormattedWarning(formatted.with_label(span, message)),
+                    backtraced => backtraced,
+                }
+            }
+
+            /// Returns this warning with an additional `note:` line.
+            pub fn with_note(self, note: impl ToString) -> Self {
+                match self {
+                    Self::FormattedWarning(formatted) => Self::FormattedWarning(formatted.with_note(note)),
+                    Self::BacktracedWarning(backtraced) => Self::BacktracedWarning(backtraced.with_note(note)),
+                }
+            }
+
+            /// Returns this warning with a suggestion to replace the source code at `span` with `replacement`.
+            /// Backtraced warnings have no spans, and are returned unchanged.
+            pub fn with_suggestion(self, span: &leo_span::Span, message: impl ToString, replacement: impl ToString) -> Self {
+                match self {
+                    Self::FormattedWarning(formatted) => {
+                        Self::FormattedWarning(formatted.with_suggestion(span, message, replacement))
+                    }
+                    backtraced => backtraced,
+                }
+            }
+
+            /// Returns this warning with the given `severity`.
+            pub fn with_severity(self, severity: Severity) -> Self {
+                match self {
+                    Self::FormattedWarning(formatted) => Self::FormattedWarning(formatted.with_severity(severity)),
+                    Self::BacktracedWarning(backtraced) => Self::BacktracedWarning(backtraced.with_severity(severity)),
+                }
+            }
+        }
+
+        // Steps over the list of functions.
+        impl $warning_type {
+            create_warnings!(@step $(($(#[$docs])* $formatted_or_backtraced_list, $codes, $names($($arg_names: $arg_types,)*), $messages, $helps),)*);
+        }
+    };
+    // Matches the function if it is a formatted warning.
+    (@step ($(#[$warning_func_docs:meta])* formatted, $code:literal, $warning_name:ident($($arg_names:ident: $arg_types:ty,)*), $message:expr, $help:expr), $(($(#[$docs:meta])* $formatted_or_backtraced_tail:ident, $codes:literal, $names:ident($($tail_arg_names:ident: $tail_arg_types:ty,)*), $messages:expr, $helps:expr),)*) => {
+        // Formatted warnings always takes a span.
+        $(#[$warning_func_docs])*
+        // Expands additional arguments for the warning defining function.
+        pub fn $warning_name($($arg_names: $arg_types,)* span: &leo_span::Span) -> Self {
+            Self::FormattedWarning(
+                FormattedError::new_from_span(
+                    $message,
+                    $help,
+                    $code + Self::exit_code_mask(),
+                    Self::code_identifier(),
+                    Self::error_type(),
+                    span,
+                    // Each function always generates its own backtrace for backtrace clarity to originate from the warning function.
+                    Backtrace::new(),
+                ).with_severity(Severity::Warning)
+            )
+        }
+
+        // Calls on the rest of the functions.
+        create_warnings!(@step $(($(#[$docs])* $formatted_or_backtraced_tail, $codes, $names($($tail_arg_names: $tail_arg_types,)*), $messages, $helps),)*);
+    };
+    // Matches the function if it is a backtraced warning.
+    (@step ($(#[$warning_func_docs:meta])* backtraced, $code:literal, $warning_name:ident($($arg_names:ident: $arg_types:ty,)*), $message:expr, $help:expr), $(($(#[$docs:meta])* $formatted_or_backtraced_tail:ident, $codes:literal, $names:ident($($tail_arg_names:ident: $tail_arg_types:ty,)*), $messages:expr, $helps:expr),)*) => {
+        $(#[$warning_func_docs])*
+        // Expands additional arguments for the warning defining function.
+        pub fn $warning_name($($arg_names: $arg_types,)*) -> Self {
+            Self::BacktracedWarning(
+                BacktracedError::new_from_backtrace(
+                    $message,
+                    $help,
+                    $code + Self::exit_code_mask(),
+                    Self::code_identifier(),
+                    Self::error_type(),
+                    // Each function always generates its own backtrace for backtrace clarity to originate from the warning function.
+                    Backtrace::new(),
+                ).with_severity(Severity::Warning)
+            )
+        }
+
+        // Calls on the rest of the functions.
+        create_warnings!(@step $(($(#[$docs])* $formatted_or_backtraced_tail, $codes, $names($($tail_arg_names: $tail_arg_types,)*), $messages, $helps),)*);
     };
 }
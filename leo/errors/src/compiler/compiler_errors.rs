@@ -30,6 +30,7 @@ create_errors!(
     /// For when the test function has invalid test context.
     @backtraced
     invalid_test_context {
+        code: 0,
         args: (name: impl Display),
         msg: format!("Cannot find input files with context name `{}`", name),
         help: None,
@@ -38,6 +39,7 @@ create_errors!(
     /// For when the compiler can't read a file from the provided path.
     @backtraced
     file_read_error {
+        code: 1,
         args: (path: impl Debug, error: impl ErrorArg),
         msg: format!("Cannot read from the provided file path '{:?}': {}", path, error),
         help: None,
@@ -46,6 +48,7 @@ create_errors!(
      /// For when there is no main function in a Leo program.
     @backtraced
     no_main_function {
+        code: 2,
         args: (),
         msg: "There must be a function named `main`",
         help: None,
@@ -54,6 +57,7 @@ create_errors!(
      /// For when the compiler can't find the test input files with the specified name.
     @backtraced
     no_test_input {
+        code: 3,
         args: (),
         msg: "Failed to find input files for the current test",
         help: None,
@@ -62,6 +66,7 @@ create_errors!(
     /// For when the console formatter expected a left or right brace after a left brace.
     @formatted
     console_fmt_expected_left_or_right_brace {
+        code: 4,
         args: (),
         msg: "Formatter given a {. Expected a { or } after",
         help: None,
@@ -70,6 +75,7 @@ create_errors!(
     /// For when the console formatter expected a right brace after a right brace.
     @formatted
     console_fmt_expected_escaped_right_brace {
+        code: 5,
         args: (),
         msg: "Formatter given a }. Expected a container {} or }}",
         help: None,
@@ -79,6 +85,7 @@ create_errors!(
     /// in a format statement.
     @formatted
     console_container_parameter_length_mismatch {
+        code: 6,
         args: (containers: impl Display, parameters: impl Display),
         msg: format!(
             "Formatter given {} containers and found {} parameters",
@@ -90,6 +97,7 @@ create_errors!(
     /// For when a experssion gadget oepration cannot be enforced due to a SnarkVM syntehsis error.
     @formatted
     cannot_enforce_expression {
+        code: 7,
         args: (operation: impl Display, error: impl ErrorArg),
         msg: format!(
             "the gadget operation `{}` failed due to synthesis error `{:?}`",
@@ -101,6 +109,7 @@ create_errors!(
     /// For when an expression has mismatching types for an operation.
     @formatted
     cannot_evaluate_expression {
+        code: 8,
         args: (operation: impl Display),
         msg: format!("Mismatched types found for operation `{}`", operation),
         help: None,
@@ -109,6 +118,7 @@ create_errors!(
     /// For when the expected circuit member could not be found.
     @formatted
     expected_circuit_member {
+        code: 9,
         args: (expected: impl Display),
         msg: format!("expected circuit member `{}`, not found", expected),
         help: None,
@@ -117,6 +127,7 @@ create_errors!(
     /// For when an array index does not resolve to an integer type.
     @formatted
     invalid_index_expression {
+        code: 10,
         args: (actual: impl Display),
         msg: format!("index must resolve to an integer, found `{}`", actual),
         help: None,
@@ -125,6 +136,7 @@ create_errors!(
     /// For when the input variable type mismatches the declared function input type.
     @formatted
     input_variable_type_mismatch {
+        code: 11,
         args: (expected: impl Display, actual: impl Display, variable: impl Display),
         msg: format!(
             "Expected input variable `{}` to be type `{}`, found type `{}`",
@@ -137,6 +149,7 @@ create_errors!(
     /// in the input file.
     @formatted
     invalid_function_input_array {
+        code: 12,
         args: (actual: impl Display),
         msg: format!("Expected function input array, found `{}`", actual),
         help: None,
@@ -145,6 +158,7 @@ create_errors!(
     /// For when the declared function input variable was expected to be an array with differing dimensions.
     @formatted
     invalid_input_array_dimensions {
+        code: 13,
         args: (expected: impl Display, actual: impl Display),
         msg: format!(
             "Input array dimensions mismatch expected {}, found array dimensions {}",
@@ -157,6 +171,7 @@ create_errors!(
     /// with a different number of arguments.
     @formatted
     input_tuple_size_mismatch {
+        code: 14,
         args: (expected: impl Display, actual: impl Display),
         msg: format!(
             "Input tuple size mismatch expected {}, found tuple with length {}",
@@ -169,6 +184,7 @@ create_errors!(
     /// in the input file.
     @formatted
     invalid_function_input_tuple {
+        code: 15,
         args: (actual: impl Display),
         msg: format!("Expected function input tuple, found `{}`", actual),
         help: None,
@@ -178,6 +194,7 @@ create_errors!(
     /// in the input file.
     @formatted
     function_input_not_found {
+        code: 16,
         args: (function: impl Display, expected: impl Display),
         msg: format!("function `{}` input {} not found", function, expected),
         help: None,
@@ -186,6 +203,7 @@ create_errors!(
     /// For when the declared function input register was not defined
     @formatted
     function_missing_input_register {
+        code: 17,
         args: (expected: impl Display),
         msg: format!("missing input '{}' for registers", expected),
         help: None,
@@ -195,6 +213,7 @@ create_errors!(
     /// in the input file.
     @formatted
     double_input_declaration {
+        code: 18,
         args: (input_name: impl Display),
         msg: format!("Input variable {} declared twice", input_name),
         help: None,
@@ -203,6 +222,7 @@ create_errors!(
     /// For when the input file does not define enough registers.
     @formatted
     output_not_enough_registers {
+        code: 19,
         args: (),
         msg: "number of input registers must be greater than or equal to output registers",
         help: None,
@@ -211,6 +231,7 @@ create_errors!(
     /// For when the input file register types do not match the output types being generated.
     @formatted
     output_mismatched_types {
+        code: 20,
         args: (left: impl Display, right: impl Display),
         msg: format!(
             "Mismatched types. Expected register output type `{}`, found type `{}`.",
@@ -222,6 +243,7 @@ create_errors!(
     /// For when a circuit was passed as input
     @formatted
     circuit_as_input {
+        code: 21,
         args: (),
         msg: "input circuits not supported for input",
         help: None,
@@ -230,6 +252,7 @@ create_errors!(
     /// For when there's an IO error with the output file.
     @backtraced
     output_file_io_error {
+        code: 22,
         args: (error: impl ErrorArg),
         msg: error,
         help: None,
@@ -238,6 +261,7 @@ create_errors!(
     /// For when the output file cannot be removed.
     @backtraced
     output_file_cannot_remove {
+        code: 23,
         args: (path: impl Debug),
         msg: format!("Cannot remove the provided ouput file - {:?}", path),
         help: None,
@@ -246,6 +270,7 @@ create_errors!(
     /// For when a function returns multiple times.
     @formatted
     statement_multiple_returns {
+        code: 24,
         args: (),
         msg: "This function returns multiple times and produces unreachable circuits with undefined behavior.",
         help: None,
@@ -254,6 +279,7 @@ create_errors!(
     /// For when a function expects a return type and has no valid return statements.
     @formatted
     statement_no_returns {
+        code: 25,
         args: (expected: impl Display),
         msg: format!(
             "function expected `{}` return type but no valid branches returned a result",
@@ -265,6 +291,7 @@ create_errors!(
     /// For when SnarkVM fails to conditionally select between values for a gadget.
     @formatted
     statement_select_fail {
+        code: 26,
         args: (first: impl Display, second: impl Display),
         msg: format!(
             "Conditional select gadget failed to select between `{}` or `{}`",
@@ -276,6 +303,7 @@ create_errors!(
     /// For when there is an invalid address value.
     @formatted
     address_value_invalid_address {
+        code: 27,
         args: (actual: impl Display),
         msg: format!("expected address input type, found `{}`", actual),
         help: None,
@@ -284,6 +312,7 @@ create_errors!(
     /// For when there is an integer type mismatch, one kind was expected but another was received.
     @formatted
     integer_value_integer_type_mismatch {
+        code: 28,
         args: (expected: impl Display, received: impl Display),
         msg: format!("expected data type `{}`, found `{}`", expected, received),
         help: None,
@@ -292,6 +321,7 @@ create_errors!(
     /// For when .len() method is used on non-array values/variables.
     @formatted
     lengthof_can_only_be_used_on_arrays {
+        code: 29,
         args: (),
         msg: "len() can only be called on an array value",
         help: None,
@@ -300,6 +330,7 @@ create_errors!(
     /// For when a circuit static const access was execpted.
     @formatted
     expected_circuit_static_const_access {
+        code: 30,
         args: (),
         msg: "A circuit static const access was expected",
         help: None,
@@ -308,8 +339,18 @@ create_errors!(
     /// For when a user tries to assign to a circuit static member.
     @formatted
     illegal_static_member_assignment {
+        code: 31,
         args: (member: impl Display),
         msg: format!("Tried to assign to static member `{}`", member),
         help: None,
     }
+
+    /// For when the AST snapshot of a compiler phase is not in the outputs directory.
+    @backtraced
+    ast_snapshot_not_found {
+        code: 32,
+        args: (phase: impl Display, directory: impl Debug),
+        msg: format!("Cannot find an AST snapshot of the phase `{}` in {:?}", phase, directory),
+        help: Some("Run `leo build` to write the AST snapshots of the compiler phases.".to_string()),
+    }
 );
@@ -0,0 +1,174 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::Emitter;
+use crate::{BacktracedError, FormattedError, Label, LeoError, LeoWarning, Severity, Suggestion};
+
+use leo_span::SpanLocation;
+
+use serde::{Deserialize, Serialize};
+use std::io::Write;
+
+/// The location of a diagnostic in a Leo file.
+/// Lines and columns start at 1, and `col_stop` is exclusive.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DiagnosticSpan {
+    /// The path to the Leo file.
+    pub file: String,
+    /// The line number where the diagnostic starts.
+    pub line_start: usize,
+    /// The line number where the diagnostic stops.
+    pub line_stop: usize,
+    /// The column number where the diagnostic starts.
+    pub col_start: usize,
+    /// The column number where the diagnostic stops.
+    pub col_stop: usize,
+}
+
+impl From<&SpanLocation> for DiagnosticSpan {
+    fn from(location: &SpanLocation) -> Self {
+        Self {
+            file: location.path.to_string(),
+            line_start: location.line_start,
+            line_stop: location.line_stop,
+            col_start: location.col_start,
+            col_stop: location.col_stop,
+        }
+    }
+}
+
+/// A secondary span of a diagnostic, with a message explaining its relation to the diagnostic.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DiagnosticLabel {
+    /// The location of the label.
+    pub span: DiagnosticSpan,
+    /// The message of the label, e.g., `first defined here`.
+    pub message: String,
+}
+
+impl From<&Label> for DiagnosticLabel {
+    fn from(label: &Label) -> Self {
+        Self {
+            span: (&label.location).into(),
+            message: label.message.clone(),
+        }
+    }
+}
+
+/// A suggested fix of a diagnostic, replacing the source code at a span.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DiagnosticSuggestion {
+    /// The location of the source code to replace.
+    pub span: DiagnosticSpan,
+    /// The message describing the fix.
+    pub message: String,
+    /// The source code replacing the span.
+    pub replacement: String,
+}
+
+impl From<&Suggestion> for DiagnosticSuggestion {
+    fn from(suggestion: &Suggestion) -> Self {
+        Self {
+            span: (&suggestion.location).into(),
+            message: suggestion.message.clone(),
+            replacement: suggestion.replacement.clone(),
+        }
+    }
+}
+
+/// A machine-readable error or warning, for editor integrations and CI annotators.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Diagnostic {
+    /// Whether this is an error, a warning, or a warning treated as an error.
+    pub severity: Severity,
+    /// The prefixed error code, e.g., `EPAR0370005`.
+    pub error_code: String,
+    /// The exit code Leo terminates with because of this diagnostic, if it is an error.
+    pub exit_code: i32,
+    /// The message, without the error code or location.
+    pub message: String,
+    /// The help message if it exists.
+    pub help: Option<String>,
+    /// The location of the diagnostic, if it has one.
+    pub span: Option<DiagnosticSpan>,
+    /// Secondary spans related to the diagnostic.
+    pub labels: Vec<DiagnosticLabel>,
+    /// Additional notes.
+    pub notes: Vec<String>,
+    /// Suggested fixes.
+    pub suggestions: Vec<DiagnosticSuggestion>,
+}
+
+impl From<&BacktracedError> for Diagnostic {
+    fn from(error: &BacktracedError) -> Self {
+        Self {
+            severity: error.severity,
+            error_code: error.error_code(),
+            exit_code: error.exit_code(),
+            message: error.message.clone(),
+            help: error.help.clone(),
+            span: None,
+            labels: Vec::new(),
+            notes: error.notes.clone(),
+            suggestions: Vec::new(),
+        }
+    }
+}
+
+impl From<&FormattedError> for Diagnostic {
+    fn from(error: &FormattedError) -> Self {
+        Self {
+            span: Some((&error.location).into()),
+            labels: error.labels.iter().map(Into::into).collect(),
+            suggestions: error.suggestions.iter().map(Into::into).collect(),
+            ..(&error.backtrace).into()
+        }
+    }
+}
+
+/// An `Emitter` writing each diagnostic as a JSON object on its own line, e.g., for `--message-format=json`.
+pub struct JsonEmitter<W: Write> {
+    /// The sink the JSON lines are written to.
+    writer: W,
+}
+
+impl<W: Write> JsonEmitter<W> {
+    /// Returns a new JSON emitter writing to `writer`.
+    pub fn new(writer: W) -> Self {
+        Self { writer }
+    }
+
+    /// Extracts the underlying writer.
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+
+    /// Writes `diagnostic` as a single line of JSON.
+    fn emit(&mut self, diagnostic: Diagnostic) {
+        let json = serde_json::to_string(&diagnostic).expect("failed to serialize a diagnostic");
+        writeln!(self.writer, "{}", json).expect("failed to write a diagnostic");
+    }
+}
+
+impl<W: Write> Emitter for JsonEmitter<W> {
+    fn emit_err(&mut self, err: LeoError) {
+        self.emit(err.diagnostic());
+    }
+
+    fn emit_warning(&mut self, warning: LeoWarning) {
+        self.emit(warning.diagnostic());
+    }
+}
@@ -14,16 +14,24 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use super::LeoError;
+use super::{LeoError, LeoWarning};
 use core::default::Default;
 use core::fmt;
 use std::cell::RefCell;
 use std::rc::Rc;
+use std::str::FromStr;
 
-/// Types that are sinks for compiler errors.
+/// Contains the emitter of machine-readable diagnostics.
+mod json;
+pub use json::*;
+
+/// Types that are sinks for compiler errors and warnings.
 pub trait Emitter {
     /// Emit the error `err`.
     fn emit_err(&mut self, err: LeoError);
+
+    /// Emit the warning `warning`.
+    fn emit_warning(&mut self, warning: LeoWarning);
 }
 
 /// A trivial `Emitter` using the standard error.
@@ -33,6 +41,54 @@ impl Emitter for StderrEmitter {
     fn emit_err(&mut self, err: LeoError) {
         eprintln!("{}", err);
     }
+
+    fn emit_warning(&mut self, warning: LeoWarning) {
+        eprintln!("{}", warning);
+    }
+}
+
+/// How errors and warnings are presented, e.g., through `--message-format`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MessageFormat {
+    /// Colored text, see [`StderrEmitter`].
+    Human,
+    /// A JSON object per line on the standard output, see [`JsonEmitter`].
+    Json,
+}
+
+impl Default for MessageFormat {
+    fn default() -> Self {
+        Self::Human
+    }
+}
+
+impl MessageFormat {
+    /// The names accepted by [`MessageFormat::from_str`].
+    pub const NAMES: &'static [&'static str] = &["human", "json"];
+
+    /// Returns an emitter presenting errors and warnings in this format.
+    pub fn emitter(self) -> Box<dyn Emitter> {
+        match self {
+            Self::Human => Box::new(StderrEmitter),
+            Self::Json => Box::new(JsonEmitter::new(std::io::stdout())),
+        }
+    }
+}
+
+impl FromStr for MessageFormat {
+    type Err = String;
+
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        match name {
+            "human" => Ok(Self::Human),
+            "json" => Ok(Self::Json),
+            _ => Err(format!(
+                "unknown message format `{}`, expected one of: {}",
+                name,
+                Self::NAMES.join(", ")
+            )),
+        }
+    }
 }
 
 /// A buffer of `T`s.
@@ -73,26 +129,38 @@ impl<T: fmt::Display> fmt::Display for Buffer<T> {
 /// A buffer of `LeoError`s.
 pub type ErrBuffer = Buffer<LeoError>;
 
+/// A buffer of `LeoWarning`s.
+pub type WarningBuffer = Buffer<LeoWarning>;
+
 /// An `Emitter` that collects into a list.
 #[derive(Default, Clone)]
-pub struct BufferEmitter(Rc<RefCell<ErrBuffer>>);
+pub struct BufferEmitter(Rc<RefCell<ErrBuffer>>, Rc<RefCell<WarningBuffer>>);
 
 impl BufferEmitter {
     /// Returns a new buffered emitter.
     pub fn new() -> Self {
-        BufferEmitter(<_>::default())
+        BufferEmitter(<_>::default(), <_>::default())
     }
 
     /// Extracts all the errors collected in this emitter.
     pub fn extract(&self) -> ErrBuffer {
         self.0.take()
     }
+
+    /// Extracts all the warnings collected in this emitter.
+    pub fn extract_warnings(&self) -> WarningBuffer {
+        self.1.take()
+    }
 }
 
 impl Emitter for BufferEmitter {
     fn emit_err(&mut self, err: LeoError) {
         self.0.borrow_mut().push(err);
     }
+
+    fn emit_warning(&mut self, warning: LeoWarning) {
+        self.1.borrow_mut().push(warning);
+    }
 }
 
 /// Contains the actual data for `Handler`.
@@ -100,18 +168,56 @@ impl Emitter for BufferEmitter {
 struct HandlerInner {
     /// Number of errors emitted thus far.
     count: usize,
+    /// The exit code of the last error emitted thus far.
+    last_exit_code: Option<i32>,
+    /// Number of warnings emitted thus far, excluding denied warnings.
+    warn_count: usize,
+    /// Whether warnings are emitted as errors.
+    deny_warnings: bool,
+    /// The lints allowed in each of the current scopes, innermost last.
+    allowed_lints: Vec<Vec<String>>,
     /// The sink through which errors will be emitted.
     emitter: Box<dyn Emitter>,
 }
 
 impl HandlerInner {
-    /// Emit the error `err`.
+    /// Emit the error `err`, unless it stands for errors that were already emitted.
     fn emit_err(&mut self, err: LeoError) {
+        if let LeoError::LastErrorCode(_) = err {
+            return;
+        }
+
         self.count = self.count.saturating_add(1);
+        self.last_exit_code = Some(err.exit_code());
         self.emitter.emit_err(err);
     }
+
+    /// Emit the warning `warning`, unless its lint is allowed.
+    fn emit_warning(&mut self, warning: LeoWarning) {
+        if self.is_lint_allowed(warning.lint_name()) {
+            return;
+        }
+
+        if self.deny_warnings {
+            self.emit_err(warning.deny());
+        } else {
+            self.warn_count = self.warn_count.saturating_add(1);
+            self.emitter.emit_warning(warning);
+        }
+    }
+
+    /// Is the lint `name` allowed in the current scope?
+    fn is_lint_allowed(&self, name: &str) -> bool {
+        self.allowed_lints
+            .iter()
+            .flatten()
+            .any(|allowed| allowed == name || allowed == ALL_LINTS)
+    }
 }
 
+/// The lint name that allows every warning, i.e., `@allow(warnings)`.
+pub const ALL_LINTS: &str = "warnings";
+
 /// A handler deals with errors and other compiler output.
 pub struct Handler {
     /// The inner handler.
@@ -128,10 +234,23 @@ impl Default for Handler {
 impl Handler {
     /// Construct a `Handler` using the given `emitter`.
     pub fn new(emitter: Box<dyn Emitter>) -> Self {
-        let inner = RefCell::new(HandlerInner { count: 0, emitter });
+        let inner = RefCell::new(HandlerInner {
+            count: 0,
+            last_exit_code: None,
+            warn_count: 0,
+            deny_warnings: false,
+            allowed_lints: Vec::new(),
+            emitter,
+        });
         Self { inner }
     }
 
+    /// Emit warnings as errors if `deny` is set, e.g., for `--deny-warnings`.
+    pub fn deny_warnings(self, deny: bool) -> Self {
+        self.inner.borrow_mut().deny_warnings = deny;
+        self
+    }
+
     /// Construct a `Handler` that will append to `buf`.
     pub fn new_with_buf() -> (Self, BufferEmitter) {
         let buf = BufferEmitter::default();
@@ -147,16 +266,28 @@ impl Handler {
     }
 
     /// Emit the error `err`.
+    /// A [`LeoError::LastErrorCode`] is not emitted, as it stands for errors that were already emitted.
     pub fn emit_err(&self, err: LeoError) {
         self.inner.borrow_mut().emit_err(err);
     }
 
-    /// Emits the error `err`.
-    /// This will immediately abort compilation.
-    pub fn fatal_err(&self, err: LeoError) -> ! {
-        let code = err.exit_code();
-        self.emit_err(err);
-        std::process::exit(code);
+    /// Emit the warning `warning`, unless its lint is allowed in the current scope.
+    /// Warnings are emitted as errors if they are denied.
+    pub fn emit_warning(&self, warning: LeoWarning) {
+        self.inner.borrow_mut().emit_warning(warning);
+    }
+
+    /// Runs `logic` with the lints `names` allowed, e.g., within a function annotated with `@allow(...)`.
+    pub fn with_allowed_lints<T>(&self, names: Vec<String>, logic: impl FnOnce() -> T) -> T {
+        self.inner.borrow_mut().allowed_lints.push(names);
+        let result = logic();
+        self.inner.borrow_mut().allowed_lints.pop();
+        result
+    }
+
+    /// Is the lint `name` allowed in the current scope?
+    pub fn is_lint_allowed(&self, name: &str) -> bool {
+        self.inner.borrow().is_lint_allowed(name)
     }
 
     /// The number of errors thus far.
@@ -169,6 +300,21 @@ impl Handler {
         self.err_count() > 0
     }
 
+    /// The number of warnings thus far, excluding allowed and denied warnings.
+    pub fn warning_count(&self) -> usize {
+        self.inner.borrow().warn_count
+    }
+
+    /// Returns an error standing for the errors emitted thus far, if there were any.
+    /// Passes call this at the end of their stage, so they report every error
+    /// of the stage instead of only the first, and later stages do not run on a broken AST.
+    pub fn last_err(&self) -> Result<(), LeoError> {
+        match self.inner.borrow().last_exit_code {
+            Some(code) => Err(LeoError::LastErrorCode(code)),
+            None => Ok(()),
+        }
+    }
+
     /// Extend handler with `error` given `res = Err(error)`.
     #[allow(clippy::result_unit_err)]
     pub fn extend_if_error<T>(&self, res: Result<T, LeoError>) -> Result<T, ()> {
@@ -186,8 +332,8 @@ impl Handler {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::ParserError;
-    use leo_span::Span;
+    use crate::{CliError, ImportError, LeoErrorCode, ParserError, ParserWarning, Severity};
+    use leo_span::{symbol::create_session_if_not_set_then, BytePos, Span};
 
     #[test]
     fn fresh_no_errors() {
@@ -222,4 +368,149 @@ mod tests {
 
         let () = Handler::with(|_| Ok(())).unwrap();
     }
+
+    #[test]
+    fn last_err() {
+        let (handler, buf) = Handler::new_with_buf();
+        let s = Span::default();
+        assert!(handler.last_err().is_ok());
+
+        handler.emit_err(ParserError::invalid_import_list(&s).into());
+        handler.emit_err(ImportError::recursive_imports("foo", &s).into());
+        let err = handler.last_err().unwrap_err();
+        let exit_code = ImportError::recursive_imports("foo", &s).exit_code();
+        assert_eq!(err.exit_code(), exit_code);
+        assert_eq!(
+            err.to_string(),
+            format!("aborting due to previous errors, the last with exit code {}", exit_code)
+        );
+
+        // The error standing for the emitted ones is not emitted again.
+        let res: Result<(), ()> = handler.extend_if_error(Err(err));
+        assert!(res.is_err());
+        assert_eq!(handler.err_count(), 2);
+        assert_eq!(buf.extract().into_inner().len(), 2);
+    }
+
+    #[test]
+    fn warnings_are_not_errors() {
+        let (handler, buf) = Handler::new_with_buf();
+        let s = Span::default();
+
+        handler.emit_warning(ParserWarning::unknown_annotation("foo", &s).into());
+        assert_eq!(handler.warning_count(), 1);
+        assert!(!handler.had_errors());
+
+        let warnings = buf.extract_warnings().into_inner();
+        assert_eq!(warnings[0].lint_name(), "unknown_annotation");
+        assert!(warnings[0].error_code().starts_with('W'));
+    }
+
+    #[test]
+    fn allowed_warnings() {
+        let (handler, buf) = Handler::new_with_buf();
+        let s = Span::default();
+
+        handler.with_allowed_lints(vec!["unknown_annotation".to_string()], || {
+            handler.emit_warning(ParserWarning::unknown_annotation("foo", &s).into());
+            handler.emit_warning(ParserWarning::unknown_lint("foo", &s).into());
+        });
+        handler.with_allowed_lints(vec![ALL_LINTS.to_string()], || {
+            handler.emit_warning(ParserWarning::unknown_lint("foo", &s).into());
+        });
+
+        // Lints are only allowed within the scope.
+        handler.emit_warning(ParserWarning::unknown_annotation("foo", &s).into());
+
+        let lints: Vec<_> = buf
+            .extract_warnings()
+            .into_inner()
+            .iter()
+            .map(|warning| warning.lint_name())
+            .collect();
+        assert_eq!(lints, vec!["unknown_lint", "unknown_annotation"]);
+    }
+
+    #[test]
+    fn denied_warnings() {
+        let (handler, buf) = Handler::new_with_buf();
+        let handler = handler.deny_warnings(true);
+
+        handler.emit_warning(ParserWarning::unknown_annotation("foo", &Span::default()).into());
+        assert_eq!(handler.warning_count(), 0);
+        assert_eq!(handler.err_count(), 1);
+
+        // Denied warnings keep their warning code.
+        let errors = buf.extract().into_inner();
+        assert!(errors[0].error_code().starts_with('W'));
+    }
+
+    #[test]
+    fn json_diagnostics() {
+        create_session_if_not_set_then(|s| {
+            let file = s.source_map.new_source("function main() {\n    let x", "main.leo");
+            let span = Span::new(
+                file.start_pos + BytePos::from_usize(22),
+                file.start_pos + BytePos::from_usize(25),
+            );
+
+            let mut emitter = JsonEmitter::new(Vec::new());
+            emitter.emit_err(ParserError::unexpected_eof(&span).into());
+            emitter.emit_warning(ParserWarning::unknown_annotation("foo", &span).into());
+            emitter.emit_err(CliError::cli_io_error(std::io::Error::from(std::io::ErrorKind::Other)).into());
+
+            // Each diagnostic is a JSON object on its own line.
+            let output = String::from_utf8(emitter.into_inner()).unwrap();
+            let diagnostics: Vec<Diagnostic> = output.lines().map(|l| serde_json::from_str(l).unwrap()).collect();
+
+            assert_eq!(diagnostics[0].severity, Severity::Error);
+            assert_eq!(diagnostics[0].error_code, "EPAR0370003");
+            assert_eq!(diagnostics[0].message, "unexpected EOF");
+            assert_eq!(
+                diagnostics[0].span,
+                Some(DiagnosticSpan {
+                    file: "main.leo".to_string(),
+                    line_start: 2,
+                    line_stop: 2,
+                    col_start: 5,
+                    col_stop: 8,
+                })
+            );
+            assert_eq!(diagnostics[1].severity, Severity::Warning);
+            assert!(diagnostics[1].error_code.starts_with('W'));
+            assert_eq!(diagnostics[2].span, None);
+        })
+    }
+
+    #[test]
+    fn labels_notes_and_suggestions() {
+        create_session_if_not_set_then(|s| {
+            let main = s.source_map.new_source("import foo.*;", "main.leo");
+            let foo = s.source_map.new_source("import main.*;", "foo.leo");
+            let span = |start: BytePos, lo: usize, hi: usize| {
+                Span::new(start + BytePos::from_usize(lo), start + BytePos::from_usize(hi))
+            };
+
+            let err = ImportError::recursive_imports("main", &span(foo.start_pos, 7, 11))
+                .with_label(&span(main.start_pos, 7, 10), "`foo` is imported here")
+                .with_note("packages cannot import each other")
+                .with_suggestion(&span(foo.start_pos, 7, 11), "import another package", "bar");
+
+            // Labels in other files point to their file, suggestions are shown applied to their line.
+            let text = err.to_string();
+            assert!(text.contains("--> foo.leo:1:8"));
+            assert!(text
+                .contains("::: main.leo:1:8\n     |\n   1 | import foo.*;\n     |        --- `foo` is imported here"));
+            assert!(text.contains("= note: packages cannot import each other"));
+            assert!(
+                text.contains("= suggestion: import another package\n     |\n   1 | import bar.*;\n     |        ~~~")
+            );
+
+            let diagnostic = LeoError::from(err).diagnostic();
+            assert_eq!(diagnostic.labels.len(), 1);
+            assert_eq!(diagnostic.labels[0].span.file, "main.leo");
+            assert_eq!(diagnostic.notes, vec!["packages cannot import each other".to_string()]);
+            assert_eq!(diagnostic.suggestions[0].replacement, "bar");
+        })
+    }
 }
@@ -0,0 +1,292 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The error index in `ERROR_INDEX.md` records the code of every error and warning.
+//! The test here fails when the index is out of date, or when the code of an existing error changes.
+
+use crate::{
+    AstError, CliError, CompilerError, ImportError, InputError, LeoErrorCode, LintWarning, PackageError, ParserError,
+    ParserWarning, StateError,
+};
+
+use std::{env, fs};
+
+/// The path of the error index.
+const INDEX_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/ERROR_INDEX.md");
+
+/// The environment variable to set to write the updated error index.
+const UPDATE_VAR: &str = "UPDATE_LEO_ERROR_INDEX";
+
+/// The text of the error index before its sections.
+const HEADER: &str = "# Leo Error Index
+
+This index lists the code of every error and warning of Leo, and is kept in sync with their definitions by the `error_index` test of `leo-errors`.
+Codes are stable: once released, a code keeps its meaning, and the code of a removed error stays here as `retired`, so that it is never reused.
+To add the codes of new errors or retire the codes of removed ones, run `UPDATE_LEO_ERROR_INDEX=1 cargo test -p leo-errors error_index`.
+";
+
+/// A row of the error index.
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct Entry {
+    /// The prefixed code, e.g., `EPAR0370005`.
+    code: String,
+    /// The name of the error or warning.
+    name: String,
+    /// Whether the error or warning was removed.
+    retired: bool,
+}
+
+impl Entry {
+    /// Returns the entry of the defined error or warning `name` with the prefixed `code`.
+    fn new(code: String, name: &str) -> Self {
+        Self {
+            code,
+            name: name.to_string(),
+            retired: false,
+        }
+    }
+}
+
+/// A section of the error index, with the entries of one error or warning type.
+#[derive(Debug, PartialEq, Eq)]
+struct Section {
+    /// The title, e.g., `Parser Errors: Error Code Range 370_000 - 370_999`.
+    title: String,
+    /// The entries, ordered by code.
+    entries: Vec<Entry>,
+}
+
+/// Returns the title of the section of the errors, or `warnings`, of `kind` with the exit code `mask`.
+fn title(kind: &str, warnings: bool, mask: i32) -> String {
+    let range = 370 + mask / 1000;
+    let (plural, singular) = if warnings {
+        ("Warnings", "Warning")
+    } else {
+        ("Errors", "Error")
+    };
+    format!(
+        "{} {}: {} Code Range {}_000 - {}_999",
+        kind, plural, singular, range, range
+    )
+}
+
+/// Returns the sections of the errors and warnings defined in this crate.
+fn defined_sections() -> Vec<Section> {
+    macro_rules! section {
+        ($kind:expr, $type_:ident, $list:ident, $warnings:expr) => {
+            Section {
+                title: title($kind, $warnings, $type_::exit_code_mask()),
+                entries: $type_::$list
+                    .iter()
+                    .map(|(code, name)| Entry::new($type_::prefixed_code(*code), name))
+                    .collect(),
+            }
+        };
+    }
+
+    vec![
+        section!("Parser", ParserError, ERRORS, false),
+        section!("Parser", ParserWarning, WARNINGS, true),
+        section!("State", StateError, ERRORS, false),
+        section!("AST", AstError, ERRORS, false),
+        section!("Import", ImportError, ERRORS, false),
+        section!("Package", PackageError, ERRORS, false),
+        section!("Compiler", CompilerError, ERRORS, false),
+        section!("CLI", CliError, ERRORS, false),
+        section!("Input", InputError, ERRORS, false),
+        section!("Lint", LintWarning, WARNINGS, true),
+    ]
+}
+
+/// Parses the sections of the error `index`.
+fn parse(index: &str) -> Vec<Section> {
+    let mut sections: Vec<Section> = Vec::new();
+    for line in index.lines() {
+        if let Some(title) = line.strip_prefix("## ") {
+            sections.push(Section {
+                title: title.to_string(),
+                entries: Vec::new(),
+            });
+        } else if let Some(row) = line.strip_prefix("| ").and_then(|row| row.strip_suffix(" |")) {
+            // Skips the header of the table, whose status column is neither of these.
+            if let [code, name, status @ ("active" | "retired")] = row.split(" | ").collect::<Vec<_>>()[..] {
+                let section = sections
+                    .last_mut()
+                    .expect("an entry of the error index is outside of a section");
+                section.entries.push(Entry {
+                    code: code.to_string(),
+                    name: name.to_string(),
+                    retired: status == "retired",
+                });
+            }
+        }
+    }
+    sections
+}
+
+/// Returns the text of the error index with the `sections`.
+fn render(sections: &[Section]) -> String {
+    let mut index = HEADER.to_string();
+    for section in sections {
+        index.push_str(&format!(
+            "\n## {}\n\n| Code | Name | Status |\n| ---- | ---- | ------ |\n",
+            section.title
+        ));
+        for entry in &section.entries {
+            let status = if entry.retired { "retired" } else { "active" };
+            index.push_str(&format!("| {} | {} | {} |\n", entry.code, entry.name, status));
+        }
+    }
+    index
+}
+
+/// Returns the `recorded` section updated with the `defined` entries,
+/// and pushes every change to a recorded code to `changes`.
+fn merge_section(title: String, recorded: Vec<Entry>, defined: Vec<Entry>, changes: &mut Vec<String>) -> Section {
+    for (index, entry) in defined.iter().enumerate() {
+        if defined[..index].iter().any(|other| other.code == entry.code) {
+            changes.push(format!("`{}` has the code {} of another error", entry.name, entry.code));
+        }
+        for old in &recorded {
+            if old.retired && old.code == entry.code {
+                changes.push(format!(
+                    "`{}` reuses the code {} of the retired `{}`",
+                    entry.name, entry.code, old.name
+                ));
+            } else if !old.retired && old.name == entry.name && old.code != entry.code {
+                changes.push(format!(
+                    "the code of `{}` changed from {} to {}",
+                    entry.name, old.code, entry.code
+                ));
+            } else if !old.retired && old.code == entry.code && old.name != entry.name {
+                changes.push(format!(
+                    "`{}` took the code {} of `{}`",
+                    entry.name, entry.code, old.name
+                ));
+            }
+        }
+    }
+
+    // Recorded codes that are no longer defined are retired.
+    let mut entries: Vec<_> = recorded
+        .into_iter()
+        .filter(|old| !defined.iter().any(|entry| entry.code == old.code))
+        .map(|old| Entry { retired: true, ..old })
+        .collect();
+    entries.extend(defined);
+    entries.sort_by(|a, b| a.code.cmp(&b.code));
+
+    Section { title, entries }
+}
+
+/// Returns the `recorded` sections updated with the `defined` ones,
+/// or the changes to recorded codes, which are never allowed.
+fn merge(recorded: Vec<Section>, mut defined: Vec<Section>) -> Result<Vec<Section>, Vec<String>> {
+    let mut changes = Vec::new();
+    let mut sections = Vec::new();
+
+    for section in recorded {
+        let entries = match defined.iter().position(|defined| defined.title == section.title) {
+            Some(position) => defined.remove(position).entries,
+            // The type was removed, so all of its codes are retired.
+            None => Vec::new(),
+        };
+        sections.push(merge_section(section.title, section.entries, entries, &mut changes));
+    }
+    // New types are added at the end of the index.
+    for section in defined {
+        sections.push(merge_section(section.title, Vec::new(), section.entries, &mut changes));
+    }
+
+    if changes.is_empty() {
+        Ok(sections)
+    } else {
+        Err(changes)
+    }
+}
+
+#[test]
+fn error_index() {
+    let recorded = fs::read_to_string(INDEX_PATH).expect("failed to read the error index");
+    let sections = merge(parse(&recorded), defined_sections())
+        .unwrap_or_else(|changes| panic!("error codes must not change:\n{}", changes.join("\n")));
+
+    let index = render(&sections);
+    if index == recorded {
+        return;
+    }
+    if env::var(UPDATE_VAR).is_ok() {
+        fs::write(INDEX_PATH, index).expect("failed to write the error index");
+    } else {
+        panic!(
+            "the error index is out of date, run `{}=1 cargo test -p leo-errors error_index` to update it",
+            UPDATE_VAR
+        );
+    }
+}
+
+#[test]
+fn codes_are_stable() {
+    let section = |entries: Vec<Entry>| {
+        vec![Section {
+            title: title("Parser", false, 0),
+            entries,
+        }]
+    };
+    let retired = |code: &str, name: &str| Entry {
+        retired: true,
+        ..Entry::new(code.to_string(), name)
+    };
+    let recorded = || {
+        section(vec![
+            Entry::new("EPAR0370000".to_string(), "foo"),
+            retired("EPAR0370001", "bar"),
+        ])
+    };
+
+    // Removed errors are retired, and new errors are added.
+    let sections = merge(recorded(), section(vec![Entry::new("EPAR0370002".to_string(), "baz")])).unwrap();
+    assert_eq!(
+        sections,
+        section(vec![
+            retired("EPAR0370000", "foo"),
+            retired("EPAR0370001", "bar"),
+            Entry::new("EPAR0370002".to_string(), "baz"),
+        ])
+    );
+    assert_eq!(parse(&render(&sections)), sections);
+
+    // Codes of existing errors cannot change, and retired codes cannot be reused.
+    let changes = merge(
+        recorded(),
+        section(vec![
+            Entry::new("EPAR0370002".to_string(), "foo"),
+            Entry::new("EPAR0370001".to_string(), "baz"),
+        ]),
+    )
+    .unwrap_err();
+    assert_eq!(
+        changes,
+        vec![
+            "the code of `foo` changed from EPAR0370000 to EPAR0370002",
+            "`baz` reuses the code EPAR0370001 of the retired `bar`",
+        ]
+    );
+
+    // The code of an existing error cannot be given to another error.
+    let changes = merge(recorded(), section(vec![Entry::new("EPAR0370000".to_string(), "qux")])).unwrap_err();
+    assert_eq!(changes, vec!["`qux` took the code EPAR0370000 of `foo`"]);
+}
@@ -30,6 +30,7 @@ create_errors!(
     /// For when an imported package has the same name as an imported core_package.
     @formatted
     conflicting_imports {
+        code: 0,
         args: (name: impl Display),
         msg: format!("conflicting imports found for `{}`.", name),
         help: None,
@@ -38,6 +39,7 @@ create_errors!(
     /// For when imports are recursive.
     @formatted
     recursive_imports {
+        code: 1,
         args: (package: impl Display),
         msg: format!("recursive imports for `{}`.", package),
         help: None,
@@ -46,6 +48,7 @@ create_errors!(
     /// For when the crate failed to convert a file path into an os string.
     @formatted
     convert_os_string {
+        code: 2,
         args: (),
         msg: "Failed to convert file string name, maybe an illegal character?",
         help: None,
@@ -54,6 +57,7 @@ create_errors!(
     /// For when the crate failed to find the directory of the current file.
     @formatted
     current_directory_error {
+        code: 3,
         args: (error: impl ErrorArg),
         msg: format!("Compilation failed trying to find current directory - {:?}.", error),
         help: None,
@@ -62,6 +66,7 @@ create_errors!(
     /// For when the crate failed to open or get the name of a directory.
     @formatted
     directory_error {
+        code: 4,
         args: (error: impl ErrorArg, path:impl Debug),
         msg: format!(
             "Compilation failed due to directory error @ '{:?}' - {:?}.",
@@ -74,6 +79,7 @@ create_errors!(
     /// For when the crate failed to find a main file for the current package.
     @formatted
     expected_main_file {
+        code: 5,
         args: (entry: impl Debug),
         msg: format!("Expected main file at `{:?}`.", entry),
         help: None,
@@ -82,6 +88,7 @@ create_errors!(
     /// For when the crate failed to import a package name.
     @formatted
     unknown_package {
+        code: 6,
         args: (name: impl Display),
         msg: format!(
             "Cannot find imported package `{}` in source files or import directory.",
@@ -93,6 +100,7 @@ create_errors!(
     /// For when the crate failed due to an IO error.
     @formatted
     io_error {
+        code: 7,
         args: (path: impl Display, error: impl ErrorArg),
         msg: format!("cannot read imported file '{}': {:?}", path, error),
         help: None,
@@ -101,6 +109,7 @@ create_errors!(
     /// For when the stdlib import file could not be found.
     @backtraced
     no_such_stdlib_file {
+        code: 8,
         args: (import: impl Display),
         msg: format!("failed to find the stdlib import file `{}`", import),
         help: None,
@@ -109,6 +118,7 @@ create_errors!(
     /// For when the stdlib import file could not be read.
     @backtraced
     failed_to_read_stdlib_file {
+        code: 9,
         args: (import: impl Display),
         msg: format!("failed to read the stdlib import file `{}`", import),
         help: None,
@@ -117,8 +127,18 @@ create_errors!(
     /// For when directory name matches file name in the same source folder.
     @formatted
     conflicting_local_imports {
+        code: 10,
         args: (names: impl Debug),
         msg: format!("unable to select import location, conflicting paths are found: `{:?}`", names),
         help: None,
     }
+
+    /// For when a function of the standard library has no body and no native implementation.
+    @formatted
+    unknown_native_function {
+        code: 11,
+        args: (name: impl Display),
+        msg: format!("the stdlib function `{}` has no body and no native implementation", name),
+        help: None,
+    }
 );
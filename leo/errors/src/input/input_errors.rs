@@ -26,6 +26,7 @@ create_errors!(
     /// For when declared variable type mismatches actual type.
     @formatted
     unexpected_type {
+        code: 0,
         args: (expected: impl Display, received: impl Display),
         msg: format!(
             "unexpected type, expected: '{}', received: '{}'",
@@ -38,6 +39,7 @@ create_errors!(
     /// For when string value is assigned to an array of non Char type.
     @formatted
     string_is_array_of_chars {
+        code: 1,
         args: (expected: impl Display),
         msg: format!(
             "strings transforms into array of 'char', expected: {}",
@@ -49,6 +51,7 @@ create_errors!(
     /// For when array init is using spread.
     @formatted
     array_spread_is_not_allowed {
+        code: 2,
         args: (),
         msg: "array spread is not allowed in inputs",
         help: None,
@@ -57,6 +60,7 @@ create_errors!(
     /// For when any of the array dimensions is zero.
     @formatted
     invalid_array_dimension_size {
+        code: 3,
         args: (),
         msg: "received dimension size of 0, expected it to be 1 or larger.",
         help: None,
@@ -65,6 +69,7 @@ create_errors!(
     /// For when the expression is not allowed in an input file.
     @formatted
     illegal_expression {
+        code: 4,
         args: (expr: impl Display),
         msg: format!("expression '{}' is not allowed in inputs", expr),
         help: None,
@@ -73,6 +78,7 @@ create_errors!(
     /// For when section name is not an allowed one.
     @formatted
     unexpected_section {
+        code: 5,
         args: (expected: &[impl Display], received: impl Display),
         msg: format!(
             "unexpected section: expected {} -- got '{}'",
@@ -89,6 +95,7 @@ create_errors!(
     /// For when declared tuple length is not equal to the value's.
     @formatted
     tuple_length_mismatch {
+        code: 6,
         args: (expected: impl Display, received: impl Display),
         msg: format!("tuple length mismatch, defined {} types, got {} values", expected, received),
         help: None,
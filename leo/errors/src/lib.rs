@@ -20,10 +20,6 @@
 /// Contains traits and types for channels through which errors go.
 pub mod emitter;
 
-/// Contains the ASG error definitions.
-pub mod asg;
-pub use self::asg::*;
-
 /// Contains the AST error definitions.
 pub mod ast;
 pub use self::ast::*;
@@ -49,6 +45,10 @@ pub use self::import::*;
 pub mod input;
 pub use self::input::*;
 
+/// Contains the Lint warning definitions.
+pub mod lint;
+pub use self::lint::*;
+
 /// Contains the Package error definitions.
 pub mod package;
 pub use self::package::*;
@@ -65,6 +65,10 @@ pub use self::snarkvm::*;
 pub mod state;
 pub use self::state::*;
 
+/// Contains the test keeping the error index in sync with the error definitions.
+#[cfg(test)]
+mod error_index;
+
 #[macro_use]
 extern crate thiserror;
 
@@ -72,10 +76,6 @@ extern crate thiserror;
 /// This allows a unified error type throughout the Leo crates.
 #[derive(Debug, Error)]
 pub enum LeoError {
-    /// Represents an ASG Error in a Leo Error.
-    #[error(transparent)]
-    AsgError(#[from] AsgError),
-
     /// Represents an AST Error in a Leo Error.
     #[error(transparent)]
     AstError(#[from] AstError),
@@ -111,6 +111,16 @@ pub enum LeoError {
     /// Represents an State Error in a Leo Error.
     #[error(transparent)]
     StateError(#[from] StateError),
+
+    /// Represents a warning that is treated as an error, see [`LeoWarning::deny`].
+    #[error(transparent)]
+    DeniedWarning(LeoWarning),
+
+    /// Represents the errors already emitted to a handler, by the exit code of the last of them.
+    /// It is returned at the end of a stage that had errors, see [`emitter::Handler::last_err`],
+    /// and is never emitted itself.
+    #[error("aborting due to previous errors, the last with exit code {0}")]
+    LastErrorCode(i32),
 }
 
 impl LeoError {
@@ -119,7 +129,6 @@ impl LeoError {
         use LeoError::*;
 
         match self {
-            AsgError(error) => error.error_code(),
             AstError(error) => error.error_code(),
             CliError(error) => error.error_code(),
             CompilerError(error) => error.error_code(),
@@ -129,6 +138,8 @@ impl LeoError {
             ParserError(error) => error.error_code(),
             SnarkVMError(_error) => Default::default(), // TODO update once snarkvm implments a global top level error similar to LeoError.
             StateError(error) => error.error_code(),
+            DeniedWarning(warning) => warning.error_code(),
+            LastErrorCode(_) => Default::default(),
         }
     }
 
@@ -137,7 +148,6 @@ impl LeoError {
         use LeoError::*;
 
         match self {
-            AsgError(error) => error.exit_code(),
             AstError(error) => error.exit_code(),
             CliError(error) => error.exit_code(),
             CompilerError(error) => error.exit_code(),
@@ -147,8 +157,117 @@ impl LeoError {
             ParserError(error) => error.exit_code(),
             SnarkVMError(_error) => 1, // TODO update once snarkvm implments a global top level error similar to LeoError.
             StateError(error) => error.exit_code(),
+            DeniedWarning(warning) => warning.exit_code(),
+            LastErrorCode(code) => *code,
         }
     }
+
+    /// Returns the kind and name of the error with the prefixed error `code`,
+    /// e.g., `("parser", "unexpected_eof")` for `EPAR0370003`.
+    /// The guide of the error is `docs/error-guides/{kind}/{name}.md`.
+    pub fn find_by_code(code: &str) -> Option<(&'static str, &'static str)> {
+        [
+            ("ast", AstError::error_name(code)),
+            ("cli", CliError::error_name(code)),
+            ("compiler", CompilerError::error_name(code)),
+            ("import", ImportError::error_name(code)),
+            ("input", InputError::error_name(code)),
+            ("package", PackageError::error_name(code)),
+            ("parser", ParserError::error_name(code)),
+            ("state", StateError::error_name(code)),
+        ]
+        .iter()
+        .find_map(|(kind, name)| Some((*kind, (*name)?)))
+    }
+
+    /// Returns the error as a machine-readable diagnostic, e.g., for `--message-format=json`.
+    pub fn diagnostic(&self) -> emitter::Diagnostic {
+        use LeoError::*;
+
+        match self {
+            AstError(error) => error.diagnostic(),
+            CliError(error) => error.diagnostic(),
+            CompilerError(error) => error.diagnostic(),
+            ImportError(error) => error.diagnostic(),
+            InputError(error) => error.diagnostic(),
+            PackageError(error) => error.diagnostic(),
+            ParserError(error) => error.diagnostic(),
+            SnarkVMError(_) | LastErrorCode(_) => emitter::Diagnostic {
+                severity: Severity::Error,
+                error_code: self.error_code(),
+                exit_code: self.exit_code(),
+                message: self.to_string(),
+                help: None,
+                span: None,
+                labels: Vec::new(),
+                notes: Vec::new(),
+                suggestions: Vec::new(),
+            },
+            StateError(error) => error.diagnostic(),
+            DeniedWarning(warning) => warning.diagnostic(),
+        }
+    }
+}
+
+/// The LeoWarning type that contains all sub warning types.
+/// This allows a unified warning type throughout the Leo crates.
+#[derive(Debug, Error)]
+pub enum LeoWarning {
+    /// Represents an Parser Warning in a Leo Warning.
+    #[error(transparent)]
+    ParserWarning(#[from] ParserWarning),
+
+    /// Represents a Lint Warning in a Leo Warning.
+    #[error(transparent)]
+    LintWarning(#[from] LintWarning),
+}
+
+impl LeoWarning {
+    /// The names of all lints, which can be allowed through `@allow(...)`.
+    pub fn lint_names() -> impl Iterator<Item = &'static str> {
+        ParserWarning::LINT_NAMES.iter().chain(LintWarning::LINT_NAMES).copied()
+    }
+
+    /// Returns the name of the lint used to allow this warning.
+    pub fn lint_name(&self) -> &'static str {
+        match self {
+            LeoWarning::ParserWarning(warning) => warning.lint_name(),
+            LeoWarning::LintWarning(warning) => warning.lint_name(),
+        }
+    }
+
+    /// Implement warning code for each type of Warning.
+    pub fn error_code(&self) -> String {
+        match self {
+            LeoWarning::ParserWarning(warning) => warning.error_code(),
+            LeoWarning::LintWarning(warning) => warning.error_code(),
+        }
+    }
+
+    /// Implement exit code for each type of Warning, used when the warning is denied.
+    pub fn exit_code(&self) -> i32 {
+        match self {
+            LeoWarning::ParserWarning(warning) => warning.exit_code(),
+            LeoWarning::LintWarning(warning) => warning.exit_code(),
+        }
+    }
+
+    /// Returns the warning as a machine-readable diagnostic, e.g., for `--message-format=json`.
+    pub fn diagnostic(&self) -> emitter::Diagnostic {
+        match self {
+            LeoWarning::ParserWarning(warning) => warning.diagnostic(),
+            LeoWarning::LintWarning(warning) => warning.diagnostic(),
+        }
+    }
+
+    /// Turns the warning into an error, keeping its warning code.
+    pub fn deny(self) -> LeoError {
+        let warning = match self {
+            LeoWarning::ParserWarning(warning) => warning.with_severity(Severity::DeniedWarning).into(),
+            LeoWarning::LintWarning(warning) => warning.with_severity(Severity::DeniedWarning).into(),
+        };
+        LeoError::DeniedWarning(warning)
+    }
 }
 
 /// A global result type for all Leo crates, that defaults the errors to be a LeoError.
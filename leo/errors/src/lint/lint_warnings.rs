@@ -0,0 +1,89 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::create_warnings;
+
+use std::fmt::Display;
+
+create_warnings!(
+    /// LintWarning enum that represents all the warnings of the Leo linter.
+    LintWarning,
+    exit_code_mask: 9000i32,
+    warning_code_prefix: "LIN",
+
+    /// For when a variable is defined but never used.
+    @formatted
+    unused_variable {
+        code: 0,
+        args: (name: impl Display),
+        msg: format!("unused variable `{}`", name),
+        help: Some("Remove the variable, or allow the lint with `@allow(unused_variable)`.".to_string()),
+    }
+
+    /// For when an imported item or package is never used.
+    @formatted
+    unused_import {
+        code: 1,
+        args: (name: impl Display),
+        msg: format!("unused import `{}`", name),
+        help: Some("Remove the import.".to_string()),
+    }
+
+    /// For when a variable has the name of a variable or parameter of an enclosing scope.
+    @formatted
+    shadowed_variable {
+        code: 2,
+        args: (name: impl Display),
+        msg: format!("`{}` shadows a variable of the same name", name),
+        help: Some("Rename one of the variables.".to_string()),
+    }
+
+    /// For when a `let` binding, which is mutable, is never reassigned and has a constant value.
+    @formatted
+    never_reassigned {
+        code: 3,
+        args: (name: impl Display),
+        msg: format!("variable `{}` is never reassigned, and its value is constant", name),
+        help: Some("Declare it with `const` instead of `let`.".to_string()),
+    }
+
+    /// For when `console.log` is left in the `main` function.
+    @formatted
+    console_log_in_main {
+        code: 4,
+        args: (),
+        msg: "`console.log` is left in `main`",
+        help: Some("Remove it once the program is debugged.".to_string()),
+    }
+
+    /// For when the name of a function is not in snake case.
+    @formatted
+    non_snake_case_function {
+        code: 5,
+        args: (name: impl Display),
+        msg: format!("function `{}` should have a snake case name", name),
+        help: None,
+    }
+
+    /// For when the condition of an `if` or of a ternary expression is a constant.
+    @formatted
+    constant_condition {
+        code: 6,
+        args: (),
+        msg: "the condition is constant",
+        help: Some("Remove the condition, and the branch that never runs.".to_string()),
+    }
+);
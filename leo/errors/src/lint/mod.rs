@@ -14,6 +14,6 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-/// This module contains the ASG error definitions.
-pub mod asg_errors;
-pub use self::asg_errors::*;
+/// This module contains the Lint warning definitions.
+pub mod lint_warnings;
+pub use self::lint_warnings::*;
@@ -32,6 +32,7 @@ create_errors!(
     /// For when the specified import does not exist.
     @backtraced
     import_does_not_exist {
+        code: 0,
         args: (package: impl Display),
         msg: format!("package {} does not exist as an import", package),
         help: None,
@@ -40,6 +41,7 @@ create_errors!(
     /// For when creating the imports directory failed.
     @backtraced
     failed_to_create_imports_directory {
+        code: 1,
         args: (error: impl ErrorArg),
         msg: format!("failed creating imports directory {}", error),
         help: None,
@@ -48,6 +50,7 @@ create_errors!(
     /// For when removing the imports directory failed.
     @backtraced
     failed_to_remove_imports_directory {
+        code: 2,
         args: (error: impl ErrorArg),
         msg: format!("failed removing imports directory {}", error),
         help: None,
@@ -56,6 +59,7 @@ create_errors!(
     /// For when getting a input file entry failed.
     @backtraced
     failed_to_get_input_file_entry {
+        code: 3,
         args: (error: impl ErrorArg),
         msg: format!("failed to get input file entry: {}", error),
         help: None,
@@ -64,6 +68,7 @@ create_errors!(
     /// For when getting the input file name failed.
     @backtraced
     failed_to_get_input_file_name {
+        code: 4,
         args: (file: impl Debug),
         msg: format!("failed to get input file name: {:?}", file),
         help: None,
@@ -72,6 +77,7 @@ create_errors!(
     /// For when getting the input file type failed.
     @backtraced
     failed_to_get_input_file_type {
+        code: 5,
         args: (file: impl Debug, error: impl ErrorArg),
         msg: format!("failed to get input file `{:?}` type: {}", file, error),
         help: None,
@@ -80,6 +86,7 @@ create_errors!(
     /// For when getting the input file has an invalid file type.
     @backtraced
     invalid_input_file_type {
+        code: 6,
         args: (file: impl Debug, type_: std::fs::FileType),
         msg: format!("input file `{:?}` has invalid type: {:?}", file, type_),
         help: None,
@@ -88,6 +95,7 @@ create_errors!(
     /// For when creating the inputs directory failed.
     @backtraced
     failed_to_create_inputs_directory {
+        code: 7,
         args: (error: impl ErrorArg),
         msg: format!("failed creating inputs directory {}", error),
         help: None,
@@ -96,6 +104,7 @@ create_errors!(
     /// For when reading the circuit file failed.
     @backtraced
     failed_to_read_circuit_file {
+        code: 8,
         args: (path: impl Debug),
         msg: format!("Cannot read circuit file from the provided file path - {:?}", path),
         help: None,
@@ -104,6 +113,7 @@ create_errors!(
     /// For when reading the input directory failed.
     @backtraced
     failed_to_read_inputs_directory {
+        code: 9,
         args: (error: impl ErrorArg),
         msg: format!("failed reading inputs directory {}", error),
         help: None,
@@ -112,6 +122,7 @@ create_errors!(
     /// For when reading the input file failed.
     @backtraced
     failed_to_read_input_file {
+        code: 10,
         args: (path: impl Debug),
         msg: format!("Cannot read input file from the provided file path - {:?}", path),
         help: None,
@@ -120,6 +131,7 @@ create_errors!(
     /// For when reading the state file failed.
     @backtraced
     failed_to_read_state_file {
+        code: 11,
         args: (path: impl Debug),
         msg: format!("Cannot read state file from the provided file path - {:?}", path),
         help: None,
@@ -129,6 +141,7 @@ create_errors!(
     /// For when reading the snapshot file failed.
     @backtraced
     failed_to_read_snapshot_file {
+        code: 12,
         args: (path: impl Debug),
         msg: format!("Cannot read snapshot file from the provided file path - {:?}", path),
         help: None,
@@ -137,6 +150,7 @@ create_errors!(
     /// For when reading the checksum file failed.
     @backtraced
     failed_to_read_checksum_file {
+        code: 13,
         args: (path: impl Debug),
         msg: format!("Cannot read checksum file from the provided file path - {:?}", path),
         help: None,
@@ -145,6 +159,7 @@ create_errors!(
     /// For when reading the proof file failed.
     @backtraced
     failed_to_read_proof_file {
+        code: 14,
         args: (path: impl Debug),
         msg: format!("Cannot read proof file from the provided file path - {:?}", path),
         help: None,
@@ -153,6 +168,7 @@ create_errors!(
     /// For when reading the proving key failed.
     @backtraced
     failed_to_read_proving_key_file {
+        code: 15,
         args: (path: impl Debug),
         msg: format!("Cannot read proving key file from the provided file path - {:?}", path),
         help: None,
@@ -161,6 +177,7 @@ create_errors!(
     /// For when reading the verification key file failed.
     @backtraced
     failed_to_read_verification_key_file {
+        code: 16,
         args: (path: impl Debug),
         msg: format!("Cannot read verification key file from the provided file path - {:?}", path),
         help: None,
@@ -169,6 +186,7 @@ create_errors!(
     /// For when creating the manifest file failed.
     @backtraced
     failed_to_create_manifest_file {
+        code: 17,
         args: (filename: impl Display, error: impl ErrorArg),
         msg: format!("failed creating manifest file `{}` {}", filename, error),
         help: None,
@@ -177,6 +195,7 @@ create_errors!(
     /// For when parsing the manifest file failed.
     @backtraced
     failed_to_parse_manifest_file {
+        code: 18,
         args: (filename: impl Display, error: impl ErrorArg),
         msg: format!("failed parsing manifest file `{}` {}", filename, error),
         help: None,
@@ -185,6 +204,7 @@ create_errors!(
     /// For when opening the manifest file failed.
     @backtraced
     failed_to_open_manifest_file {
+        code: 19,
         args: (filename: impl Display, error: impl ErrorArg),
         msg: format!("failed openining manifest file `{}` {}", filename, error),
         help: None,
@@ -193,6 +213,7 @@ create_errors!(
     /// For when reading the manifest file failed.
     @backtraced
     failed_to_read_manifest_file {
+        code: 20,
         args: (filename: impl Display, error: impl ErrorArg),
         msg: format!("failed reading manifest file `{}` {}", filename, error),
         help: None,
@@ -201,6 +222,7 @@ create_errors!(
     /// For when writing the manifest file failed.
     @backtraced
     failed_to_write_manifest_file {
+        code: 21,
         args: (filename: impl Display, error: impl ErrorArg),
         msg: format!("failed writing manifest file `{}` {}", filename, error),
         help: None,
@@ -209,6 +231,7 @@ create_errors!(
     /// For when the manifest file has an IO error.
     @backtraced
     io_error_manifest_file {
+        code: 22,
         args: (error: impl ErrorArg),
         msg: format!("IO error manifest file from the provided file path - {}", error),
         help: None,
@@ -217,6 +240,7 @@ create_errors!(
     /// For when getting the manifest metadata file failed.
     @backtraced
     failed_to_get_manifest_metadata_file {
+        code: 23,
         args: (filename: impl Display, error: impl ErrorArg),
         msg: format!("failed getting manifest metadata file `{}` {}", filename, error),
         help: None,
@@ -226,6 +250,7 @@ create_errors!(
     /// For when creating the zip file failed.
     @backtraced
     failed_to_create_zip_file {
+        code: 24,
         args: (error: impl ErrorArg),
         msg: format!("failed creating zip file {}", error),
         help: None,
@@ -234,6 +259,7 @@ create_errors!(
     /// For when opening the zip file failed.
     @backtraced
     failed_to_open_zip_file {
+        code: 25,
         args: (error: impl ErrorArg),
         msg: format!("failed opening zip file {}", error),
         help: None,
@@ -242,6 +268,7 @@ create_errors!(
     /// For when reading the zip file failed.
     @backtraced
     failed_to_read_zip_file {
+        code: 26,
         args: (error: impl ErrorArg),
         msg: format!("failed reading zip file {}", error),
         help: None,
@@ -250,6 +277,7 @@ create_errors!(
     /// For when writing the zip file failed.
     @backtraced
     failed_to_write_zip_file {
+        code: 27,
         args: (error: impl ErrorArg),
         msg: format!("failed writing zip file {}", error),
         help: None,
@@ -258,6 +286,7 @@ create_errors!(
     /// For when the zip file has an IO error.
     @backtraced
     io_error_zip_file {
+        code: 28,
         args: (error: impl ErrorArg),
         msg: format!("IO error zip file from the provided file path - {}", error),
         help: None,
@@ -266,6 +295,7 @@ create_errors!(
     /// For when the circuit file has an IO error.
     @backtraced
     io_error_circuit_file {
+        code: 29,
         args: (error: impl ErrorArg),
         msg: format!("IO error circuit file from the provided file path - {}", error),
         help: None,
@@ -274,6 +304,7 @@ create_errors!(
     /// For when the checksum file has an IO error.
     @backtraced
     io_error_checksum_file {
+        code: 30,
         args: (error: impl ErrorArg),
         msg: format!("IO error checksum file from the provided file path - {}", error),
         help: None,
@@ -282,6 +313,7 @@ create_errors!(
     /// For when the proof file has an IO error.
     @backtraced
     io_error_proof_file {
+        code: 31,
         args: (error: impl ErrorArg),
         msg: format!("IO error proof file from the provided file path - {}", error),
         help: None,
@@ -290,6 +322,7 @@ create_errors!(
     /// For when the readme file has an IO error.
     @backtraced
     io_error_readme_file {
+        code: 32,
         args: (error: impl ErrorArg),
         msg: format!("IO error readme file from the provided file path - {}", error),
         help: None,
@@ -298,6 +331,7 @@ create_errors!(
     /// For when the main file has an IO error.
     @backtraced
     io_error_main_file {
+        code: 33,
         args: (error: impl ErrorArg),
         msg: format!("IO error main file from the provided file path - {}", error),
         help: None,
@@ -306,6 +340,7 @@ create_errors!(
     /// For when removing the circuit file failed.
     @backtraced
     failed_to_remove_circuit_file {
+        code: 34,
         args: (path: impl Debug),
         msg: format!("failed removing circuit file from the provided file path - {:?}", path),
         help: None,
@@ -314,6 +349,7 @@ create_errors!(
     /// For when removing the checksum file failed.
     @backtraced
     failed_to_remove_checksum_file {
+        code: 35,
         args: (path: impl Debug),
         msg: format!("failed removing checksum file from the provided file path - {:?}", path),
         help: None,
@@ -322,6 +358,7 @@ create_errors!(
     /// For when removing the zip file failed.
     @backtraced
     failed_to_remove_zip_file {
+        code: 36,
         args: (path: impl Debug),
         msg: format!("failed removing zip file from the provided file path - {:?}", path),
         help: None,
@@ -330,6 +367,7 @@ create_errors!(
     /// For when removing the snapshot file failed.
     @backtraced
     failed_to_remove_snapshot_file {
+        code: 37,
         args: (path: impl Debug),
         msg: format!("failed removing snapshot file from the provided file path - {:?}", path),
         help: None,
@@ -339,6 +377,7 @@ create_errors!(
     /// For when removing the proof file failed.
     @backtraced
     failed_to_remove_proof_file {
+        code: 38,
         args: (path: impl Debug),
         msg: format!("failed removing proof file from the provided file path - {:?}", path),
         help: None,
@@ -347,6 +386,7 @@ create_errors!(
     /// For when removing the proving_key file failed.
     @backtraced
     failed_to_remove_proving_key_file {
+        code: 39,
         args: (path: impl Debug),
         msg: format!("failed removing proving_key file from the provided file path - {:?}", path),
         help: None,
@@ -355,6 +395,7 @@ create_errors!(
     /// For when removing the verification_key file failed.
     @backtraced
     failed_to_remove_verification_key_file {
+        code: 40,
         args: (path: impl Debug),
         msg: format!("failed removing verification_key file from the provided file path - {:?}", path),
         help: None,
@@ -363,6 +404,7 @@ create_errors!(
     /// For when the input file has an IO error.
     @backtraced
     io_error_input_file {
+        code: 41,
         args: (error: impl ErrorArg),
         msg: format!("IO error input file from the provided file path - {}", error),
         help: None,
@@ -372,6 +414,7 @@ create_errors!(
     /// For when the state file has an IO error.
     @backtraced
     io_error_state_file {
+        code: 42,
         args: (error: impl ErrorArg),
         msg: format!("IO error state file from the provided file path - {}", error),
         help: None,
@@ -380,6 +423,7 @@ create_errors!(
     /// For when the proving_key file has an IO error.
     @backtraced
     io_error_proving_key_file {
+        code: 43,
         args: (error: impl ErrorArg),
         msg: format!("IO error proving_key file from the provided file path - {}", error),
         help: None,
@@ -388,6 +432,7 @@ create_errors!(
     /// For when the verification_key file has an IO error.
     @backtraced
     io_error_verification_key_file {
+        code: 44,
         args: (error: impl ErrorArg),
         msg: format!("IO error verification_key file from the provided file path - {}", error),
         help: None,
@@ -396,6 +441,7 @@ create_errors!(
     /// For when the gitignore file has an IO error.
     @backtraced
     io_error_gitignore_file {
+        code: 45,
         args: (error: impl ErrorArg),
         msg: format!("IO error gitignore file from the provided file path - {}", error),
         help: None,
@@ -404,6 +450,7 @@ create_errors!(
     /// For when creating the source directory failed.
     @backtraced
     failed_to_create_source_directory {
+        code: 46,
         args: (error: impl ErrorArg),
         msg: format!("failed creating source directory {}", error),
         help: None,
@@ -412,6 +459,7 @@ create_errors!(
     /// For when getting a source file entry failed.
     @backtraced
     failed_to_get_source_file_entry {
+        code: 47,
         args: (error: impl ErrorArg),
         msg: format!("failed to get input file entry: {}", error),
         help: None,
@@ -420,6 +468,7 @@ create_errors!(
     /// For when getting the source file extension failed.
     @backtraced
     failed_to_get_source_file_extension {
+        code: 48,
         args: (extension: impl Debug),
         msg: format!("failed to get source file extension: {:?}", extension),
         help: None,
@@ -428,6 +477,7 @@ create_errors!(
     /// For when getting the source file type failed.
     @backtraced
     failed_to_get_source_file_type {
+        code: 49,
         args: (file: impl Debug, error: impl ErrorArg),
         msg: format!("failed to get source file `{:?}` type: {}", file, error),
         help: None,
@@ -436,6 +486,7 @@ create_errors!(
     /// For when getting the source file has an invalid extension.
     @backtraced
     invalid_source_file_extension {
+        code: 50,
         args: (file: impl Debug, extension: impl Debug),
         msg: format!("source file `{:?}` has invalid extension: {:?}", file, extension),
         help: None,
@@ -444,6 +495,7 @@ create_errors!(
     /// For when getting the source file has an invalid file type.
     @backtraced
     invalid_source_file_type {
+        code: 51,
         args: (file: impl Debug, type_: std::fs::FileType),
         msg: format!("source file `{:?}` has invalid type: {:?}", file, type_),
         help: None,
@@ -452,6 +504,7 @@ create_errors!(
     /// For when the package failed to initalize.
     @backtraced
     failed_to_initialize_package {
+        code: 52,
         args: (package: impl Display, path: impl Debug),
         msg: format!("failed to initialize package {} {:?}", package, path),
         help: None,
@@ -460,6 +513,7 @@ create_errors!(
     /// For when the package has an invalid name.
     @backtraced
     invalid_package_name {
+        code: 53,
         args: (package: impl Display),
         msg: format!("invalid project name {}", package),
         help: None,
@@ -467,6 +521,7 @@ create_errors!(
 
     @backtraced
     failed_to_create_lock_file {
+        code: 54,
         args: (filename: impl Display, error: impl ErrorArg),
         msg: format!("failed creating lock file `{}` {}", filename, error),
         help: None,
@@ -475,6 +530,7 @@ create_errors!(
     /// For when getting the lock file metadata failed.
     @backtraced
     failed_to_get_lock_file_metadata {
+        code: 55,
         args: (filename: impl Display, error: impl ErrorArg),
         msg: format!("failed getting lock file metadata `{}` {}", filename, error),
         help: None,
@@ -483,6 +539,7 @@ create_errors!(
     /// For when opening the lock file failed.
     @backtraced
     failed_to_open_lock_file {
+        code: 56,
         args: (filename: impl Display, error: impl ErrorArg),
         msg: format!("failed openining lock file `{}` {}", filename, error),
         help: None,
@@ -491,6 +548,7 @@ create_errors!(
     /// For when parsing the lock file failed.
     @backtraced
     failed_to_parse_lock_file {
+        code: 57,
         args: (filename: impl Display, error: impl ErrorArg),
         msg: format!("failed parsing lock file `{}` {}", filename, error),
         help: None,
@@ -499,6 +557,7 @@ create_errors!(
     /// For when reading the lock file failed.
     @backtraced
     failed_to_read_lock_file {
+        code: 58,
         args: (filename: impl Display, error: impl ErrorArg),
         msg: format!("failed reading lock file `{}` {}", filename, error),
         help: None,
@@ -507,6 +566,7 @@ create_errors!(
     /// For when writing the lock file failed.
     @backtraced
     failed_to_write_lock_file {
+        code: 59,
         args: (filename: impl Display, error: impl ErrorArg),
         msg: format!("failed writing lock file `{}` {}", filename, error),
         help: None,
@@ -514,6 +574,7 @@ create_errors!(
 
     @backtraced
     failed_to_serialize_lock_file {
+        code: 60,
         args: (error: impl ErrorArg),
         msg: format!("serialization failed: {}", error),
         help: None,
@@ -522,6 +583,7 @@ create_errors!(
     /// For when creating a directory failed.
     @backtraced
     failed_to_create_directory {
+        code: 61,
         args: (dirname: impl Display, error: impl ErrorArg),
         msg: format!("failed to create directory: {}, error: {}", dirname, error),
         help: None,
@@ -530,6 +592,7 @@ create_errors!(
     /// For when removing a directory failed.
     @backtraced
     failed_to_remove_directory {
+        code: 62,
         args: (dirname: impl Display, error: impl ErrorArg),
         msg: format!("failed to remove directory: {}, error: {}", dirname, error),
         help: None,
@@ -538,6 +601,7 @@ create_errors!(
     /// For when file could not be read.
     @backtraced
     failed_to_read_file {
+        code: 63,
         args: (path: impl Display, error: impl ErrorArg),
         msg: format!("failed to read file: {}, error: {}", path, error),
         help: None,
@@ -546,6 +610,7 @@ create_errors!(
     /// For when file failed to remove.
     @backtraced
     failed_to_remove_file {
+        code: 64,
         args: (path: impl Display, error: impl ErrorArg),
         msg: format!("failed to remove file: {}, error: {}", path, error),
         help: None,
@@ -554,8 +619,216 @@ create_errors!(
     /// For when I/O operation failed.
     @backtraced
     io_error {
+        code: 65,
         args: (file: impl Display, error: impl ErrorArg),
         msg: format!("i/o operation failed, file: {}, error: {}", file, error),
         help: None,
     }
+
+    /// For when a local path dependency does not point at a Leo package.
+    @backtraced
+    local_dependency_not_found {
+        code: 66,
+        args: (import_name: impl Display, path: impl Debug),
+        msg: format!("dependency `{}` does not point at a Leo package: {:?}", import_name, path),
+        help: Some("Path dependencies must point at a directory containing a `Leo.toml` manifest.".to_string()),
+    }
+
+    /// For when checking out a git dependency failed.
+    @backtraced
+    failed_to_fetch_git_dependency {
+        code: 67,
+        args: (import_name: impl Display, url: impl Display, error: impl Display),
+        msg: format!("failed to fetch git dependency `{}` from {}: {}", import_name, url, error),
+        help: None,
+    }
+
+    /// For when computing the checksum of a dependency failed.
+    @backtraced
+    failed_to_hash_dependency {
+        code: 68,
+        args: (import_name: impl Display, error: impl ErrorArg),
+        msg: format!("failed to compute the checksum of dependency `{}`: {}", import_name, error),
+        help: None,
+    }
+
+    /// For when a dependency version requirement is not valid semver.
+    @backtraced
+    invalid_version_requirement {
+        code: 69,
+        args: (import_name: impl Display, requirement: impl Display, error: impl ErrorArg),
+        msg: format!("invalid version requirement `{}` for dependency `{}`: {}", requirement, import_name, error),
+        help: None,
+    }
+
+    /// For when no installed version of a dependency satisfies its requirement.
+    @backtraced
+    dependency_not_installed {
+        code: 70,
+        args: (package: impl Display, requirement: impl Display),
+        msg: format!("no version of package `{}` matching `{}` found in `imports/`", package, requirement),
+        help: Some("Run `leo fetch` to install the dependencies.".to_string()),
+    }
+
+    /// For when the requirements on a dependency cannot be satisfied by a single version.
+    @backtraced
+    dependency_version_conflict {
+        code: 71,
+        args: (package: impl Display, requirements: impl Display),
+        msg: format!("failed to select a version for package `{}` satisfying all requirements:\n{}", package, requirements),
+        help: None,
+    }
+
+    /// For when a dependency transitively depends on itself.
+    @backtraced
+    recursive_dependency {
+        code: 72,
+        args: (tree: impl Display),
+        msg: format!("recursive dependency found:\n{}", tree),
+        help: None,
+    }
+
+    /// For when the lock file does not match the manifest or the installed dependencies.
+    @backtraced
+    lock_file_out_of_date {
+        code: 73,
+        args: (filename: impl Display),
+        msg: format!("lock file `{}` does not match the manifest or the installed dependencies", filename),
+        help: Some("Run `leo fetch` to update the lock file.".to_string()),
+    }
+
+    /// For when reading the registry index failed.
+    @backtraced
+    failed_to_read_registry_index {
+        code: 74,
+        args: (path: impl Debug, error: impl Display),
+        msg: format!("failed reading registry index {:?}: {}", path, error),
+        help: None,
+    }
+
+    /// For when writing the registry index failed.
+    @backtraced
+    failed_to_write_registry_index {
+        code: 75,
+        args: (path: impl Debug, error: impl Display),
+        msg: format!("failed writing registry index {:?}: {}", path, error),
+        help: None,
+    }
+
+    /// For when a package is not found in the registry.
+    @backtraced
+    registry_package_not_found {
+        code: 76,
+        args: (remote: impl Display),
+        msg: format!("package `{}` not found in the registry", remote),
+        help: None,
+    }
+
+    /// For when a package version is published to the registry twice.
+    @backtraced
+    registry_package_already_published {
+        code: 77,
+        args: (remote: impl Display, version: impl Display),
+        msg: format!("package `{}` version {} is already published to the registry", remote, version),
+        help: Some("Increase the package version in `Leo.toml` and publish again.".to_string()),
+    }
+
+    /// For when a package archive does not match the checksum in the registry index.
+    @backtraced
+    registry_checksum_mismatch {
+        code: 78,
+        args: (remote: impl Display, version: impl Display),
+        msg: format!("archive of package `{}` version {} does not match the registry index checksum", remote, version),
+        help: None,
+    }
+
+    /// For when a package archive has no checksum manifest.
+    @backtraced
+    archive_manifest_missing {
+        code: 79,
+        args: (path: impl Debug, manifest: impl Display),
+        msg: format!("package archive {:?} does not contain a `{}` checksum manifest", path, manifest),
+        help: Some("Create the archive again with `leo publish`.".to_string()),
+    }
+
+    /// For when the checksum manifest of a package archive is malformed.
+    @backtraced
+    invalid_archive_manifest {
+        code: 80,
+        args: (line: impl Display),
+        msg: format!("invalid checksum manifest line `{}`", line),
+        help: None,
+    }
+
+    /// For when a file in a package archive does not match its checksum.
+    @backtraced
+    archive_checksum_mismatch {
+        code: 81,
+        args: (file: impl Display),
+        msg: format!("file `{}` does not match the checksum in the archive manifest", file),
+        help: None,
+    }
+
+    /// For when a file listed in the checksum manifest is missing from a package archive.
+    @backtraced
+    archive_file_missing {
+        code: 82,
+        args: (file: impl Display),
+        msg: format!("file `{}` is listed in the archive manifest but missing from the archive", file),
+        help: None,
+    }
+
+    /// For when a package archive contains a file not listed in its checksum manifest.
+    @backtraced
+    archive_file_not_listed {
+        code: 83,
+        args: (file: impl Display),
+        msg: format!("file `{}` is not listed in the archive manifest", file),
+        help: None,
+    }
+
+    /// For when a workspace member does not contain a manifest.
+    @backtraced
+    workspace_member_not_found {
+        code: 84,
+        args: (member: impl Display),
+        msg: format!("workspace member `{}` does not contain a `Leo.toml` manifest", member),
+        help: None,
+    }
+
+    /// For when a registry dependency is resolved as a path or git dependency.
+    @backtraced
+    not_a_local_dependency {
+        code: 85,
+        args: (import_name: impl Display),
+        msg: format!("dependency `{}` is not a path or git dependency", import_name),
+        help: Some("Registry dependencies are installed into `imports/` by `leo fetch`.".to_string()),
+    }
+
+    /// For when the url or revision of a git dependency would be passed to git as an option.
+    @backtraced
+    invalid_git_dependency {
+        code: 86,
+        args: (import_name: impl Display, value: impl Display),
+        msg: format!("git dependency `{}` has an invalid url or revision `{}`", import_name, value),
+        help: Some("The `git` and `rev` values of a dependency cannot start with `-`.".to_string()),
+    }
+
+    /// For when the author, name or version of a package cannot be stored in the registry.
+    @backtraced
+    invalid_registry_package {
+        code: 87,
+        args: (remote: impl Display, version: impl Display),
+        msg: format!("invalid package `{}` version `{}` for the registry", remote, version),
+        help: Some("Authors and names must be valid package names, and versions semver versions, e.g., `0.1.0`.".to_string()),
+    }
+
+    /// For when an entry of the registry index points at a file outside the registry.
+    @backtraced
+    invalid_registry_entry_file {
+        code: 88,
+        args: (file: impl Display),
+        msg: format!("registry index entry points outside the registry: `{}`", file),
+        help: Some("Index entries must point inside the registry, e.g., at `author/name-0.1.0.zip`.".to_string()),
+    }
 );
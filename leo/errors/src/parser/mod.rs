@@ -17,3 +17,7 @@
 /// This module contains the Parser error definitions.
 pub mod parser_errors;
 pub use self::parser_errors::*;
+
+/// This module contains the Parser warning definitions.
+pub mod parser_warnings;
+pub use self::parser_warnings::*;
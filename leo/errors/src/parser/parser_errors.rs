@@ -27,6 +27,7 @@ create_errors!(
     /// For when the parser encountered an unexpected token.
     @formatted
     unexpected_token {
+        code: 0,
         args: (message: impl Display),
         msg: message,
         help: None,
@@ -35,6 +36,7 @@ create_errors!(
     /// For when the parser encountered an invalid address literal.
     @formatted
     invalid_address_lit {
+        code: 1,
         args: (token: impl Display),
         msg: format!("invalid address literal: '{}'", token),
         help: None,
@@ -43,6 +45,7 @@ create_errors!(
     /// For when the parser encountered an empty import list.
     @formatted
     invalid_import_list {
+        code: 2,
         args: (),
         msg: "Cannot import empty list",
         help: None,
@@ -51,6 +54,7 @@ create_errors!(
     /// For when the parser encountered an unexpected End of File.
     @formatted
     unexpected_eof {
+        code: 3,
         args: (),
         msg: "unexpected EOF",
         help: None,
@@ -59,6 +63,7 @@ create_errors!(
     /// For when the parser encountered an unexpected whitespace.
     @formatted
     unexpected_whitespace {
+        code: 4,
         args: (left: impl Display, right: impl Display),
         msg: format!("Unexpected white space between terms {} and {}", left, right),
         help: None,
@@ -67,6 +72,7 @@ create_errors!(
     /// For when the parser encountered an unexpected list of tokens.
     @formatted
     unexpected {
+        code: 5,
         args: (got: impl Display, expected: impl Display),
         msg: format!("expected {} -- got '{}'", expected, got),
         help: None,
@@ -75,6 +81,7 @@ create_errors!(
     /// For when the parser encountered a mix of commas and semi-colons in circuit member variables.
     @formatted
     mixed_commas_and_semicolons {
+        code: 6,
         args: (),
         msg: "Cannot mix use of commas and semi-colons for circuit member variable declarations.",
         help: None,
@@ -83,6 +90,7 @@ create_errors!(
     /// For when the parser encountered an unexpected identifier.
     @formatted
     unexpected_ident {
+        code: 7,
         args: (got: impl Display, expected: &[impl Display]),
         msg: format!(
             "unexpected identifier: expected {} -- got '{}'",
@@ -99,6 +107,7 @@ create_errors!(
     /// For when the parser encountered an unexpected statement.
     @formatted
     unexpected_statement {
+        code: 8,
         args: (got: impl Display, expected: impl Display),
         msg: format!("unexpected statement: expected '{}', got '{}'", expected, got),
         help: None,
@@ -107,6 +116,7 @@ create_errors!(
     /// For when the parser encountered an unexpected string.
     @formatted
     unexpected_str {
+        code: 9,
         args: (got: impl Display, expected: impl Display),
         msg: format!("unexpected string: expected '{}', got '{}'", expected, got),
         help: None,
@@ -115,6 +125,7 @@ create_errors!(
     /// For when the parser encountered an unexpected spread in an array init expression.
     @formatted
     spread_in_array_init {
+        code: 10,
         args: (),
         msg: "illegal spread in array initializer",
         help: None,
@@ -123,6 +134,7 @@ create_errors!(
     /// For when the parser encountered an invalid assignment target.
     @formatted
     invalid_assignment_target {
+        code: 11,
         args: (),
         msg: "invalid assignment target",
         help: None,
@@ -131,6 +143,7 @@ create_errors!(
     /// For when the parser encountered an invalid package name.
     @formatted
     invalid_package_name {
+        code: 12,
         args: (),
         msg: "package names must be lowercase alphanumeric ascii with underscores and singular dashes",
         help: None,
@@ -139,6 +152,7 @@ create_errors!(
     /// For when the parser encountered a deprecated `mut` argument in a function.
     @formatted
     mut_function_input {
+        code: 13,
         args: (),
         msg: "function func(mut a: u32) { ... } is deprecated. Passed variables are mutable by default.",
         help: None,
@@ -147,6 +161,7 @@ create_errors!(
     /// For when the parser encountered a deprecated `mut` argument in a let statement.
     @formatted
     let_mut_statement {
+        code: 14,
         args: (),
         msg: "let mut = ... is deprecated. `let` keyword implies mutabality by default.",
         help: None,
@@ -155,6 +170,7 @@ create_errors!(
     /// For when the parser encountered a deprecated `test function`.
     @formatted
     test_function {
+        code: 15,
         args: (),
         msg: "\"test function...\" is deprecated. Did you mean @test annotation?",
         help: None,
@@ -163,6 +179,7 @@ create_errors!(
     /// For when the parser encountered a deprecated `@context(...)` annotation.
     @formatted
     context_annotation {
+        code: 16,
         args: (),
         msg: "\"@context(...)\" is deprecated. Did you mean @test annotation?",
         help: None,
@@ -171,6 +188,7 @@ create_errors!(
     /// For when the parser failed to parse array dimensions.
     @formatted
     unable_to_parse_array_dimensions {
+        code: 17,
         args: (),
         msg: "unable to parse array dimensions",
         help: None,
@@ -179,6 +197,7 @@ create_errors!(
     /// For when the parser encountered a deprecated `mut self` parameter in a member function declaration.
     @formatted
     mut_self_parameter {
+        code: 18,
         args: (),
         msg: "`mut self` is no longer accepted. Use `&self` if you would like to pass in a mutable reference to `self`",
         help: None,
@@ -187,6 +206,7 @@ create_errors!(
     /// When a member const comes after a member variable.
     @formatted
     member_const_after_var {
+        code: 19,
         args: (),
         msg: "Member variables must come after member consts.",
         help: None,
@@ -195,6 +215,7 @@ create_errors!(
     /// When a member const comes after a member function.
     @formatted
     member_const_after_fun {
+        code: 20,
         args: (),
         msg: "Member functions must come after member consts.",
         help: None,
@@ -203,6 +224,7 @@ create_errors!(
     /// When a member variable comes after a member function.
     @formatted
     member_var_after_fun {
+        code: 21,
         args: (),
         msg: "Member functions must come after member variables.",
         help: None,
@@ -211,6 +233,7 @@ create_errors!(
     /// E.g., on `[u8; ()]`.
     @formatted
     array_tuple_dimensions_empty {
+        code: 22,
         args: (),
         msg: "Array dimensions specified as a tuple cannot be empty.",
         help: None,
@@ -219,6 +242,7 @@ create_errors!(
     /// When an empty input tendril was expected but not found.
     @backtraced
     lexer_empty_input_tendril {
+        code: 23,
         args: (),
         msg: "Expected more characters to lex but found none.",
         help: None,
@@ -227,6 +251,7 @@ create_errors!(
     /// When an integer is started with a leading zero.
     @backtraced
     lexer_expected_valid_escaped_char {
+    code: 24,
     args: (input: impl Display),
     msg: format!("Expected a valid escape character but found `{}`.", input),
     help: None,
@@ -235,6 +260,7 @@ create_errors!(
     /// When a string is not properly closed.
     @backtraced
     lexer_string_not_closed {
+    code: 25,
     args: (input: impl Display),
     msg: format!("Expected a closed string but found `{}`.", input),
     help: None,
@@ -243,6 +269,7 @@ create_errors!(
     /// When a string is not properly closed.
     @backtraced
     lexer_char_not_closed {
+    code: 26,
     args: (input: impl Display),
     msg: format!("Expected a closed char but found `{}`.", input),
     help: None,
@@ -251,6 +278,7 @@ create_errors!(
     /// When a string is not properly closed.
     @backtraced
     lexer_invalid_char {
+    code: 27,
     args: (input: impl Display),
     msg: format!("Expected valid character but found `{}`.", input),
     help: None,
@@ -259,6 +287,7 @@ create_errors!(
     /// When a block comment is empty.
     @backtraced
     lexer_empty_block_comment {
+    code: 28,
     args: (),
     msg: "Empty block comment.",
     help: None,
@@ -267,6 +296,7 @@ create_errors!(
     /// When a block comment is not closed before end of file.
     @backtraced
     lexer_block_comment_does_not_close_before_eof {
+    code: 29,
     args: (input: impl Display),
     msg: format!("Block comment does not close with content: `{}`.", input),
     help: None,
@@ -275,6 +305,7 @@ create_errors!(
     /// When the lexer could not lex some text.
     @backtraced
     could_not_lex {
+    code: 30,
     args: (input: impl Display),
     msg: format!("Could not lex the following content: `{}`.", input),
     help: None,
@@ -283,6 +314,7 @@ create_errors!(
     /// When a valid hex character was expected.
     @backtraced
     lexer_expected_valid_hex_char {
+    code: 31,
     args: (input: impl Display),
     msg: format!("Expected a valid hex character but found `{}`.", input),
     help: None,
@@ -291,6 +323,7 @@ create_errors!(
     /// When a escaped unicode char was given but no following closing symbol.
     @backtraced
     lexer_unclosed_escaped_unicode_char {
+        code: 32,
         args: (input: impl Display),
         msg: format!("There was no closing `}}` after a escaped unicode `{}`.", input),
         help: None,
@@ -299,6 +332,7 @@ create_errors!(
     /// When a escaped unicode char was given but it had an incorrect length. 38
     @backtraced
     lexer_invalid_escaped_unicode_length {
+        code: 33,
         args: (input: impl Display),
         msg: format!("The escaped unicode char `{}` is not within valid length of [1, 6].", input),
         help: None,
@@ -307,6 +341,7 @@ create_errors!(
     /// When a escaped unicode char was given but exceeded maximum value.
     @backtraced
     lexer_invalid_character_exceeded_max_value {
+        code: 34,
         args: (input: impl Display),
         msg: format!("The escaped unicode char `{}` is greater than 0x10FFFF.", input),
         help: None,
@@ -314,6 +349,7 @@ create_errors!(
     /// When a hex number is provided.
     @backtraced
     lexer_hex_number_provided {
+        code: 35,
         args: (input: impl Display),
         msg: format!("A hex number `{}..` was provided but hex is not allowed.", input),
         help: None,
@@ -322,6 +358,7 @@ create_errors!(
     /// When a function recieved a self argument outside the first argument.
     @backtraced
     parser_self_outside_first_argument {
+        code: 36,
         args: (),
         msg: "A function received a self argument as not the first argument.",
         help: None,
@@ -330,6 +367,7 @@ create_errors!(
     /// When a escaped unicode char was given but not opened.
     @backtraced
     lexer_unopened_escaped_unicode_char {
+        code: 37,
         args: (input: impl Display),
         msg: format!("There was no opening `{{` after starting an escaped unicode `{}`.", input),
         help: None,
@@ -338,6 +376,7 @@ create_errors!(
     /// For when a user puts parens around a single defined variable.
     @formatted
     invalid_parens_around_single_variable {
+        code: 38,
         args: (),
         msg: "do not put parens around single variable names",
         help: None,
@@ -346,6 +385,7 @@ create_errors!(
     /// For when a user puts parens around a single defined variable.
     @formatted
     invalid_parens_around_single_array_dimension_size {
+        code: 39,
         args: (),
         msg: "do not put parens around single dimension array size",
         help: None,
@@ -354,8 +394,18 @@ create_errors!(
     /// For when a user puts parens around a single defined variable.
     @backtraced
     lexer_expected_but_found {
+        code: 40,
         args: (found: impl Display, expected: impl Display),
         msg: format!("Found the char `{}`, but expected `{}`", found, expected),
         help: None,
     }
+
+    /// For when a function outside the standard library has no body.
+    @formatted
+    native_function_outside_stdlib {
+        code: 41,
+        args: (name: impl Display),
+        msg: format!("function `{}` has no body, which only native functions of the standard library may omit", name),
+        help: Some("Add a body to the function, e.g., `{ ... }`.".to_string()),
+    }
 );
@@ -0,0 +1,44 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::create_warnings;
+
+use std::fmt::Display;
+
+create_warnings!(
+    /// ParserWarning enum that represents all the warnings for the `leo-parser` crate.
+    ParserWarning,
+    exit_code_mask: 0000i32,
+    warning_code_prefix: "PAR",
+
+    /// For when the parser encountered an annotation which has no effect.
+    @formatted
+    unknown_annotation {
+        code: 0,
+        args: (name: impl Display),
+        msg: format!("unknown annotation `@{}` has no effect", name),
+        help: None,
+    }
+
+    /// For when an `@allow` annotation names a lint which does not exist.
+    @formatted
+    unknown_lint {
+        code: 1,
+        args: (name: impl Display),
+        msg: format!("unknown lint `{}`", name),
+        help: Some("Lints are named after warnings, e.g., `@allow(unknown_annotation)`.".to_string()),
+    }
+);
@@ -30,6 +30,7 @@ create_errors!(
     /// For when it cannot parse the state boolean value.
     @backtraced
     parse_bool_error {
+        code: 0,
         args: (error: impl ErrorArg),
         msg: format!("failed to parse state file bool: {}", error),
         help: None,
@@ -38,6 +39,7 @@ create_errors!(
     /// For when it cannot parse the state int value.
     @backtraced
     parse_int_error {
+        code: 1,
         args: (error: impl ErrorArg),
         msg: format!("failed to parse state file int: {}", error),
         help: None,
@@ -46,6 +48,7 @@ create_errors!(
     /// For when it expected an array of u8 bytes but found something else.
     @backtraced
     expected_bytes {
+        code: 2,
         args: (found: impl Display),
         msg: format!("expected parameter array of u8 bytes, found `{}`", found),
         help: None,
@@ -54,6 +57,7 @@ create_errors!(
     /// For when an int was expected but received something else.
     @backtraced
     expected_int {
+        code: 3,
         args: (found: impl Display),
         msg: format!("expected integer parameter, found `{}`", found),
         help: None,
@@ -62,6 +66,7 @@ create_errors!(
     /// For when it cannot parse the state int value.
     @backtraced
     missing_parameter {
+        code: 4,
         args: (parameter: impl Display),
         msg: format!("input parameter `{}` not found in state file", parameter),
         help: None,
@@ -70,6 +75,7 @@ create_errors!(
     /// For when the crate encounters an IO error.
     @backtraced
     state_io_error {
+        code: 5,
         args: (error: impl ErrorArg),
         msg: format!("io error found {}", error),
         help: None,
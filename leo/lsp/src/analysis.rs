@@ -0,0 +1,578 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The analysis of a Leo file, with its diagnostics and a table of the symbols it defines.
+//! The table records the byte offsets of each definition,
+//! so that it outlives the session in which the file was parsed.
+
+use leo_ast::{
+    Block, CircuitMember, DefinitionStatement, Expression, Function, FunctionInput, Identifier, ImportTree,
+    ImportTreeKind, Program, Statement, Type,
+};
+use leo_errors::emitter::{Diagnostic, Handler};
+use leo_span::{
+    sym,
+    symbol::{create_session_if_not_set_then, with_session_globals},
+    Span,
+};
+
+use std::ops::Range;
+
+/// How deep type aliases are followed to find the circuit they name.
+const MAX_ALIAS_DEPTH: usize = 16;
+
+/// The kind of a definition.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DefinitionKind {
+    /// An imported package or item.
+    Import,
+    /// A type alias.
+    Alias,
+    /// A global constant, or a constant of a circuit.
+    Constant,
+    /// A circuit.
+    Circuit,
+    /// A function, or a function of a circuit without `self`.
+    Function,
+    /// A variable of a circuit.
+    Field,
+    /// A function of a circuit with `self`.
+    Method,
+    /// A parameter of a function.
+    Parameter,
+    /// A variable defined in a function.
+    Variable,
+}
+
+/// A named definition of a file.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Definition {
+    /// The name, e.g., `double` for `function double(x: u32) -> u32 { ... }`.
+    pub name: String,
+    /// The kind of the definition.
+    pub kind: DefinitionKind,
+    /// The code shown when hovering the name, e.g., `function double(x: u32) -> u32`.
+    pub signature: String,
+    /// The byte offsets of the whole definition.
+    pub span: Range<usize>,
+    /// The byte offsets of the name.
+    pub name_span: Range<usize>,
+    /// The name of the circuit type of the definition, if it has one, e.g., `Point` for `let p: Point = ...;`.
+    /// This is used to find the members of a value after `.`.
+    pub circuit: Option<String>,
+    /// The members of a circuit.
+    pub members: Vec<Definition>,
+}
+
+/// A parameter or variable, visible within a part of a function.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Local {
+    /// The definition of the parameter or variable.
+    pub definition: Definition,
+    /// The byte offsets where the parameter or variable is visible,
+    /// i.e., from the end of its definition to the end of its block.
+    pub scope: Range<usize>,
+}
+
+/// How a name is accessed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Access {
+    /// A member of a value, e.g., `p.x`.
+    Member,
+    /// A constant or function of a circuit, e.g., `Point::new`.
+    Static,
+}
+
+/// The analysis of a Leo file.
+#[derive(Debug, Default)]
+pub struct Analysis {
+    /// The errors and warnings of the file.
+    pub diagnostics: Vec<Diagnostic>,
+    /// The imports, aliases, constants, circuits, and functions of the file, in the order they are defined.
+    /// The file must parse for these to be known.
+    pub definitions: Vec<Definition>,
+    /// The parameters and variables of the functions of the file.
+    pub locals: Vec<Local>,
+}
+
+impl Analysis {
+    /// Parses the `source` of the file at `path`, and returns its analysis.
+    pub fn new(path: &str, source: &str) -> Self {
+        create_session_if_not_set_then(|_| {
+            let (handler, buffer) = Handler::new_with_buf();
+            let program = handler.extend_if_error(leo_parser::parse(&handler, path, source)).ok();
+
+            let errors = buffer
+                .extract()
+                .into_inner()
+                .into_iter()
+                .map(|error| error.diagnostic());
+            let warnings = buffer
+                .extract_warnings()
+                .into_inner()
+                .into_iter()
+                .map(|warning| warning.diagnostic());
+            let mut analysis = Self {
+                diagnostics: errors.chain(warnings).collect(),
+                ..Self::default()
+            };
+            if let Some(program) = program {
+                analysis.collect_program(&program, source);
+            }
+            analysis
+        })
+    }
+
+    /// Records the definitions of `program`, whose source code is `source`.
+    fn collect_program(&mut self, program: &Program, source: &str) {
+        for import in &program.import_statements {
+            self.collect_import(&import.tree, "", offsets(&import.span));
+        }
+
+        for alias in program.aliases.values() {
+            self.definitions.push(Definition {
+                name: alias.name.name.to_string(),
+                kind: DefinitionKind::Alias,
+                signature: format!("type {} = {}", alias.name, type_string(&alias.represents)),
+                span: offsets(&alias.span),
+                name_span: offsets(&alias.name.span),
+                circuit: circuit_of_type(&alias.represents, None),
+                members: Vec::new(),
+            });
+        }
+
+        for definition in program.global_consts.values() {
+            let definitions = variables(definition, None);
+            self.definitions
+                .extend(definitions.into_iter().map(|definition| Definition {
+                    kind: DefinitionKind::Constant,
+                    ..definition
+                }));
+        }
+
+        for circuit in program.circuits.values() {
+            let name = circuit.circuit_name.name.to_string();
+            let name_span = offsets(&circuit.circuit_name.span);
+
+            let mut members = Vec::new();
+            for member in &circuit.members {
+                members.push(match member {
+                    CircuitMember::CircuitConst(identifier, type_, ..) => Definition {
+                        name: identifier.name.to_string(),
+                        kind: DefinitionKind::Constant,
+                        signature: format!("const {}: {}", identifier, type_string(type_)),
+                        span: offsets(&identifier.span),
+                        name_span: offsets(&identifier.span),
+                        circuit: circuit_of_type(type_, Some(&name)),
+                        members: Vec::new(),
+                    },
+                    CircuitMember::CircuitVariable(identifier, type_, _) => Definition {
+                        name: identifier.name.to_string(),
+                        kind: DefinitionKind::Field,
+                        signature: format!("{}: {}", identifier, type_string(type_)),
+                        span: offsets(&identifier.span),
+                        name_span: offsets(&identifier.span),
+                        circuit: circuit_of_type(type_, Some(&name)),
+                        members: Vec::new(),
+                    },
+                    CircuitMember::CircuitFunction(function) => self.collect_function(function, Some(&name)),
+                });
+            }
+
+            // The circuit has no span of its own, so it spans from its keyword to its closing brace.
+            let last = members
+                .iter()
+                .map(|member| member.span.end)
+                .max()
+                .unwrap_or(name_span.end);
+            let start = source[..name_span.start].rfind("circuit").unwrap_or(name_span.start);
+            let end = source[last..].find('}').map_or(last, |index| last + index + 1);
+            self.definitions.push(Definition {
+                signature: format!("circuit {}", name),
+                name,
+                kind: DefinitionKind::Circuit,
+                span: start..end,
+                name_span,
+                circuit: None,
+                members,
+            });
+        }
+
+        for function in program.functions.values() {
+            let definition = self.collect_function(function, None);
+            self.definitions.push(definition);
+        }
+    }
+
+    /// Records the imports of `tree`, whose path starts with `prefix`, in the import statement at `span`.
+    fn collect_import(&mut self, tree: &ImportTree, prefix: &str, span: Range<usize>) {
+        let path = tree
+            .base
+            .iter()
+            .map(|identifier| identifier.to_string())
+            .collect::<Vec<_>>()
+            .join(".");
+        let path = format!("{}{}", prefix, path);
+
+        match &tree.kind {
+            ImportTreeKind::Leaf { alias } => {
+                let (identifier, signature) = match alias {
+                    Some(alias) => (alias, format!("import {} as {}", path, alias)),
+                    None => (tree.base.last().unwrap(), format!("import {}", path)),
+                };
+                self.definitions.push(Definition {
+                    name: identifier.name.to_string(),
+                    kind: DefinitionKind::Import,
+                    signature,
+                    span,
+                    name_span: offsets(&identifier.span),
+                    circuit: None,
+                    members: Vec::new(),
+                });
+            }
+            ImportTreeKind::Nested { tree } => {
+                for tree in tree {
+                    self.collect_import(tree, &format!("{}.", path), span.clone());
+                }
+            }
+            // A glob import does not name what it imports.
+            ImportTreeKind::Glob { .. } => {}
+        }
+    }
+
+    /// Records the parameters and variables of `function`, defined in the circuit `circuit` if any,
+    /// and returns the definition of the function.
+    fn collect_function(&mut self, function: &Function, circuit: Option<&str>) -> Definition {
+        // The parameters of a native function are only in scope in its declaration.
+        let block = offsets(function.block.as_ref().map_or(&function.span, |block| &block.span));
+        for input in &function.input {
+            if let FunctionInput::Variable(variable) = input {
+                self.locals.push(Local {
+                    definition: Definition {
+                        name: variable.identifier.name.to_string(),
+                        kind: DefinitionKind::Parameter,
+                        signature: input_string(input),
+                        span: offsets(&variable.span),
+                        name_span: offsets(&variable.identifier.span),
+                        circuit: circuit_of_type(&variable.type_, circuit),
+                        members: Vec::new(),
+                    },
+                    scope: block.clone(),
+                });
+            }
+        }
+        if let Some(block) = &function.block {
+            self.collect_block(block, circuit);
+        }
+
+        let parameters = function.input.iter().map(input_string).collect::<Vec<_>>().join(", ");
+        let mut signature = format!("function {}({})", function.identifier, parameters);
+        if let Some(output) = &function.output {
+            signature.push_str(&format!(" -> {}", type_string(output)));
+        }
+
+        let kind = if circuit.is_some() && function.contains_self() {
+            DefinitionKind::Method
+        } else {
+            DefinitionKind::Function
+        };
+        Definition {
+            name: function.identifier.name.to_string(),
+            kind,
+            signature,
+            span: offsets(&function.span),
+            name_span: offsets(&function.identifier.span),
+            circuit: None,
+            members: Vec::new(),
+        }
+    }
+
+    /// Records the variables defined in `block`.
+    fn collect_block(&mut self, block: &Block, circuit: Option<&str>) {
+        let end = offsets(&block.span).end;
+        for statement in &block.statements {
+            self.collect_statement(statement, end, circuit);
+        }
+    }
+
+    /// Records the variables defined in `statement`, which are visible until `end`.
+    fn collect_statement(&mut self, statement: &Statement, end: usize, circuit: Option<&str>) {
+        match statement {
+            Statement::Definition(definition) => {
+                let start = offsets(&definition.span).end;
+                self.locals
+                    .extend(variables(definition, circuit).into_iter().map(|definition| Local {
+                        definition,
+                        scope: start..end,
+                    }));
+            }
+            Statement::Conditional(conditional) => {
+                self.collect_block(&conditional.block, circuit);
+                if let Some(next) = &conditional.next {
+                    self.collect_statement(next, end, circuit);
+                }
+            }
+            Statement::Iteration(iteration) => {
+                self.locals.push(Local {
+                    definition: Definition {
+                        name: iteration.variable.name.to_string(),
+                        kind: DefinitionKind::Variable,
+                        signature: format!(
+                            "for {} in {}..{}{}",
+                            iteration.variable,
+                            iteration.start,
+                            if iteration.inclusive { "=" } else { "" },
+                            iteration.stop
+                        ),
+                        span: offsets(&iteration.variable.span),
+                        name_span: offsets(&iteration.variable.span),
+                        circuit: None,
+                        members: Vec::new(),
+                    },
+                    scope: offsets(&iteration.block.span),
+                });
+                self.collect_block(&iteration.block, circuit);
+            }
+            Statement::Block(block) => self.collect_block(block, circuit),
+            Statement::Return(_) | Statement::Assign(_) | Statement::Console(_) | Statement::Expression(_) => {}
+        }
+    }
+
+    /// Returns the definition of the name at `offset` in `source`, and the byte offsets of the name.
+    pub fn definition_at(&self, source: &str, offset: usize) -> Option<(&Definition, Range<usize>)> {
+        let word = word_at(source, offset)?;
+        let name = &source[word.clone()];
+
+        // The names within an import are only defined by the import itself.
+        if let Some(import) = self
+            .definitions
+            .iter()
+            .find(|definition| definition.kind == DefinitionKind::Import && definition.span.contains(&offset))
+        {
+            return import
+                .name_span
+                .contains(&offset)
+                .then(|| (import, import.name_span.clone()));
+        }
+
+        let definition = match access_before(source, word.start) {
+            Some((access, receiver)) => self
+                .members(access, receiver, offset)
+                .into_iter()
+                .find(|member| member.name == name)?,
+            None => self.resolve(name, offset)?,
+        };
+        Some((definition, word))
+    }
+
+    /// Returns the definitions which may complete the name being typed at `offset` in `source`.
+    /// After `.` and `::`, these are the members of the circuit before them.
+    pub fn completions(&self, source: &str, offset: usize) -> Vec<&Definition> {
+        let offset = offset.min(source.len());
+        if let Some((access, receiver)) = access_before(source, word_start(source, offset)) {
+            return self.members(access, receiver, offset);
+        }
+
+        let mut completions: Vec<&Definition> = Vec::new();
+        for local in self.locals.iter().filter(|local| local.scope.contains(&offset)) {
+            // A variable shadows the earlier ones with the same name.
+            completions.retain(|completion| completion.name != local.definition.name);
+            completions.push(&local.definition);
+        }
+        completions.extend(&self.definitions);
+        completions
+    }
+
+    /// Returns the definition that `name` refers to at `offset`.
+    fn resolve(&self, name: &str, offset: usize) -> Option<&Definition> {
+        let local = self
+            .locals
+            .iter()
+            .filter(|local| local.definition.name == name && local.scope.contains(&offset))
+            .max_by_key(|local| local.definition.name_span.start);
+        match local {
+            Some(local) => Some(&local.definition),
+            None => self.definitions.iter().find(|definition| definition.name == name),
+        }
+    }
+
+    /// Returns the members accessible with `access` on the `receiver` at `offset`.
+    fn members(&self, access: Access, receiver: &str, offset: usize) -> Vec<&Definition> {
+        let circuit = match receiver {
+            "self" | "Self" => self
+                .definitions
+                .iter()
+                .find(|definition| definition.kind == DefinitionKind::Circuit && definition.span.contains(&offset)),
+            _ => self
+                .resolve(receiver, offset)
+                .and_then(|definition| self.circuit_of(definition, 0)),
+        };
+
+        let kinds: &[DefinitionKind] = match access {
+            Access::Member => &[DefinitionKind::Field, DefinitionKind::Method],
+            Access::Static => &[DefinitionKind::Constant, DefinitionKind::Function],
+        };
+        circuit
+            .into_iter()
+            .flat_map(|circuit| &circuit.members)
+            .filter(|member| kinds.contains(&member.kind))
+            .collect()
+    }
+
+    /// Returns the circuit that `definition` is, or has the type of.
+    fn circuit_of<'a>(&'a self, definition: &'a Definition, depth: usize) -> Option<&'a Definition> {
+        if definition.kind == DefinitionKind::Circuit {
+            return Some(definition);
+        }
+        if depth > MAX_ALIAS_DEPTH {
+            return None;
+        }
+
+        let name = definition.circuit.as_ref()?;
+        let definition = self.definitions.iter().find(|definition| {
+            &definition.name == name && matches!(definition.kind, DefinitionKind::Circuit | DefinitionKind::Alias)
+        })?;
+        self.circuit_of(definition, depth + 1)
+    }
+}
+
+/// Returns the byte offsets of `span` into its file.
+fn offsets(span: &Span) -> Range<usize> {
+    with_session_globals(|s| s.source_map.find_source_file(span.lo))
+        .map(|file| (span.lo - file.start_pos).to_usize()..(span.hi - file.start_pos).to_usize())
+        .unwrap_or_default()
+}
+
+/// Returns `type_` as it is written in Leo, e.g., `Point` rather than `circuit Point`.
+fn type_string(type_: &Type) -> String {
+    match type_ {
+        Type::Identifier(identifier) => identifier.to_string(),
+        Type::SelfType => "Self".to_string(),
+        Type::Array(element, dimensions) => format!("[{}; {}]", type_string(element), dimensions),
+        Type::Tuple(types) => format!("({})", types.iter().map(type_string).collect::<Vec<_>>().join(", ")),
+        type_ => type_.to_string(),
+    }
+}
+
+/// Returns the parameter `input` as it is written in Leo.
+fn input_string(input: &FunctionInput) -> String {
+    match input {
+        FunctionInput::Variable(variable) if variable.const_ => {
+            format!("const {}: {}", variable.identifier, type_string(&variable.type_))
+        }
+        FunctionInput::Variable(variable) => format!("{}: {}", variable.identifier, type_string(&variable.type_)),
+        input => input.to_string(),
+    }
+}
+
+/// Returns the name of the circuit named `identifier`, in the circuit `circuit` if any.
+fn circuit_of_name(identifier: &Identifier, circuit: Option<&str>) -> Option<String> {
+    if identifier.name == sym::SelfUpper {
+        circuit.map(str::to_string)
+    } else {
+        Some(identifier.name.to_string())
+    }
+}
+
+/// Returns the name of the circuit of `type_`, if it is one, in the circuit `circuit` if any.
+/// Aliases are resolved later, when looking up the circuit.
+fn circuit_of_type(type_: &Type, circuit: Option<&str>) -> Option<String> {
+    match type_ {
+        Type::Identifier(identifier) => circuit_of_name(identifier, circuit),
+        Type::SelfType => circuit.map(str::to_string),
+        _ => None,
+    }
+}
+
+/// Returns the definitions of the variables of `definition`, in the circuit `circuit` if any.
+fn variables(definition: &DefinitionStatement, circuit: Option<&str>) -> Vec<Definition> {
+    let count = definition.variable_names.len();
+    definition
+        .variable_names
+        .iter()
+        .enumerate()
+        .map(|(index, variable)| {
+            // The type of each variable of a tuple is the matching element of the tuple type.
+            let type_ = match &definition.type_ {
+                Some(Type::Tuple(types)) if count > 1 => types.get(index),
+                type_ => type_.as_ref(),
+            };
+            let mut signature = format!("{} {}", definition.declaration_type, variable.identifier);
+            if let Some(type_) = type_ {
+                signature.push_str(&format!(": {}", type_string(type_)));
+            }
+
+            let circuit = match (type_, &definition.value) {
+                (Some(type_), _) => circuit_of_type(type_, circuit),
+                (None, Expression::CircuitInit(init)) if count == 1 => circuit_of_name(&init.name, circuit),
+                _ => None,
+            };
+
+            Definition {
+                name: variable.identifier.name.to_string(),
+                kind: DefinitionKind::Variable,
+                signature,
+                span: offsets(&definition.span),
+                name_span: offsets(&variable.identifier.span),
+                circuit,
+                members: Vec::new(),
+            }
+        })
+        .collect()
+}
+
+/// Returns whether `c` may be part of an identifier.
+fn is_identifier_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || c == '_'
+}
+
+/// Returns the byte offset of the start of the identifier ending at `offset` in `source`.
+fn word_start(source: &str, offset: usize) -> usize {
+    source[..offset]
+        .char_indices()
+        .rev()
+        .take_while(|(_, c)| is_identifier_char(*c))
+        .last()
+        .map_or(offset, |(index, _)| index)
+}
+
+/// Returns the byte offsets of the identifier at `offset` in `source`, if any.
+fn word_at(source: &str, offset: usize) -> Option<Range<usize>> {
+    let offset = offset.min(source.len());
+    let start = word_start(source, offset);
+    let end = source[offset..]
+        .char_indices()
+        .find(|(_, c)| !is_identifier_char(*c))
+        .map_or(source.len(), |(index, _)| offset + index);
+    (start < end).then(|| start..end)
+}
+
+/// Returns how the name starting at `start` in `source` is accessed, and the name of the receiver,
+/// e.g., `Access::Static` and `Point` for `Point::new`.
+fn access_before(source: &str, start: usize) -> Option<(Access, &str)> {
+    let before = source[..start].trim_end();
+    let (access, before) = if let Some(before) = before.strip_suffix("::") {
+        (Access::Static, before)
+    } else {
+        // A range `0..n` is not a member access.
+        let before = before.strip_suffix('.').filter(|before| !before.ends_with('.'))?;
+        (Access::Member, before)
+    };
+
+    let before = before.trim_end();
+    let receiver = word_at(before, before.len())?;
+    Some((access, &before[receiver]))
+}
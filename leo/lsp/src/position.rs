@@ -0,0 +1,91 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Conversions between byte offsets into a file and the positions of the Language Server Protocol,
+//! whose columns count UTF-16 code units.
+
+use lsp_types::{Position, Range};
+
+/// Returns the byte offset of the start of each line of `text`.
+fn line_starts(text: &str) -> impl Iterator<Item = usize> + '_ {
+    std::iter::once(0).chain(text.match_indices('\n').map(|(index, _)| index + 1))
+}
+
+/// Returns the position of the byte `offset` into `text`.
+pub fn position(text: &str, offset: usize) -> Position {
+    let offset = offset.min(text.len());
+    let (line, start) = line_starts(text)
+        .enumerate()
+        .take_while(|(_, start)| *start <= offset)
+        .last()
+        .unwrap_or((0, 0));
+    let character = text[start..offset].encode_utf16().count();
+    Position::new(line as u32, character as u32)
+}
+
+/// Returns the range of the bytes `span` of `text`.
+pub fn range(text: &str, span: std::ops::Range<usize>) -> Range {
+    Range::new(position(text, span.start), position(text, span.end))
+}
+
+/// Returns the byte offset of `position` in `text`.
+/// Positions past the end of their line are at the end of the line.
+pub fn offset(text: &str, position: Position) -> usize {
+    let start = match line_starts(text).nth(position.line as usize) {
+        Some(start) => start,
+        None => return text.len(),
+    };
+    let line = text[start..].split('\n').next().unwrap_or_default();
+
+    let mut units = 0;
+    for (index, c) in line.char_indices() {
+        if units >= position.character as usize {
+            return start + index;
+        }
+        units += c.len_utf16();
+    }
+    start + line.len()
+}
+
+/// Returns the byte offset of the `line` and `col`umn of a diagnostic, both starting at 1.
+/// Columns count bytes, as in a `SpanLocation`.
+pub fn line_col_offset(text: &str, line: usize, col: usize) -> usize {
+    match line_starts(text).nth(line.saturating_sub(1)) {
+        Some(start) => (start + col.saturating_sub(1)).min(text.len()),
+        None => text.len(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn positions_count_utf16_code_units() {
+        let text = "let a = 'é';\nlet b = '😀'; // b\n";
+        let b = text.find('b').unwrap();
+        assert_eq!(position(text, b), Position::new(1, 4));
+        assert_eq!(offset(text, Position::new(1, 4)), b);
+
+        let comment = text.find("//").unwrap();
+        assert_eq!(position(text, comment), Position::new(1, 14));
+        assert_eq!(offset(text, Position::new(1, 14)), comment);
+
+        assert_eq!(offset(text, Position::new(0, 100)), text.find('\n').unwrap());
+        assert_eq!(offset(text, Position::new(5, 0)), text.len());
+        assert_eq!(line_col_offset(text, 2, 5), b);
+    }
+}
@@ -0,0 +1,373 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The language server, which answers the requests of an editor about the Leo files it opened.
+
+use crate::{position, Analysis, Definition, DefinitionKind};
+
+use leo_errors::{
+    emitter::{Diagnostic, DiagnosticSpan, Handler},
+    Severity,
+};
+use leo_package::root::Manifest;
+use leo_parser::{format_program, FormatOptions, DEFAULT_MAX_WIDTH};
+
+use lsp_server::{Connection, ErrorCode, Message, Notification, Request, Response};
+use lsp_types::{
+    notification::{
+        DidChangeTextDocument, DidCloseTextDocument, DidOpenTextDocument, DidSaveTextDocument,
+        Notification as LspNotification, PublishDiagnostics,
+    },
+    request::{Completion, DocumentSymbolRequest, Formatting, GotoDefinition, HoverRequest, Request as LspRequest},
+    CompletionItem, CompletionItemKind, CompletionOptions, CompletionParams, CompletionResponse,
+    DiagnosticRelatedInformation, DiagnosticSeverity, DocumentFormattingParams, DocumentSymbol, DocumentSymbolParams,
+    DocumentSymbolResponse, GotoDefinitionParams, GotoDefinitionResponse, Hover, HoverContents, HoverParams,
+    HoverProviderCapability, Location, MarkupContent, MarkupKind, NumberOrString, OneOf, PublishDiagnosticsParams,
+    ServerCapabilities, SymbolKind, TextDocumentPositionParams, TextDocumentSyncCapability, TextDocumentSyncKind,
+    TextDocumentSyncOptions, TextDocumentSyncSaveOptions, TextEdit, Url,
+};
+use std::{collections::HashMap, error::Error};
+
+/// The result of running the language server.
+pub type ServerResult<T> = Result<T, Box<dyn Error + Send + Sync>>;
+
+/// Returns the features of the language server.
+pub fn capabilities() -> ServerCapabilities {
+    ServerCapabilities {
+        text_document_sync: Some(TextDocumentSyncCapability::Options(TextDocumentSyncOptions {
+            open_close: Some(true),
+            change: Some(TextDocumentSyncKind::FULL),
+            save: Some(TextDocumentSyncSaveOptions::Supported(true)),
+            ..Default::default()
+        })),
+        hover_provider: Some(HoverProviderCapability::Simple(true)),
+        definition_provider: Some(OneOf::Left(true)),
+        document_symbol_provider: Some(OneOf::Left(true)),
+        completion_provider: Some(CompletionOptions {
+            trigger_characters: Some(vec![".".to_string(), ":".to_string()]),
+            ..Default::default()
+        }),
+        document_formatting_provider: Some(OneOf::Left(true)),
+        ..Default::default()
+    }
+}
+
+/// Runs the language server on `connection` until the editor shuts it down.
+pub fn run(connection: Connection) -> ServerResult<()> {
+    connection.initialize(serde_json::to_value(capabilities())?)?;
+
+    let mut server = Server::default();
+    for message in &connection.receiver {
+        match message {
+            Message::Request(request) => {
+                if connection.handle_shutdown(&request)? {
+                    break;
+                }
+                connection.sender.send(server.handle_request(request).into())?;
+            }
+            Message::Notification(notification) => {
+                if let Some(notification) = server.handle_notification(notification) {
+                    connection.sender.send(notification.into())?;
+                }
+            }
+            // The server sends no requests, so it expects no responses.
+            Message::Response(_) => {}
+        }
+    }
+    Ok(())
+}
+
+/// The state of the language server.
+#[derive(Debug, Default)]
+pub struct Server {
+    /// The text of each file opened in the editor.
+    documents: HashMap<Url, String>,
+}
+
+impl Server {
+    /// Returns the response to `request`.
+    pub fn handle_request(&self, request: Request) -> Response {
+        match request.method.as_str() {
+            HoverRequest::METHOD => self.respond::<HoverRequest>(request, Self::hover),
+            GotoDefinition::METHOD => self.respond::<GotoDefinition>(request, Self::definition),
+            DocumentSymbolRequest::METHOD => self.respond::<DocumentSymbolRequest>(request, Self::document_symbols),
+            Completion::METHOD => self.respond::<Completion>(request, Self::completion),
+            Formatting::METHOD => self.respond::<Formatting>(request, Self::formatting),
+            _ => Response::new_err(
+                request.id,
+                ErrorCode::MethodNotFound as i32,
+                format!("unknown request `{}`", request.method),
+            ),
+        }
+    }
+
+    /// Updates the open files with `notification`,
+    /// and returns the diagnostics to publish because of it, if any.
+    pub fn handle_notification(&mut self, notification: Notification) -> Option<Notification> {
+        match notification.method.as_str() {
+            DidOpenTextDocument::METHOD => {
+                let params = params::<DidOpenTextDocument>(notification)?;
+                let uri = params.text_document.uri;
+                self.documents.insert(uri.clone(), params.text_document.text);
+                Some(self.publish_diagnostics(uri))
+            }
+            DidChangeTextDocument::METHOD => {
+                let params = params::<DidChangeTextDocument>(notification)?;
+                // The files are synchronized in full, so the last change is the whole text.
+                let text = params.content_changes.into_iter().last()?.text;
+                self.documents.insert(params.text_document.uri, text);
+                None
+            }
+            DidSaveTextDocument::METHOD => {
+                let params = params::<DidSaveTextDocument>(notification)?;
+                let uri = params.text_document.uri;
+                if let Some(text) = params.text {
+                    self.documents.insert(uri.clone(), text);
+                }
+                Some(self.publish_diagnostics(uri))
+            }
+            DidCloseTextDocument::METHOD => {
+                let params = params::<DidCloseTextDocument>(notification)?;
+                let uri = params.text_document.uri;
+                self.documents.remove(&uri);
+                // Clears the diagnostics of the closed file.
+                Some(Notification::new(
+                    PublishDiagnostics::METHOD.to_string(),
+                    PublishDiagnosticsParams::new(uri, Vec::new(), None),
+                ))
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns the response to `request` of type `R`, handled by `handle`.
+    fn respond<R: LspRequest>(&self, request: Request, handle: impl FnOnce(&Self, R::Params) -> R::Result) -> Response {
+        let id = request.id.clone();
+        match request.extract::<R::Params>(R::METHOD) {
+            Ok((id, params)) => Response::new_ok(id, handle(self, params)),
+            Err(error) => Response::new_err(id, ErrorCode::InvalidParams as i32, error.to_string()),
+        }
+    }
+
+    /// Returns the text and the analysis of the open file at `uri`.
+    fn analyze(&self, uri: &Url) -> Option<(&str, Analysis)> {
+        let text = self.documents.get(uri)?;
+        Some((text, Analysis::new(&path_of(uri), text)))
+    }
+
+    /// Returns the definition of the name at `params`, and the range of the name.
+    fn definition_at(&self, params: &TextDocumentPositionParams) -> Option<(&str, Definition, lsp_types::Range)> {
+        let (text, analysis) = self.analyze(&params.text_document.uri)?;
+        let offset = position::offset(text, params.position);
+        let (definition, name) = analysis.definition_at(text, offset)?;
+        Some((text, definition.clone(), position::range(text, name)))
+    }
+
+    /// Shows the signature of the name being hovered.
+    fn hover(&self, params: HoverParams) -> Option<Hover> {
+        let (_, definition, range) = self.definition_at(&params.text_document_position_params)?;
+        Some(Hover {
+            contents: HoverContents::Markup(MarkupContent {
+                kind: MarkupKind::Markdown,
+                value: format!("```leo\n{}\n```", definition.signature),
+            }),
+            range: Some(range),
+        })
+    }
+
+    /// Returns the location of the definition of a name.
+    fn definition(&self, params: GotoDefinitionParams) -> Option<GotoDefinitionResponse> {
+        let params = params.text_document_position_params;
+        let (text, definition, _) = self.definition_at(&params)?;
+        Some(GotoDefinitionResponse::Scalar(Location::new(
+            params.text_document.uri,
+            position::range(text, definition.name_span),
+        )))
+    }
+
+    /// Returns the outline of a file.
+    fn document_symbols(&self, params: DocumentSymbolParams) -> Option<DocumentSymbolResponse> {
+        let (text, analysis) = self.analyze(&params.text_document.uri)?;
+        Some(DocumentSymbolResponse::Nested(
+            analysis
+                .definitions
+                .iter()
+                .map(|definition| document_symbol(text, definition))
+                .collect(),
+        ))
+    }
+
+    /// Returns the names which may complete the name being typed,
+    /// e.g., the members of a circuit after `.` or `::`.
+    fn completion(&self, params: CompletionParams) -> Option<CompletionResponse> {
+        let params = params.text_document_position;
+        let (text, analysis) = self.analyze(&params.text_document.uri)?;
+        let offset = position::offset(text, params.position);
+        let items = analysis
+            .completions(text, offset)
+            .into_iter()
+            .map(|definition| CompletionItem {
+                label: definition.name.clone(),
+                kind: Some(completion_kind(definition.kind)),
+                detail: Some(definition.signature.clone()),
+                ..Default::default()
+            })
+            .collect();
+        Some(CompletionResponse::Array(items))
+    }
+
+    /// Returns the edit formatting a file as `leo fmt` does, if it has no syntax errors.
+    fn formatting(&self, params: DocumentFormattingParams) -> Option<Vec<TextEdit>> {
+        let uri = &params.text_document.uri;
+        let text = self.documents.get(uri)?;
+        let options = FormatOptions {
+            max_width: manifest_max_width(uri).unwrap_or(DEFAULT_MAX_WIDTH),
+        };
+
+        // The syntax errors are already published as diagnostics.
+        let (handler, _) = Handler::new_with_buf();
+        let formatted = format_program(&handler, &path_of(uri), text, &options).ok()?;
+        if &formatted == text {
+            return Some(Vec::new());
+        }
+        Some(vec![TextEdit::new(position::range(text, 0..text.len()), formatted)])
+    }
+
+    /// Returns the notification publishing the diagnostics of the open file at `uri`.
+    fn publish_diagnostics(&self, uri: Url) -> Notification {
+        let diagnostics = match self.analyze(&uri) {
+            Some((text, analysis)) => analysis
+                .diagnostics
+                .iter()
+                .map(|diagnostic| lsp_diagnostic(&uri, text, diagnostic))
+                .collect(),
+            None => Vec::new(),
+        };
+        Notification::new(
+            PublishDiagnostics::METHOD.to_string(),
+            PublishDiagnosticsParams::new(uri, diagnostics, None),
+        )
+    }
+}
+
+/// Returns the parameters of `notification` of type `N`.
+fn params<N: LspNotification>(notification: Notification) -> Option<N::Params> {
+    notification.extract(N::METHOD).ok()
+}
+
+/// Returns the path of the file at `uri`, as shown in diagnostics.
+fn path_of(uri: &Url) -> String {
+    match uri.to_file_path() {
+        Ok(path) => path.to_string_lossy().to_string(),
+        Err(()) => uri.to_string(),
+    }
+}
+
+/// Returns the maximum width of a line set in the `[fmt]` section of the manifest of the package of the file at `uri`.
+fn manifest_max_width(uri: &Url) -> Option<usize> {
+    let path = uri.to_file_path().ok()?;
+    let package = path.ancestors().skip(1).find(|path| Manifest::exists_at(path))?;
+    Manifest::try_from(package).ok()?.get_fmt_max_width()
+}
+
+/// Returns the Language Server Protocol diagnostic of `diagnostic` in the file at `uri` with the `text`.
+fn lsp_diagnostic(uri: &Url, text: &str, diagnostic: &Diagnostic) -> lsp_types::Diagnostic {
+    let range = |span: &DiagnosticSpan| {
+        let start = position::line_col_offset(text, span.line_start, span.col_start);
+        let stop = position::line_col_offset(text, span.line_stop, span.col_stop);
+        position::range(text, start..stop)
+    };
+
+    let mut message = diagnostic.message.clone();
+    if let Some(help) = &diagnostic.help {
+        message.push_str(&format!("\nhelp: {}", help));
+    }
+    for note in &diagnostic.notes {
+        message.push_str(&format!("\nnote: {}", note));
+    }
+
+    let related_information = diagnostic
+        .labels
+        .iter()
+        .map(|label| DiagnosticRelatedInformation {
+            location: Location::new(uri.clone(), range(&label.span)),
+            message: label.message.clone(),
+        })
+        .collect::<Vec<_>>();
+
+    lsp_types::Diagnostic {
+        range: diagnostic.span.as_ref().map(range).unwrap_or_default(),
+        severity: Some(match diagnostic.severity {
+            Severity::Warning => DiagnosticSeverity::WARNING,
+            Severity::Error | Severity::DeniedWarning => DiagnosticSeverity::ERROR,
+        }),
+        code: Some(NumberOrString::String(diagnostic.error_code.clone())),
+        source: Some("leo".to_string()),
+        message,
+        related_information: (!related_information.is_empty()).then(|| related_information),
+        ..Default::default()
+    }
+}
+
+/// Returns the symbol of `definition` in the outline of a file with the `text`.
+// `DocumentSymbol::deprecated` is superseded by `tags`, but must still be set.
+#[allow(deprecated)]
+fn document_symbol(text: &str, definition: &Definition) -> DocumentSymbol {
+    let children = definition
+        .members
+        .iter()
+        .map(|member| document_symbol(text, member))
+        .collect::<Vec<_>>();
+
+    DocumentSymbol {
+        name: definition.name.clone(),
+        detail: Some(definition.signature.clone()),
+        kind: symbol_kind(definition.kind),
+        tags: None,
+        deprecated: None,
+        range: position::range(text, definition.span.clone()),
+        selection_range: position::range(text, definition.name_span.clone()),
+        children: (!children.is_empty()).then(|| children),
+    }
+}
+
+/// Returns the kind of the symbol of a definition of `kind`.
+fn symbol_kind(kind: DefinitionKind) -> SymbolKind {
+    match kind {
+        DefinitionKind::Import => SymbolKind::MODULE,
+        DefinitionKind::Alias => SymbolKind::TYPE_PARAMETER,
+        DefinitionKind::Constant => SymbolKind::CONSTANT,
+        DefinitionKind::Circuit => SymbolKind::STRUCT,
+        DefinitionKind::Function => SymbolKind::FUNCTION,
+        DefinitionKind::Field => SymbolKind::FIELD,
+        DefinitionKind::Method => SymbolKind::METHOD,
+        DefinitionKind::Parameter | DefinitionKind::Variable => SymbolKind::VARIABLE,
+    }
+}
+
+/// Returns the kind of the completion of a definition of `kind`.
+fn completion_kind(kind: DefinitionKind) -> CompletionItemKind {
+    match kind {
+        DefinitionKind::Import => CompletionItemKind::MODULE,
+        DefinitionKind::Alias => CompletionItemKind::TYPE_PARAMETER,
+        DefinitionKind::Constant => CompletionItemKind::CONSTANT,
+        DefinitionKind::Circuit => CompletionItemKind::STRUCT,
+        DefinitionKind::Function => CompletionItemKind::FUNCTION,
+        DefinitionKind::Field => CompletionItemKind::FIELD,
+        DefinitionKind::Method => CompletionItemKind::METHOD,
+        DefinitionKind::Parameter | DefinitionKind::Variable => CompletionItemKind::VARIABLE,
+    }
+}
@@ -0,0 +1,325 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Tests of the `leo-lsp` binary, driven over stdio by a scripted client.
+
+use lsp_server::{Message, Notification, Request, RequestId, Response};
+use serde_json::{json, Value};
+use std::{
+    io::BufReader,
+    process::{Child, ChildStdin, ChildStdout, Command, Stdio},
+};
+
+/// The URI of the file opened by the tests.
+const URI: &str = "file:///leo-lsp-test/src/main.leo";
+
+/// A program using circuits, aliases, constants, and imports.
+const PROGRAM: &str = "import core.unstable.blake2s.Blake2s;
+
+type Meters = Point;
+
+const ORIGIN: u32 = 0;
+
+circuit Point {
+    static const ZERO: u32 = 0;
+    x: u32,
+    y: u32,
+
+    function new(x: u32, y: u32) -> Self {
+        return Self { x, y };
+    }
+
+    function sum(self) -> u32 {
+        return self.x + self.y;
+    }
+}
+
+function main(a: u32) -> u32 {
+    let p = Point::new(a, ORIGIN);
+    let q: Meters = Point { x: 1, y: 2 };
+    return q.sum() + p.x;
+}
+";
+
+/// A client playing the role of an editor.
+struct Client {
+    /// The `leo-lsp` process.
+    server: Child,
+    /// The standard input of the server, which the client writes its messages to.
+    stdin: ChildStdin,
+    /// The standard output of the server, which the client reads its messages from.
+    stdout: BufReader<ChildStdout>,
+    /// The ID of the next request.
+    next_id: i32,
+    /// The notifications received while waiting for a response.
+    notifications: Vec<Notification>,
+}
+
+impl Client {
+    /// Starts and initializes a server.
+    fn start() -> Self {
+        let mut server = Command::new(env!("CARGO_BIN_EXE_leo-lsp"))
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .spawn()
+            .expect("failed to start leo-lsp");
+        let stdin = server.stdin.take().unwrap();
+        let stdout = BufReader::new(server.stdout.take().unwrap());
+
+        let mut client = Self {
+            server,
+            stdin,
+            stdout,
+            next_id: 0,
+            notifications: Vec::new(),
+        };
+        let initialized = client.request("initialize", json!({ "capabilities": {} }));
+        assert!(initialized["capabilities"]["hoverProvider"].as_bool().unwrap());
+        client.notify("initialized", json!({}));
+        client
+    }
+
+    /// Sends `message` to the server.
+    fn send(&mut self, message: Message) {
+        message.write(&mut self.stdin).expect("failed to send a message");
+    }
+
+    /// Returns the next message of the server.
+    fn receive(&mut self) -> Message {
+        Message::read(&mut self.stdout)
+            .expect("failed to receive a message")
+            .expect("the server closed its output")
+    }
+
+    /// Sends the request `method` with `params`, and returns the result of its response.
+    fn request(&mut self, method: &str, params: Value) -> Value {
+        let id = RequestId::from(self.next_id);
+        self.next_id += 1;
+        self.send(Request::new(id.clone(), method.to_string(), params).into());
+
+        loop {
+            match self.receive() {
+                Message::Response(Response {
+                    id: response_id,
+                    result,
+                    error,
+                }) if response_id == id => {
+                    assert!(error.is_none(), "`{}` failed: {:?}", method, error);
+                    return result.unwrap_or(Value::Null);
+                }
+                Message::Notification(notification) => self.notifications.push(notification),
+                message => panic!("unexpected message {:?}", message),
+            }
+        }
+    }
+
+    /// Sends the notification `method` with `params`.
+    fn notify(&mut self, method: &str, params: Value) {
+        self.send(Notification::new(method.to_string(), params).into());
+    }
+
+    /// Returns the next diagnostics published by the server.
+    fn diagnostics(&mut self) -> Value {
+        loop {
+            let notification = match self.notifications.pop() {
+                Some(notification) => notification,
+                None => match self.receive() {
+                    Message::Notification(notification) => notification,
+                    message => panic!("unexpected message {:?}", message),
+                },
+            };
+            if notification.method == "textDocument/publishDiagnostics" {
+                assert_eq!(notification.params["uri"], URI);
+                return notification.params["diagnostics"].clone();
+            }
+        }
+    }
+
+    /// Opens the file at `URI` with the `text`, and returns its diagnostics.
+    fn open(&mut self, text: &str) -> Value {
+        self.notify(
+            "textDocument/didOpen",
+            json!({ "textDocument": { "uri": URI, "languageId": "leo", "version": 0, "text": text } }),
+        );
+        self.diagnostics()
+    }
+
+    /// Sends the request `method` about the `position` in the file at `URI`.
+    fn request_at(&mut self, method: &str, (line, character): (u32, u32)) -> Value {
+        self.request(
+            method,
+            json!({
+                "textDocument": { "uri": URI },
+                "position": { "line": line, "character": character },
+            }),
+        )
+    }
+
+    /// Returns the labels of the completions at `position`.
+    fn completions(&mut self, position: (u32, u32)) -> Vec<String> {
+        let completions = self.request_at("textDocument/completion", position);
+        completions
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|item| item["label"].as_str().unwrap().to_string())
+            .collect()
+    }
+
+    /// Shuts the server down, and checks that it exits successfully.
+    fn shutdown(mut self) {
+        assert_eq!(self.request("shutdown", Value::Null), Value::Null);
+        self.notify("exit", Value::Null);
+        assert!(self.server.wait().unwrap().success());
+    }
+}
+
+/// Returns the range from `start` to `end`, as lines and characters.
+fn range(start: (u32, u32), end: (u32, u32)) -> Value {
+    json!({
+        "start": { "line": start.0, "character": start.1 },
+        "end": { "line": end.0, "character": end.1 },
+    })
+}
+
+#[test]
+fn diagnostics_are_published_on_open_and_save() {
+    let mut client = Client::start();
+
+    let diagnostics = client.open("function main() {\n    let a = 1u8\n}\n");
+    let diagnostics = diagnostics.as_array().unwrap();
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0]["severity"], 1);
+    assert_eq!(diagnostics[0]["source"], "leo");
+    assert!(diagnostics[0]["code"].as_str().unwrap().starts_with("EPAR"));
+    assert_eq!(diagnostics[0]["range"]["start"]["line"], 2);
+
+    // Changes are only checked once they are saved.
+    client.notify(
+        "textDocument/didChange",
+        json!({
+            "textDocument": { "uri": URI, "version": 1 },
+            "contentChanges": [{ "text": PROGRAM }],
+        }),
+    );
+    client.notify("textDocument/didSave", json!({ "textDocument": { "uri": URI } }));
+    assert_eq!(client.diagnostics(), json!([]));
+
+    client.notify("textDocument/didClose", json!({ "textDocument": { "uri": URI } }));
+    assert_eq!(client.diagnostics(), json!([]));
+    client.shutdown();
+}
+
+#[test]
+fn names_are_resolved() {
+    let mut client = Client::start();
+    assert_eq!(client.open(PROGRAM), json!([]));
+
+    // `Point` in `Point::new(a, ORIGIN)`.
+    let definition = client.request_at("textDocument/definition", (21, 14));
+    assert_eq!(definition, json!({ "uri": URI, "range": range((6, 8), (6, 13)) }));
+
+    // `new` in `Point::new(a, ORIGIN)`.
+    let definition = client.request_at("textDocument/definition", (21, 19));
+    assert_eq!(definition["range"], range((11, 13), (11, 16)));
+
+    // `ORIGIN` in `Point::new(a, ORIGIN)`.
+    let definition = client.request_at("textDocument/definition", (21, 27));
+    assert_eq!(definition["range"], range((4, 6), (4, 12)));
+
+    // `Meters` in `let q: Meters = ...`.
+    let definition = client.request_at("textDocument/definition", (22, 12));
+    assert_eq!(definition["range"], range((2, 5), (2, 11)));
+
+    // `sum` in `q.sum()`, through the alias `Meters`.
+    let definition = client.request_at("textDocument/definition", (23, 14));
+    assert_eq!(definition["range"], range((15, 13), (15, 16)));
+
+    // `Blake2s` in the import.
+    let definition = client.request_at("textDocument/definition", (0, 32));
+    assert_eq!(definition["range"], range((0, 29), (0, 36)));
+
+    let hover = client.request_at("textDocument/hover", (21, 19));
+    assert_eq!(
+        hover["contents"]["value"],
+        "```leo\nfunction new(x: u32, y: u32) -> Self\n```"
+    );
+    assert_eq!(hover["range"], range((21, 19), (21, 22)));
+
+    // `a` in `Point::new(a, ORIGIN)`.
+    let hover = client.request_at("textDocument/hover", (21, 23));
+    assert_eq!(hover["contents"]["value"], "```leo\na: u32\n```");
+
+    // `q` in `q.sum()`.
+    let hover = client.request_at("textDocument/hover", (23, 11));
+    assert_eq!(hover["contents"]["value"], "```leo\nlet q: Meters\n```");
+
+    // Nothing is defined by keywords.
+    assert_eq!(client.request_at("textDocument/hover", (20, 2)), Value::Null);
+    client.shutdown();
+}
+
+#[test]
+fn members_are_completed() {
+    let mut client = Client::start();
+    client.open(PROGRAM);
+
+    // After `self.` in `sum`.
+    assert_eq!(client.completions((16, 20)), vec!["x", "y", "sum"]);
+    // After `Point::` in `main`.
+    assert_eq!(client.completions((21, 19)), vec!["ZERO", "new"]);
+    // While typing `p.x`.
+    assert_eq!(client.completions((23, 24)), Vec::<String>::new());
+    // After `q.`, which is a `Point` through the alias `Meters`.
+    assert_eq!(client.completions((23, 13)), vec!["x", "y", "sum"]);
+    // Anywhere else, the parameters, variables, and definitions visible there.
+    assert_eq!(
+        client.completions((23, 4)),
+        vec!["a", "p", "q", "Blake2s", "Meters", "ORIGIN", "Point", "main"]
+    );
+    client.shutdown();
+}
+
+#[test]
+fn files_are_outlined_and_formatted() {
+    let mut client = Client::start();
+    client.open("circuit Foo{x:u8, function get(self)->u8{return self.x;}}\n");
+
+    let symbols = client.request("textDocument/documentSymbol", json!({ "textDocument": { "uri": URI } }));
+    assert_eq!(symbols[0]["name"], "Foo");
+    assert_eq!(symbols[0]["kind"], 23);
+    assert_eq!(symbols[0]["range"], range((0, 0), (0, 57)));
+    let members: Vec<_> = symbols[0]["children"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|member| (member["name"].as_str().unwrap(), member["detail"].as_str().unwrap()))
+        .collect();
+    assert_eq!(members, vec![("x", "x: u8"), ("get", "function get(self) -> u8")]);
+
+    let edits = client.request(
+        "textDocument/formatting",
+        json!({ "textDocument": { "uri": URI }, "options": { "tabSize": 4, "insertSpaces": true } }),
+    );
+    assert_eq!(
+        edits,
+        json!([{
+            "range": range((0, 0), (1, 0)),
+            "newText": "circuit Foo {\n    x: u8,\n    function get(self) -> u8 {\n        return self.x;\n    }\n}\n",
+        }])
+    );
+    client.shutdown();
+}
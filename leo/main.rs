@@ -22,13 +22,19 @@ pub mod logger;
 pub mod updater;
 
 use commands::{
-    // package::{Clone, Fetch, Login, Logout, Publish},
+    ast::Diff,
+    package::{Add, Clone, Fetch, Login, Logout, Publish, Remove, Verify},
     Build,
     // Clean,
     Command,
-    // Deploy, Init, Lint, New, Prove, Run, Setup, Test, Update, Watch,
+    Doc,
+    Explain,
+    Fmt,
+    Lint,
+    Test,
+    // Deploy, Init, New, Prove, Run, Setup, Update, Watch,
 };
-use leo_errors::Result;
+use leo_errors::{emitter::MessageFormat, LeoError, Result};
 // use snarkvm_utilities::Write;
 
 use std::{path::PathBuf, process::exit};
@@ -47,7 +53,10 @@ struct Opt {
     #[structopt(subcommand)]
     command: CommandOpts,
 
-    #[structopt(help = "Custom Aleo PM backend URL", env = "APM_URL")]
+    #[structopt(
+        help = "Custom Aleo PM backend URL, or a `file://` path to an offline registry without authentication",
+        env = "APM_URL"
+    )]
     api: Option<String>,
 
     #[structopt(
@@ -57,6 +66,15 @@ struct Opt {
         parse(from_os_str)
     )]
     path: Option<PathBuf>,
+
+    #[structopt(
+        long,
+        global = true,
+        help = "How errors and warnings are printed",
+        default_value = "human",
+        possible_values = MessageFormat::NAMES
+    )]
+    message_format: MessageFormat,
 }
 
 ///Leo compiler and package manager
@@ -116,58 +134,89 @@ enum CommandOpts {
     //     command: Update,
     // },
     //
-    // #[structopt(about = "Compile and run all tests in the current package")]
-    // Test {
-    //     #[structopt(flatten)]
-    //     command: Test,
-    // },
-    //
-    // #[structopt(about = "Import a package from the Aleo Package Manager")]
-    // Add {
-    //     #[structopt(flatten)]
-    //     command: Add,
-    // },
-    // #[structopt(about = "Pull dependencies from Aleo Package Manager")]
-    // Fetch {
-    //     #[structopt(flatten)]
-    //     command: Fetch,
-    // },
-    //
-    // #[structopt(about = "Clone a package from the Aleo Package Manager")]
-    // Clone {
-    //     #[structopt(flatten)]
-    //     command: Clone,
-    // },
-    //
-    // #[structopt(about = "Login to the Aleo Package Manager")]
-    // Login {
-    //     #[structopt(flatten)]
-    //     command: Login,
-    // },
-    //
-    // #[structopt(about = "Logout of the Aleo Package Manager")]
-    // Logout {
-    //     #[structopt(flatten)]
-    //     command: Logout,
-    // },
-    //
-    // #[structopt(about = "Publish the current package to the Aleo Package Manager")]
-    // Publish {
-    //     #[structopt(flatten)]
-    //     command: Publish,
-    // },
-    //
-    // #[structopt(about = "Uninstall a package from the current package")]
-    // Remove {
-    //     #[structopt(flatten)]
-    //     command: Remove,
-    // },
-    // #[structopt(about = "Lints the Leo files in the package (*)")]
-    // Lint {
-    //     #[structopt(flatten)]
-    //     command: Lint,
-    // },
+    #[structopt(about = "Compile all tests in the current package or workspace")]
+    Test {
+        #[structopt(flatten)]
+        command: Test,
+    },
     //
+    #[structopt(about = "Generate the documentation of the current package from its doc comments")]
+    Doc {
+        #[structopt(flatten)]
+        command: Doc,
+    },
+
+    #[structopt(about = "Explain an error code, e.g., `leo explain EPAR0370005`")]
+    Explain {
+        #[structopt(flatten)]
+        command: Explain,
+    },
+
+    #[structopt(about = "Format the Leo files of the current package")]
+    Fmt {
+        #[structopt(flatten)]
+        command: Fmt,
+    },
+
+    #[structopt(about = "Lint the Leo files of the current package")]
+    Lint {
+        #[structopt(flatten)]
+        command: Lint,
+    },
+
+    #[structopt(about = "Import a package from the Aleo Package Manager")]
+    Add {
+        #[structopt(flatten)]
+        command: Add,
+    },
+
+    #[structopt(about = "Pull dependencies from Aleo Package Manager")]
+    Fetch {
+        #[structopt(flatten)]
+        command: Fetch,
+    },
+
+    #[structopt(about = "Clone a package from the Aleo Package Manager")]
+    Clone {
+        #[structopt(flatten)]
+        command: Clone,
+    },
+
+    #[structopt(about = "Login to the Aleo Package Manager")]
+    Login {
+        #[structopt(flatten)]
+        command: Login,
+    },
+
+    #[structopt(about = "Logout of the Aleo Package Manager")]
+    Logout {
+        #[structopt(flatten)]
+        command: Logout,
+    },
+
+    #[structopt(about = "Publish the current package to the Aleo Package Manager")]
+    Publish {
+        #[structopt(flatten)]
+        command: Publish,
+    },
+
+    #[structopt(about = "Uninstall a package from the current package")]
+    Remove {
+        #[structopt(flatten)]
+        command: Remove,
+    },
+
+    #[structopt(about = "Inspect package zip files")]
+    Package {
+        #[structopt(subcommand)]
+        command: PackageCommandOpts,
+    },
+
+    #[structopt(about = "Inspect the AST snapshots of the compiler phases")]
+    Ast {
+        #[structopt(subcommand)]
+        command: AstCommandOpts,
+    },
     // #[structopt(about = "Deploy the current package as a program to the network (*)")]
     // Deploy {
     //     #[structopt(flatten)]
@@ -175,8 +224,32 @@ enum CommandOpts {
     // },
 }
 
+/// Subcommands of `leo package`
+#[derive(StructOpt, Debug)]
+#[structopt(setting = AppSettings::ColoredHelp)]
+enum PackageCommandOpts {
+    #[structopt(about = "Verify the integrity of a package zip file and list its contents")]
+    Verify {
+        #[structopt(flatten)]
+        command: Verify,
+    },
+}
+
+/// Subcommands of `leo ast`
+#[derive(StructOpt, Debug)]
+#[structopt(setting = AppSettings::ColoredHelp)]
+enum AstCommandOpts {
+    #[structopt(about = "Show the differences between the AST snapshots of two compiler phases")]
+    Diff {
+        #[structopt(flatten)]
+        command: Diff,
+    },
+}
+
 fn main() {
-    handle_error(run_with_args(Opt::from_args()))
+    let opt = Opt::from_args();
+    let message_format = opt.message_format;
+    handle_error(message_format, run_with_args(opt))
 }
 
 /// Run command with custom build arguments.
@@ -194,10 +267,14 @@ fn run_with_args(opt: Opt) -> Result<()> {
 
     // Get custom root folder and create context for it.
     // If not specified, default context will be created in cwd.
-    let context = handle_error(match opt.path {
-        Some(path) => context::create_context(path, opt.api),
-        None => context::get_context(opt.api),
-    });
+    let mut context = handle_error(
+        opt.message_format,
+        match opt.path {
+            Some(path) => context::create_context(path, opt.api),
+            None => context::get_context(opt.api),
+        },
+    );
+    context.message_format = opt.message_format;
 
     match opt.command {
         // CommandOpts::Init { command } => command.try_execute(context),
@@ -205,30 +282,43 @@ fn run_with_args(opt: Opt) -> Result<()> {
         CommandOpts::Build { command } => command.try_execute(context),
         // CommandOpts::Setup { command } => command.try_execute(context),
         // CommandOpts::Prove { command } => command.try_execute(context),
-        // CommandOpts::Test { command } => command.try_execute(context),
+        CommandOpts::Test { command } => command.try_execute(context),
         // CommandOpts::Run { command } => command.try_execute(context),
         // CommandOpts::Clean { command } => command.try_execute(context),
         // CommandOpts::Watch { command } => command.try_execute(context),
         // CommandOpts::Update { command } => command.try_execute(context),
         //
-        // // CommandOpts::Add { command } => command.try_execute(context),
-        // CommandOpts::Fetch { command } => command.try_execute(context),
-        // CommandOpts::Clone { command } => command.try_execute(context),
-        // CommandOpts::Login { command } => command.try_execute(context),
-        // CommandOpts::Logout { command } => command.try_execute(context),
-        // CommandOpts::Publish { command } => command.try_execute(context),
-        // // CommandOpts::Remove { command } => command.try_execute(context),
-        // CommandOpts::Lint { command } => command.try_execute(context),
+        CommandOpts::Doc { command } => command.try_execute(context),
+        CommandOpts::Explain { command } => command.try_execute(context),
+        CommandOpts::Fmt { command } => command.try_execute(context),
+        CommandOpts::Lint { command } => command.try_execute(context),
+        CommandOpts::Add { command } => command.try_execute(context),
+        CommandOpts::Fetch { command } => command.try_execute(context),
+        CommandOpts::Clone { command } => command.try_execute(context),
+        CommandOpts::Login { command } => command.try_execute(context),
+        CommandOpts::Logout { command } => command.try_execute(context),
+        CommandOpts::Publish { command } => command.try_execute(context),
+        CommandOpts::Remove { command } => command.try_execute(context),
+        CommandOpts::Package { command } => match command {
+            PackageCommandOpts::Verify { command } => command.try_execute(context),
+        },
+        CommandOpts::Ast { command } => match command {
+            AstCommandOpts::Diff { command } => command.try_execute(context),
+        },
         // CommandOpts::Deploy { command } => command.try_execute(context),
     }
 }
 
-fn handle_error<T>(res: Result<T>) -> T {
+fn handle_error<T>(message_format: MessageFormat, res: Result<T>) -> T {
     match res {
         Ok(t) => t,
         Err(err) => {
-            eprintln!("{}", err);
-            exit(err.exit_code());
+            let code = err.exit_code();
+            // The errors standing behind the last error code were already emitted.
+            if !matches!(err, LeoError::LastErrorCode(_)) {
+                message_format.emitter().emit_err(err);
+            }
+            exit(code);
         }
     }
 }
@@ -311,7 +401,6 @@ fn handle_error<T>(res: Result<T>) -> T {
 //     #[test]
 //     #[should_panic]
 //     fn unimplemented() {
-//         assert!(run_cmd("leo lint", &None).is_err());
 //         assert!(run_cmd("leo deploy", &None).is_err());
 //     }
 //
@@ -0,0 +1,158 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Resolution of path and git dependencies.
+
+use crate::{
+    imports::{ImportsDirectory, IMPORTS_DIRECTORY_NAME},
+    root::{zip::is_included, Dependency, Manifest},
+};
+use leo_errors::{PackageError, Result};
+
+use sha2::{Digest, Sha256};
+use std::{
+    convert::TryFrom,
+    fs,
+    path::{Path, PathBuf},
+    process::Command,
+};
+use walkdir::WalkDir;
+
+/// A dependency which was resolved to a directory on the local file system.
+pub struct LocalDependency {
+    /// The name under which the dependency is imported.
+    pub import_name: String,
+    /// The manifest of the dependency package.
+    pub manifest: Manifest,
+    /// The root directory of the dependency package.
+    pub path: PathBuf,
+    /// The source of the dependency as recorded in the lock file.
+    pub source: String,
+    /// The SHA256 checksum of the dependency contents.
+    pub checksum: String,
+}
+
+impl LocalDependency {
+    /// Resolves a path or git dependency of the package at `package_path`.
+    /// Git dependencies are checked out into the `imports/` directory of the `root` package.
+    pub fn resolve(root: &Path, package_path: &Path, import_name: &str, dependency: &Dependency) -> Result<Self> {
+        let path = match dependency {
+            Dependency::Path { path } => package_path.join(path),
+            Dependency::Git { git, rev } => Self::checkout(root, import_name, git, rev)?,
+            Dependency::Registry { .. } => return Err(PackageError::not_a_local_dependency(import_name).into()),
+        };
+
+        if !Manifest::exists_at(&path) {
+            return Err(PackageError::local_dependency_not_found(import_name, path).into());
+        }
+
+        Ok(Self {
+            import_name: import_name.to_string(),
+            manifest: Manifest::try_from(path.as_path())?,
+            checksum: Self::checksum(import_name, &path)?,
+            source: dependency.identifier(),
+            path,
+        })
+    }
+
+    /// Resolves every path and git dependency of the package at `package_path`, see [`LocalDependency::resolve`].
+    pub fn resolve_all(root: &Path, package_path: &Path, manifest: &Manifest) -> Result<Vec<Self>> {
+        manifest
+            .get_local_dependencies()
+            .iter()
+            .map(|(import_name, dependency)| Self::resolve(root, package_path, import_name, dependency))
+            .collect()
+    }
+
+    /// Returns the directory in `imports/` a git dependency is checked out to.
+    /// The directory is named after the revision and a hash of the `url`,
+    /// so that a checkout is not reused when the url of the dependency changes.
+    pub fn checkout_directory(package_path: &Path, import_name: &str, url: &str, rev: &str) -> PathBuf {
+        let url_hash = format!("{:x}", Sha256::digest(url.as_bytes()));
+
+        let mut path = package_path.to_path_buf();
+        path.push(IMPORTS_DIRECTORY_NAME);
+        path.push(format!("{}@{}_{}", import_name, rev, &url_hash[..16]));
+        path
+    }
+
+    /// Clones the repository at `url` and checks out `rev`, unless it is already checked out.
+    fn checkout(package_path: &Path, import_name: &str, url: &str, rev: &str) -> Result<PathBuf> {
+        // Values starting with `-` would be passed to git as options, e.g., `--upload-pack=...`.
+        for value in [url, rev] {
+            if value.starts_with('-') {
+                return Err(PackageError::invalid_git_dependency(import_name, value).into());
+            }
+        }
+
+        let path = Self::checkout_directory(package_path, import_name, url, rev);
+        if path.exists() {
+            return Ok(path);
+        }
+
+        ImportsDirectory::create(package_path)?;
+
+        let git = |args: &[&str]| -> Result<()> {
+            let output = Command::new("git")
+                .args(args)
+                .output()
+                .map_err(|error| PackageError::failed_to_fetch_git_dependency(import_name, url, error))?;
+
+            if !output.status.success() {
+                let stderr = String::from_utf8_lossy(&output.stderr);
+                return Err(PackageError::failed_to_fetch_git_dependency(import_name, url, stderr.trim()).into());
+            }
+            Ok(())
+        };
+
+        let destination = path.to_string_lossy();
+        // The `--` after the revision marks the end of the revisions, as no paths are checked out.
+        let result = git(&["clone", "--quiet", "--", url, &*destination])
+            .and_then(|_| git(&["-C", &*destination, "checkout", "--quiet", rev, "--"]));
+
+        // Do not leave a partial checkout behind, it would be picked up by the next build.
+        if result.is_err() && path.exists() {
+            fs::remove_dir_all(&path)
+                .map_err(|error| PackageError::failed_to_remove_directory(path.display(), error))?;
+        }
+
+        result.map(|_| path)
+    }
+
+    /// Returns the SHA256 checksum of the files that would be published from the package at `path`.
+    /// Files are hashed in sorted order, each prefixed by its relative path.
+    pub fn checksum(import_name: &str, path: &Path) -> Result<String> {
+        let mut hasher = Sha256::new();
+
+        for entry in WalkDir::new(path).sort_by_file_name() {
+            let entry = entry.map_err(|error| PackageError::failed_to_hash_dependency(import_name, error))?;
+            let name = entry.path().strip_prefix(path).unwrap();
+
+            if !entry.file_type().is_file() || !is_included(name) {
+                continue;
+            }
+
+            let contents =
+                fs::read(entry.path()).map_err(|error| PackageError::failed_to_hash_dependency(import_name, error))?;
+
+            hasher.update(name.to_string_lossy().replace('\\', "/").as_bytes());
+            hasher.update([0u8]);
+            hasher.update(&contents);
+        }
+
+        Ok(format!("{:x}", hasher.finalize()))
+    }
+}
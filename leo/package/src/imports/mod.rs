@@ -16,3 +16,9 @@
 
 pub mod directory;
 pub use directory::*;
+
+pub mod dependency;
+pub use dependency::*;
+
+pub mod resolver;
+pub use resolver::*;
@@ -0,0 +1,332 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Resolution of the complete dependency graph into a lock file.
+
+use crate::{
+    imports::{LocalDependency, IMPORTS_DIRECTORY_NAME},
+    root::{Dependency, LockFile, Manifest, Member, Package},
+};
+use leo_errors::{PackageError, Result};
+
+use indexmap::{IndexMap, IndexSet};
+use semver::{Version, VersionReq};
+use std::{
+    collections::{BTreeMap, HashSet},
+    convert::TryFrom,
+    fs,
+    path::{Path, PathBuf},
+};
+
+/// Resolves the dependencies of a package, and the dependencies of those, into a lock file.
+///
+/// Registry dependencies are looked up in the `imports/` directory of the root package or workspace,
+/// where each installed version is stored as `author-package@version`.
+/// Their `version` is a semver requirement, e.g. `0.1.0`, `^0.2` or `>=1.0, <2.0`.
+pub struct DependencyResolver {
+    /// The root directory of the package or workspace being resolved.
+    root: PathBuf,
+    /// The versions installed in `imports/`, keyed by `author-package`, highest first.
+    installed: IndexMap<String, Vec<Version>>,
+}
+
+/// The state of a single pass over the dependency graph.
+#[derive(Default)]
+struct Resolution {
+    /// The requirements on each registry package, along with the package requiring it.
+    requirements: IndexMap<String, Vec<(String, VersionReq)>>,
+    /// The lock file records, keyed by identifier.
+    packages: BTreeMap<String, Package>,
+    /// The packages currently being visited, to detect recursive dependencies.
+    stack: IndexSet<String>,
+}
+
+impl DependencyResolver {
+    /// Creates a resolver for the package at `root`, reading the packages installed in `imports/`.
+    pub fn new(root: &Path) -> Result<Self> {
+        let mut installed: IndexMap<String, Vec<Version>> = IndexMap::new();
+
+        let imports_directory = root.join(IMPORTS_DIRECTORY_NAME);
+        if imports_directory.is_dir() {
+            let entries = fs::read_dir(&imports_directory)
+                .map_err(|error| PackageError::io_error(imports_directory.display(), error))?;
+
+            for entry in entries {
+                let entry = entry.map_err(|error| PackageError::io_error(imports_directory.display(), error))?;
+                let file_name = entry.file_name().to_string_lossy().to_string();
+
+                // Git checkouts are stored as `name@rev_hash`, which is skipped as it is not a version.
+                if let Some((key, version)) = file_name.rsplit_once('@') {
+                    if let Ok(version) = Version::parse(version) {
+                        installed.entry(key.to_string()).or_default().push(version);
+                    }
+                }
+            }
+        }
+
+        for versions in installed.values_mut() {
+            versions.sort_by(|a, b| b.cmp(a));
+        }
+
+        Ok(Self {
+            root: root.to_path_buf(),
+            installed,
+        })
+    }
+
+    /// Resolves the dependency graph of `manifest` into a complete lock file.
+    /// Versions recorded in `locked` are kept as long as they satisfy every requirement on them.
+    pub fn resolve(&self, manifest: &Manifest, locked: Option<&LockFile>) -> Result<LockFile> {
+        self.resolve_graph(&[(self.root.as_path(), manifest)], locked, false)
+    }
+
+    /// Resolves the dependency graphs of the workspace `members` into the lock file shared by the workspace,
+    /// where each member records its direct dependencies. A single version of each registry package is
+    /// selected for the whole workspace. The resolver must be created for the workspace root.
+    pub fn resolve_workspace(&self, members: &[(PathBuf, Manifest)], locked: Option<&LockFile>) -> Result<LockFile> {
+        let members: Vec<_> = members
+            .iter()
+            .map(|(path, manifest)| (path.as_path(), manifest))
+            .collect();
+
+        self.resolve_graph(&members, locked, true)
+    }
+
+    /// Resolves the dependency graphs of the `packages` at the given paths together.
+    fn resolve_graph(
+        &self,
+        packages: &[(&Path, &Manifest)],
+        locked: Option<&LockFile>,
+        workspace: bool,
+    ) -> Result<LockFile> {
+        let mut selected: IndexMap<String, Version> = locked
+            .into_iter()
+            .flat_map(|lock_file| lock_file.package.iter())
+            .filter(|package| package.source.is_none())
+            .filter_map(|package| {
+                let version = Version::parse(&package.version).ok()?;
+                Some((format!("{}-{}", package.author, package.name), version))
+            })
+            .collect();
+
+        let mut previous_selections = HashSet::new();
+
+        // Selecting a version for a package may add the requirements of its own dependencies,
+        // so passes over the graph are repeated until every selection satisfies all requirements.
+        loop {
+            let mut resolution = Resolution::default();
+            let mut members = Vec::new();
+
+            for (path, manifest) in packages.iter() {
+                let dependencies = manifest.get_package_dependencies().unwrap_or_default();
+
+                resolution.stack.insert(manifest.get_package_name());
+                let mut dependencies = self.visit(&mut resolution, &selected, path, &dependencies, !workspace)?;
+                resolution.stack.pop();
+
+                if workspace {
+                    dependencies.sort_keys();
+                    members.push(Member {
+                        name: manifest.get_package_name(),
+                        path: self.member_path(path),
+                        dependencies,
+                    });
+                }
+            }
+
+            let mut changed = None;
+            for (key, requirements) in resolution.requirements.iter() {
+                let satisfied = selected.get(key).map_or(false, |version| {
+                    requirements.iter().all(|(_, req)| req.matches(version))
+                });
+
+                if !satisfied {
+                    selected.insert(key.clone(), self.select(key, requirements)?);
+                    changed = Some((key, requirements));
+                }
+            }
+
+            match changed {
+                None => {
+                    members.sort_by(|a, b| a.path.cmp(&b.path));
+
+                    return Ok(LockFile {
+                        package: resolution.packages.into_values().collect(),
+                        member: members,
+                    });
+                }
+                // The same selections were made in an earlier pass, so the passes would never settle.
+                Some((key, requirements)) if !previous_selections.insert(format!("{:?}", selected)) => {
+                    return Err(PackageError::dependency_version_conflict(key, Self::describe(requirements)).into());
+                }
+                Some(_) => {}
+            }
+        }
+    }
+
+    /// Returns the `/` separated path of a workspace member, relative to the workspace root.
+    fn member_path(&self, path: &Path) -> String {
+        let path = path.strip_prefix(&self.root).unwrap_or(path);
+        match path.as_os_str().is_empty() {
+            true => ".".to_string(),
+            false => path
+                .components()
+                .map(|component| component.as_os_str().to_string_lossy())
+                .collect::<Vec<_>>()
+                .join("/"),
+        }
+    }
+
+    /// Visits the `dependencies` of the package at `package_path`, recording requirements and lock records.
+    /// Returns the identifiers of the dependencies, keyed by import name.
+    fn visit(
+        &self,
+        resolution: &mut Resolution,
+        selected: &IndexMap<String, Version>,
+        package_path: &Path,
+        dependencies: &IndexMap<String, Dependency>,
+        is_root: bool,
+    ) -> Result<IndexMap<String, String>> {
+        let requester = resolution.stack.last().cloned().unwrap_or_default();
+        let mut identifiers = IndexMap::new();
+
+        for (import_name, dependency) in dependencies.iter() {
+            // Registry dependencies are identified by the selected version.
+            let selection = match dependency {
+                Dependency::Registry {
+                    author,
+                    version,
+                    package,
+                } => {
+                    let key = format!("{}-{}", author, package);
+                    let requirement = VersionReq::parse(version)
+                        .map_err(|error| PackageError::invalid_version_requirement(import_name, version, error))?;
+
+                    let version = match selected.get(&key) {
+                        Some(version) => version.clone(),
+                        None => self.select(&key, &[(requester.clone(), requirement.clone())])?,
+                    };
+
+                    resolution
+                        .requirements
+                        .entry(key)
+                        .or_default()
+                        .push((requester.clone(), requirement));
+
+                    Some((author, package, version))
+                }
+                _ => None,
+            };
+
+            let identifier = match &selection {
+                Some((author, package, version)) => format!("{}-{}@{}", author, package, version),
+                None => dependency.identifier(),
+            };
+
+            identifiers.insert(import_name.clone(), identifier.clone());
+
+            if resolution.stack.contains(&identifier) {
+                let tree = resolution
+                    .stack
+                    .iter()
+                    .chain(std::iter::once(&identifier))
+                    .enumerate()
+                    .map(|(i, name)| format!("{}└─{}", " ".repeat(i * 2), name))
+                    .collect::<Vec<_>>()
+                    .join("\n");
+
+                return Err(PackageError::recursive_dependency(tree).into());
+            }
+
+            // Packages depended upon several times are only visited once.
+            if let Some(package) = resolution.packages.get_mut(&identifier) {
+                if is_root {
+                    package.import_name = Some(import_name.clone());
+                }
+                continue;
+            }
+
+            let (mut package, path) = match selection {
+                Some((author, package, version)) => {
+                    // A version kept from the lock file may have been removed from `imports/`.
+                    let path = self.root.join(IMPORTS_DIRECTORY_NAME).join(&identifier);
+                    if !path.is_dir() {
+                        let key = format!("{}-{}", author, package);
+                        return Err(PackageError::dependency_not_installed(key, format!("={}", version)).into());
+                    }
+
+                    let package = Package {
+                        name: package.clone(),
+                        version: version.to_string(),
+                        author: author.clone(),
+                        import_name: None,
+                        source: None,
+                        checksum: Some(LocalDependency::checksum(import_name, &path)?),
+                        dependencies: Default::default(),
+                    };
+                    (package, path)
+                }
+                None => {
+                    let dependency = LocalDependency::resolve(&self.root, package_path, import_name, dependency)?;
+                    (Package::from(&dependency), dependency.path)
+                }
+            };
+
+            let dependencies = Manifest::try_from(path.as_path())?
+                .get_package_dependencies()
+                .unwrap_or_default();
+
+            resolution.stack.insert(identifier.clone());
+            let mut dependencies = self.visit(resolution, selected, &path, &dependencies, false)?;
+            resolution.stack.pop();
+
+            dependencies.sort_keys();
+            package.dependencies = dependencies;
+            package.import_name = is_root.then(|| import_name.clone());
+
+            resolution.packages.insert(identifier, package);
+        }
+
+        Ok(identifiers)
+    }
+
+    /// Selects the highest installed version of the package `key` satisfying all `requirements`.
+    fn select(&self, key: &str, requirements: &[(String, VersionReq)]) -> Result<Version> {
+        let installed = self.installed.get(key).map(Vec::as_slice).unwrap_or_default();
+
+        if let Some((_, requirement)) = requirements
+            .iter()
+            .find(|(_, requirement)| !installed.iter().any(|version| requirement.matches(version)))
+        {
+            return Err(PackageError::dependency_not_installed(key, requirement).into());
+        }
+
+        installed
+            .iter()
+            .find(|version| requirements.iter().all(|(_, requirement)| requirement.matches(version)))
+            .cloned()
+            .ok_or_else(|| PackageError::dependency_version_conflict(key, Self::describe(requirements)).into())
+    }
+
+    /// Lists `requirements` along with the packages requiring them.
+    fn describe(requirements: &[(String, VersionReq)]) -> String {
+        requirements
+            .iter()
+            .map(|(requester, requirement)| format!("    `{}` required by `{}`", requirement, requester))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
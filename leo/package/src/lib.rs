@@ -20,6 +20,7 @@ pub mod imports;
 pub mod inputs;
 pub mod outputs;
 pub mod package;
+pub mod registry;
 pub mod root;
 pub mod source;
 
@@ -0,0 +1,152 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! A package registry on the file system.
+
+use crate::{
+    package::Package,
+    registry::{RegistryEntry, RegistryIndex},
+    root::ZIP_FILE_EXTENSION,
+};
+use leo_errors::{PackageError, Result};
+
+use semver::Version;
+use sha2::{Digest, Sha256};
+use std::{
+    fs,
+    path::{Component, Path, PathBuf},
+};
+
+/// The URL scheme selecting a file system registry instead of the Aleo Package Manager.
+pub const FILE_REGISTRY_SCHEME: &str = "file://";
+
+/// A package registry stored on the file system, for offline and air-gapped builds.
+///
+/// The registry is a directory of the package archives written by `ZipFile::write`,
+/// stored as `author/name-version.zip`, along with an `index.toml` listing them.
+/// The registry is not authenticated: anyone who can write to its directory may publish under any author.
+#[derive(Clone, Debug)]
+pub struct FileRegistry {
+    root: PathBuf,
+}
+
+impl FileRegistry {
+    pub fn new(root: PathBuf) -> Self {
+        Self { root }
+    }
+
+    /// Returns the registry at a `file://` URL, or `None` for any other URL.
+    pub fn from_url(url: &str) -> Option<Self> {
+        url.strip_prefix(FILE_REGISTRY_SCHEME)
+            .map(|path| Self::new(PathBuf::from(path)))
+    }
+
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    /// Reads the registry index.
+    pub fn index(&self) -> Result<RegistryIndex> {
+        RegistryIndex::read_from(&self.root)
+    }
+
+    /// Reads the archive of `author/name` matching `version`, or of its latest version.
+    /// The archive is checked against the checksum recorded in the index.
+    pub fn fetch(&self, author: &str, name: &str, version: Option<&str>) -> Result<(RegistryEntry, Vec<u8>)> {
+        let remote = format!("{}/{}", author, name);
+
+        let index = self.index()?;
+        let entry = index
+            .find(author, name, version)
+            .ok_or_else(|| PackageError::registry_package_not_found(&remote))?;
+
+        // The index may have been written by hand, so its entries must not point outside the registry.
+        validate_package(&entry.author, &entry.name, &entry.version)?;
+        let file = Path::new(&entry.file);
+        if !file
+            .components()
+            .all(|component| matches!(component, Component::Normal(_)))
+        {
+            return Err(PackageError::invalid_registry_entry_file(&entry.file).into());
+        }
+
+        let path = self.root.join(file);
+        let bytes = fs::read(&path).map_err(|error| PackageError::failed_to_read_file(path.display(), error))?;
+
+        if checksum(&bytes) != entry.checksum {
+            return Err(PackageError::registry_checksum_mismatch(remote, &entry.version).into());
+        }
+
+        Ok((entry.clone(), bytes))
+    }
+
+    /// Copies the package archive at `archive` into the registry and records it in the index.
+    pub fn publish(&self, author: &str, name: &str, version: &str, archive: &Path) -> Result<RegistryEntry> {
+        // The author, name and version make up the path of the archive in the registry.
+        validate_package(author, name, version)?;
+
+        let mut index = self.index()?;
+
+        if index
+            .package
+            .iter()
+            .any(|entry| entry.author == author && entry.name == name && entry.version == version)
+        {
+            return Err(
+                PackageError::registry_package_already_published(format!("{}/{}", author, name), version).into(),
+            );
+        }
+
+        let bytes = fs::read(archive).map_err(|error| PackageError::failed_to_read_file(archive.display(), error))?;
+
+        let file = format!("{}/{}-{}{}", author, name, version, ZIP_FILE_EXTENSION);
+        let path = self.root.join(&file);
+        let directory = path.parent().unwrap();
+        fs::create_dir_all(directory)
+            .map_err(|error| PackageError::failed_to_create_directory(directory.display(), error))?;
+        fs::write(&path, &bytes).map_err(|error| PackageError::io_error(path.display(), error))?;
+
+        let entry = RegistryEntry {
+            author: author.to_string(),
+            name: name.to_string(),
+            version: version.to_string(),
+            file,
+            checksum: checksum(&bytes),
+        };
+
+        index.package.push(entry.clone());
+        index.write_to(&self.root)?;
+
+        Ok(entry)
+    }
+}
+
+/// Checks that the `author`, `name` and `version` of a package are valid,
+/// i.e., that the author and name follow the rules of package names and that the version is a semver version.
+fn validate_package(author: &str, name: &str, version: &str) -> Result<()> {
+    if !Package::is_package_name_valid(author)
+        || !Package::is_package_name_valid(name)
+        || Version::parse(version).is_err()
+    {
+        return Err(PackageError::invalid_registry_package(format!("{}/{}", author, name), version).into());
+    }
+    Ok(())
+}
+
+/// Returns the SHA256 checksum of a package archive.
+fn checksum(bytes: &[u8]) -> String {
+    format!("{:x}", Sha256::digest(bytes))
+}
@@ -0,0 +1,97 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The registry index file.
+
+use leo_errors::{PackageError, Result};
+
+use semver::{Version, VersionReq};
+use serde::{Deserialize, Serialize};
+use std::{fs, path::Path};
+
+pub const REGISTRY_INDEX_FILENAME: &str = "index.toml";
+
+/// The index of a file system registry, listing every published package version.
+#[derive(Default, Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RegistryIndex {
+    #[serde(default)]
+    pub package: Vec<RegistryEntry>,
+}
+
+/// A single published package version.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RegistryEntry {
+    pub author: String,
+    pub name: String,
+    pub version: String,
+    /// The path of the package archive, relative to the registry root.
+    pub file: String,
+    /// The SHA256 checksum of the package archive.
+    pub checksum: String,
+}
+
+impl RegistryIndex {
+    /// Reads the index of the registry at `path`. A registry without an index is empty.
+    pub fn read_from(path: &Path) -> Result<Self> {
+        let path = path.join(REGISTRY_INDEX_FILENAME);
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+
+        let string =
+            fs::read_to_string(&path).map_err(|error| PackageError::failed_to_read_registry_index(&path, error))?;
+        Ok(toml::from_str(&string).map_err(|error| PackageError::failed_to_read_registry_index(&path, error))?)
+    }
+
+    /// Writes the index to the registry at `path`, with the entries in a stable order.
+    pub fn write_to(mut self, path: &Path) -> Result<()> {
+        let path = path.join(REGISTRY_INDEX_FILENAME);
+
+        self.package.sort_by(|a, b| {
+            (&a.author, &a.name, Version::parse(&a.version).ok()).cmp(&(
+                &b.author,
+                &b.name,
+                Version::parse(&b.version).ok(),
+            ))
+        });
+
+        let string =
+            toml::to_string(&self).map_err(|error| PackageError::failed_to_write_registry_index(&path, error))?;
+        fs::write(&path, string).map_err(|error| PackageError::failed_to_write_registry_index(&path, error))?;
+        Ok(())
+    }
+
+    /// Returns the entry of `author/name` matching `version`, which may be a semver requirement.
+    /// The highest matching version is returned, or the latest version if `version` is `None`.
+    pub fn find(&self, author: &str, name: &str, version: Option<&str>) -> Option<&RegistryEntry> {
+        let requirement = match version {
+            Some(version) => Some(VersionReq::parse(version).ok()?),
+            None => None,
+        };
+
+        self.package
+            .iter()
+            .filter(|entry| entry.author == author && entry.name == name)
+            .filter_map(|entry| Some((Version::parse(&entry.version).ok()?, entry)))
+            .filter(|(version, _)| {
+                requirement
+                    .as_ref()
+                    .map_or(true, |requirement| requirement.matches(version))
+            })
+            .max_by(|(a, _), (b, _)| a.cmp(b))
+            .map(|(_, entry)| entry)
+    }
+}
@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::root::Dependency;
+use crate::{imports::LocalDependency, root::Dependency};
 use leo_errors::{PackageError, Result};
 
 use indexmap::IndexMap;
@@ -32,25 +32,47 @@ pub const LOCKFILE_FILENAME: &str = "Leo.lock";
 
 /// Lock-file struct, contains all information about imported dependencies
 /// and their relationships.
-#[derive(Default, Debug, Clone, Serialize, Deserialize)]
+#[derive(Default, Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct LockFile {
     pub package: Vec<Package>,
+    /// The members of a workspace, when the lock file is shared by a workspace.
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    pub member: Vec<Member>,
+}
+
+/// Workspace member record, listing the direct dependencies of the member.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Member {
+    pub name: String,
+    /// The member directory, relative to the workspace root.
+    pub path: String,
+    #[serde(skip_serializing_if = "IndexMap::is_empty", default)]
+    pub dependencies: IndexMap<String, String>,
 }
 
 /// Single dependency record.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Package {
     pub name: String,
     pub version: String,
     pub author: String,
     pub import_name: Option<String>,
+    /// Where a path or git dependency was resolved from, `None` for the package manager.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub source: Option<String>,
+    /// The SHA256 checksum of the dependency contents.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub checksum: Option<String>,
     #[serde(skip_serializing_if = "IndexMap::is_empty", default)]
     pub dependencies: IndexMap<String, String>,
 }
 
 impl LockFile {
     pub fn new() -> Self {
-        LockFile { package: vec![] }
+        LockFile {
+            package: vec![],
+            member: vec![],
+        }
     }
 
     /// Check if LockFile exists in a directory.
@@ -73,12 +95,32 @@ impl LockFile {
         Ok(toml::to_string(self).map_err(PackageError::failed_to_serialize_lock_file)?)
     }
 
+    /// Add a Package record to the lock file, replacing the record with the same import name. Chainable.
+    pub fn insert_package(&mut self, package: Package) -> &mut Self {
+        match self
+            .package
+            .iter_mut()
+            .find(|existing| existing.import_name == package.import_name)
+        {
+            Some(existing) => *existing = package,
+            None => self.package.push(package),
+        }
+        self
+    }
+
+    /// Returns the Package record of a path or git dependency with the given import name.
+    pub fn get_local_package(&self, import_name: &str) -> Option<&Package> {
+        self.package
+            .iter()
+            .find(|package| package.source.is_some() && package.import_name.as_deref() == Some(import_name))
+    }
+
     /// Form a IndexMap of kind:
     /// ``` imported_name => package_name ```
-    /// for all imported packages.
+    /// for all packages imported from the package manager.
     pub fn to_import_map(&self) -> IndexMap<String, String> {
         let mut result = IndexMap::new();
-        for package in self.package.iter() {
+        for package in self.package.iter().filter(|package| package.source.is_none()) {
             match &package.import_name {
                 Some(name) => result.insert(name.clone(), package.to_string()),
                 None => result.insert(package.name.clone(), package.to_string()),
@@ -88,6 +130,25 @@ impl LockFile {
         result
     }
 
+    /// Returns `true` if `other` records the same dependency graph, i.e., the same packages at the same versions
+    /// with the same dependencies and contents. The checksums of path dependencies are not compared,
+    /// as they change with every edit of the dependency sources, unlike those of a git revision.
+    pub fn same_graph(&self, other: &LockFile) -> bool {
+        let without_path_checksums = |lock_file: &LockFile| {
+            let mut lock_file = lock_file.clone();
+            for package in lock_file
+                .package
+                .iter_mut()
+                .filter(|package| package.is_path_dependency())
+            {
+                package.checksum = None;
+            }
+            lock_file
+        };
+
+        without_path_checksums(self) == without_path_checksums(other)
+    }
+
     /// Write Leo.lock to the given location.
     pub fn write_to(self, path: &Path) -> Result<()> {
         let mut path = Cow::from(path);
@@ -129,11 +190,30 @@ impl TryFrom<&Path> for LockFile {
 }
 
 impl Package {
+    /// Returns the record of the package `name` by `author` installed from the package manager at `version`.
+    pub fn registry(author: &str, name: &str, version: &str) -> Self {
+        Package {
+            name: name.to_string(),
+            author: author.to_string(),
+            version: version.to_string(),
+            dependencies: Default::default(),
+            import_name: None,
+            source: None,
+            checksum: None,
+        }
+    }
+
+    /// Returns `true` if the package is a path dependency, whose sources are edited in place.
+    pub fn is_path_dependency(&self) -> bool {
+        self.source
+            .as_deref()
+            .map_or(false, |source| source.starts_with("path+"))
+    }
+
     /// Fill dependencies from Leo Manifest data.
     pub fn add_dependencies(&mut self, dependencies: &IndexMap<String, Dependency>) {
         for (import_name, dependency) in dependencies.iter() {
-            self.dependencies
-                .insert(import_name.clone(), Package::from(dependency).to_string());
+            self.dependencies.insert(import_name.clone(), dependency.identifier());
         }
     }
 }
@@ -146,14 +226,24 @@ impl Display for Package {
     }
 }
 
-impl From<&Dependency> for Package {
-    fn from(dependency: &Dependency) -> Package {
-        Package {
-            name: dependency.package.clone(),
-            author: dependency.author.clone(),
-            version: dependency.version.clone(),
+impl From<&LocalDependency> for Package {
+    fn from(dependency: &LocalDependency) -> Package {
+        let mut package = Package {
+            name: dependency.manifest.get_package_name(),
+            author: dependency
+                .manifest
+                .get_package_remote()
+                .map(|remote| remote.author)
+                .unwrap_or_default(),
+            version: dependency.manifest.get_package_version(),
             dependencies: Default::default(),
-            import_name: None,
+            import_name: Some(dependency.import_name.clone()),
+            source: Some(dependency.source.clone()),
+            checksum: Some(dependency.checksum.clone()),
+        };
+        if let Some(dependencies) = dependency.manifest.get_package_dependencies() {
+            package.add_dependencies(&dependencies);
         }
+        package
     }
 }
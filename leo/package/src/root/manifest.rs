@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::package::Package;
+use crate::{package::Package, root::Workspace};
 use leo_errors::{PackageError, Result};
 
 use indexmap::IndexMap;
@@ -25,7 +25,7 @@ use std::{
     convert::TryFrom,
     fs::File,
     io::{Read, Write},
-    path::Path,
+    path::{Path, PathBuf},
 };
 
 pub const MANIFEST_FILENAME: &str = "Leo.toml";
@@ -36,11 +36,60 @@ pub struct Remote {
     pub author: String,
 }
 
+/// A single entry of the `[dependencies]` section.
 #[derive(Clone, Debug, Deserialize)]
-pub struct Dependency {
-    pub author: String,
-    pub version: String,
-    pub package: String,
+#[serde(untagged)]
+pub enum Dependency {
+    /// A package published to the Aleo Package Manager and stored in `imports/`.
+    Registry {
+        author: String,
+        version: String,
+        package: String,
+    },
+    /// A package on the local file system, relative to the manifest directory.
+    Path { path: PathBuf },
+    /// A package checked out from a git repository at a fixed revision.
+    Git { git: String, rev: String },
+}
+
+impl Dependency {
+    /// Returns `true` if the dependency is resolved without the package manager.
+    pub fn is_local(&self) -> bool {
+        !matches!(self, Dependency::Registry { .. })
+    }
+
+    /// Returns the identifier under which the dependency is recorded in the lock file.
+    /// For registry dependencies, this is also the directory name inside `imports/`.
+    pub fn identifier(&self) -> String {
+        match self {
+            Dependency::Registry {
+                author,
+                version,
+                package,
+            } => format!("{}-{}@{}", author, package, version),
+            Dependency::Path { path } => format!("path+{}", path.display()),
+            Dependency::Git { git, rev } => format!("git+{}#{}", git, rev),
+        }
+    }
+}
+
+/// The `[fmt]` section, configuring `leo fmt`.
+#[derive(Clone, Debug, Default, Deserialize)]
+pub struct Fmt {
+    /// The maximum width of a formatted line.
+    pub max_width: Option<usize>,
+}
+
+/// The level of a lint, set in the `[lint]` section, e.g., `unused_variable = "deny"`.
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum LintLevel {
+    /// The lint is not checked.
+    Allow,
+    /// The lint is reported as a warning, which is the default.
+    Warn,
+    /// The lint is reported as an error.
+    Deny,
 }
 
 #[derive(Deserialize)]
@@ -48,6 +97,9 @@ pub struct Manifest {
     pub project: Package,
     pub remote: Option<Remote>,
     pub dependencies: Option<IndexMap<String, Dependency>>,
+    pub workspace: Option<Workspace>,
+    pub fmt: Option<Fmt>,
+    pub lint: Option<IndexMap<String, LintLevel>>,
 }
 
 impl Manifest {
@@ -56,6 +108,9 @@ impl Manifest {
             project: Package::new(package_name)?,
             remote: author.map(|author| Remote { author }),
             dependencies: Some(IndexMap::<String, Dependency>::new()),
+            workspace: None,
+            fmt: None,
+            lint: None,
         })
     }
 
@@ -90,20 +145,37 @@ impl Manifest {
     /// Get HashMap of kind:
     ///     import name => import directory
     /// Which then used in AST/ASG to resolve import paths.
+    /// Local dependencies are resolved separately, see [`Manifest::get_local_dependencies`].
     pub fn get_imports_map(&self) -> Option<HashMap<String, String>> {
         self.dependencies.clone().map(|dependencies| {
             dependencies
                 .into_iter()
-                .map(|(name, dependency)| {
-                    (
-                        name,
-                        format!("{}-{}@{}", dependency.author, dependency.package, dependency.version),
-                    )
-                })
+                .filter(|(_, dependency)| !dependency.is_local())
+                .map(|(name, dependency)| (name, dependency.identifier()))
                 .collect()
         })
     }
 
+    /// Get the path and git dependencies, which do not require the package manager.
+    pub fn get_local_dependencies(&self) -> IndexMap<String, Dependency> {
+        self.dependencies
+            .iter()
+            .flatten()
+            .filter(|(_, dependency)| dependency.is_local())
+            .map(|(name, dependency)| (name.clone(), dependency.clone()))
+            .collect()
+    }
+
+    /// Get the maximum line width of `leo fmt`, if it is configured.
+    pub fn get_fmt_max_width(&self) -> Option<usize> {
+        self.fmt.as_ref().and_then(|fmt| fmt.max_width)
+    }
+
+    /// Get the levels of the lints set in the `[lint]` section, by their names.
+    pub fn get_lint_levels(&self) -> IndexMap<String, LintLevel> {
+        self.lint.clone().unwrap_or_default()
+    }
+
     pub fn get_package_license(&self) -> Option<String> {
         self.project.license.clone()
     }
@@ -149,6 +221,8 @@ proving_system = "groth16"
 [dependencies]
 # Define dependencies here in format:
 # name = {{ package = "package-name", author = "author", version = "version" }}
+# name = {{ path = "../package-name" }}
+# name = {{ git = "https://github.com/author/package-name.git", rev = "commit" }}
 "#,
             name = self.project.name,
             author = author
@@ -191,8 +265,14 @@ impl TryFrom<&Path> for Manifest {
 
         // Read each individual line of the toml file
         for line in buffer.lines() {
-            // Determine if the old remote format is being used
-            if line.starts_with("remote") {
+            // Determine if the old remote format is being used.
+            // Its value is a string, unlike that of a dependency named `remote`.
+            if line.starts_with("remote")
+                && line
+                    .split('=')
+                    .nth(1)
+                    .map_or(false, |value| value.trim_start().starts_with('"'))
+            {
                 let remote = line
                     .split('=') // Split the line as 'remote' = '"{author}/{package_name}"'
                     .nth(1).unwrap(); // Fetch just '"{author}/{package_name}"'
@@ -0,0 +1,182 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The `[workspace]` section of a root `Leo.toml`.
+
+use crate::root::{Dependency, Manifest, MANIFEST_FILENAME};
+use leo_errors::{PackageError, Result};
+
+use indexmap::IndexSet;
+use serde::Deserialize;
+use std::{
+    convert::TryFrom,
+    fs,
+    path::{Path, PathBuf},
+};
+
+/// A set of packages which share a `Leo.lock` and an `imports/` directory at the workspace root.
+///
+/// The root `Leo.toml` lists the member directories, relative to the root:
+/// ```toml
+/// [workspace]
+/// members = ["math", "token"]
+/// ```
+/// The root manifest does not need a `[project]` section, unless the root is a member itself.
+#[derive(Clone, Debug, Default, Deserialize)]
+pub struct Workspace {
+    #[serde(default)]
+    pub members: Vec<PathBuf>,
+}
+
+/// A root manifest, of which only the `[workspace]` section is read.
+#[derive(Deserialize)]
+struct WorkspaceManifest {
+    workspace: Option<Workspace>,
+}
+
+impl Workspace {
+    /// Reads the `[workspace]` section of the manifest in the directory `path`, if there is one.
+    pub fn read_from(path: &Path) -> Result<Option<Self>> {
+        let path = path.join(MANIFEST_FILENAME);
+        if !path.exists() {
+            return Ok(None);
+        }
+
+        let string = fs::read_to_string(&path)
+            .map_err(|error| PackageError::failed_to_read_manifest_file(MANIFEST_FILENAME, error))?;
+        let manifest: WorkspaceManifest = toml::from_str(&string)
+            .map_err(|error| PackageError::failed_to_parse_manifest_file(MANIFEST_FILENAME, error))?;
+
+        Ok(manifest.workspace)
+    }
+
+    /// Returns the root directory of the workspace the package at `path` belongs to, if any.
+    /// This is the closest ancestor directory, or `path` itself, listing the package as a member.
+    pub fn find_root(path: &Path) -> Result<Option<PathBuf>> {
+        let path = canonicalize(path)?;
+
+        for root in path.ancestors() {
+            let workspace = match Self::read_from(root)? {
+                Some(workspace) => workspace,
+                None => continue,
+            };
+
+            if root == path || workspace.member_paths(root)?.contains(&path) {
+                return Ok(Some(root.to_path_buf()));
+            }
+        }
+
+        Ok(None)
+    }
+
+    /// Returns the member directories of the workspace at `root`, in the order they are listed.
+    pub fn member_paths(&self, root: &Path) -> Result<Vec<PathBuf>> {
+        self.members
+            .iter()
+            .map(|member| {
+                let path = root.join(member);
+                if !Manifest::exists_at(&path) {
+                    return Err(PackageError::workspace_member_not_found(member.display()).into());
+                }
+                canonicalize(&path)
+            })
+            .collect()
+    }
+
+    /// Returns the members of the workspace at `root` along with their manifests,
+    /// ordered so that each member comes after the members it depends on through a path dependency.
+    pub fn members_in_build_order(&self, root: &Path) -> Result<Vec<(PathBuf, Manifest)>> {
+        let members = self
+            .member_paths(root)?
+            .into_iter()
+            .map(|path| {
+                let manifest = Manifest::try_from(path.as_path())?;
+                Ok((path, manifest))
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let paths: Vec<PathBuf> = members.iter().map(|(path, _)| path.clone()).collect();
+
+        // The members each member depends on, as indices into `members`.
+        let mut dependencies = Vec::with_capacity(members.len());
+        for (path, manifest) in members.iter() {
+            let mut member_dependencies = Vec::new();
+            for dependency in manifest.get_local_dependencies().values() {
+                if let Dependency::Path { path: dependency_path } = dependency {
+                    // Missing dependencies are reported when the member is resolved.
+                    let dependency_path = path.join(dependency_path);
+                    if !dependency_path.exists() {
+                        continue;
+                    }
+
+                    let dependency_path = canonicalize(&dependency_path)?;
+                    if let Some(index) = paths.iter().position(|member| *member == dependency_path) {
+                        member_dependencies.push(index);
+                    }
+                }
+            }
+            dependencies.push(member_dependencies);
+        }
+
+        let mut order = IndexSet::new();
+        let mut stack = IndexSet::new();
+        for index in 0..members.len() {
+            Self::visit(index, &members, &dependencies, &mut order, &mut stack)?;
+        }
+
+        let mut members: Vec<_> = members.into_iter().map(Some).collect();
+        Ok(order.into_iter().map(|index| members[index].take().unwrap()).collect())
+    }
+
+    /// Adds the member at `index` to `order`, after the members it depends on.
+    fn visit(
+        index: usize,
+        members: &[(PathBuf, Manifest)],
+        dependencies: &[Vec<usize>],
+        order: &mut IndexSet<usize>,
+        stack: &mut IndexSet<usize>,
+    ) -> Result<()> {
+        if order.contains(&index) {
+            return Ok(());
+        }
+
+        if !stack.insert(index) {
+            let tree = stack
+                .iter()
+                .chain(std::iter::once(&index))
+                .enumerate()
+                .map(|(i, member)| format!("{}└─{}", " ".repeat(i * 2), members[*member].1.get_package_name()))
+                .collect::<Vec<_>>()
+                .join("\n");
+
+            return Err(PackageError::recursive_dependency(tree).into());
+        }
+
+        for dependency in dependencies[index].iter() {
+            Self::visit(*dependency, members, dependencies, order, stack)?;
+        }
+
+        stack.pop();
+        order.insert(index);
+        Ok(())
+    }
+}
+
+fn canonicalize(path: &Path) -> Result<PathBuf> {
+    Ok(path
+        .canonicalize()
+        .map_err(|error| PackageError::io_error(path.display(), error))?)
+}
@@ -28,7 +28,9 @@ use crate::{
 };
 use leo_errors::{PackageError, Result};
 
+use indexmap::IndexMap;
 use serde::Deserialize;
+use sha2::{Digest, Sha256};
 use std::{
     borrow::Cow,
     fs::{
@@ -38,9 +40,22 @@ use std::{
     path::Path,
 };
 use walkdir::WalkDir;
-use zip::write::{FileOptions, ZipWriter};
+use zip::{
+    write::{FileOptions, ZipWriter},
+    DateTime, ZipArchive,
+};
 
 pub static ZIP_FILE_EXTENSION: &str = ".zip";
+pub static ARCHIVE_MANIFEST_FILENAME: &str = "Leo.sha256";
+
+/// A file of the package zip file, as listed in its checksum manifest.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ArchiveEntry {
+    pub name: String,
+    /// The SHA256 checksum of the file.
+    pub checksum: String,
+    pub size: u64,
+}
 
 #[derive(Deserialize)]
 pub struct ZipFile {
@@ -71,9 +86,12 @@ impl ZipFile {
     // }
 
     /// Writes the current package contents to a zip file.
+    ///
+    /// The archive is reproducible: entries are written in sorted order with fixed timestamps and
+    /// permissions, and a checksum manifest listing the SHA-256 of each file is embedded last.
     pub fn write(&self, src_dir: &Path) -> Result<()> {
-        // Build walkdir iterator from current package
-        let walkdir = WalkDir::new(src_dir);
+        // Build walkdir iterator from current package, independent of the file system order
+        let walkdir = WalkDir::new(src_dir).sort_by(|a, b| a.file_name().cmp(b.file_name()));
 
         // Create zip file
         let path = self.setup_file_path(src_dir);
@@ -82,9 +100,11 @@ impl ZipFile {
         let mut zip = ZipWriter::new(file);
         let options = FileOptions::default()
             .compression_method(zip::CompressionMethod::Stored)
+            .last_modified_time(DateTime::default())
             .unix_permissions(0o755);
 
         // Walk through files in directory and write desired ones to the zip file
+        let mut manifest = String::new();
         let mut buffer = Vec::new();
         for entry in walkdir.into_iter().filter_map(|e| e.ok()) {
             let path = entry.path();
@@ -98,11 +118,13 @@ impl ZipFile {
                 continue;
             }
 
+            // Use `/` separated names on every platform
+            let name = archive_name(name);
+
             // Write file or directory
             if path.is_file() {
                 tracing::info!("Adding file {:?} as {:?}", path, name);
-                #[allow(deprecated)]
-                zip.start_file_from_path(name, options)
+                zip.start_file(&name, options)
                     .map_err(PackageError::io_error_zip_file)?;
 
                 let mut f = File::open(path).map_err(PackageError::failed_to_open_zip_file)?;
@@ -111,17 +133,24 @@ impl ZipFile {
                 zip.write_all(&*buffer)
                     .map_err(PackageError::failed_to_write_zip_file)?;
 
+                manifest.push_str(&format!("{}  {}\n", checksum(&buffer), name));
+
                 buffer.clear();
-            } else if !name.as_os_str().is_empty() {
+            } else if !name.is_empty() {
                 // Only if not root Avoids path spec / warning
                 // and mapname conversion failed error on unzip
                 tracing::info!("Adding directory {:?} as {:?}", path, name);
-                #[allow(deprecated)]
-                zip.add_directory_from_path(name, options)
+                zip.add_directory(&name, options)
                     .map_err(PackageError::io_error_zip_file)?;
             }
         }
 
+        // Embed the checksum manifest
+        zip.start_file(ARCHIVE_MANIFEST_FILENAME, options)
+            .map_err(PackageError::io_error_zip_file)?;
+        zip.write_all(manifest.as_bytes())
+            .map_err(PackageError::failed_to_write_zip_file)?;
+
         zip.finish().map_err(PackageError::io_error_zip_file)?;
 
         tracing::info!("Package zip file created successfully {:?}", path);
@@ -129,6 +158,64 @@ impl ZipFile {
         Ok(())
     }
 
+    /// Checks every file of the package zip file at the given path against its embedded
+    /// checksum manifest, and returns the verified files.
+    pub fn verify(path: &Path) -> Result<Vec<ArchiveEntry>> {
+        let file = File::open(path).map_err(PackageError::failed_to_open_zip_file)?;
+        let mut archive = ZipArchive::new(file).map_err(PackageError::failed_to_read_zip_file)?;
+
+        // Read the expected checksums
+        let mut manifest = String::new();
+        archive
+            .by_name(ARCHIVE_MANIFEST_FILENAME)
+            .map_err(|_| PackageError::archive_manifest_missing(path, ARCHIVE_MANIFEST_FILENAME))?
+            .read_to_string(&mut manifest)
+            .map_err(PackageError::failed_to_read_zip_file)?;
+
+        let mut expected = IndexMap::new();
+        for line in manifest.lines() {
+            let (checksum, name) = line
+                .split_once("  ")
+                .ok_or_else(|| PackageError::invalid_archive_manifest(line))?;
+            expected.insert(name.to_string(), checksum.to_string());
+        }
+
+        // Check the archive files against the manifest
+        let mut entries = Vec::new();
+        let mut buffer = Vec::new();
+        for index in 0..archive.len() {
+            let mut file = archive.by_index(index).map_err(PackageError::failed_to_read_zip_file)?;
+            if file.is_dir() || file.name() == ARCHIVE_MANIFEST_FILENAME {
+                continue;
+            }
+
+            let name = file.name().to_string();
+            let expected_checksum = expected
+                .remove(&name)
+                .ok_or_else(|| PackageError::archive_file_not_listed(&name))?;
+
+            file.read_to_end(&mut buffer)
+                .map_err(PackageError::failed_to_read_zip_file)?;
+            let checksum = checksum(&buffer);
+            if checksum != expected_checksum {
+                return Err(PackageError::archive_checksum_mismatch(name).into());
+            }
+
+            entries.push(ArchiveEntry {
+                name,
+                checksum,
+                size: buffer.len() as u64,
+            });
+            buffer.clear();
+        }
+
+        if let Some(name) = expected.keys().next() {
+            return Err(PackageError::archive_file_missing(name).into());
+        }
+
+        Ok(entries)
+    }
+
     /// Removes the zip file at the given path if it exists. Returns `true` on success,
     /// `false` if the file doesn't exist, and `Error` if the file system fails during operation.
     pub fn remove(&self, path: &Path) -> Result<bool> {
@@ -154,8 +241,20 @@ impl ZipFile {
     }
 }
 
+/// Returns the `/` separated name of the path in the package zip file.
+fn archive_name(path: &Path) -> String {
+    path.components()
+        .map(|component| component.as_os_str().to_string_lossy())
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+fn checksum(bytes: &[u8]) -> String {
+    format!("{:x}", Sha256::digest(bytes))
+}
+
 /// Check if the file path should be included in the package zip file.
-fn is_included(path: &Path) -> bool {
+pub(crate) fn is_included(path: &Path) -> bool {
     // DO NOT include `imports` and `outputs` directories.
     if path.starts_with(IMPORTS_DIRECTORY_NAME) || path.starts_with(OUTPUTS_DIRECTORY_NAME) {
         return false;
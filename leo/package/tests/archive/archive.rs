@@ -0,0 +1,138 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+// Tests for reproducible and verifiable package zip files
+
+use crate::test_dir;
+use leo_package::{
+    outputs::OutputsDirectory,
+    package::Package as LeoPackage,
+    root::{ZipFile, ARCHIVE_MANIFEST_FILENAME},
+};
+
+use std::{
+    fs::{self, File},
+    io::Write,
+    path::{Path, PathBuf},
+    thread,
+    time::Duration,
+};
+use zip::write::{FileOptions, ZipWriter};
+
+const TEST_PACKAGE_NAME: &str = "test-package";
+
+/// Initialize a package in a new test directory and return its path.
+fn initialize_package() -> PathBuf {
+    let package_path = test_dir().join(TEST_PACKAGE_NAME);
+    fs::create_dir_all(&package_path).unwrap();
+    LeoPackage::initialize(TEST_PACKAGE_NAME, &package_path, None).unwrap();
+    OutputsDirectory::create(&package_path).unwrap();
+    package_path
+}
+
+/// Write the package zip file and return its bytes.
+fn write_archive(package_path: &Path) -> Vec<u8> {
+    let zip_file = ZipFile::new(TEST_PACKAGE_NAME);
+    zip_file.write(package_path).unwrap();
+    fs::read(zip_file.get_file_path(package_path)).unwrap()
+}
+
+/// Write a zip file at `path` with the given files.
+fn write_zip(path: &Path, files: &[(&str, &str)]) {
+    let mut zip = ZipWriter::new(File::create(path).unwrap());
+    for (name, contents) in files {
+        zip.start_file(*name, FileOptions::default()).unwrap();
+        zip.write_all(contents.as_bytes()).unwrap();
+    }
+    zip.finish().unwrap();
+}
+
+#[test]
+fn test_archive_is_reproducible() {
+    let package_path = initialize_package();
+    let first = write_archive(&package_path);
+
+    // Touching the package files does not change the archive.
+    thread::sleep(Duration::from_secs(2));
+    let main_file = package_path.join("src").join("main.leo");
+    fs::write(&main_file, fs::read(&main_file).unwrap()).unwrap();
+
+    assert_eq!(first, write_archive(&package_path));
+}
+
+#[test]
+fn test_verify_archive() {
+    let package_path = initialize_package();
+    write_archive(&package_path);
+
+    let entries = ZipFile::verify(&ZipFile::new(TEST_PACKAGE_NAME).get_file_path(&package_path)).unwrap();
+    let names: Vec<_> = entries.iter().map(|entry| entry.name.as_str()).collect();
+
+    assert!(names.contains(&"Leo.toml"));
+    assert!(names.contains(&"src/main.leo"));
+    assert!(!names.contains(&ARCHIVE_MANIFEST_FILENAME));
+
+    // Entries are sorted by path.
+    let mut sorted = names.clone();
+    sorted.sort_unstable();
+    assert_eq!(names, sorted);
+}
+
+#[test]
+fn test_verify_missing_manifest() {
+    let path = test_dir().join("package.zip");
+    write_zip(&path, &[("Leo.toml", "")]);
+
+    assert!(ZipFile::verify(&path).is_err());
+}
+
+#[test]
+fn test_verify_modified_file() {
+    let path = test_dir().join("package.zip");
+    // The checksum of the empty file.
+    let manifest = "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855  Leo.toml\n";
+    write_zip(
+        &path,
+        &[("Leo.toml", "modified"), (ARCHIVE_MANIFEST_FILENAME, manifest)],
+    );
+
+    assert!(ZipFile::verify(&path).is_err());
+}
+
+#[test]
+fn test_verify_unlisted_file() {
+    let path = test_dir().join("package.zip");
+    let manifest = "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855  Leo.toml\n";
+    write_zip(
+        &path,
+        &[
+            ("Leo.toml", ""),
+            ("src/extra.leo", ""),
+            (ARCHIVE_MANIFEST_FILENAME, manifest),
+        ],
+    );
+
+    assert!(ZipFile::verify(&path).is_err());
+}
+
+#[test]
+fn test_verify_missing_file() {
+    let path = test_dir().join("package.zip");
+    let manifest = "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855  Leo.toml\n";
+    write_zip(&path, &[(ARCHIVE_MANIFEST_FILENAME, manifest)]);
+
+    assert!(ZipFile::verify(&path).is_err());
+}
@@ -0,0 +1,364 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+// Tests for path and git dependencies
+
+use crate::{initialize_package, test_dir};
+use leo_package::{
+    imports::{DependencyResolver, LocalDependency},
+    root::{Dependency, LockFile, Manifest, Package},
+};
+
+use std::{
+    convert::TryFrom,
+    fs::{self, File},
+    io::Write,
+    path::{Path, PathBuf},
+};
+
+const TEST_PACKAGE_NAME: &str = "test-package";
+const TEST_DEPENDENCY_NAME: &str = "shared";
+
+const LOCAL_DEPENDENCIES: &str = r#"
+shared = { path = "../shared" }
+remote = { git = "https://github.com/author/remote.git", rev = "a1b2c3d" }
+registry = { package = "registry", author = "author", version = "0.1.0" }
+"#;
+
+/// Create a package with a path dependency, returning the package and dependency paths.
+fn create_path_dependency() -> (PathBuf, PathBuf) {
+    let test_directory = test_dir();
+    let package_path = test_directory.join(TEST_PACKAGE_NAME);
+    let dependency_path = test_directory.join(TEST_DEPENDENCY_NAME);
+
+    initialize_package(&dependency_path, TEST_DEPENDENCY_NAME, "");
+    initialize_package(&package_path, TEST_PACKAGE_NAME, r#"shared = { path = "../shared" }"#);
+
+    (package_path, dependency_path)
+}
+
+#[test]
+fn test_manifest_dependency_kinds() {
+    let test_directory = test_dir();
+    initialize_package(&test_directory, TEST_PACKAGE_NAME, LOCAL_DEPENDENCIES);
+
+    let manifest = Manifest::try_from(test_directory.as_path()).unwrap();
+    let dependencies = manifest.get_package_dependencies().unwrap();
+
+    assert!(matches!(dependencies["shared"], Dependency::Path { .. }));
+    assert!(matches!(dependencies["remote"], Dependency::Git { .. }));
+    assert!(matches!(dependencies["registry"], Dependency::Registry { .. }));
+
+    // Only registry dependencies are resolved from `imports/` by name.
+    let imports_map = manifest.get_imports_map().unwrap();
+    assert_eq!(imports_map.len(), 1);
+    assert_eq!(imports_map["registry"], "author-registry@0.1.0");
+
+    let local_dependencies = manifest.get_local_dependencies();
+    assert_eq!(local_dependencies.keys().collect::<Vec<_>>(), vec!["shared", "remote"]);
+
+    // Registry dependencies are rejected instead of being resolved as local ones.
+    let error = LocalDependency::resolve(&test_directory, &test_directory, "registry", &dependencies["registry"]);
+    assert!(error.is_err());
+}
+
+#[test]
+fn test_path_dependency_lock_entry() {
+    let (package_path, dependency_path) = create_path_dependency();
+
+    let manifest = Manifest::try_from(package_path.as_path()).unwrap();
+    let dependency = &manifest.get_local_dependencies()[TEST_DEPENDENCY_NAME];
+    let resolved = LocalDependency::resolve(&package_path, &package_path, TEST_DEPENDENCY_NAME, dependency).unwrap();
+    assert_eq!(resolved.path, package_path.join("../shared"));
+
+    let mut lock_file = LockFile::new();
+    lock_file.insert_package(Package::from(&resolved));
+    lock_file.write_to(&package_path).unwrap();
+
+    let lock_file = LockFile::try_from(package_path.as_path()).unwrap();
+    let package = lock_file.get_local_package(TEST_DEPENDENCY_NAME).unwrap();
+    assert_eq!(package.name, TEST_DEPENDENCY_NAME);
+    assert_eq!(package.source.as_deref(), Some("path+../shared"));
+    assert_eq!(
+        package.checksum.as_deref(),
+        Some(&*LocalDependency::checksum(TEST_DEPENDENCY_NAME, &dependency_path).unwrap())
+    );
+
+    // Path dependencies are not looked up in `imports/`.
+    assert!(lock_file.to_import_map().is_empty());
+}
+
+#[test]
+fn test_path_dependency_checksum_tracks_sources() {
+    let (_, dependency_path) = create_path_dependency();
+
+    let checksum = LocalDependency::checksum(TEST_DEPENDENCY_NAME, &dependency_path).unwrap();
+    assert_eq!(
+        checksum,
+        LocalDependency::checksum(TEST_DEPENDENCY_NAME, &dependency_path).unwrap()
+    );
+
+    // Build outputs do not change the checksum.
+    fs::create_dir_all(dependency_path.join("outputs")).unwrap();
+    File::create(dependency_path.join("outputs/shared.sum")).unwrap();
+    assert_eq!(
+        checksum,
+        LocalDependency::checksum(TEST_DEPENDENCY_NAME, &dependency_path).unwrap()
+    );
+
+    // Source changes do.
+    let mut main = fs::OpenOptions::new()
+        .append(true)
+        .open(dependency_path.join("src/main.leo"))
+        .unwrap();
+    main.write_all(b"\n// changed\n").unwrap();
+    assert_ne!(
+        checksum,
+        LocalDependency::checksum(TEST_DEPENDENCY_NAME, &dependency_path).unwrap()
+    );
+}
+
+#[test]
+fn test_path_dependency_not_a_package() {
+    let test_directory = test_dir();
+    initialize_package(
+        &test_directory,
+        TEST_PACKAGE_NAME,
+        r#"shared = { path = "../missing" }"#,
+    );
+
+    let manifest = Manifest::try_from(test_directory.as_path()).unwrap();
+    let dependency = &manifest.get_local_dependencies()[TEST_DEPENDENCY_NAME];
+    assert!(LocalDependency::resolve(&test_directory, &test_directory, TEST_DEPENDENCY_NAME, dependency).is_err());
+}
+
+#[test]
+fn test_git_dependency_checkout_directory_tracks_url() {
+    let test_directory = test_dir();
+
+    let checkout = |url: &str| LocalDependency::checkout_directory(&test_directory, "remote", url, "a1b2c3d");
+    let first = checkout("https://github.com/author/remote.git");
+    assert_eq!(first, checkout("https://github.com/author/remote.git"));
+    assert_ne!(first, checkout("https://github.com/other/remote.git"));
+    assert!(first
+        .file_name()
+        .unwrap()
+        .to_string_lossy()
+        .starts_with("remote@a1b2c3d_"));
+}
+
+#[test]
+fn test_git_dependency_rejects_options() {
+    let test_directory = test_dir();
+
+    for (git, rev) in [
+        ("--upload-pack=touch pwned", "a1b2c3d"),
+        ("https://github.com/author/remote.git", "--output=pwned"),
+    ] {
+        let dependency = Dependency::Git {
+            git: git.to_string(),
+            rev: rev.to_string(),
+        };
+        let error = LocalDependency::resolve(&test_directory, &test_directory, "remote", &dependency)
+            .err()
+            .unwrap();
+        assert!(
+            error.to_string().contains("has an invalid url or revision"),
+            "{}",
+            error
+        );
+    }
+    assert!(!test_directory.join("imports").exists());
+}
+
+/// Install a registry package into the `imports/` directory of the package at `path`.
+fn install_package(path: &Path, name: &str, version: &str, dependencies: &str) {
+    let package_path = path.join("imports").join(format!("author-{}@{}", name, version));
+    initialize_package(&package_path, name, dependencies);
+}
+
+/// Create a package with registry dependencies and a few installed versions of them.
+fn create_registry_dependencies(dependencies: &str) -> PathBuf {
+    let test_directory = test_dir();
+    initialize_package(&test_directory, TEST_PACKAGE_NAME, dependencies);
+
+    install_package(&test_directory, "math", "0.1.0", "");
+    install_package(&test_directory, "math", "0.1.4", "");
+    install_package(&test_directory, "math", "0.2.0", "");
+    install_package(
+        &test_directory,
+        "geometry",
+        "1.0.0",
+        r#"math = { package = "math", author = "author", version = ">=0.1.2, <0.3" }"#,
+    );
+
+    test_directory
+}
+
+/// Resolve the dependencies of the package at `path`.
+fn resolve(path: &Path, locked: Option<&LockFile>) -> leo_errors::Result<LockFile> {
+    let manifest = Manifest::try_from(path).unwrap();
+    DependencyResolver::new(path)?.resolve(&manifest, locked)
+}
+
+#[test]
+fn test_resolve_semver_requirements() {
+    let test_directory = create_registry_dependencies(
+        r#"geometry = { package = "geometry", author = "author", version = "^1.0" }
+math = { package = "math", author = "author", version = "0.1" }"#,
+    );
+
+    let lock_file = resolve(&test_directory, None).unwrap();
+    let packages = lock_file
+        .package
+        .iter()
+        .map(|package| package.to_string())
+        .collect::<Vec<_>>();
+
+    // The highest version satisfying both `0.1` and `>=0.1.2, <0.3` is selected.
+    assert_eq!(packages, vec!["author-geometry@1.0.0", "author-math@0.1.4"]);
+
+    let geometry = &lock_file.package[0];
+    assert_eq!(geometry.import_name.as_deref(), Some("geometry"));
+    assert_eq!(geometry.dependencies["math"], "author-math@0.1.4");
+    assert!(geometry.checksum.is_some());
+
+    // Resolution is deterministic.
+    assert_eq!(lock_file, resolve(&test_directory, None).unwrap());
+    assert_eq!(
+        lock_file.to_string().unwrap(),
+        resolve(&test_directory, Some(&lock_file)).unwrap().to_string().unwrap()
+    );
+}
+
+#[test]
+fn test_resolve_transitive_dependencies() {
+    let test_directory =
+        create_registry_dependencies(r#"geometry = { package = "geometry", author = "author", version = "1.0.0" }"#);
+
+    let lock_file = resolve(&test_directory, None).unwrap();
+    let math = lock_file.package.iter().find(|package| package.name == "math").unwrap();
+
+    // Transitive dependencies are locked, but not importable from the root package.
+    assert_eq!(math.version, "0.2.0");
+    assert_eq!(math.import_name, None);
+}
+
+#[test]
+fn test_resolve_keeps_locked_versions() {
+    let test_directory =
+        create_registry_dependencies(r#"math = { package = "math", author = "author", version = "0.1" }"#);
+
+    let mut locked = resolve(&test_directory, None).unwrap();
+    assert_eq!(locked.package[0].version, "0.1.4");
+
+    // An older version recorded in the lock file is kept while it satisfies the manifest.
+    install_package(&test_directory, "math", "0.1.2", "");
+    locked.package[0].version = "0.1.2".to_string();
+    locked.package[0].checksum =
+        Some(LocalDependency::checksum("math", &test_directory.join("imports/author-math@0.1.2")).unwrap());
+    assert_eq!(locked, resolve(&test_directory, Some(&locked)).unwrap());
+}
+
+#[test]
+fn test_resolve_version_conflict() {
+    let test_directory = create_registry_dependencies(
+        r#"geometry = { package = "geometry", author = "author", version = "1.0.0" }
+math = { package = "math", author = "author", version = "=0.1.0" }"#,
+    );
+
+    assert!(resolve(&test_directory, None).is_err());
+}
+
+#[test]
+fn test_resolve_missing_dependency() {
+    let test_directory =
+        create_registry_dependencies(r#"math = { package = "math", author = "author", version = "^0.3" }"#);
+
+    assert!(resolve(&test_directory, None).is_err());
+}
+
+#[test]
+fn test_resolve_detects_modified_dependency() {
+    let test_directory =
+        create_registry_dependencies(r#"math = { package = "math", author = "author", version = "0.2.0" }"#);
+
+    let locked = resolve(&test_directory, None).unwrap();
+
+    let mut main = fs::OpenOptions::new()
+        .append(true)
+        .open(test_directory.join("imports/author-math@0.2.0/src/main.leo"))
+        .unwrap();
+    main.write_all(b"\n// changed\n").unwrap();
+
+    assert_ne!(locked, resolve(&test_directory, Some(&locked)).unwrap());
+}
+
+#[test]
+fn test_lock_file_graph_ignores_local_checksums() {
+    let (package_path, dependency_path) = create_path_dependency();
+    let locked = resolve(&package_path, None).unwrap();
+
+    let mut main = fs::OpenOptions::new()
+        .append(true)
+        .open(dependency_path.join("src/main.leo"))
+        .unwrap();
+    main.write_all(b"\n// changed\n").unwrap();
+
+    // Editing a path dependency changes its checksum, but not the dependency graph.
+    let lock_file = resolve(&package_path, Some(&locked)).unwrap();
+    assert_ne!(locked, lock_file);
+    assert!(locked.same_graph(&lock_file));
+
+    // Removing the dependency from the manifest does.
+    let manifest = fs::read_to_string(package_path.join("Leo.toml")).unwrap();
+    fs::write(
+        package_path.join("Leo.toml"),
+        manifest.replace(r#"shared = { path = "../shared" }"#, ""),
+    )
+    .unwrap();
+    assert!(!locked.same_graph(&resolve(&package_path, Some(&locked)).unwrap()));
+}
+
+#[test]
+fn test_lock_file_graph_compares_git_checksums() {
+    let git = |checksum: &str| {
+        let mut lock_file = LockFile::new();
+        lock_file.insert_package(Package {
+            import_name: Some("remote".to_string()),
+            source: Some("git+https://github.com/author/remote.git#a1b2c3d".to_string()),
+            checksum: Some(checksum.to_string()),
+            ..Package::registry("author", "remote", "0.1.0")
+        });
+        lock_file
+    };
+
+    // The contents of a git revision must not change.
+    assert!(git("locked").same_graph(&git("locked")));
+    assert!(!git("locked").same_graph(&git("modified")));
+}
+
+#[test]
+fn test_lock_file_graph_compares_registry_checksums() {
+    let test_directory =
+        create_registry_dependencies(r#"math = { package = "math", author = "author", version = "0.2.0" }"#);
+    let mut locked = resolve(&test_directory, None).unwrap();
+
+    // The contents of an installed version must not change.
+    let lock_file = locked.clone();
+    locked.package[0].checksum = Some("modified".to_string());
+    assert!(!locked.same_graph(&lock_file));
+}
@@ -17,7 +17,7 @@
 // Tests for package manifest
 
 use crate::test_dir;
-use leo_package::root::{Manifest, MANIFEST_FILENAME};
+use leo_package::root::{LintLevel, Manifest, MANIFEST_FILENAME};
 
 use std::{
     convert::TryFrom,
@@ -167,3 +167,29 @@ fn test_manifest_refactors() {
     // Check that the manifest file remote has been updated.
     assert!(remote_is_updated(&manifest_path));
 }
+
+#[test]
+fn test_manifest_lint_levels() {
+    let mut path = test_dir();
+    path.push(MANIFEST_FILENAME);
+    let mut file = File::create(&path).unwrap();
+    file.write_all(
+        br#"[project]
+name = "test-package"
+version = "0.1.0"
+description = "Testing lint levels."
+license = "MIT"
+
+[lint]
+unused_variable = "allow"
+console_log_in_main = "deny"
+"#,
+    )
+    .unwrap();
+
+    let manifest = Manifest::try_from(path.as_path()).unwrap();
+    let levels = manifest.get_lint_levels();
+    assert_eq!(levels["unused_variable"], LintLevel::Allow);
+    assert_eq!(levels["console_log_in_main"], LintLevel::Deny);
+    assert_eq!(levels.get("unused_import"), None);
+}
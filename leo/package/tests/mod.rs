@@ -16,14 +16,20 @@
 
 #![allow(clippy::module_inception)]
 
+pub mod archive;
+pub mod dependencies;
 pub mod initialize;
 pub mod manifest;
+pub mod registry;
+pub mod workspace;
 
 use lazy_static::lazy_static;
+use leo_package::package::Package;
 use std::{
     cell::RefCell,
     env, fs,
-    path::PathBuf,
+    io::Write,
+    path::{Path, PathBuf},
     sync::atomic::{AtomicUsize, Ordering},
 };
 
@@ -72,3 +78,12 @@ pub(crate) fn test_dir() -> PathBuf {
 
     path
 }
+
+/// Initialize a package at `path` and append `dependencies` to its manifest.
+pub(crate) fn initialize_package(path: &Path, name: &str, dependencies: &str) {
+    fs::create_dir_all(path).unwrap();
+    Package::initialize(name, path, Some("author".to_string())).unwrap();
+
+    let mut file = fs::OpenOptions::new().append(true).open(path.join("Leo.toml")).unwrap();
+    file.write_all(dependencies.as_bytes()).unwrap();
+}
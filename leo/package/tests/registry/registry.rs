@@ -0,0 +1,145 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+// Tests for the offline file system registry
+
+use crate::test_dir;
+use leo_package::{
+    outputs::OutputsDirectory,
+    package::Package as LeoPackage,
+    registry::{FileRegistry, RegistryEntry, RegistryIndex},
+    root::ZipFile,
+};
+
+use std::{fs, path::PathBuf};
+
+const TEST_AUTHOR: &str = "author";
+const TEST_PACKAGE_NAME: &str = "test-package";
+
+/// Initialize a package and write its zip file, returning the path of the archive.
+fn package_archive() -> PathBuf {
+    let package_path = test_dir().join(TEST_PACKAGE_NAME);
+    fs::create_dir_all(&package_path).unwrap();
+    LeoPackage::initialize(TEST_PACKAGE_NAME, &package_path, Some(TEST_AUTHOR.to_string())).unwrap();
+    OutputsDirectory::create(&package_path).unwrap();
+
+    let zip_file = ZipFile::new(TEST_PACKAGE_NAME);
+    zip_file.write(&package_path).unwrap();
+    zip_file.get_file_path(&package_path).into_owned()
+}
+
+#[test]
+fn test_registry_from_url() {
+    let registry = FileRegistry::from_url("file:///tmp/registry").unwrap();
+    assert_eq!(registry.root(), PathBuf::from("/tmp/registry"));
+
+    assert!(FileRegistry::from_url("https://api.aleo.pm/").is_none());
+}
+
+#[test]
+fn test_publish_and_fetch() {
+    let archive = package_archive();
+    let registry = FileRegistry::new(test_dir());
+
+    registry
+        .publish(TEST_AUTHOR, TEST_PACKAGE_NAME, "0.1.0", &archive)
+        .unwrap();
+    registry
+        .publish(TEST_AUTHOR, TEST_PACKAGE_NAME, "0.2.0", &archive)
+        .unwrap();
+
+    // The latest version is fetched by default.
+    let (entry, bytes) = registry.fetch(TEST_AUTHOR, TEST_PACKAGE_NAME, None).unwrap();
+    assert_eq!(entry.version, "0.2.0");
+    assert_eq!(bytes, fs::read(&archive).unwrap());
+
+    // Versions may be selected with a semver requirement.
+    let (entry, _) = registry.fetch(TEST_AUTHOR, TEST_PACKAGE_NAME, Some("^0.1")).unwrap();
+    assert_eq!(entry.version, "0.1.0");
+
+    let index = RegistryIndex::read_from(registry.root()).unwrap();
+    assert_eq!(index.package.len(), 2);
+}
+
+#[test]
+fn test_publish_twice() {
+    let archive = package_archive();
+    let registry = FileRegistry::new(test_dir());
+
+    registry
+        .publish(TEST_AUTHOR, TEST_PACKAGE_NAME, "0.1.0", &archive)
+        .unwrap();
+    assert!(registry
+        .publish(TEST_AUTHOR, TEST_PACKAGE_NAME, "0.1.0", &archive)
+        .is_err());
+}
+
+#[test]
+fn test_fetch_missing_package() {
+    let registry = FileRegistry::new(test_dir());
+
+    assert!(registry.fetch(TEST_AUTHOR, TEST_PACKAGE_NAME, None).is_err());
+}
+
+#[test]
+fn test_fetch_modified_archive() {
+    let archive = package_archive();
+    let registry = FileRegistry::new(test_dir());
+
+    let entry = registry
+        .publish(TEST_AUTHOR, TEST_PACKAGE_NAME, "0.1.0", &archive)
+        .unwrap();
+    fs::write(registry.root().join(&entry.file), b"modified").unwrap();
+
+    assert!(registry.fetch(TEST_AUTHOR, TEST_PACKAGE_NAME, None).is_err());
+}
+
+#[test]
+fn test_publish_rejects_paths() {
+    let archive = package_archive();
+    let root = test_dir().join("registry");
+    let registry = FileRegistry::new(root.clone());
+
+    for (author, name, version) in [
+        ("..", TEST_PACKAGE_NAME, "0.1.0"),
+        (TEST_AUTHOR, "../outside", "0.1.0"),
+        (TEST_AUTHOR, TEST_PACKAGE_NAME, "0.1.0/../../outside"),
+    ] {
+        assert!(registry.publish(author, name, version, &archive).is_err());
+    }
+    assert!(!root.exists());
+}
+
+#[test]
+fn test_fetch_rejects_entries_outside_the_registry() {
+    let archive = package_archive();
+    let registry = FileRegistry::new(test_dir().join("registry"));
+
+    let entry = registry
+        .publish(TEST_AUTHOR, TEST_PACKAGE_NAME, "0.1.0", &archive)
+        .unwrap();
+    for file in ["../outside.zip", "/tmp/outside.zip"] {
+        let index = RegistryIndex {
+            package: vec![RegistryEntry {
+                file: file.to_string(),
+                ..entry.clone()
+            }],
+        };
+        index.write_to(registry.root()).unwrap();
+
+        assert!(registry.fetch(TEST_AUTHOR, TEST_PACKAGE_NAME, None).is_err());
+    }
+}
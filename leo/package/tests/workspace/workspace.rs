@@ -0,0 +1,120 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+// Tests for workspaces of several packages
+
+use crate::{initialize_package, test_dir};
+use leo_package::{
+    imports::DependencyResolver,
+    root::{LockFile, Workspace},
+};
+
+use std::{convert::TryFrom, fs, io::Write, path::PathBuf};
+
+/// Create a workspace of an `app` package depending on a `math` library and a registry package,
+/// returning the workspace root.
+fn create_workspace() -> PathBuf {
+    let root = test_dir().canonicalize().unwrap();
+    fs::write(root.join("Leo.toml"), "[workspace]\nmembers = [\"app\", \"math\"]\n").unwrap();
+
+    initialize_package(
+        &root.join("app"),
+        "app",
+        r#"math = { path = "../math" }
+token = { package = "token", author = "author", version = "^0.1" }"#,
+    );
+    initialize_package(&root.join("math"), "math", "");
+
+    // Registry packages are installed in the `imports/` directory of the workspace root.
+    initialize_package(&root.join("imports").join("author-token@0.1.2"), "token", "");
+
+    root
+}
+
+#[test]
+fn test_find_workspace_root() {
+    let root = create_workspace();
+
+    assert_eq!(Workspace::find_root(&root).unwrap(), Some(root.clone()));
+    assert_eq!(Workspace::find_root(&root.join("app")).unwrap(), Some(root.clone()));
+
+    // Packages which are not listed as members do not belong to the workspace.
+    initialize_package(&root.join("other"), "other", "");
+    assert_eq!(Workspace::find_root(&root.join("other")).unwrap(), None);
+}
+
+#[test]
+fn test_members_in_build_order() {
+    let root = create_workspace();
+    let workspace = Workspace::read_from(&root).unwrap().unwrap();
+
+    // `app` is listed first, but depends on `math`.
+    let members = workspace.members_in_build_order(&root).unwrap();
+    let names = members
+        .iter()
+        .map(|(_, manifest)| manifest.get_package_name())
+        .collect::<Vec<_>>();
+    assert_eq!(names, vec!["math", "app"]);
+}
+
+#[test]
+fn test_recursive_members() {
+    let root = create_workspace();
+    let mut file = fs::OpenOptions::new()
+        .append(true)
+        .open(root.join("math").join("Leo.toml"))
+        .unwrap();
+    file.write_all(br#"app = { path = "../app" }"#).unwrap();
+
+    let workspace = Workspace::read_from(&root).unwrap().unwrap();
+    assert!(workspace.members_in_build_order(&root).is_err());
+}
+
+#[test]
+fn test_missing_member() {
+    let root = create_workspace();
+    fs::write(root.join("Leo.toml"), "[workspace]\nmembers = [\"missing\"]\n").unwrap();
+
+    let workspace = Workspace::read_from(&root).unwrap().unwrap();
+    assert!(workspace.members_in_build_order(&root).is_err());
+}
+
+#[test]
+fn test_resolve_workspace_lock_file() {
+    let root = create_workspace();
+    let workspace = Workspace::read_from(&root).unwrap().unwrap();
+    let members = workspace.members_in_build_order(&root).unwrap();
+
+    let lock_file = DependencyResolver::new(&root)
+        .unwrap()
+        .resolve_workspace(&members, None)
+        .unwrap();
+
+    let members = lock_file
+        .member
+        .iter()
+        .map(|member| (member.path.as_str(), member.dependencies.len()))
+        .collect::<Vec<_>>();
+    assert_eq!(members, vec![("app", 2), ("math", 0)]);
+
+    let app = &lock_file.member[0];
+    assert_eq!(app.dependencies["math"], "path+../math");
+    assert_eq!(app.dependencies["token"], "author-token@0.1.2");
+
+    // The shared lock file round-trips.
+    lock_file.clone().write_to(&root).unwrap();
+    assert_eq!(LockFile::try_from(root.as_path()).unwrap(), lock_file);
+}
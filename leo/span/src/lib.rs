@@ -20,7 +20,10 @@ pub mod symbol;
 pub use symbol::{sym, Symbol};
 
 pub mod span;
-pub use span::Span;
+pub use span::{BytePos, Span};
+
+pub mod source_map;
+pub use source_map::{SourceMap, SpanLocation};
 
 pub mod span_json;
 
@@ -0,0 +1,212 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The source map holds every source file of a compilation session once,
+//! and gives each of them a range in a shared address space of [`BytePos`]itions.
+//! This allows [`Span`]s to be just two positions, with their lines, columns,
+//! and content computed on demand.
+
+use crate::span::{BytePos, Span};
+
+use serde::Deserialize;
+use std::{cell::RefCell, convert::TryFrom, rc::Rc, sync::Arc};
+
+/// The source map containing all recorded sources,
+/// methods to register new ones,
+/// and methods to query about spans in relation to recorded sources.
+#[derive(Default)]
+pub struct SourceMap {
+    /// The actual source map data.
+    /// `RefCell` is used here, as the source map is shared through the session globals.
+    inner: RefCell<SourceMapInner>,
+}
+
+/// Actual data of the source map.
+/// We use this setup for purposes of interior mutability.
+#[derive(Default)]
+struct SourceMapInner {
+    /// The address space below this value is currently used by the files in the source map.
+    used_address_space: u32,
+
+    /// All the source files recorded thus far, ordered by their start positions.
+    source_files: Vec<Rc<SourceFile>>,
+}
+
+impl SourceMap {
+    /// Registers the `source` of the file at `path`, and returns the source file,
+    /// whose start position is used to make spans from offsets into `source`.
+    pub fn new_source(&self, source: &str, path: &str) -> Rc<SourceFile> {
+        let len = u32::try_from(source.len()).expect("source files larger than 4 GiB are not supported");
+        let mut inner = self.inner.borrow_mut();
+
+        // Positions start at 1, so that no file contains the dummy span.
+        // There is also a position between files, so that the end of one file is not the start of the next.
+        let start_pos = BytePos(inner.used_address_space + 1);
+        inner.used_address_space = start_pos.0 + len;
+
+        let source_file = Rc::new(SourceFile::new(path, source, start_pos));
+        inner.source_files.push(source_file.clone());
+        source_file
+    }
+
+    /// Returns the source file containing the position `pos`, if any.
+    pub fn find_source_file(&self, pos: BytePos) -> Option<Rc<SourceFile>> {
+        let inner = self.inner.borrow();
+        let index = inner
+            .source_files
+            .partition_point(|file| file.start_pos <= pos)
+            .checked_sub(1)?;
+        let file = &inner.source_files[index];
+        (pos <= file.end_pos).then(|| file.clone())
+    }
+
+    /// Returns the lines, columns, path, and content of `span`,
+    /// or `None` if it is a dummy span or does not lie within a single source file.
+    pub fn span_to_location(&self, span: &Span) -> Option<SpanLocation> {
+        if span.is_dummy() {
+            return None;
+        }
+
+        let file = self.find_source_file(span.lo)?;
+        if span.hi > file.end_pos || span.hi < span.lo {
+            return None;
+        }
+
+        let (line_start, col_start) = file.line_col(span.lo);
+        let (line_stop, col_stop) = file.line_col(span.hi);
+        Some(SpanLocation {
+            path: file.path.clone(),
+            line_start,
+            line_stop,
+            col_start,
+            col_stop,
+            content: file.lines(line_start, line_stop).to_string(),
+        })
+    }
+
+    /// Returns the source file last registered at `path`, if any.
+    pub fn find_source_file_by_path(&self, path: &str) -> Option<Rc<SourceFile>> {
+        let inner = self.inner.borrow();
+        inner
+            .source_files
+            .iter()
+            .rev()
+            .find(|file| file.path.as_str() == path)
+            .cloned()
+    }
+
+    /// Returns the span at the lines and columns of `location`, if its file is in the source map.
+    /// This is the inverse of [`SourceMap::span_to_location`].
+    pub fn location_to_span(&self, location: &SpanLocation) -> Option<Span> {
+        let file = self.find_source_file_by_path(&location.path)?;
+        Some(Span::new(
+            file.pos(location.line_start, location.col_start)?,
+            file.pos(location.line_stop, location.col_stop)?,
+        ))
+    }
+
+    /// Returns the source code of `span`, if it lies within a single source file.
+    pub fn contents_of_span(&self, span: &Span) -> Option<String> {
+        let file = self.find_source_file(span.lo)?;
+        if span.hi > file.end_pos || span.hi < span.lo {
+            return None;
+        }
+
+        Some(file.src[file.offset(span.lo)..file.offset(span.hi)].to_string())
+    }
+}
+
+/// A single source file in the source map.
+pub struct SourceFile {
+    /// The path of the source file.
+    pub path: Arc<String>,
+    /// The complete source code of the file.
+    pub src: String,
+    /// The position of the first byte of the file in the source map.
+    pub start_pos: BytePos,
+    /// The position after the last byte of the file in the source map.
+    pub end_pos: BytePos,
+    /// The positions where each line of the file starts.
+    lines: Vec<BytePos>,
+}
+
+impl SourceFile {
+    /// Returns a new source file with the given `path` and `src`, starting at `start_pos`.
+    fn new(path: &str, src: &str, start_pos: BytePos) -> Self {
+        let lines = std::iter::once(0)
+            .chain(src.match_indices('\n').map(|(index, _)| index + 1))
+            .map(|offset| start_pos + BytePos::from_usize(offset))
+            .collect();
+
+        Self {
+            path: Arc::new(path.to_string()),
+            src: src.to_string(),
+            start_pos,
+            end_pos: start_pos + BytePos::from_usize(src.len()),
+            lines,
+        }
+    }
+
+    /// Returns the offset of `pos` into the source code of the file.
+    fn offset(&self, pos: BytePos) -> usize {
+        (pos - self.start_pos).to_usize()
+    }
+
+    /// Returns the line and column of `pos`, both starting at 1.
+    /// Columns count bytes, as the tokenizer does.
+    pub fn line_col(&self, pos: BytePos) -> (usize, usize) {
+        let line = self.lines.partition_point(|start| *start <= pos);
+        let col = (pos - self.lines[line - 1]).to_usize() + 1;
+        (line, col)
+    }
+
+    /// Returns the position of the `line` and `col`umn, both starting at 1, if it lies within the file.
+    pub fn pos(&self, line: usize, col: usize) -> Option<BytePos> {
+        let line_start = *self.lines.get(line.checked_sub(1)?)?;
+        let pos = line_start + BytePos::from_usize(col.checked_sub(1)?);
+        (pos <= self.end_pos).then(|| pos)
+    }
+
+    /// Returns the source code of the lines `line_start` through `line_stop`, without the final newline.
+    pub fn lines(&self, line_start: usize, line_stop: usize) -> &str {
+        let start = self.offset(self.lines[line_start - 1]);
+        let stop = self.src[start..]
+            .match_indices('\n')
+            .nth(line_stop - line_start)
+            .map(|(index, _)| start + index)
+            .unwrap_or_else(|| self.src.len());
+        &self.src[start..stop]
+    }
+}
+
+/// The lines, columns, path, and content of a [`Span`], as computed by the [`SourceMap`].
+#[derive(Clone, Debug, Default, Deserialize, Eq, Hash, PartialEq)]
+pub struct SpanLocation {
+    /// The path to the Leo file containing the span.
+    #[serde(default)]
+    pub path: Arc<String>,
+    /// The line number where the span starts.
+    pub line_start: usize,
+    /// The line number where the span stops.
+    pub line_stop: usize,
+    /// The column number where the span starts.
+    pub col_start: usize,
+    /// The column number where the span stops.
+    pub col_stop: usize,
+    /// The content of the line(s) that the span is found on.
+    #[serde(default)]
+    pub content: String,
+}
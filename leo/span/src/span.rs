@@ -16,98 +16,139 @@
 
 //! Defines the `Span` type used to track where code comes from.
 
-use std::{fmt, sync::Arc, usize};
+use crate::source_map::SpanLocation;
+use crate::symbol::with_session_globals_if_set;
 
+use std::{fmt, ops, usize};
+
+use serde::de::{Deserialize, Deserializer};
 use serde::ser::{Serialize, SerializeStruct, Serializer};
-use serde::Deserialize;
+
+/// A position in the source code, as a byte offset into the address space of the session's
+/// [`SourceMap`](crate::source_map::SourceMap), which holds every source file once.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct BytePos(pub u32);
+
+impl BytePos {
+    /// Returns the position `offset` bytes after the start of the address space.
+    pub fn from_usize(offset: usize) -> Self {
+        Self(offset as u32)
+    }
+
+    /// Returns the number of bytes from the start of the address space.
+    pub fn to_usize(self) -> usize {
+        self.0 as usize
+    }
+}
+
+impl ops::Add for BytePos {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self(self.0 + other.0)
+    }
+}
+
+impl ops::Sub for BytePos {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Self(self.0 - other.0)
+    }
+}
 
 /// The span type which tracks where formatted errors originate from in a Leo file.
 /// This is used in many spots throughout the rest of the Leo crates.
-#[derive(Clone, Debug, Default, Deserialize, Eq, Hash, PartialEq)]
+///
+/// A span is only a range of positions in the session's source map.
+/// Its lines, columns, and content are computed on demand, see [`Span::location`].
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct Span {
-    /// The line number where the error started.
-    pub line_start: usize,
-    /// The line number where the error stopped.
-    pub line_stop: usize,
-    /// The column number where the error started.
-    pub col_start: usize,
-    /// The column number where the error stopped.
-    pub col_stop: usize,
-    /// The path to the Leo file containing the error.
-    pub path: Arc<String>,
-    /// The content of the line(s) that the span is found on.
-    pub content: String,
+    /// The position of the first byte of the span.
+    pub lo: BytePos,
+    /// The position after the last byte of the span.
+    pub hi: BytePos,
 }
 
 impl Span {
-    /// Generate a new span from:
-    /// - Where the Leo line starts.
-    /// - Where the Leo line stops.
-    /// - Where the Leo column starts.
-    /// - Where the Leo column stops.
-    /// - The path to the Leo file.
-    /// - The content of those specified bounds.
-    pub fn new(
-        line_start: usize,
-        line_stop: usize,
-        col_start: usize,
-        col_stop: usize,
-        path: Arc<String>,
-        content: String,
-    ) -> Self {
-        Self {
-            line_start,
-            line_stop,
-            col_start,
-            col_stop,
-            path,
-            content,
-        }
+    /// Generate a new span from the positions where it starts and stops.
+    pub fn new(lo: BytePos, hi: BytePos) -> Self {
+        Self { lo, hi }
+    }
+
+    /// Generates a dummy span, which does not point into any source file.
+    pub fn dummy() -> Self {
+        Self::default()
+    }
+
+    /// Is this a dummy span, e.g., of a node that was not parsed from a source file?
+    pub fn is_dummy(&self) -> bool {
+        self == &Self::dummy()
+    }
+
+    /// Returns the lines, columns, path, and content of the span.
+    /// These are empty if the span is a dummy span, or if there is no session.
+    pub fn location(&self) -> SpanLocation {
+        with_session_globals_if_set(|s| s.source_map.span_to_location(self))
+            .flatten()
+            .unwrap_or_default()
     }
 }
 
 impl Serialize for Span {
-    /// Custom serialization for testing purposes.
+    /// Custom serialization, locating the span by its path, lines, and columns, along with its content.
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
     {
+        let location = self.location();
         let mut state = serializer.serialize_struct("Color", 3)?;
-        state.serialize_field("line_start", &self.line_start)?;
-        state.serialize_field("line_stop", &self.line_stop)?;
-        state.serialize_field("col_start", &self.col_start)?;
-        state.serialize_field("col_stop", &self.col_stop)?;
-        // This is for testing purposes since the tests are run on a variety of OSes.
-        if std::env::var("LEO_TESTFRAMEWORK")
-            .unwrap_or_default()
-            .trim()
-            .to_owned()
-            .is_empty()
-        {
-            state.serialize_field("path", &self.path)?;
-        } else {
-            state.serialize_field("path", "")?;
-        }
-        state.serialize_field("content", &self.content)?;
+        state.serialize_field("line_start", &location.line_start)?;
+        state.serialize_field("line_stop", &location.line_stop)?;
+        state.serialize_field("col_start", &location.col_start)?;
+        state.serialize_field("col_stop", &location.col_stop)?;
+        state.serialize_field("path", &location.path)?;
+        state.serialize_field("content", &location.content)?;
         state.end()
     }
 }
 
+impl<'de> Deserialize<'de> for Span {
+    /// Custom deserialization, finding the span in the session's source map by its path, lines, and columns.
+    /// The span is a dummy span if the file is not in the source map.
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let location = SpanLocation::deserialize(deserializer)?;
+        Ok(
+            with_session_globals_if_set(|s| s.source_map.location_to_span(&location))
+                .flatten()
+                .unwrap_or_default(),
+        )
+    }
+}
+
 impl fmt::Display for Span {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        if self.line_start == self.line_stop {
-            write!(f, "{}:{}-{}", self.line_start, self.col_start, self.col_stop)
+        let location = self.location();
+        if location.line_start == location.line_stop {
+            write!(
+                f,
+                "{}:{}-{}",
+                location.line_start, location.col_start, location.col_stop
+            )
         } else {
             write!(
                 f,
                 "{}:{}-{}:{}",
-                self.line_start, self.col_start, self.line_stop, self.col_stop
+                location.line_start, location.col_start, location.line_stop, location.col_stop
             )
         }
     }
 }
 
-impl std::ops::Add for &Span {
+impl ops::Add for &Span {
     type Output = Span;
 
     fn add(self, other: &Span) -> Span {
@@ -115,65 +156,17 @@ impl std::ops::Add for &Span {
     }
 }
 
-impl std::ops::Add for Span {
+impl ops::Add for Span {
     type Output = Self;
 
-    #[allow(clippy::comparison_chain)]
+    /// Returns the span covering both spans, and everything in between.
     fn add(self, other: Self) -> Self {
-        if self.line_start == other.line_stop {
-            Span {
-                line_start: self.line_start,
-                line_stop: self.line_stop,
-                col_start: self.col_start.min(other.col_start),
-                col_stop: self.col_stop.max(other.col_stop),
-                path: self.path,
-                content: self.content,
-            }
+        if self.is_dummy() {
+            other
+        } else if other.is_dummy() {
+            self
         } else {
-            let mut new_content = vec![];
-            let self_lines = self.content.lines().collect::<Vec<_>>();
-            let other_lines = other.content.lines().collect::<Vec<_>>();
-            for line in self.line_start.min(other.line_start)..self.line_stop.max(other.line_stop) + 1 {
-                if line >= self.line_start && line <= self.line_stop {
-                    new_content.push(
-                        self_lines
-                            .get(line - self.line_start)
-                            .copied()
-                            .unwrap_or_default()
-                            .to_string(),
-                    );
-                } else if line >= other.line_start && line <= other.line_stop {
-                    new_content.push(
-                        other_lines
-                            .get(line - other.line_start)
-                            .copied()
-                            .unwrap_or_default()
-                            .to_string(),
-                    );
-                } else if new_content.last().map(|x| *x != "...").unwrap_or(true) {
-                    new_content.push(format!("{:<1$}...", " ", other.col_start + 4));
-                }
-            }
-            let new_content = new_content.join("\n");
-            if self.line_start < other.line_stop {
-                Span {
-                    line_start: self.line_start,
-                    line_stop: other.line_stop,
-                    col_start: self.col_start,
-                    col_stop: other.col_stop,
-                    path: self.path,
-                    content: new_content,
-                }
-            } else {
-                Span {
-                    line_start: other.line_start,
-                    line_stop: self.line_stop,
-                    col_start: other.col_start,
-                    col_stop: self.col_stop,
-                    path: self.path,
-                    content: new_content,
-                }
-            }
+            Span::new(self.lo.min(other.lo), self.hi.max(other.hi))
         }
     }
 }
@@ -15,6 +15,7 @@
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::dropless::DroplessArena;
+use crate::source_map::SourceMap;
 
 use core::cmp::PartialEq;
 use core::convert::AsRef;
@@ -24,7 +25,7 @@ use core::{fmt, str};
 use fxhash::FxBuildHasher;
 use indexmap::IndexSet;
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
-use std::cell::RefCell;
+use std::cell::{Cell, RefCell};
 use std::intrinsics::transmute;
 use std::marker::PhantomData;
 
@@ -100,6 +101,7 @@ macro_rules! symbols {
 
 symbols! {
     address,
+    allow,
     AlwaysConst,
     Ampersand: "&",
     array,
@@ -206,7 +208,7 @@ impl Symbol {
     }
 
     fn serde_to_symbol<'de, D: Deserializer<'de>>(de: D) -> Result<NonZeroU32, D::Error> {
-        Ok(Symbol::intern(<&str>::deserialize(de)?).0)
+        Ok(Symbol::intern(&String::deserialize(de)?).0)
     }
 
     fn serde_from_symbol<S: Serializer>(index: &NonZeroU32, ser: S) -> Result<S::Ok, S::Error> {
@@ -293,14 +295,33 @@ impl fmt::Display for SymbolStr {
 pub struct SessionGlobals {
     /// The interner for `Symbol`s used in the compiler.
     symbol_interner: Interner,
+    /// The source map holding every source file of the session, which `Span`s point into.
+    pub source_map: SourceMap,
+    /// The number of AST node IDs handed out in the session.
+    node_ids: Cell<u32>,
 }
 
 impl SessionGlobals {
     fn new() -> Self {
         Self {
             symbol_interner: Interner::prefilled(),
+            source_map: SourceMap::default(),
+            node_ids: Cell::new(0),
         }
     }
+
+    /// Returns a number not returned before in the session, starting from 1, for the ID of an AST node.
+    pub fn next_node_id(&self) -> u32 {
+        let id = self.node_ids.get() + 1;
+        self.node_ids.set(id);
+        id
+    }
+
+    /// Marks `id` as handed out, e.g., for the ID of a deserialized AST node,
+    /// so that [`next_node_id`](Self::next_node_id) returns larger numbers only.
+    pub fn reserve_node_id(&self, id: u32) {
+        self.node_ids.set(self.node_ids.get().max(id));
+    }
 }
 
 scoped_tls::scoped_thread_local!(static SESSION_GLOBALS: SessionGlobals);
@@ -322,6 +343,12 @@ pub fn with_session_globals<R>(f: impl FnOnce(&SessionGlobals) -> R) -> R {
     SESSION_GLOBALS.with(f)
 }
 
+/// Gives access to read or modify the session globals in `f`, if there is a session.
+#[inline]
+pub fn with_session_globals_if_set<R>(f: impl FnOnce(&SessionGlobals) -> R) -> Option<R> {
+    SESSION_GLOBALS.is_set().then(|| SESSION_GLOBALS.with(f))
+}
+
 /// The inner interner.
 /// This construction is used to get interior mutability in `Interner`.
 struct InnerInterner {
@@ -44,6 +44,96 @@ pub fn format_event() -> Result<()> {
     Ok(())
 }
 
+#[test]
+pub fn explain_error_code() -> Result<()> {
+    let guide = crate::commands::explain::error_guide("EPAR0370003")?;
+    assert!(guide.starts_with("# An unexpected end of file"));
+    assert!(crate::commands::explain::error_guide("EPAR0379999").is_err());
+    Ok(())
+}
+
+#[test]
+pub fn error_guides_explain_existing_errors() {
+    use leo_errors::{AstError, ParserError};
+
+    for (kind, name, _) in crate::commands::explain::ERROR_GUIDES {
+        let errors = match *kind {
+            "ast" => AstError::ERRORS,
+            "parser" => ParserError::ERRORS,
+            _ => panic!("there are no guides for errors of kind `{}`", kind),
+        };
+        assert!(
+            errors.iter().any(|(_, error_name)| error_name == name),
+            "the guide `{}/{}.md` does not explain an existing error",
+            kind,
+            name
+        );
+    }
+}
+
+#[test]
+pub fn fmt_formats_the_package() -> Result<()> {
+    use crate::{
+        commands::{Command, Fmt},
+        context::create_context,
+    };
+    use leo_package::root::Manifest;
+    use std::{fs, path::PathBuf};
+
+    let path = std::env::temp_dir().join("leo-fmt-test");
+    let main_path = path.join("src").join("main.leo");
+    let _ = fs::remove_dir_all(&path);
+    fs::create_dir_all(path.join("src")).unwrap();
+    Manifest::new("fmt-test", None)?.write_to(&path)?;
+    fs::write(&main_path, "function main(a:u32)->u32{return a;}").unwrap();
+
+    let fmt = |check| -> Result<Vec<PathBuf>> {
+        (Fmt { check, max_width: None }).apply(create_context(path.clone(), None)?, ())
+    };
+    assert!(fmt(true).is_err());
+    assert_eq!(fmt(false)?, vec![main_path.clone()]);
+    assert_eq!(
+        fs::read_to_string(&main_path).unwrap(),
+        "function main(a: u32) -> u32 {\n    return a;\n}\n"
+    );
+    assert!(fmt(true)?.is_empty());
+
+    fs::remove_dir_all(&path).unwrap();
+    Ok(())
+}
+
+#[test]
+pub fn lint_denies_configured_lints() -> Result<()> {
+    use crate::{
+        commands::{Command, Lint},
+        context::create_context,
+    };
+    use leo_package::root::{Manifest, MANIFEST_FILENAME};
+    use std::fs;
+
+    let path = std::env::temp_dir().join("leo-lint-test");
+    let _ = fs::remove_dir_all(&path);
+    fs::create_dir_all(path.join("src")).unwrap();
+    Manifest::new("lint-test", None)?.write_to(&path)?;
+    fs::write(
+        path.join("src").join("main.leo"),
+        "function main(a: u32) -> u32 {\n    let b = a;\n    return a;\n}\n",
+    )
+    .unwrap();
+
+    let lint = || (Lint {}).apply(create_context(path.clone(), None)?, ());
+    // The unused variable is only a warning by default.
+    assert!(lint().is_ok());
+
+    let manifest_path = path.join(MANIFEST_FILENAME);
+    let manifest = fs::read_to_string(&manifest_path).unwrap();
+    fs::write(&manifest_path, manifest + "\n[lint]\nunused_variable = \"deny\"\n").unwrap();
+    assert!(lint().is_err());
+
+    fs::remove_dir_all(&path).unwrap();
+    Ok(())
+}
+
 // todo (collin): uncomment after refactor
 // #[test]
 // pub fn build_pedersen_hash() -> Result<()> {
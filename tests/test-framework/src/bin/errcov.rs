@@ -14,9 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use leo_errors::{
-    AsgError, AstError, CliError, CompilerError, ImportError, LeoErrorCode, PackageError, ParserError, StateError,
-};
+use leo_errors::{AstError, CliError, CompilerError, ImportError, InputError, PackageError, ParserError, StateError};
 use leo_test_framework::{
     fetch::find_tests,
     output::TestExpectation,
@@ -26,7 +24,11 @@ use leo_test_framework::{
 use regex::Regex;
 use serde_yaml::Value;
 use std::collections::{BTreeMap, HashSet};
-use std::{error::Error, fs, io, path::PathBuf};
+use std::{
+    error::Error,
+    fs, io,
+    path::{Path, PathBuf},
+};
 use structopt::{clap::AppSettings, StructOpt};
 
 #[derive(StructOpt)]
@@ -109,62 +111,67 @@ fn run_with_args(opt: Opt) -> Result<(), Box<dyn Error>> {
 
     // Collect all defined error codes.
     let mut all_codes = HashSet::new();
-    collect_error_codes(
-        &mut all_codes,
-        AsgError::error_type(),
-        AsgError::code_identifier(),
-        AsgError::exit_code_mask(),
-        AsgError::num_exit_codes(),
-    );
-    collect_error_codes(
-        &mut all_codes,
-        AstError::error_type(),
-        AstError::code_identifier(),
-        AstError::exit_code_mask(),
-        AstError::num_exit_codes(),
-    );
-    collect_error_codes(
-        &mut all_codes,
-        CliError::error_type(),
-        CliError::code_identifier(),
-        CliError::exit_code_mask(),
-        CliError::num_exit_codes(),
-    );
-    collect_error_codes(
-        &mut all_codes,
-        CompilerError::error_type(),
-        CompilerError::code_identifier(),
-        CompilerError::exit_code_mask(),
-        CompilerError::num_exit_codes(),
-    );
-    collect_error_codes(
-        &mut all_codes,
-        ImportError::error_type(),
-        ImportError::code_identifier(),
-        ImportError::exit_code_mask(),
-        ImportError::num_exit_codes(),
-    );
-    collect_error_codes(
-        &mut all_codes,
-        PackageError::error_type(),
-        PackageError::code_identifier(),
-        PackageError::exit_code_mask(),
-        PackageError::num_exit_codes(),
-    );
-    collect_error_codes(
-        &mut all_codes,
-        ParserError::error_type(),
-        ParserError::code_identifier(),
-        ParserError::exit_code_mask(),
-        ParserError::num_exit_codes(),
-    );
-    collect_error_codes(
-        &mut all_codes,
-        StateError::error_type(),
-        StateError::code_identifier(),
-        StateError::exit_code_mask(),
-        StateError::num_exit_codes(),
-    );
+    collect_error_codes(&mut all_codes, AstError::ERRORS, AstError::prefixed_code);
+    collect_error_codes(&mut all_codes, CliError::ERRORS, CliError::prefixed_code);
+    collect_error_codes(&mut all_codes, CompilerError::ERRORS, CompilerError::prefixed_code);
+    collect_error_codes(&mut all_codes, ImportError::ERRORS, ImportError::prefixed_code);
+    collect_error_codes(&mut all_codes, InputError::ERRORS, InputError::prefixed_code);
+    collect_error_codes(&mut all_codes, PackageError::ERRORS, PackageError::prefixed_code);
+    collect_error_codes(&mut all_codes, ParserError::ERRORS, ParserError::prefixed_code);
+    collect_error_codes(&mut all_codes, StateError::ERRORS, StateError::prefixed_code);
+
+    // Every error needs a guide in the directory of its kind in `docs/error-guides`, for `leo explain`.
+    let mut guides_dir = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    guides_dir.push("../../docs/error-guides");
+    let mut missing_guides = Vec::new();
+    collect_missing_guides(
+        &mut missing_guides,
+        &guides_dir.join("ast"),
+        AstError::ERRORS,
+        AstError::prefixed_code,
+    )?;
+    collect_missing_guides(
+        &mut missing_guides,
+        &guides_dir.join("cli"),
+        CliError::ERRORS,
+        CliError::prefixed_code,
+    )?;
+    collect_missing_guides(
+        &mut missing_guides,
+        &guides_dir.join("compiler"),
+        CompilerError::ERRORS,
+        CompilerError::prefixed_code,
+    )?;
+    collect_missing_guides(
+        &mut missing_guides,
+        &guides_dir.join("import"),
+        ImportError::ERRORS,
+        ImportError::prefixed_code,
+    )?;
+    collect_missing_guides(
+        &mut missing_guides,
+        &guides_dir.join("input"),
+        InputError::ERRORS,
+        InputError::prefixed_code,
+    )?;
+    collect_missing_guides(
+        &mut missing_guides,
+        &guides_dir.join("package"),
+        PackageError::ERRORS,
+        PackageError::prefixed_code,
+    )?;
+    collect_missing_guides(
+        &mut missing_guides,
+        &guides_dir.join("parser"),
+        ParserError::ERRORS,
+        ParserError::prefixed_code,
+    )?;
+    collect_missing_guides(
+        &mut missing_guides,
+        &guides_dir.join("state"),
+        StateError::ERRORS,
+        StateError::prefixed_code,
+    )?;
 
     // Repackage data into values compatible with serde_yaml
     let mut covered_errors = serde_yaml::Mapping::new();
@@ -230,6 +237,20 @@ fn run_with_args(opt: Opt) -> Result<(), Box<dyn Error>> {
         Value::Mapping(unknown_information),
     );
 
+    let mut missing_guides_information = serde_yaml::Mapping::new();
+    missing_guides_information.insert(
+        Value::String(String::from("count")),
+        Value::Number(serde_yaml::Number::from(missing_guides.len())),
+    );
+    missing_guides_information.insert(
+        Value::String(String::from("codes")),
+        Value::Sequence(missing_guides.iter().cloned().map(Value::String).collect()),
+    );
+    results.insert(
+        Value::String(String::from("missing_guides")),
+        Value::Mapping(missing_guides_information),
+    );
+
     // Output error coverage results
     if let Some(pathbuf) = opt.output {
         let file = fs::File::create(pathbuf).expect("error creating output file");
@@ -238,26 +259,42 @@ fn run_with_args(opt: Opt) -> Result<(), Box<dyn Error>> {
         serde_yaml::to_writer(io::stdout(), &results).expect("serialization failed for error coverage report");
     }
 
+    if !missing_guides.is_empty() {
+        return Err(format!(
+            "{} error code(s) have no guide in docs/error-guides: {}",
+            missing_guides.len(),
+            missing_guides.join(", ")
+        )
+        .into());
+    }
+
     Ok(())
 }
 
-fn collect_error_codes(
-    codes: &mut HashSet<String>,
-    error_type: String,
-    code_identifier: i8,
-    exit_code_mask: i32,
-    num_exit_codes: i32,
-) {
-    for exit_code in 0..num_exit_codes {
-        codes.insert(format!(
-            "E{}{:0>3}{:0>4}",
-            error_type,
-            code_identifier,
-            exit_code_mask + exit_code,
-        ));
+fn collect_error_codes(codes: &mut HashSet<String>, errors: &[(i32, &str)], prefixed_code: fn(i32) -> String) {
+    for (code, _) in errors {
+        codes.insert(prefixed_code(*code));
     }
 }
 
+/// Collects the errors without a guide in `guides_dir`, which must exist.
+fn collect_missing_guides(
+    missing: &mut Vec<String>,
+    guides_dir: &Path,
+    errors: &[(i32, &str)],
+    prefixed_code: fn(i32) -> String,
+) -> Result<(), Box<dyn Error>> {
+    if !guides_dir.is_dir() {
+        return Err(format!("the error guides directory {} does not exist", guides_dir.display()).into());
+    }
+    for (code, name) in errors {
+        if !guides_dir.join(format!("{}.md", name)).exists() {
+            missing.push(format!("{} ({})", prefixed_code(*code), name));
+        }
+    }
+    Ok(())
+}
+
 fn handle_error(res: Result<(), Box<dyn Error>>) {
     match res {
         Ok(_) => (),
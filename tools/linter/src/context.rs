@@ -0,0 +1,92 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The configuration of the lints, and the context reporting their warnings at the configured levels.
+
+use leo_errors::{
+    emitter::{Handler, ALL_LINTS},
+    CliError, LeoWarning, LintWarning, Result,
+};
+use leo_package::root::LintLevel;
+
+use indexmap::IndexMap;
+
+/// The levels of the lints, e.g., from the `[lint]` section of Leo.toml.
+/// The level of `warnings` applies to the lints without a level of their own,
+/// and lints are warnings by default.
+#[derive(Clone, Debug, Default)]
+pub struct LintConfig {
+    /// The levels of the lints by their names.
+    levels: IndexMap<String, LintLevel>,
+}
+
+impl LintConfig {
+    /// Returns the configuration with the `levels` of the lints.
+    /// The result is an error if `levels` names a lint which does not exist.
+    pub fn new(levels: IndexMap<String, LintLevel>) -> Result<Self> {
+        if let Some(name) = levels
+            .keys()
+            .find(|name| *name != ALL_LINTS && !LintWarning::LINT_NAMES.contains(&name.as_str()))
+        {
+            return Err(CliError::unknown_lint(name).into());
+        }
+        Ok(Self { levels })
+    }
+
+    /// Returns the level of the lint `name`.
+    pub fn level(&self, name: &str) -> LintLevel {
+        self.levels
+            .get(name)
+            .or_else(|| self.levels.get(ALL_LINTS))
+            .copied()
+            .unwrap_or(LintLevel::Warn)
+    }
+}
+
+/// The context in which the lint passes run.
+pub struct LintContext<'a> {
+    /// The handler the warnings are emitted through.
+    handler: &'a Handler,
+    /// The levels of the lints.
+    config: &'a LintConfig,
+}
+
+impl<'a> LintContext<'a> {
+    /// Returns a context emitting through `handler` at the levels of `config`.
+    pub fn new(handler: &'a Handler, config: &'a LintConfig) -> Self {
+        Self { handler, config }
+    }
+
+    /// Returns the handler the warnings are emitted through.
+    pub fn handler(&self) -> &'a Handler {
+        self.handler
+    }
+
+    /// Emits `warning`, as an error if its lint is denied,
+    /// and not at all if it is allowed in Leo.toml or through `@allow(...)`.
+    pub fn emit(&self, warning: LintWarning) {
+        let warning = LeoWarning::from(warning);
+        if self.handler.is_lint_allowed(warning.lint_name()) {
+            return;
+        }
+
+        match self.config.level(warning.lint_name()) {
+            LintLevel::Allow => {}
+            LintLevel::Warn => self.handler.emit_warning(warning),
+            LintLevel::Deny => self.handler.emit_err(warning.deny()),
+        }
+    }
+}
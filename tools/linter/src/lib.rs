@@ -0,0 +1,76 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The linter of Leo programs, which runs the passes of its rules over the AST.
+//!
+//! The [`lint_file()`] method parses a file and emits the warnings of its rules.
+
+#![doc = include_str!("../README.md")]
+
+pub mod context;
+pub use context::*;
+
+pub mod rules;
+pub use rules::*;
+
+use leo_ast::{CircuitMember, Function, Program};
+use leo_errors::{emitter::Handler, Result};
+use leo_span::{sym, symbol::create_session_if_not_set_then};
+
+/// Runs the passes of all the rules over `program`.
+/// The lints named by the `@allow(...)` annotation of a function are not emitted for it.
+pub fn lint_program(context: &LintContext, program: &Program) {
+    let mut passes = passes();
+
+    for pass in passes.iter_mut() {
+        pass.check_program(context, program);
+    }
+
+    let functions = program.functions.values().map(|function| (function, false));
+    let circuit_functions = program.circuits.values().flat_map(|circuit| {
+        circuit.members.iter().filter_map(|member| match member {
+            CircuitMember::CircuitFunction(function) => Some((&**function, true)),
+            _ => None,
+        })
+    });
+    for (function, in_circuit) in functions.chain(circuit_functions) {
+        context.handler().with_allowed_lints(allowed_lints(function), || {
+            for pass in passes.iter_mut() {
+                pass.check_function(context, function, in_circuit);
+            }
+        });
+    }
+}
+
+/// Returns the names of the lints allowed by the `@allow(...)` annotation of `function`.
+fn allowed_lints(function: &Function) -> Vec<String> {
+    function
+        .annotations
+        .get(&sym::allow)
+        .map(|annotation| annotation.arguments.iter().map(|name| name.to_string()).collect())
+        .unwrap_or_default()
+}
+
+/// Parses the file at `path` with the `source` code text, and lints it with `config`.
+/// The result is an error if the file does not parse, or if a denied lint was emitted.
+/// A session is created if there is none.
+pub fn lint_file(handler: &Handler, path: &str, source: &str, config: &LintConfig) -> Result<()> {
+    create_session_if_not_set_then(|_| {
+        let program = leo_parser::parse(handler, path, source)?;
+        lint_program(&LintContext::new(handler, config), &program);
+        handler.last_err()
+    })
+}
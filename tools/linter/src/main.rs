@@ -14,6 +14,47 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
+use leo_errors::{emitter::Handler, CliError, LeoError, Result};
+use leo_linter::{lint_file, LintConfig};
+use leo_package::{root::Manifest, source::SourceDirectory};
+
+use std::{convert::TryFrom, env, fs, path::PathBuf, process::exit};
+
+/// Lints the files given as arguments, or the files of the package in the current directory if there are none.
+/// The levels of the lints are read from the `[lint]` section of the Leo.toml in the current directory.
 fn main() {
-    println!("Hello, world!");
+    let handler = Handler::default();
+    if let Err(error) = run(&handler) {
+        let code = error.exit_code();
+        // The errors standing behind the last error code were already emitted.
+        if !matches!(error, LeoError::LastErrorCode(_)) {
+            handler.emit_err(error);
+        }
+        exit(code);
+    }
+}
+
+fn run(handler: &Handler) -> Result<()> {
+    let package_path = env::current_dir().map_err(CliError::cli_io_error)?;
+    let config = match Manifest::exists_at(&package_path) {
+        true => LintConfig::new(Manifest::try_from(package_path.as_path())?.get_lint_levels())?,
+        false => LintConfig::default(),
+    };
+
+    let mut files: Vec<PathBuf> = env::args().skip(1).map(PathBuf::from).collect();
+    if files.is_empty() {
+        files = SourceDirectory::files(&package_path)?;
+    }
+
+    for path in files {
+        let source = fs::read_to_string(&path).map_err(CliError::cli_io_error)?;
+        // Keep linting the other files after an error.
+        if let Err(error) = lint_file(handler, &path.to_string_lossy(), &source, &config) {
+            if !matches!(error, LeoError::LastErrorCode(_)) {
+                handler.emit_err(error);
+            }
+        }
+    }
+
+    handler.last_err()
 }
@@ -0,0 +1,220 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::{is_constant, LintPass};
+use crate::LintContext;
+
+use leo_ast::{
+    walk_assign, walk_block, walk_circuit_variable_initializer, walk_definition, walk_expression, walk_function,
+    AssignOperation, AssignStatement, Block, CircuitVariableInitializer, Declare, DefinitionStatement, Expression,
+    Function, FunctionInput, Identifier, IterationStatement, Visitor,
+};
+use leo_errors::LintWarning;
+use leo_span::{BytePos, Span};
+
+/// The kind of a binding, i.e., what introduces it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum BindingKind {
+    /// A parameter of the function.
+    Parameter,
+    /// A `let` binding.
+    Let,
+    /// A `const` binding.
+    Const,
+    /// The variable of a `for` loop.
+    Loop,
+}
+
+/// A variable in scope, and how it has been used so far.
+struct Binding {
+    /// The name of the variable, located where it is bound.
+    identifier: Identifier,
+    /// What introduces the variable.
+    kind: BindingKind,
+    /// Whether the variable is read anywhere.
+    used: bool,
+    /// Whether the variable is assigned to after its definition.
+    reassigned: bool,
+    /// The span of the `let` statement defining the variable to a constant value, if it does.
+    constant_definition: Option<Span>,
+}
+
+/// Warns about unused variables, variables shadowing others,
+/// and `let` bindings of constant values which are never reassigned.
+pub struct BindingsPass;
+
+impl LintPass for BindingsPass {
+    fn check_function(&mut self, context: &LintContext, function: &Function, _in_circuit: bool) {
+        Bindings {
+            context,
+            scopes: Vec::new(),
+        }
+        .visit_function(function);
+    }
+}
+
+/// Tracks the bindings of a function through a stack of scopes,
+/// and emits the warnings of each scope when leaving it.
+struct Bindings<'a, 'b> {
+    context: &'a LintContext<'b>,
+    scopes: Vec<Vec<Binding>>,
+}
+
+impl Bindings<'_, '_> {
+    /// Runs `logic` in a new scope, then leaves it.
+    fn in_scope(&mut self, logic: impl FnOnce(&mut Self)) {
+        self.scopes.push(Vec::new());
+        logic(self);
+        if let Some(scope) = self.scopes.pop() {
+            scope.into_iter().for_each(|binding| self.check(binding));
+        }
+    }
+
+    /// Emits the warnings of `binding`, which goes out of scope.
+    fn check(&self, binding: Binding) {
+        let name = &binding.identifier;
+        match binding.kind {
+            BindingKind::Let | BindingKind::Const if !binding.used => {
+                self.context.emit(LintWarning::unused_variable(name, &name.span));
+            }
+            BindingKind::Let if !binding.reassigned => {
+                if let Some(span) = binding.constant_definition {
+                    // The statement starts with the `let` keyword.
+                    let keyword = Span::new(span.lo, span.lo + BytePos(3));
+                    self.context
+                        .emit(LintWarning::never_reassigned(name, &name.span).with_suggestion(
+                            &keyword,
+                            "declare it with `const`",
+                            "const",
+                        ));
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Binds `identifier` in the current scope, warning if it shadows another variable.
+    fn bind(&mut self, identifier: &Identifier, kind: BindingKind, constant_definition: Option<Span>) {
+        if let Some(shadowed) = self.lookup(identifier) {
+            let previous = shadowed.identifier.span.clone();
+            self.context.emit(
+                LintWarning::shadowed_variable(identifier, &identifier.span)
+                    .with_label(&previous, "previously defined here"),
+            );
+        }
+
+        if let Some(scope) = self.scopes.last_mut() {
+            scope.push(Binding {
+                identifier: identifier.clone(),
+                kind,
+                used: false,
+                reassigned: false,
+                constant_definition,
+            });
+        }
+    }
+
+    /// Binds the variables of `definition`.
+    fn bind_definition(&mut self, definition: &DefinitionStatement) {
+        let kind = match definition.declaration_type {
+            Declare::Const => BindingKind::Const,
+            Declare::Let => BindingKind::Let,
+        };
+        // Only a single variable can be declared `const` without changing the other variables.
+        let constant_definition =
+            (kind == BindingKind::Let && definition.variable_names.len() == 1 && is_constant(&definition.value))
+                .then(|| definition.span.clone());
+
+        for variable in &definition.variable_names {
+            self.bind(&variable.identifier, kind, constant_definition.clone());
+        }
+    }
+
+    /// Returns the innermost binding of the name of `identifier`.
+    fn lookup(&mut self, identifier: &Identifier) -> Option<&mut Binding> {
+        self.scopes
+            .iter_mut()
+            .rev()
+            .flat_map(|scope| scope.iter_mut().rev())
+            .find(|binding| binding.identifier.name == identifier.name)
+    }
+
+    /// Marks the variable `identifier` as used.
+    fn use_variable(&mut self, identifier: &Identifier) {
+        if let Some(binding) = self.lookup(identifier) {
+            binding.used = true;
+        }
+    }
+}
+
+impl Visitor<'_> for Bindings<'_, '_> {
+    fn visit_function(&mut self, function: &Function) {
+        self.in_scope(|this| {
+            for input in &function.input {
+                if let FunctionInput::Variable(variable) = input {
+                    this.bind(&variable.identifier, BindingKind::Parameter, None);
+                }
+            }
+            walk_function(this, function);
+        });
+    }
+
+    fn visit_block(&mut self, block: &Block) {
+        self.in_scope(|this| walk_block(this, block));
+    }
+
+    fn visit_definition(&mut self, definition: &DefinitionStatement) {
+        // The variables are only in scope after their definition.
+        walk_definition(self, definition);
+        self.bind_definition(definition);
+    }
+
+    fn visit_iteration(&mut self, iteration: &IterationStatement) {
+        self.visit_expression(&iteration.start);
+        self.visit_expression(&iteration.stop);
+        self.in_scope(|this| {
+            this.bind(&iteration.variable, BindingKind::Loop, None);
+            this.visit_block(&iteration.block);
+        });
+    }
+
+    fn visit_assign(&mut self, assign: &AssignStatement) {
+        let assignee = &assign.assignee;
+        // Assigning to a part of the variable, or with an operator, also reads it.
+        if assign.operation != AssignOperation::Assign || !assignee.accesses.is_empty() {
+            self.use_variable(&assignee.identifier);
+        }
+        if let Some(binding) = self.lookup(&assignee.identifier) {
+            binding.reassigned = true;
+        }
+        walk_assign(self, assign);
+    }
+
+    fn visit_expression(&mut self, expression: &Expression) {
+        if let Expression::Identifier(identifier) = expression {
+            self.use_variable(identifier);
+        }
+        walk_expression(self, expression);
+    }
+
+    fn visit_circuit_variable_initializer(&mut self, variable: &CircuitVariableInitializer) {
+        // A member without an expression is initialized with the variable of the same name.
+        if variable.expression.is_none() {
+            self.use_variable(&variable.identifier);
+        }
+        walk_circuit_variable_initializer(self, variable);
+    }
+}
@@ -0,0 +1,58 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::{is_constant, LintPass};
+use crate::LintContext;
+
+use leo_ast::{
+    walk_conditional, walk_ternary, ConditionalStatement, Expression, Function, Node, TernaryExpression, Visitor,
+};
+use leo_errors::LintWarning;
+
+/// Warns about the conditions of `if` statements and ternary expressions which are constants.
+pub struct ConditionsPass;
+
+impl LintPass for ConditionsPass {
+    fn check_function(&mut self, context: &LintContext, function: &Function, _in_circuit: bool) {
+        ConstantConditions { context }.visit_function(function);
+    }
+}
+
+/// Emits the warnings of constant conditions.
+struct ConstantConditions<'a, 'b> {
+    context: &'a LintContext<'b>,
+}
+
+impl ConstantConditions<'_, '_> {
+    /// Emits a warning if `condition` is a constant.
+    fn check(&self, condition: &Expression) {
+        if is_constant(condition) {
+            self.context.emit(LintWarning::constant_condition(condition.span()));
+        }
+    }
+}
+
+impl Visitor<'_> for ConstantConditions<'_, '_> {
+    fn visit_conditional(&mut self, conditional: &ConditionalStatement) {
+        self.check(&conditional.condition);
+        walk_conditional(self, conditional);
+    }
+
+    fn visit_ternary(&mut self, ternary: &TernaryExpression) {
+        self.check(&ternary.condition);
+        walk_ternary(self, ternary);
+    }
+}
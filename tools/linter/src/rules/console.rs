@@ -0,0 +1,46 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::LintPass;
+use crate::LintContext;
+
+use leo_ast::{walk_console, ConsoleFunction, ConsoleStatement, Function, Visitor};
+use leo_errors::LintWarning;
+
+/// Warns about `console.log` statements left in the `main` function.
+pub struct ConsolePass;
+
+impl LintPass for ConsolePass {
+    fn check_function(&mut self, context: &LintContext, function: &Function, in_circuit: bool) {
+        if !in_circuit && function.is_main() {
+            ConsoleLogs { context }.visit_function(function);
+        }
+    }
+}
+
+/// Emits the warnings of `console.log` statements.
+struct ConsoleLogs<'a, 'b> {
+    context: &'a LintContext<'b>,
+}
+
+impl Visitor<'_> for ConsoleLogs<'_, '_> {
+    fn visit_console(&mut self, console: &ConsoleStatement) {
+        if let ConsoleFunction::Log(_) = console.function {
+            self.context.emit(LintWarning::console_log_in_main(&console.span));
+        }
+        walk_console(self, console);
+    }
+}
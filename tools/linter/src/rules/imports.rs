@@ -0,0 +1,87 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::LintPass;
+use crate::LintContext;
+
+use leo_ast::{walk_expression, walk_type, Expression, ImportTree, ImportTreeKind, Program, Type, Visitor};
+use leo_errors::LintWarning;
+use leo_span::Symbol;
+
+use indexmap::IndexSet;
+
+/// Warns about imported items and packages which are never used.
+pub struct ImportsPass;
+
+impl LintPass for ImportsPass {
+    fn check_program(&mut self, context: &LintContext, program: &Program) {
+        let mut used = UsedNames::default();
+        used.visit_program(program);
+
+        for import in &program.import_statements {
+            check_tree(context, &used.names, &import.tree);
+        }
+    }
+}
+
+/// Emits a warning for each name imported by `tree` which is not in `used`.
+/// Glob imports are not checked, as they do not name what they import.
+fn check_tree(context: &LintContext, used: &IndexSet<Symbol>, tree: &ImportTree) {
+    match &tree.kind {
+        ImportTreeKind::Glob { .. } => {}
+        ImportTreeKind::Leaf { alias } => {
+            let name = alias.as_ref().or_else(|| tree.base.last());
+            if let Some(name) = name {
+                if !used.contains(&name.name) {
+                    context.emit(LintWarning::unused_import(name, &tree.span));
+                }
+            }
+        }
+        ImportTreeKind::Nested { tree } => tree.iter().for_each(|tree| check_tree(context, used, tree)),
+    }
+}
+
+/// Collects the names referred to by expressions and types.
+#[derive(Default)]
+struct UsedNames {
+    names: IndexSet<Symbol>,
+}
+
+impl Visitor<'_> for UsedNames {
+    fn visit_expression(&mut self, expression: &Expression) {
+        match expression {
+            Expression::Identifier(identifier) => {
+                self.names.insert(identifier.name);
+            }
+            Expression::CircuitInit(init) => {
+                self.names.insert(init.name.name);
+                // A member without an expression is initialized with the variable of the same name.
+                for member in init.members.iter().filter(|member| member.expression.is_none()) {
+                    self.names.insert(member.identifier.name);
+                }
+            }
+            _ => {}
+        }
+        walk_expression(self, expression);
+    }
+
+    fn visit_type(&mut self, type_: &Type) {
+        if let Type::Identifier(identifier) = type_ {
+            self.names.insert(identifier.name);
+        }
+        walk_type(self, type_);
+    }
+}
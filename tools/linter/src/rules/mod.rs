@@ -0,0 +1,74 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The lint rules, each of which is a pass over the program.
+
+use crate::LintContext;
+
+use leo_ast::{Expression, Function, Program, SpreadOrExpression};
+
+mod bindings;
+pub use bindings::*;
+
+mod conditions;
+pub use conditions::*;
+
+mod console;
+pub use console::*;
+
+mod imports;
+pub use imports::*;
+
+mod naming;
+pub use naming::*;
+
+/// A lint rule, which checks the whole program and each of its functions.
+pub trait LintPass {
+    /// Checks `program` as a whole, e.g., its imports.
+    fn check_program(&mut self, _context: &LintContext, _program: &Program) {}
+
+    /// Checks `function`, which is a member of a circuit if `in_circuit` is set.
+    fn check_function(&mut self, _context: &LintContext, _function: &Function, _in_circuit: bool) {}
+}
+
+/// Returns the passes of all the lint rules.
+pub fn passes() -> Vec<Box<dyn LintPass>> {
+    vec![
+        Box::new(BindingsPass),
+        Box::new(ConditionsPass),
+        Box::new(ConsolePass),
+        Box::new(ImportsPass),
+        Box::new(NamingPass),
+    ]
+}
+
+/// Returns whether `expression` is made of values only, and so is a constant.
+pub(crate) fn is_constant(expression: &Expression) -> bool {
+    match expression {
+        Expression::Value(_) => true,
+        Expression::Unary(unary) => is_constant(&unary.inner),
+        Expression::Binary(binary) => is_constant(&binary.left) && is_constant(&binary.right),
+        Expression::Cast(cast) => is_constant(&cast.inner),
+        Expression::TupleInit(tuple) => tuple.elements.iter().all(is_constant),
+        Expression::ArrayInit(array) => is_constant(&array.element),
+        Expression::ArrayInline(array) => array.elements.iter().all(|element| match element {
+            SpreadOrExpression::Spread(expression) | SpreadOrExpression::Expression(expression) => {
+                is_constant(expression)
+            }
+        }),
+        _ => false,
+    }
+}
@@ -0,0 +1,66 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::LintPass;
+use crate::LintContext;
+
+use leo_ast::Function;
+use leo_errors::LintWarning;
+
+/// Warns about the names of functions which are not in snake case.
+pub struct NamingPass;
+
+impl LintPass for NamingPass {
+    fn check_function(&mut self, context: &LintContext, function: &Function, _in_circuit: bool) {
+        let name = function.name().to_string();
+        let snake_case = to_snake_case(&name);
+        if name != snake_case {
+            let span = &function.identifier.span;
+            context.emit(LintWarning::non_snake_case_function(&name, span).with_suggestion(
+                span,
+                format!("rename it to `{}`", snake_case),
+                snake_case,
+            ));
+        }
+    }
+}
+
+/// Returns `name` in snake case, e.g., `fooBar` becomes `foo_bar`.
+pub fn to_snake_case(name: &str) -> String {
+    let mut snake_case = String::with_capacity(name.len());
+    let mut previous: Option<char> = None;
+    let mut chars = name.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c.is_uppercase() {
+            // A new word starts at an uppercase letter after a lowercase letter or a digit,
+            // or at the last uppercase letter of an acronym, as in `ABCFoo`.
+            let starts_word = match previous {
+                Some(previous) if previous != '_' => {
+                    !previous.is_uppercase() || chars.peek().map_or(false, |next| next.is_lowercase())
+                }
+                _ => false,
+            };
+            if starts_word {
+                snake_case.push('_');
+            }
+            snake_case.extend(c.to_lowercase());
+        } else {
+            snake_case.push(c);
+        }
+        previous = Some(c);
+    }
+    snake_case
+}
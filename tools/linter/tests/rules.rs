@@ -0,0 +1,223 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Tests of the lint rules, each linting a small program.
+
+use leo_errors::{emitter::Handler, LeoError};
+use leo_linter::{lint_file, to_snake_case, LintConfig};
+use leo_package::root::LintLevel;
+use leo_span::symbol::create_session_if_not_set_then;
+
+use indexmap::IndexMap;
+
+/// Lints `source` with `config`, returning the names of the lints warned and denied.
+fn lint_with(source: &str, config: &LintConfig) -> (Vec<&'static str>, Vec<&'static str>) {
+    create_session_if_not_set_then(|_| {
+        let (handler, buf) = Handler::new_with_buf();
+        let _ = lint_file(&handler, "test.leo", source, config);
+
+        let warnings = buf
+            .extract_warnings()
+            .into_inner()
+            .iter()
+            .map(|warning| warning.lint_name())
+            .collect();
+        let denied = buf
+            .extract()
+            .into_inner()
+            .into_iter()
+            .map(|error| match error {
+                LeoError::DeniedWarning(warning) => warning.lint_name(),
+                error => panic!("unexpected error: {}", error),
+            })
+            .collect();
+        (warnings, denied)
+    })
+}
+
+/// Lints `source` with the default levels, returning the names of the lints warned.
+fn lint(source: &str) -> Vec<&'static str> {
+    lint_with(source, &LintConfig::default()).0
+}
+
+/// Returns the configuration with `levels`.
+fn config(levels: &[(&str, LintLevel)]) -> LintConfig {
+    let levels: IndexMap<String, LintLevel> = levels.iter().map(|(name, level)| (name.to_string(), *level)).collect();
+    LintConfig::new(levels).unwrap()
+}
+
+#[test]
+fn test_clean_program() {
+    let source = "
+function main(a: u32) -> u32 {
+    let b = a + 1;
+    b += 1;
+    return b;
+}";
+    assert!(lint(source).is_empty());
+}
+
+#[test]
+fn test_unused_variable() {
+    let source = "
+function main(a: u32) -> u32 {
+    let b = a + 1;
+    const c = 2u32;
+    return a;
+}";
+    assert_eq!(lint(source), ["unused_variable", "unused_variable"]);
+}
+
+#[test]
+fn test_circuit_init_shorthand_uses_variable() {
+    let source = "
+circuit Point {
+    x: u32,
+}
+
+function main(x: u32) -> Point {
+    let y = x;
+    return Point { x: y };
+}";
+    assert!(lint(source).is_empty());
+}
+
+#[test]
+fn test_unused_import() {
+    let source = "
+import lib.(Point, unused_function as other);
+import core.unstable.blake2s.*;
+
+function main(p: Point) -> Point {
+    return p;
+}";
+    assert_eq!(lint(source), ["unused_import"]);
+}
+
+#[test]
+fn test_shadowed_variable() {
+    let source = "
+function main(a: u32) -> u32 {
+    let b = a;
+    for i in 0..2 {
+        let a = b + i;
+        b = a;
+    }
+    return b;
+}";
+    assert_eq!(lint(source), ["shadowed_variable"]);
+}
+
+#[test]
+fn test_never_reassigned() {
+    let source = "
+function main(a: u32) -> u32 {
+    let b = 2u32;
+    let c = 3u32;
+    c += a;
+    return b + c;
+}";
+    assert_eq!(lint(source), ["never_reassigned"]);
+}
+
+#[test]
+fn test_console_log_in_main() {
+    let source = "
+circuit Foo {
+    function main() {
+        console.log(\"in a circuit\");
+    }
+}
+
+function helper() {
+    console.log(\"in a helper\");
+}
+
+function main() {
+    console.log(\"in main\");
+}";
+    assert_eq!(lint(source), ["console_log_in_main"]);
+}
+
+#[test]
+fn test_non_snake_case_function() {
+    let source = "
+function doThing() {}
+
+function do_other_thing() {}
+
+function main() {}";
+    assert_eq!(lint(source), ["non_snake_case_function"]);
+    assert_eq!(to_snake_case("doThing"), "do_thing");
+    assert_eq!(to_snake_case("parseABCFoo"), "parse_abc_foo");
+    assert_eq!(to_snake_case("sha256Hash"), "sha256_hash");
+}
+
+#[test]
+fn test_constant_condition() {
+    let source = "
+function main(a: bool) -> u32 {
+    let b = a ? 1u32 : 2u32;
+    if 1u32 + 1u32 == 2u32 {
+        b = true ? 3u32 : 4u32;
+    }
+    return b;
+}";
+    assert_eq!(lint(source), ["constant_condition", "constant_condition"]);
+}
+
+#[test]
+fn test_allow_annotation() {
+    let source = "
+@allow(unused_variable)
+function main(a: u32) -> u32 {
+    let b = a;
+    return a;
+}
+
+@allow(warnings)
+function helper(a: u32) -> u32 {
+    let b = a;
+    return a;
+}";
+    assert!(lint(source).is_empty());
+}
+
+#[test]
+fn test_lint_levels() {
+    let source = "
+function doThing(a: u32) -> u32 {
+    let b = a;
+    console.log(\"{}\", a);
+    return a;
+}";
+    let levels = config(&[
+        ("unused_variable", LintLevel::Deny),
+        ("non_snake_case_function", LintLevel::Allow),
+    ]);
+    assert_eq!(lint_with(source, &levels), (vec![], vec!["unused_variable"]));
+
+    let levels = config(&[("warnings", LintLevel::Allow), ("unused_variable", LintLevel::Warn)]);
+    assert_eq!(lint_with(source, &levels), (vec!["unused_variable"], vec![]));
+}
+
+#[test]
+fn test_unknown_lint() {
+    let levels = [("unused_variables".to_string(), LintLevel::Deny)]
+        .into_iter()
+        .collect();
+    assert!(LintConfig::new(levels).is_err());
+}